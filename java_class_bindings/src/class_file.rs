@@ -0,0 +1,197 @@
+use std::{collections::BTreeMap, collections::BTreeSet, env, fs, path::PathBuf};
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Ident, LitStr, Result, Type};
+
+use classfile::ClassInfo;
+use java_constants::{FieldAccessFlags, MethodAccessFlags};
+
+use crate::parse::{Member, MemberKind};
+
+pub struct ClassFile {
+    pub path: String,
+    pub info: ClassInfo,
+}
+
+impl ClassFile {
+    // paths are relative to the invoking crate, like include_bytes!
+    pub fn load(path: &LitStr) -> Result<Self> {
+        let full_path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap_or_default()).join(path.value());
+
+        let data = fs::read(&full_path).map_err(|x| syn::Error::new(path.span(), format!("can't read {}: {x}", full_path.display())))?;
+        let info = ClassInfo::parse(&data).map_err(|x| syn::Error::new(path.span(), format!("invalid class file: {x:?}")))?;
+
+        Ok(Self {
+            path: full_path.to_string_lossy().into_owned(),
+            info,
+        })
+    }
+
+    // public members, with overloads numbered in class file order
+    pub fn members(&self, classes: &BTreeMap<String, Ident>, span: Span) -> Result<Vec<Member>> {
+        let mut used_names = BTreeSet::new();
+        let mut members = Vec::new();
+
+        for method in &self.info.methods {
+            let flags = method.access_flags;
+            if !flags.contains(MethodAccessFlags::PUBLIC) || flags.intersects(MethodAccessFlags::SYNTHETIC | MethodAccessFlags::BRIDGE) {
+                continue;
+            }
+
+            let kind = match method.name.as_str() {
+                "<clinit>" => continue,
+                "<init>" => MemberKind::Constructor,
+                _ => MemberKind::Method {
+                    is_static: flags.contains(MethodAccessFlags::STATIC),
+                },
+            };
+            let rust_name = match kind {
+                MemberKind::Constructor => "new".into(),
+                _ => snake_case(&method.name),
+            };
+
+            let Some((parameter_types, return_type)) = split_method_descriptor(&method.descriptor) else {
+                return Err(syn::Error::new(span, format!("invalid descriptor {}", method.descriptor)));
+            };
+
+            let parameters = parameter_types
+                .iter()
+                .enumerate()
+                .map(|(i, x)| Ok((format_ident!("arg{}", i), rust_type(x, classes, span)?)))
+                .collect::<Result<Vec<_>>>()?;
+            let r#type = match kind {
+                MemberKind::Constructor => syn::parse_quote!(Self),
+                _ => rust_type(return_type, classes, span)?,
+            };
+
+            members.push(Member {
+                attrs: Vec::new(),
+                ident: unique_ident(&rust_name, &mut used_names, span),
+                java_name: method.name.to_string(),
+                kind,
+                parameters,
+                r#type,
+            });
+        }
+
+        for field in &self.info.fields {
+            let flags = field.access_flags;
+            if !flags.contains(FieldAccessFlags::PUBLIC) || flags.contains(FieldAccessFlags::SYNTHETIC) {
+                continue;
+            }
+
+            members.push(Member {
+                attrs: Vec::new(),
+                ident: unique_ident(&snake_case(&field.name), &mut used_names, span),
+                java_name: field.name.to_string(),
+                kind: MemberKind::Field {
+                    is_static: flags.contains(FieldAccessFlags::STATIC),
+                    writable: !flags.contains(FieldAccessFlags::FINAL),
+                },
+                parameters: Vec::new(),
+                r#type: rust_type(&field.descriptor, classes, span)?,
+            });
+        }
+
+        Ok(members)
+    }
+}
+
+// descriptors are parsed here rather than with `jvm::JavaType`, so the proc macro doesn't build the whole jvm crate
+fn rust_type(descriptor: &str, classes: &BTreeMap<String, Ident>, span: Span) -> Result<Type> {
+    Ok(match descriptor.as_bytes().first() {
+        Some(b'L' | b'[') => {
+            let element = element_type(descriptor, classes, span)?;
+            syn::parse_quote!(::jvm::ClassInstanceRef<#element>)
+        }
+        _ => syn::parse2(element_type(descriptor, classes, span)?)?,
+    })
+}
+
+// the rust type naming a java type, as used in `ClassInstanceRef<T>` and `Array<T>`
+fn element_type(descriptor: &str, classes: &BTreeMap<String, Ident>, span: Span) -> Result<TokenStream> {
+    Ok(match descriptor.as_bytes() {
+        b"V" => quote!(()),
+        b"Z" => quote!(bool),
+        b"B" => quote!(i8),
+        b"C" => quote!(::jvm::JavaChar),
+        b"S" => quote!(i16),
+        b"I" => quote!(i32),
+        b"J" => quote!(i64),
+        b"F" => quote!(f32),
+        b"D" => quote!(f64),
+        [b'[', ..] => {
+            let element = element_type(&descriptor[1..], classes, span)?;
+            quote!(::jvm::Array<#element>)
+        }
+        [b'L', .., b';'] => {
+            let name = &descriptor[1..descriptor.len() - 1];
+            let Some(ident) = classes.get(name) else {
+                return Err(syn::Error::new(span, format!("{name} isn't bound, add `class Name = \"{name}\";`")));
+            };
+            quote!(#ident)
+        }
+        _ => return Err(syn::Error::new(span, format!("invalid descriptor {descriptor}"))),
+    })
+}
+
+// (ILjava/lang/String;)V -> (["I", "Ljava/lang/String;"], "V")
+fn split_method_descriptor(descriptor: &str) -> Option<(Vec<&str>, &str)> {
+    let (parameters, return_type) = descriptor.strip_prefix('(')?.split_once(')')?;
+
+    let mut rest = parameters;
+    let mut parameter_types = Vec::new();
+    while !rest.is_empty() {
+        let (r#type, remaining) = rest.split_at(field_type_length(rest)?);
+        parameter_types.push(r#type);
+        rest = remaining;
+    }
+
+    Some((parameter_types, return_type))
+}
+
+fn field_type_length(descriptor: &str) -> Option<usize> {
+    match descriptor.as_bytes().first()? {
+        b'L' => Some(descriptor.find(';')? + 1),
+        b'[' => Some(field_type_length(&descriptor[1..])? + 1),
+        _ => Some(1),
+    }
+}
+
+fn unique_ident(name: &str, used_names: &mut BTreeSet<String>, span: Span) -> Ident {
+    let mut unique = name.to_string();
+    let mut index = 1;
+    while used_names.contains(&unique) {
+        unique = format!("{name}_{index}");
+        index += 1;
+    }
+    used_names.insert(unique.clone());
+
+    if syn::parse_str::<Ident>(&unique).is_ok() {
+        Ident::new(&unique, span)
+    } else if matches!(unique.as_str(), "self" | "Self" | "super" | "crate") {
+        format_ident!("{}_", unique, span = span)
+    } else {
+        Ident::new_raw(&unique, span)
+    }
+}
+
+// getURL -> get_url, MAX_VALUE -> max_value
+fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+
+    let mut result = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|x| x.is_lowercase());
+            if previous.is_lowercase() || previous.is_ascii_digit() || (previous.is_uppercase() && next_is_lower) {
+                result.push('_');
+            }
+        }
+        result.extend(c.to_lowercase());
+    }
+
+    result
+}
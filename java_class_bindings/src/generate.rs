@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Result, Visibility};
+
+use crate::{
+    class_file::ClassFile,
+    parse::{Bindings, ClassSource, Member, MemberKind},
+};
+
+pub fn generate(bindings: Bindings) -> Result<TokenStream> {
+    let mut class_files = BTreeMap::new();
+    let mut classes = BTreeMap::new();
+    for class in &bindings.classes {
+        let name = match &class.source {
+            ClassSource::Declared { name, .. } => name.value(),
+            ClassSource::ClassFile(path) => {
+                let class_file = ClassFile::load(path)?;
+                let name = class_file.info.this_class.to_string();
+                class_files.insert(class.ident.clone(), class_file);
+
+                name
+            }
+        };
+        classes.insert(name, class.ident.clone());
+    }
+
+    let mut result = TokenStream::new();
+    for class in bindings.classes {
+        let (name, members, include) = match class.source {
+            ClassSource::Declared { name, members } => (name.value(), members, None),
+            ClassSource::ClassFile(path) => {
+                let class_file = &class_files[&class.ident];
+                let members = class_file.members(&classes, path.span())?;
+
+                // so the bindings are regenerated when the class file changes
+                let include_path = &class_file.path;
+                let include = quote!(
+                    const _: &[u8] = include_bytes!(#include_path);
+                );
+
+                (class_file.info.this_class.to_string(), members, Some(include))
+            }
+        };
+
+        let attrs = &class.attrs;
+        let vis = &class.vis;
+        let ident = &class.ident;
+        let members = members.iter().map(|x| member(vis, x));
+
+        result.extend(quote! {
+            #(#attrs)*
+            #vis struct #ident;
+
+            impl ::jvm::JavaClassName for #ident {
+                const NAME: &'static str = #name;
+            }
+
+            impl #ident {
+                #(#members)*
+            }
+
+            #include
+        });
+    }
+
+    Ok(result)
+}
+
+fn member(vis: &Visibility, member: &Member) -> TokenStream {
+    let Member {
+        attrs,
+        ident,
+        java_name,
+        kind,
+        parameters,
+        r#type,
+    } = member;
+
+    let names = parameters.iter().map(|(name, _)| name).collect::<Vec<_>>();
+    let types = parameters.iter().map(|(_, r#type)| r#type).collect::<Vec<_>>();
+    // converted like method protos convert theirs, so String, Option and Vec parameters work the same way
+    let args = if names.is_empty() {
+        quote!(())
+    } else {
+        quote!([#(<#types as ::java_class_proto::TypeConverter<#types>>::from_rust(jvm, #names).await?),*])
+    };
+    let parameter_list = crate::parameter_list(types.iter().copied());
+    let to_rust = quote!(<#r#type as ::java_class_proto::TypeConverter<#r#type>>::to_rust);
+    let from_rust = quote!(<#r#type as ::java_class_proto::TypeConverter<#r#type>>::from_rust);
+    let class_name = quote!(<Self as ::jvm::JavaClassName>::NAME);
+
+    match kind {
+        MemberKind::Constructor => quote! {
+            #(#attrs)*
+            #vis async fn #ident(jvm: &::jvm::Jvm, #(#names: #types),*) -> ::jvm::Result<::jvm::ClassInstanceRef<Self>> {
+                let descriptor = <::jvm::MethodDescriptor<#parameter_list, ()>>::DESCRIPTOR;
+                Ok(jvm.new_class(#class_name, descriptor, #args).await?.into())
+            }
+        },
+        MemberKind::Method { is_static } => {
+            let descriptor = quote! {
+                let descriptor = <::jvm::MethodDescriptor<#parameter_list, #r#type>>::DESCRIPTOR;
+            };
+
+            if *is_static {
+                quote! {
+                    #(#attrs)*
+                    #vis async fn #ident(jvm: &::jvm::Jvm, #(#names: #types),*) -> ::jvm::Result<#r#type> {
+                        #descriptor
+                        let result = jvm.invoke_static(#class_name, #java_name, descriptor, #args).await?;
+                        #to_rust(jvm, result).await
+                    }
+                }
+            } else {
+                quote! {
+                    #(#attrs)*
+                    #vis async fn #ident(jvm: &::jvm::Jvm, this: &::jvm::ClassInstanceRef<Self>, #(#names: #types),*) -> ::jvm::Result<#r#type> {
+                        #descriptor
+                        let result = jvm.invoke_virtual(this, #java_name, descriptor, #args).await?;
+                        #to_rust(jvm, result).await
+                    }
+                }
+            }
+        }
+        MemberKind::Field { is_static, writable } => {
+            let setter = format_ident!("set_{}", ident);
+            let descriptor = quote!(let descriptor = <#r#type as ::jvm::JavaDescriptor>::DESCRIPTOR;);
+
+            let (getter, setter) = if *is_static {
+                (
+                    quote! {
+                        #(#attrs)*
+                        #vis async fn #ident(jvm: &::jvm::Jvm) -> ::jvm::Result<#r#type> {
+                            #descriptor
+                            let value = jvm.get_static_field(#class_name, #java_name, descriptor).await?;
+                            #to_rust(jvm, value).await
+                        }
+                    },
+                    quote! {
+                        #vis async fn #setter(jvm: &::jvm::Jvm, value: #r#type) -> ::jvm::Result<()> {
+                            #descriptor
+                            let value = #from_rust(jvm, value).await?;
+                            jvm.put_static_field(#class_name, #java_name, descriptor, value).await
+                        }
+                    },
+                )
+            } else {
+                (
+                    quote! {
+                        #(#attrs)*
+                        #vis async fn #ident(jvm: &::jvm::Jvm, this: &::jvm::ClassInstanceRef<Self>) -> ::jvm::Result<#r#type> {
+                            #descriptor
+                            let value = jvm.get_field(this, #java_name, descriptor).await?;
+                            #to_rust(jvm, value).await
+                        }
+                    },
+                    quote! {
+                        #vis async fn #setter(jvm: &::jvm::Jvm, this: &mut ::jvm::ClassInstanceRef<Self>, value: #r#type) -> ::jvm::Result<()> {
+                            #descriptor
+                            let value = #from_rust(jvm, value).await?;
+                            jvm.put_field(this, #java_name, descriptor, value).await
+                        }
+                    },
+                )
+            };
+
+            if *writable { quote!(#getter #setter) } else { getter }
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Expr, FnArg, GenericArgument, Ident, ImplItem, ImplItemFn, ItemImpl, LitStr, Pat, PathArguments, Result, ReturnType, Token, Type,
+    ext::IdentExt,
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+mod kw {
+    syn::custom_keyword!(extends);
+    syn::custom_keyword!(implements);
+    syn::custom_keyword!(flags);
+    syn::custom_keyword!(fields);
+    syn::custom_keyword!(name);
+}
+
+pub struct ClassArgs {
+    name: LitStr,
+    extends: Option<LitStr>,
+    implements: Vec<LitStr>,
+    flags: Option<Expr>,
+    fields: Vec<FieldArg>,
+}
+
+struct FieldArg {
+    ident: Ident,
+    r#type: Type,
+    flags: Option<Expr>,
+}
+
+#[derive(Default)]
+struct MethodArgs {
+    name: Option<LitStr>,
+    flags: Option<Expr>,
+}
+
+impl Parse for ClassArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut result = Self {
+            name: input.parse()?,
+            extends: None,
+            implements: Vec::new(),
+            flags: None,
+            fields: Vec::new(),
+        };
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let lookahead = input.lookahead1();
+            if lookahead.peek(kw::extends) {
+                input.parse::<kw::extends>()?;
+                input.parse::<Token![=]>()?;
+                result.extends = Some(input.parse()?);
+            } else if lookahead.peek(kw::implements) {
+                input.parse::<kw::implements>()?;
+                let content;
+                parenthesized!(content in input);
+                result.implements = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?.into_iter().collect();
+            } else if lookahead.peek(kw::flags) {
+                input.parse::<kw::flags>()?;
+                input.parse::<Token![=]>()?;
+                result.flags = Some(input.parse()?);
+            } else if lookahead.peek(kw::fields) {
+                input.parse::<kw::fields>()?;
+                let content;
+                parenthesized!(content in input);
+                result.fields = Punctuated::<FieldArg, Token![,]>::parse_terminated(&content)?.into_iter().collect();
+            } else {
+                return Err(lookahead.error());
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl Parse for FieldArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident = input.call(Ident::parse_any)?;
+        input.parse::<Token![:]>()?;
+        let r#type = input.parse()?;
+        let flags = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(Self { ident, r#type, flags })
+    }
+}
+
+impl Parse for MethodArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut result = Self::default();
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(kw::name) {
+                input.parse::<kw::name>()?;
+                input.parse::<Token![=]>()?;
+                result.name = Some(input.parse()?);
+            } else if lookahead.peek(kw::flags) {
+                input.parse::<kw::flags>()?;
+                input.parse::<Token![=]>()?;
+                result.flags = Some(input.parse()?);
+            } else {
+                return Err(lookahead.error());
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+pub fn java_class(args: ClassArgs, mut item: ItemImpl) -> Result<TokenStream> {
+    let mut methods = Vec::new();
+    let mut context = None;
+
+    for impl_item in &mut item.items {
+        let ImplItem::Fn(function) = impl_item else {
+            continue;
+        };
+        let Some(index) = function.attrs.iter().position(|x| x.path().is_ident("java_method")) else {
+            continue;
+        };
+
+        let attr = function.attrs.remove(index);
+        let method_args = if matches!(attr.meta, syn::Meta::Path(_)) {
+            MethodArgs::default()
+        } else {
+            attr.parse_args()?
+        };
+
+        let (method, method_context) = method_proto(function, method_args)?;
+        methods.push(method);
+        context.get_or_insert(method_context);
+    }
+
+    let self_ty = &item.self_ty;
+    let name = &args.name;
+    let class_name = quote! {
+        impl ::jvm::JavaClassName for #self_ty {
+            const NAME: &'static str = #name;
+        }
+    };
+
+    // a hand-written proto only takes the name, through `<Self as JavaClassName>::NAME`
+    let Some(context) = context else {
+        if args.extends.is_some() || !args.implements.is_empty() || args.flags.is_some() || !args.fields.is_empty() {
+            return Err(syn::Error::new_spanned(
+                self_ty,
+                "#[java_class] without a #[java_method] only takes the class name",
+            ));
+        }
+
+        return Ok(quote! {
+            #item
+
+            #class_name
+        });
+    };
+
+    let parent_class = match &args.extends {
+        Some(x) => quote!(Some(#x)),
+        None if name.value() == "java/lang/Object" => quote!(None),
+        None => quote!(Some("java/lang/Object")),
+    };
+    let interfaces = &args.implements;
+    let class_flags = flags_or_default(args.flags.as_ref());
+    let fields = args.fields.iter().map(|x| {
+        let field_name = x.ident.to_string();
+        let r#type = &x.r#type;
+        let flags = flags_or_default(x.flags.as_ref());
+
+        quote!(::java_class_proto::JavaFieldProto::new(#field_name, <#r#type as ::jvm::JavaDescriptor>::DESCRIPTOR, #flags))
+    });
+
+    Ok(quote! {
+        #item
+
+        impl #self_ty {
+            pub fn as_proto() -> ::java_class_proto::JavaClassProto<#context> {
+                ::java_class_proto::JavaClassProto {
+                    name: #name,
+                    parent_class: #parent_class,
+                    interfaces: ::core::iter::IntoIterator::into_iter([#(#interfaces),*]).collect(),
+                    methods: ::core::iter::IntoIterator::into_iter([#(#methods),*]).collect(),
+                    fields: ::core::iter::IntoIterator::into_iter([#(#fields),*]).collect(),
+                    access_flags: #class_flags,
+                }
+            }
+        }
+
+        #class_name
+    })
+}
+
+// the body takes (jvm, context, [this,] args..) and returns Result<T>
+fn method_proto(function: &ImplItemFn, args: MethodArgs) -> Result<(TokenStream, Type)> {
+    let signature = &function.sig;
+    let ident = &signature.ident;
+
+    let mut inputs = signature.inputs.iter().map(|x| match x {
+        FnArg::Typed(x) => Ok(x),
+        FnArg::Receiver(x) => Err(syn::Error::new_spanned(
+            x,
+            "java methods are associated functions taking `jvm` and `context`",
+        )),
+    });
+
+    let error = || syn::Error::new_spanned(signature, "expected `(jvm: &Jvm, context: &mut C, ..)`");
+    inputs.next().ok_or_else(error)??;
+    let context = match &*inputs.next().ok_or_else(error)??.ty {
+        Type::Reference(x) if x.mutability.is_some() => (*x.elem).clone(),
+        _ => return Err(error()),
+    };
+
+    let mut parameters = inputs.collect::<Result<Vec<_>>>()?;
+    let is_static = !parameters.first().is_some_and(|x| matches!(&*x.pat, Pat::Ident(x) if x.ident == "this"));
+    if !is_static {
+        parameters.remove(0);
+    }
+
+    let return_type = result_type(&signature.output)?;
+    let parameter_types = crate::parameter_list(parameters.iter().map(|x| &*x.ty));
+
+    let name = match args.name {
+        Some(x) => x.value(),
+        None => match ident.to_string().as_str() {
+            "init" => "<init>".into(),
+            "clinit" => "<clinit>".into(),
+            x => crate::camel_case(x),
+        },
+    };
+    let flags = match (args.flags, is_static) {
+        (Some(x), true) => quote!((#x) | ::java_constants::MethodAccessFlags::STATIC),
+        (None, true) => quote!(::java_constants::MethodAccessFlags::STATIC),
+        (x, false) => flags_or_default(x.as_ref()),
+    };
+
+    Ok((
+        quote! {
+            ::java_class_proto::JavaMethodProto::new(
+                #name,
+                <::jvm::MethodDescriptor<#parameter_types, #return_type>>::DESCRIPTOR,
+                Self::#ident,
+                #flags,
+            )
+        },
+        context,
+    ))
+}
+
+// T of Result<T>
+fn result_type(output: &ReturnType) -> Result<&Type> {
+    if let ReturnType::Type(_, r#type) = output
+        && let Type::Path(path) = &**r#type
+        && let Some(segment) = path.path.segments.last()
+        && segment.ident == "Result"
+        && let PathArguments::AngleBracketed(arguments) = &segment.arguments
+        && let Some(GenericArgument::Type(x)) = arguments.args.first()
+    {
+        return Ok(x);
+    }
+
+    Err(syn::Error::new_spanned(output, "java methods return `Result<T>`"))
+}
+
+fn flags_or_default(flags: Option<&Expr>) -> TokenStream {
+    match flags {
+        Some(x) => quote!(#x),
+        None => quote!(::core::default::Default::default()),
+    }
+}
@@ -0,0 +1,116 @@
+//! Typed rust bindings for calling java classes through [`jvm::Jvm`].
+//!
+//! ```ignore
+//! java_bindings! {
+//!     pub class JavaString = "java/lang/String";
+//!     pub class PrintStream = "java/io/PrintStream";
+//!
+//!     pub class Integer = "java/lang/Integer" {
+//!         fn new(value: i32) -> Self;
+//!         static fn value_of(value: i32) -> ClassInstanceRef<Self>;
+//!         fn int_value(&self) -> i32;
+//!         #[java_name = "MAX_VALUE"]
+//!         static field max_value: i32;
+//!     }
+//!
+//!     // every public member of the class file; referenced classes have to be bound in the same block
+//!     pub class Hello from "test_data/Hello.class";
+//! }
+//!
+//! let value = Integer::value_of(&jvm, 42).await?;
+//! assert_eq!(Integer::int_value(&jvm, &value).await?, 42);
+//! ```
+//!
+//! Descriptors are derived from the rust types through [`jvm::JavaDescriptor`], so a type without a java
+//! equivalent is a compile error rather than a `NoSuchMethodError` at runtime.
+//! Arguments and results are converted with `java_class_proto::TypeConverter`, as in method protos, so `String`,
+//! `Option` and `Vec` work as well. The generated code refers to `java_class_proto`, which re-exports this macro.
+//! Java names default to the lowerCamelCase form of the rust name, `#[java_name = "..."]` overrides it.
+
+mod class_file;
+mod generate;
+mod java_class;
+mod parse;
+
+use proc_macro::TokenStream;
+use syn::{ItemImpl, Type, parse_macro_input};
+
+use self::{java_class::ClassArgs, parse::Bindings};
+
+#[proc_macro]
+pub fn java_bindings(input: TokenStream) -> TokenStream {
+    let bindings = parse_macro_input!(input as Bindings);
+
+    match generate::generate(bindings) {
+        Ok(x) => x.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Generates `as_proto()` for an impl block of java method bodies, with descriptors derived from the rust signatures.
+///
+/// ```ignore
+/// pub struct Counter;
+///
+/// #[java_class("com/example/Counter", flags = ClassAccessFlags::PUBLIC, fields(count: i32))]
+/// impl Counter {
+///     #[java_method(flags = MethodAccessFlags::PUBLIC)]
+///     async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> { .. }
+///
+///     // static, as it doesn't take `this`
+///     #[java_method(name = "of")]
+///     async fn of(jvm: &Jvm, _: &mut RuntimeContext, count: i32) -> Result<ClassInstanceRef<Self>> { .. }
+/// }
+/// ```
+///
+/// Method names default to `<init>`/`<clinit>` for `init`/`clinit` and lowerCamelCase otherwise.
+/// Parent class defaults to `java/lang/Object`. Abstract methods and interfaces still need a hand-written proto.
+///
+/// Without any `#[java_method]`, only the [`jvm::JavaClassName`] impl is generated, for classes with a hand-written
+/// proto that appear in derived descriptors. The proto then takes its name from `<Self as JavaClassName>::NAME`.
+#[proc_macro_attribute]
+pub fn java_class(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as ClassArgs);
+    let item = parse_macro_input!(item as ItemImpl);
+
+    match java_class::java_class(args, item) {
+        Ok(x) => x.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Marks a method of a `#[java_class]` impl block, which consumes it.
+#[proc_macro_attribute]
+pub fn java_method(_: TokenStream, item: TokenStream) -> TokenStream {
+    let mut result = TokenStream::from(
+        syn::Error::new(proc_macro2::Span::call_site(), "#[java_method] is only valid inside a #[java_class] impl").to_compile_error(),
+    );
+    result.extend(item);
+
+    result
+}
+
+// (A, (B, ())), the `jvm::JavaParameters` form of a parameter list
+fn parameter_list<'a>(types: impl DoubleEndedIterator<Item = &'a Type>) -> proc_macro2::TokenStream {
+    types.rev().fold(quote::quote!(()), |rest, r#type| quote::quote!((#r#type, #rest)))
+}
+
+// get_message -> getMessage
+fn camel_case(name: &str) -> String {
+    let name = name.strip_prefix("r#").unwrap_or(name);
+
+    let mut result = String::new();
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = !result.is_empty();
+        } else if upper {
+            result.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
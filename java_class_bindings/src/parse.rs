@@ -0,0 +1,189 @@
+use syn::{
+    Attribute, Expr, ExprLit, Ident, Lit, LitStr, Meta, Result, Token, Type, Visibility, braced, parenthesized,
+    parse::{Parse, ParseStream},
+};
+
+mod kw {
+    syn::custom_keyword!(class);
+    syn::custom_keyword!(field);
+    syn::custom_keyword!(from);
+}
+
+pub struct Bindings {
+    pub classes: Vec<ClassDecl>,
+}
+
+pub struct ClassDecl {
+    pub attrs: Vec<Attribute>,
+    pub vis: Visibility,
+    pub ident: Ident,
+    pub source: ClassSource,
+}
+
+pub enum ClassSource {
+    Declared { name: LitStr, members: Vec<Member> },
+    ClassFile(LitStr),
+}
+
+pub enum MemberKind {
+    Constructor,
+    Method { is_static: bool },
+    Field { is_static: bool, writable: bool },
+}
+
+pub struct Member {
+    pub attrs: Vec<Attribute>,
+    pub ident: Ident,
+    pub java_name: String,
+    pub kind: MemberKind,
+    pub parameters: Vec<(Ident, Type)>,
+    // return type for methods, value type for fields
+    pub r#type: Type,
+}
+
+impl Parse for Bindings {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut classes = Vec::new();
+        while !input.is_empty() {
+            classes.push(input.parse()?);
+        }
+
+        Ok(Self { classes })
+    }
+}
+
+impl Parse for ClassDecl {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<kw::class>()?;
+        let ident = input.parse()?;
+
+        let source = if input.peek(kw::from) {
+            input.parse::<kw::from>()?;
+            let path = input.parse()?;
+            input.parse::<Token![;]>()?;
+
+            ClassSource::ClassFile(path)
+        } else {
+            input.parse::<Token![=]>()?;
+            let name = input.parse()?;
+
+            let mut members = Vec::new();
+            if input.peek(syn::token::Brace) {
+                let content;
+                braced!(content in input);
+                while !content.is_empty() {
+                    members.push(content.parse()?);
+                }
+            } else {
+                input.parse::<Token![;]>()?;
+            }
+
+            ClassSource::Declared { name, members }
+        };
+
+        Ok(Self { attrs, vis, ident, source })
+    }
+}
+
+impl Parse for Member {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut attrs = input.call(Attribute::parse_outer)?;
+        let java_name = take_java_name(&mut attrs)?;
+        let is_static = input.parse::<Option<Token![static]>>()?.is_some();
+
+        if input.peek(kw::field) {
+            input.parse::<kw::field>()?;
+            let ident: Ident = input.parse()?;
+            input.parse::<Token![:]>()?;
+            let r#type = input.parse()?;
+            input.parse::<Token![;]>()?;
+
+            return Ok(Self {
+                attrs,
+                java_name: java_name.unwrap_or_else(|| crate::camel_case(&ident.to_string())),
+                ident,
+                kind: MemberKind::Field { is_static, writable: true },
+                parameters: Vec::new(),
+                r#type,
+            });
+        }
+
+        input.parse::<Token![fn]>()?;
+        let ident: Ident = input.parse()?;
+
+        let content;
+        parenthesized!(content in input);
+
+        let has_receiver = content.peek(Token![&]);
+        if has_receiver {
+            content.parse::<Token![&]>()?;
+            content.parse::<Token![self]>()?;
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+
+        let mut parameters = Vec::new();
+        while !content.is_empty() {
+            let name = content.parse()?;
+            content.parse::<Token![:]>()?;
+            parameters.push((name, content.parse()?));
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+
+        let r#type = if input.peek(Token![->]) {
+            input.parse::<Token![->]>()?;
+            input.parse()?
+        } else {
+            syn::parse_quote!(())
+        };
+        input.parse::<Token![;]>()?;
+
+        let is_constructor = matches!(&r#type, Type::Path(x) if x.qself.is_none() && x.path.is_ident("Self"));
+        let kind = match (is_static, has_receiver, is_constructor) {
+            (true, true, _) => return Err(syn::Error::new(ident.span(), "static methods can't take `&self`")),
+            (true, false, _) => MemberKind::Method { is_static: true },
+            (false, true, _) => MemberKind::Method { is_static: false },
+            (false, false, true) => MemberKind::Constructor,
+            (false, false, false) => {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "expected `&self`, `static fn` or a constructor returning `Self`",
+                ));
+            }
+        };
+
+        let java_name = match kind {
+            MemberKind::Constructor => "<init>".into(),
+            _ => java_name.unwrap_or_else(|| crate::camel_case(&ident.to_string())),
+        };
+
+        Ok(Self {
+            attrs,
+            ident,
+            java_name,
+            kind,
+            parameters,
+            r#type,
+        })
+    }
+}
+
+fn take_java_name(attrs: &mut Vec<Attribute>) -> Result<Option<String>> {
+    let Some(index) = attrs.iter().position(|x| x.path().is_ident("java_name")) else {
+        return Ok(None);
+    };
+
+    let attr = attrs.remove(index);
+    if let Meta::NameValue(x) = &attr.meta
+        && let Expr::Lit(ExprLit { lit: Lit::Str(name), .. }) = &x.value
+    {
+        return Ok(Some(name.value()));
+    }
+
+    Err(syn::Error::new_spanned(attr, "expected `#[java_name = \"...\"]`"))
+}
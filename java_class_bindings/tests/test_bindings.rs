@@ -0,0 +1,87 @@
+use jvm::{Array, ClassInstanceRef, Result, runtime::JavaLangString};
+use jvm_rust::ClassDefinitionImpl;
+
+use java_class_bindings::java_bindings;
+
+use test_utils::test_jvm;
+
+java_bindings! {
+    pub class JavaString = "java/lang/String" {
+        fn length(&self) -> i32;
+        fn char_at(&self, index: i32) -> u16;
+        fn get_bytes(&self) -> ClassInstanceRef<Array<i8>>;
+    }
+
+    pub class StringBuffer = "java/lang/StringBuffer" {
+        fn new() -> Self;
+        #[java_name = "append"]
+        fn append_int(&self, value: i32) -> ClassInstanceRef<Self>;
+        #[java_name = "append"]
+        fn append_string(&self, value: ClassInstanceRef<JavaString>) -> ClassInstanceRef<Self>;
+        #[java_name = "append"]
+        fn append_rust_string(&self, value: String) -> ClassInstanceRef<Self>;
+        fn to_string(&self) -> ClassInstanceRef<JavaString>;
+        #[java_name = "toString"]
+        fn to_rust_string(&self) -> String;
+    }
+
+    pub class Integer = "java/lang/Integer" {
+        fn new(value: i32) -> Self;
+        static fn value_of(value: i32) -> ClassInstanceRef<Self>;
+        fn int_value(&self) -> i32;
+        #[java_name = "MAX_VALUE"]
+        static field max_value: i32;
+    }
+
+    pub class Field from "../test_data/Field.class";
+}
+
+#[tokio::test]
+async fn test_declared_bindings() -> Result<()> {
+    let jvm = test_jvm().await?;
+
+    let value = Integer::value_of(&jvm, 42).await?;
+    assert_eq!(Integer::int_value(&jvm, &value).await?, 42);
+    let value = Integer::new(&jvm, 7).await?;
+    assert_eq!(Integer::int_value(&jvm, &value).await?, 7);
+    assert_eq!(Integer::max_value(&jvm).await?, i32::MAX);
+
+    let string: ClassInstanceRef<JavaString> = JavaLangString::from_rust_string(&jvm, "abc").await?.into();
+    assert_eq!(JavaString::length(&jvm, &string).await?, 3);
+    assert_eq!(JavaString::char_at(&jvm, &string, 1).await?, 'b' as u16);
+    let bytes = JavaString::get_bytes(&jvm, &string).await?;
+    assert_eq!(jvm.array_length(&bytes).await?, 3);
+
+    let buffer = StringBuffer::new(&jvm).await?;
+    let buffer = StringBuffer::append_string(&jvm, &buffer, string).await?;
+    let buffer = StringBuffer::append_int(&jvm, &buffer, 12).await?;
+    let result = StringBuffer::to_string(&jvm, &buffer).await?;
+    assert_eq!(JavaLangString::to_rust_string(&jvm, &result).await?, "abc12");
+
+    let buffer = StringBuffer::append_rust_string(&jvm, &buffer, "def".into()).await?;
+    assert_eq!(StringBuffer::to_rust_string(&jvm, &buffer).await?, "abc12def");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_class_file_bindings() -> Result<()> {
+    let jvm = test_jvm().await?;
+
+    let class = ClassDefinitionImpl::from_classfile(include_bytes!("../../test_data/Field.class")).unwrap();
+    jvm.register_class(Box::new(class), None).await?;
+
+    Field::set_static_field(&jvm, 1234).await?;
+    assert_eq!(Field::static_field(&jvm).await?, 1234);
+
+    let mut field: ClassInstanceRef<Field> = jvm.new_class("Field", "()V", ()).await?.into();
+    Field::set_long_field(&jvm, &mut field, 1 << 40).await?;
+    assert_eq!(Field::long_field(&jvm, &field).await?, 1 << 40);
+
+    let string = JavaLangString::from_rust_string(&jvm, "test").await?.into();
+    Field::set_string_field(&jvm, &mut field, string).await?;
+    let string = Field::string_field(&jvm, &field).await?;
+    assert_eq!(JavaLangString::to_rust_string(&jvm, &string).await?, "test");
+
+    Ok(())
+}
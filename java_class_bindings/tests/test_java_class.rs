@@ -0,0 +1,224 @@
+use std::{collections::BTreeMap, string::String as RustString};
+
+use java_class_proto::{JavaClassProto, JavaFieldProto, java_class};
+use java_constants::{FieldAccessFlags, MethodAccessFlags};
+use java_runtime::{
+    RuntimeContext,
+    classes::java::lang::{Object, String},
+};
+use jvm::{Array, ClassInstance, ClassInstanceRef, JavaClassName, JavaDescriptor, JavaError, Jvm, Result, runtime::JavaLangString};
+use jvm_rust::ClassDefinitionImpl;
+
+use test_utils::{TestRuntime, create_test_jvm};
+
+struct Counter;
+
+#[java_class(
+    "Counter",
+    fields(count: i32, label: ClassInstanceRef<String>, created: i32 = FieldAccessFlags::STATIC)
+)]
+impl Counter {
+    #[java_method(flags = MethodAccessFlags::PUBLIC)]
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>, label: ClassInstanceRef<String>) -> Result<()> {
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+        jvm.put_field(&mut this, "label", "Ljava/lang/String;", label).await?;
+
+        let created: i32 = jvm.get_static_field("Counter", "created", "I").await?;
+        jvm.put_static_field("Counter", "created", "I", created + 1).await
+    }
+
+    #[java_method(flags = MethodAccessFlags::PUBLIC)]
+    async fn add(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>, value: i32) -> Result<i32> {
+        let count: i32 = jvm.get_field(&this, "count", "I").await?;
+        jvm.put_field(&mut this, "count", "I", count + value).await?;
+
+        Ok(count + value)
+    }
+
+    #[java_method(name = "sumBytes")]
+    async fn sum(jvm: &Jvm, _: &mut RuntimeContext, values: ClassInstanceRef<Array<i8>>) -> Result<i64> {
+        let length = jvm.array_length(&values).await?;
+        let values: Vec<i8> = jvm.load_array(&values, 0, length).await?;
+
+        Ok(values.into_iter().map(|x| x as i64).sum())
+    }
+}
+
+struct Strings;
+
+#[java_class("Strings")]
+impl Strings {
+    #[java_method]
+    async fn repeat(_: &Jvm, _: &mut RuntimeContext, value: RustString, count: Option<i32>) -> Result<RustString> {
+        Ok(value.repeat(count.unwrap_or(1) as usize))
+    }
+
+    #[java_method]
+    async fn upper(_: &Jvm, _: &mut RuntimeContext, value: Option<RustString>) -> Result<Option<RustString>> {
+        Ok(value.map(|x| x.to_uppercase()))
+    }
+
+    #[java_method]
+    async fn reverse(_: &Jvm, _: &mut RuntimeContext, mut values: Vec<u8>) -> Result<Vec<u8>> {
+        values.reverse();
+
+        Ok(values)
+    }
+
+    #[java_method]
+    async fn last(_: &Jvm, _: &mut RuntimeContext, values: Vec<ClassInstanceRef<Object>>) -> Result<Option<ClassInstanceRef<Object>>> {
+        Ok(values.into_iter().last())
+    }
+}
+
+// hand-written proto, named through the attribute
+struct Named;
+
+#[java_class("com/example/Named")]
+impl Named {
+    fn as_proto() -> JavaClassProto<RuntimeContext> {
+        JavaClassProto {
+            name: <Self as JavaClassName>::NAME,
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec![],
+            methods: vec![],
+            fields: vec![JavaFieldProto::new(
+                "next",
+                <ClassInstanceRef<Self> as JavaDescriptor>::DESCRIPTOR,
+                Default::default(),
+            )],
+            access_flags: Default::default(),
+        }
+    }
+}
+
+#[test]
+fn test_java_class_name_only() {
+    let proto = Named::as_proto();
+    assert_eq!(proto.name, "com/example/Named");
+    assert_eq!(proto.fields[0].descriptor, "Lcom/example/Named;");
+
+    // runtime classes with hand-written protos
+    assert_eq!(<ClassInstanceRef<String> as JavaDescriptor>::DESCRIPTOR, "Ljava/lang/String;");
+    assert_eq!(<ClassInstanceRef<Object> as JavaDescriptor>::DESCRIPTOR, "Ljava/lang/Object;");
+}
+
+#[test]
+fn test_java_class_proto() {
+    let proto = Counter::as_proto();
+    assert_eq!(proto.name, "Counter");
+    assert_eq!(proto.parent_class, Some("java/lang/Object"));
+
+    let methods = proto.methods.iter().map(|x| (x.name.as_str(), x.descriptor.as_str())).collect::<Vec<_>>();
+    assert_eq!(methods, [("<init>", "(Ljava/lang/String;)V"), ("add", "(I)I"), ("sumBytes", "([B)J")]);
+    assert!(proto.methods[2].access_flags.contains(MethodAccessFlags::STATIC));
+
+    let fields = proto.fields.iter().map(|x| (x.name.as_str(), x.descriptor.as_str())).collect::<Vec<_>>();
+    assert_eq!(fields, [("count", "I"), ("label", "Ljava/lang/String;"), ("created", "I")]);
+}
+
+#[tokio::test]
+async fn test_java_class_invoke() -> Result<()> {
+    let runtime = TestRuntime::new(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    let class = ClassDefinitionImpl::from_class_proto(Counter::as_proto(), Box::new(runtime) as Box<_>);
+    jvm.register_class(Box::new(class), None).await?;
+
+    let label = JavaLangString::from_rust_string(&jvm, "counter").await?;
+    let counter = jvm.new_class("Counter", "(Ljava/lang/String;)V", (label,)).await?;
+
+    let _: i32 = jvm.invoke_virtual(&counter, "add", "(I)I", (2,)).await?;
+    let count: i32 = jvm.invoke_virtual(&counter, "add", "(I)I", (3,)).await?;
+    assert_eq!(count, 5);
+
+    let created: i32 = jvm.get_static_field("Counter", "created", "I").await?;
+    assert_eq!(created, 1);
+
+    let mut bytes = jvm.instantiate_array("B", 3).await?;
+    jvm.store_array(&mut bytes, 0, vec![1i8, 2, -4]).await?;
+    let sum: i64 = jvm.invoke_static("Counter", "sumBytes", "([B)J", (bytes,)).await?;
+    assert_eq!(sum, -1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_java_class_type_converters() -> Result<()> {
+    let runtime = TestRuntime::new(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    let proto = Strings::as_proto();
+    let methods = proto.methods.iter().map(|x| (x.name.as_str(), x.descriptor.as_str())).collect::<Vec<_>>();
+    assert_eq!(
+        methods,
+        [
+            ("repeat", "(Ljava/lang/String;Ljava/lang/Integer;)Ljava/lang/String;"),
+            ("upper", "(Ljava/lang/String;)Ljava/lang/String;"),
+            ("reverse", "([B)[B"),
+            ("last", "([Ljava/lang/Object;)Ljava/lang/Object;"),
+        ]
+    );
+
+    let class = ClassDefinitionImpl::from_class_proto(proto, Box::new(runtime) as Box<_>);
+    jvm.register_class(Box::new(class), None).await?;
+
+    let value = JavaLangString::from_rust_string(&jvm, "ab").await?;
+    let count = jvm.new_class("java/lang/Integer", "(I)V", (3,)).await?;
+    let result: Box<dyn ClassInstance> = jvm
+        .invoke_static(
+            "Strings",
+            "repeat",
+            "(Ljava/lang/String;Ljava/lang/Integer;)Ljava/lang/String;",
+            (value.clone(), count),
+        )
+        .await?;
+    assert_eq!(JavaLangString::to_rust_string(&jvm, &result).await?, "ababab");
+
+    // null boxed primitive is None
+    let result: Box<dyn ClassInstance> = jvm
+        .invoke_static(
+            "Strings",
+            "repeat",
+            "(Ljava/lang/String;Ljava/lang/Integer;)Ljava/lang/String;",
+            (value, None),
+        )
+        .await?;
+    assert_eq!(JavaLangString::to_rust_string(&jvm, &result).await?, "ab");
+
+    // null for a non-optional string throws
+    let result: Result<ClassInstanceRef<String>> = jvm
+        .invoke_static(
+            "Strings",
+            "repeat",
+            "(Ljava/lang/String;Ljava/lang/Integer;)Ljava/lang/String;",
+            (None, None),
+        )
+        .await;
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("expected NullPointerException");
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/NullPointerException"));
+
+    let result: ClassInstanceRef<String> = jvm
+        .invoke_static("Strings", "upper", "(Ljava/lang/String;)Ljava/lang/String;", (None,))
+        .await?;
+    assert!(result.is_null());
+
+    let mut bytes = jvm.instantiate_array("B", 3).await?;
+    jvm.store_array(&mut bytes, 0, vec![1i8, 2, -1]).await?;
+    let reversed: Box<dyn ClassInstance> = jvm.invoke_static("Strings", "reverse", "([B)[B", (bytes,)).await?;
+    let reversed: Vec<i8> = jvm.load_array(&reversed, 0, 3).await?;
+    assert_eq!(reversed, [-1, 2, 1]);
+
+    let first = jvm.new_class("java/lang/Object", "()V", ()).await?;
+    let second = jvm.new_class("java/lang/Object", "()V", ()).await?;
+    let mut objects = jvm.instantiate_array("Ljava/lang/Object;", 2).await?;
+    jvm.store_array(&mut objects, 0, vec![first, second.clone()]).await?;
+    let last: Box<dyn ClassInstance> = jvm
+        .invoke_static("Strings", "last", "([Ljava/lang/Object;)Ljava/lang/Object;", (objects,))
+        .await?;
+    assert!(second.equals(&*last)?);
+
+    Ok(())
+}
@@ -3,8 +3,14 @@ extern crate alloc;
 
 mod method;
 mod proto;
+mod raw_args;
+mod stub;
+mod type_converter;
 
 pub use {
-    method::{MethodBody, TypeConverter},
+    java_class_bindings::{java_bindings, java_class, java_method},
+    method::MethodBody,
     proto::{JavaClassProto, JavaFieldProto, JavaMethodProto},
+    raw_args::RawArgs,
+    type_converter::TypeConverter,
 };
@@ -3,7 +3,7 @@ use alloc::{boxed::Box, format, string::String, vec::Vec};
 use java_constants::{ClassAccessFlags, FieldAccessFlags, MethodAccessFlags};
 use jvm::{JavaError, JavaValue, Jvm};
 
-use crate::method::{MethodBody, MethodImpl};
+use crate::method::{MethodBody, MethodImpl, RawMethodImpl};
 
 pub struct JavaClassProto<C>
 where
@@ -59,6 +59,19 @@ where
         }
     }
 
+    // for bodies taking `RawArgs`, as typed bodies are limited to 11 parameters
+    pub fn new_raw<M, F, R>(name: &str, descriptor: &str, method: M, flag: MethodAccessFlags) -> Self
+    where
+        M: RawMethodImpl<F, C, R, JavaError>,
+    {
+        Self {
+            name: name.into(),
+            descriptor: descriptor.into(),
+            body: method.into_body(),
+            access_flags: flag,
+        }
+    }
+
     pub fn new_abstract(name: &str, descriptor: &str, flag: MethodAccessFlags) -> Self {
         struct AbstractCall {
             name: String,
@@ -87,7 +100,7 @@ where
                 name: name.into(),
                 descriptor: descriptor.into(),
             }),
-            access_flags: flag,
+            access_flags: flag | MethodAccessFlags::ABSTRACT,
         }
     }
 }
@@ -0,0 +1,45 @@
+use alloc::{boxed::Box, format};
+
+use jvm::{JavaError, JavaValue, Jvm};
+
+use crate::TypeConverter;
+
+// arguments of a method body taking them unconverted, for methods with more parameters than typed bodies support.
+// instance methods have `this` at index 0
+pub struct RawArgs {
+    args: Box<[JavaValue]>,
+}
+
+impl RawArgs {
+    pub fn new(args: Box<[JavaValue]>) -> Self {
+        Self { args }
+    }
+
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    pub async fn get<T>(&self, jvm: &Jvm, index: usize) -> Result<T, JavaError>
+    where
+        T: TypeConverter<T>,
+    {
+        let raw = self
+            .args
+            .get(index)
+            .ok_or_else(|| JavaError::internal(format!("argument {index} out of range, got {}", self.args.len())))?;
+
+        T::to_rust(jvm, raw.clone()).await
+    }
+
+    pub fn raw(&self) -> &[JavaValue] {
+        &self.args
+    }
+
+    pub fn into_raw(self) -> Box<[JavaValue]> {
+        self.args
+    }
+}
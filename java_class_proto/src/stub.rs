@@ -0,0 +1,134 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use crate::JavaClassProto;
+
+const MAGIC: u32 = 0xCAFEBABE;
+const MAJOR_VERSION: u16 = 52; // java 8
+
+const CONSTANT_UTF8: u8 = 1;
+const CONSTANT_CLASS: u8 = 7;
+
+impl<C> JavaClassProto<C>
+where
+    C: ?Sized + Send,
+{
+    // class file with the proto's hierarchy, fields, methods and flags but no code, to compile java against
+    pub fn stub_class(&self) -> Vec<u8> {
+        let mut constant_pool = ConstantPool::default();
+
+        let this_class = constant_pool.class(self.name);
+        let super_class = self.parent_class.map(|x| constant_pool.class(x)).unwrap_or(0);
+        let interfaces = self.interfaces.iter().map(|x| constant_pool.class(x)).collect::<Vec<_>>();
+
+        let fields = self
+            .fields
+            .iter()
+            .map(|x| (x.access_flags.bits(), constant_pool.utf8(&x.name), constant_pool.utf8(&x.descriptor)))
+            .collect::<Vec<_>>();
+
+        let methods = self
+            .methods
+            .iter()
+            .map(|x| (x.access_flags.bits(), constant_pool.utf8(&x.name), constant_pool.utf8(&x.descriptor)))
+            .collect::<Vec<_>>();
+
+        let mut result = Vec::new();
+        result.extend(MAGIC.to_be_bytes());
+        result.extend(0u16.to_be_bytes());
+        result.extend(MAJOR_VERSION.to_be_bytes());
+        constant_pool.write(&mut result);
+
+        result.extend(self.access_flags.bits().to_be_bytes());
+        result.extend(this_class.to_be_bytes());
+        result.extend(super_class.to_be_bytes());
+
+        result.extend((interfaces.len() as u16).to_be_bytes());
+        for interface in interfaces {
+            result.extend(interface.to_be_bytes());
+        }
+
+        for members in [fields, methods] {
+            result.extend((members.len() as u16).to_be_bytes());
+            for (access_flags, name, descriptor) in members {
+                result.extend(access_flags.to_be_bytes());
+                result.extend(name.to_be_bytes());
+                result.extend(descriptor.to_be_bytes());
+                result.extend(0u16.to_be_bytes()); // attributes
+            }
+        }
+
+        result.extend(0u16.to_be_bytes()); // attributes
+
+        result
+    }
+}
+
+#[derive(Default)]
+struct ConstantPool {
+    items: Vec<Vec<u8>>,
+    utf8s: BTreeMap<String, u16>,
+    classes: BTreeMap<String, u16>,
+}
+
+impl ConstantPool {
+    fn utf8(&mut self, value: &str) -> u16 {
+        if let Some(x) = self.utf8s.get(value) {
+            return *x;
+        }
+
+        let bytes = modified_utf8(value);
+        let mut item = Vec::with_capacity(bytes.len() + 3);
+        item.push(CONSTANT_UTF8);
+        item.extend((bytes.len() as u16).to_be_bytes());
+        item.extend(bytes);
+
+        let index = self.push(item);
+        self.utf8s.insert(value.into(), index);
+
+        index
+    }
+
+    fn class(&mut self, name: &str) -> u16 {
+        if let Some(x) = self.classes.get(name) {
+            return *x;
+        }
+
+        let name_index = self.utf8(name);
+        let mut item = Vec::with_capacity(3);
+        item.push(CONSTANT_CLASS);
+        item.extend(name_index.to_be_bytes());
+
+        let index = self.push(item);
+        self.classes.insert(name.into(), index);
+
+        index
+    }
+
+    // constant pool indices start from 1
+    fn push(&mut self, item: Vec<u8>) -> u16 {
+        self.items.push(item);
+
+        self.items.len() as u16
+    }
+
+    fn write(&self, result: &mut Vec<u8>) {
+        result.extend((self.items.len() as u16 + 1).to_be_bytes());
+        for item in &self.items {
+            result.extend(item);
+        }
+    }
+}
+
+// class files store strings as utf-16 units encoded in up to 3 bytes each, with nul as 2 bytes
+fn modified_utf8(value: &str) -> Vec<u8> {
+    let mut result = Vec::with_capacity(value.len());
+    for unit in value.encode_utf16() {
+        match unit {
+            0x01..=0x7f => result.push(unit as u8),
+            0x00 | 0x80..=0x7ff => result.extend([0xc0 | (unit >> 6) as u8, 0x80 | (unit & 0x3f) as u8]),
+            _ => result.extend([0xe0 | (unit >> 12) as u8, 0x80 | ((unit >> 6) & 0x3f) as u8, 0x80 | (unit & 0x3f) as u8]),
+        }
+    }
+
+    result
+}
@@ -0,0 +1,202 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::future::Future;
+
+use jvm::{ClassInstance, ClassInstanceRef, JavaChar, JavaDescriptor, JavaError, JavaValue, Jvm, runtime::JavaLangString};
+
+// conversion between java values and rust types of method parameters and return values.
+// async, as strings and arrays allocate java objects. not boxed, so primitive conversions compile down to a ready future
+pub trait TypeConverter<T> {
+    fn to_rust(jvm: &Jvm, raw: JavaValue) -> impl Future<Output = Result<T, JavaError>> + Send;
+    fn from_rust(jvm: &Jvm, rust: T) -> impl Future<Output = Result<JavaValue, JavaError>> + Send;
+}
+
+macro_rules! impl_primitive_converter {
+    ($($type: ty),*) => {
+        $(
+            impl TypeConverter<$type> for $type {
+                async fn to_rust(_: &Jvm, raw: JavaValue) -> Result<$type, JavaError> {
+                    raw.try_into()
+                }
+
+                async fn from_rust(_: &Jvm, rust: $type) -> Result<JavaValue, JavaError> {
+                    Ok(rust.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive_converter!(i8, i16, i32, JavaChar, i64, bool, f32, f64);
+
+impl TypeConverter<()> for () {
+    async fn to_rust(_: &Jvm, _: JavaValue) -> Result<(), JavaError> {
+        Ok(())
+    }
+
+    async fn from_rust(_: &Jvm, _: ()) -> Result<JavaValue, JavaError> {
+        Ok(JavaValue::Void)
+    }
+}
+
+impl<T> TypeConverter<ClassInstanceRef<T>> for ClassInstanceRef<T>
+where
+    T: Sync + Send + 'static,
+{
+    async fn to_rust(_: &Jvm, raw: JavaValue) -> Result<Self, JavaError> {
+        Ok(Self::new(raw.try_into()?))
+    }
+
+    async fn from_rust(_: &Jvm, value: Self) -> Result<JavaValue, JavaError> {
+        Ok(value.instance.into())
+    }
+}
+
+impl<T> TypeConverter<Option<ClassInstanceRef<T>>> for Option<ClassInstanceRef<T>>
+where
+    T: Sync + Send + 'static,
+{
+    async fn to_rust(_: &Jvm, raw: JavaValue) -> Result<Self, JavaError> {
+        let instance: Option<Box<dyn ClassInstance>> = raw.try_into()?;
+
+        Ok(instance.map(|x| ClassInstanceRef::new(Some(x))))
+    }
+
+    async fn from_rust(_: &Jvm, value: Self) -> Result<JavaValue, JavaError> {
+        Ok(value.and_then(|x| x.instance).into())
+    }
+}
+
+impl TypeConverter<String> for String {
+    async fn to_rust(jvm: &Jvm, raw: JavaValue) -> Result<Self, JavaError> {
+        let instance = non_null(jvm, raw).await?;
+
+        JavaLangString::to_rust_string(jvm, &instance).await
+    }
+
+    async fn from_rust(jvm: &Jvm, value: Self) -> Result<JavaValue, JavaError> {
+        Ok(JavaLangString::from_rust_string(jvm, &value).await?.into())
+    }
+}
+
+impl TypeConverter<Option<String>> for Option<String> {
+    async fn to_rust(jvm: &Jvm, raw: JavaValue) -> Result<Self, JavaError> {
+        let instance: Option<Box<dyn ClassInstance>> = raw.try_into()?;
+
+        Ok(match instance {
+            Some(x) => Some(JavaLangString::to_rust_string(jvm, &x).await?),
+            None => None,
+        })
+    }
+
+    async fn from_rust(jvm: &Jvm, value: Self) -> Result<JavaValue, JavaError> {
+        Ok(match value {
+            Some(x) => JavaLangString::from_rust_string(jvm, &x).await?.into(),
+            None => JavaValue::Object(None),
+        })
+    }
+}
+
+// primitive arrays, `Vec<u8>` being a byte[] as well
+macro_rules! impl_array_converter {
+    ($($type: ty => $element: ty, $descriptor: literal),*) => {
+        $(
+            impl TypeConverter<Vec<$type>> for Vec<$type> {
+                async fn to_rust(jvm: &Jvm, raw: JavaValue) -> Result<Self, JavaError> {
+                    let array = non_null(jvm, raw).await?;
+                    let length = jvm.array_length(&array).await?;
+                    let values: Vec<$element> = jvm.load_array(&array, 0, length).await?;
+
+                    Ok(values.into_iter().map(|x| x as _).collect())
+                }
+
+                async fn from_rust(jvm: &Jvm, value: Self) -> Result<JavaValue, JavaError> {
+                    let mut array = jvm.instantiate_array($descriptor, value.len()).await?;
+                    jvm.store_array(&mut array, 0, value.into_iter().map(|x| x as $element).collect::<Vec<_>>()).await?;
+
+                    Ok(array.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_array_converter!(
+    bool => bool, "Z",
+    i8 => i8, "B",
+    u8 => i8, "B",
+    JavaChar => JavaChar, "C",
+    i16 => i16, "S",
+    i32 => i32, "I",
+    i64 => i64, "J",
+    f32 => f32, "F",
+    f64 => f64, "D"
+);
+
+impl<T> TypeConverter<Vec<ClassInstanceRef<T>>> for Vec<ClassInstanceRef<T>>
+where
+    T: Sync + Send + 'static,
+    ClassInstanceRef<T>: JavaDescriptor,
+{
+    async fn to_rust(jvm: &Jvm, raw: JavaValue) -> Result<Self, JavaError> {
+        let array = non_null(jvm, raw).await?;
+        let length = jvm.array_length(&array).await?;
+        let values: Vec<Option<Box<dyn ClassInstance>>> = jvm.load_array(&array, 0, length).await?;
+
+        Ok(values.into_iter().map(ClassInstanceRef::new).collect())
+    }
+
+    async fn from_rust(jvm: &Jvm, value: Self) -> Result<JavaValue, JavaError> {
+        let mut array = jvm
+            .instantiate_array(<ClassInstanceRef<T> as JavaDescriptor>::DESCRIPTOR, value.len())
+            .await?;
+        jvm.store_array(&mut array, 0, value.into_iter().map(|x| x.instance).collect::<Vec<_>>())
+            .await?;
+
+        Ok(array.into())
+    }
+}
+
+// boxed primitives, None being null
+macro_rules! impl_boxed_converter {
+    ($($type: ty => $class: literal, $getter: literal, $descriptor: literal),*) => {
+        $(
+            impl TypeConverter<Option<$type>> for Option<$type> {
+                async fn to_rust(jvm: &Jvm, raw: JavaValue) -> Result<Self, JavaError> {
+                    let instance: Option<Box<dyn ClassInstance>> = raw.try_into()?;
+
+                    Ok(match instance {
+                        Some(x) => Some(jvm.invoke_virtual(&x, $getter, concat!("()", $descriptor), ()).await?),
+                        None => None,
+                    })
+                }
+
+                async fn from_rust(jvm: &Jvm, value: Self) -> Result<JavaValue, JavaError> {
+                    Ok(match value {
+                        Some(x) => jvm.new_class($class, concat!("(", $descriptor, ")V"), (x,)).await?.into(),
+                        None => JavaValue::Object(None),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_boxed_converter!(
+    bool => "java/lang/Boolean", "booleanValue", "Z",
+    i8 => "java/lang/Byte", "byteValue", "B",
+    JavaChar => "java/lang/Character", "charValue", "C",
+    i16 => "java/lang/Short", "shortValue", "S",
+    i32 => "java/lang/Integer", "intValue", "I",
+    i64 => "java/lang/Long", "longValue", "J",
+    f32 => "java/lang/Float", "floatValue", "F",
+    f64 => "java/lang/Double", "doubleValue", "D"
+);
+
+async fn non_null(jvm: &Jvm, raw: JavaValue) -> Result<Box<dyn ClassInstance>, JavaError> {
+    let instance: Option<Box<dyn ClassInstance>> = raw.try_into()?;
+
+    match instance {
+        Some(x) => Ok(x),
+        None => Err(jvm.exception("java/lang/NullPointerException", "null").await),
+    }
+}
@@ -0,0 +1,62 @@
+use std::{collections::BTreeMap, string::String};
+
+use java_class_proto::{JavaMethodProto, RawArgs};
+use java_constants::{ClassAccessFlags, MethodAccessFlags};
+use java_runtime::{RuntimeClassProto, RuntimeContext};
+use jvm::{ClassInstance, JavaError, JavaValue, Jvm, Result, runtime::JavaLangString};
+use jvm_rust::ClassDefinitionImpl;
+
+use test_utils::{TestRuntime, create_test_jvm};
+
+const DESCRIPTOR: &str = "(Ljava/lang/String;IIIIIIIIIIII)Ljava/lang/String;";
+
+async fn join(jvm: &Jvm, _: &mut RuntimeContext, args: RawArgs) -> Result<String> {
+    let mut result = args.get::<String>(jvm, 0).await?;
+    for index in 1..args.len() {
+        let value: i32 = args.get(jvm, index).await?;
+        result.push_str(&value.to_string());
+    }
+
+    Ok(result)
+}
+
+fn proto() -> RuntimeClassProto {
+    RuntimeClassProto {
+        name: "Joiner",
+        parent_class: Some("java/lang/Object"),
+        interfaces: vec![],
+        methods: vec![JavaMethodProto::new_raw("join", DESCRIPTOR, join, MethodAccessFlags::STATIC)],
+        fields: vec![],
+        access_flags: ClassAccessFlags::PUBLIC,
+    }
+}
+
+#[tokio::test]
+async fn test_raw_args() -> Result<()> {
+    let runtime = TestRuntime::new(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    let class = ClassDefinitionImpl::from_class_proto(proto(), Box::new(runtime) as Box<_>);
+    jvm.register_class(Box::new(class), None).await?;
+
+    let prefix = JavaLangString::from_rust_string(&jvm, "digits:").await?;
+    let mut args = vec![JavaValue::from(prefix)];
+    args.extend([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 1, 2].map(JavaValue::from));
+
+    let result: Box<dyn ClassInstance> = jvm.invoke_static("Joiner", "join", DESCRIPTOR, args).await?;
+    assert_eq!(JavaLangString::to_rust_string(&jvm, &result).await?, "digits:012345678912");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_raw_args_out_of_range() -> Result<()> {
+    let jvm = create_test_jvm(TestRuntime::new(BTreeMap::new())).await?;
+
+    let args = RawArgs::new(Box::new([1.into()]));
+    assert_eq!(args.len(), 1);
+    assert_eq!(args.get::<i32>(&jvm, 0).await?, 1);
+    assert!(matches!(args.get::<i32>(&jvm, 1).await, Err(JavaError::Internal(_))));
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use classfile::ClassInfo;
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_constants::{ClassAccessFlags, FieldAccessFlags, MethodAccessFlags};
+use java_runtime::{RuntimeClassProto, RuntimeContext};
+use jvm::{Jvm, Result};
+
+async fn size(_: &Jvm, _: &mut RuntimeContext) -> Result<i32> {
+    Ok(0)
+}
+
+#[test]
+fn test_stub_class() {
+    let proto = RuntimeClassProto {
+        name: "org/example/Shape\u{e9}",
+        parent_class: Some("java/lang/Object"),
+        interfaces: vec!["java/lang/Comparable", "java/io/Serializable"],
+        methods: vec![
+            JavaMethodProto::new("size", "()I", size, MethodAccessFlags::PUBLIC | MethodAccessFlags::NATIVE),
+            JavaMethodProto::new_abstract("compareTo", "(Ljava/lang/Object;)I", MethodAccessFlags::PUBLIC),
+        ],
+        fields: vec![JavaFieldProto::new("size", "I", FieldAccessFlags::PRIVATE | FieldAccessFlags::STATIC)],
+        access_flags: ClassAccessFlags::PUBLIC | ClassAccessFlags::ABSTRACT,
+    };
+
+    let class = ClassInfo::parse(&proto.stub_class()).unwrap();
+    assert_eq!(class.major_version, 52);
+    assert_eq!(*class.this_class, "org/example/Shape\u{e9}");
+    assert_eq!(class.super_class.as_deref().map(|x| x.as_str()), Some("java/lang/Object"));
+    assert_eq!(
+        class.interfaces.iter().map(|x| x.as_str()).collect::<Vec<_>>(),
+        ["java/lang/Comparable", "java/io/Serializable"]
+    );
+    assert_eq!(class.access_flags, ClassAccessFlags::PUBLIC | ClassAccessFlags::ABSTRACT);
+
+    let methods = class
+        .methods
+        .iter()
+        .map(|x| (x.name.as_str(), x.descriptor.as_str(), x.access_flags, x.attributes.len()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        methods,
+        [
+            ("size", "()I", MethodAccessFlags::PUBLIC | MethodAccessFlags::NATIVE, 0),
+            (
+                "compareTo",
+                "(Ljava/lang/Object;)I",
+                MethodAccessFlags::PUBLIC | MethodAccessFlags::ABSTRACT,
+                0
+            ),
+        ]
+    );
+
+    assert_eq!(class.fields.len(), 1);
+    assert_eq!(*class.fields[0].name, "size");
+    assert_eq!(*class.fields[0].descriptor, "I");
+    assert_eq!(class.fields[0].access_flags, FieldAccessFlags::PRIVATE | FieldAccessFlags::STATIC);
+}
@@ -1,7 +1,7 @@
-use alloc::{vec, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{Array, ClassInstanceRef, JavaChar, Jvm, Result};
+use jvm::{Array, ClassInstance, ClassInstanceRef, JavaChar, Jvm, Result};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -56,7 +56,7 @@ impl BufferedReader {
         let mut buf_size: i32 = jvm.get_field(&this, "bufSize", "I").await?;
         let mut pos = None;
         while pos.is_none() {
-            let r#in = jvm.get_field(&this, "in", "Ljava/io/Reader;").await?;
+            let r#in: Box<dyn ClassInstance> = jvm.get_field(&this, "in", "Ljava/io/Reader;").await?;
             let read: i32 = jvm
                 .invoke_virtual(&r#in, "read", "([CII)I", (buf.clone(), buf_size, (BUF_SIZE as i32) - buf_size))
                 .await?;
@@ -105,7 +105,7 @@ impl BufferedReader {
     async fn close(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.io.BufferedReader::close({this:?})");
 
-        let r#in = jvm.get_field(&this, "in", "Ljava/io/Reader;").await?;
+        let r#in: Box<dyn ClassInstance> = jvm.get_field(&this, "in", "Ljava/io/Reader;").await?;
         let _: () = jvm.invoke_virtual(&r#in, "close", "()V", ()).await?;
 
         Ok(())
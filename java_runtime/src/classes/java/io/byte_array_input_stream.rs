@@ -1,7 +1,7 @@
-use alloc::vec;
+use alloc::{boxed::Box, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{Array, ClassInstanceRef, Jvm, Result};
+use jvm::{Array, ClassInstance, ClassInstanceRef, Jvm, Result};
 
 use crate::{RuntimeClassProto, RuntimeContext};
 
@@ -127,7 +127,7 @@ impl ByteArrayInputStream {
     async fn read_byte(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>) -> Result<i32> {
         tracing::debug!("java.io.ByteArrayInputStream::readByte({this:?})");
 
-        let buf = jvm.get_field(&this, "buf", "[B").await?;
+        let buf: Box<dyn ClassInstance> = jvm.get_field(&this, "buf", "[B").await?;
         let count: i32 = jvm.get_field(&this, "count", "I").await?;
         let pos: i32 = jvm.get_field(&this, "pos", "I").await?;
 
@@ -1,7 +1,7 @@
-use alloc::{vec, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{Array, ClassInstanceRef, Jvm, Result};
+use jvm::{Array, ClassInstance, ClassInstanceRef, Jvm, Result};
 
 use crate::{RuntimeClassProto, RuntimeContext, classes::java::lang::String};
 
@@ -77,7 +77,7 @@ impl ByteArrayOutputStream {
 
         let pos: i32 = jvm.get_field(&this, "pos", "I").await?;
         Self::ensure_capacity(jvm, &mut this, (pos + len) as usize).await?;
-        let mut buf = jvm.get_field(&this, "buf", "[B").await?;
+        let mut buf: Box<dyn ClassInstance> = jvm.get_field(&this, "buf", "[B").await?;
         let values: Vec<i8> = jvm.load_array(&bytes, off as usize, len as usize).await?;
         jvm.store_array(&mut buf, pos as usize, values).await?;
         jvm.put_field(&mut this, "pos", "I", pos + len).await
@@ -89,7 +89,7 @@ impl ByteArrayOutputStream {
         let pos: i32 = jvm.get_field(&this, "pos", "I").await?;
         Self::ensure_capacity(jvm, &mut this, (pos + 1) as _).await?;
 
-        let mut buf = jvm.get_field(&this, "buf", "[B").await?;
+        let mut buf: Box<dyn ClassInstance> = jvm.get_field(&this, "buf", "[B").await?;
         jvm.store_array(&mut buf, pos as _, vec![b as i8]).await?;
 
         jvm.put_field(&mut this, "pos", "I", pos + 1).await?;
@@ -155,7 +155,7 @@ impl ByteArrayOutputStream {
     }
 
     async fn ensure_capacity(jvm: &Jvm, this: &mut ClassInstanceRef<Self>, capacity: usize) -> Result<()> {
-        let old_buf = jvm.get_field(this, "buf", "[B").await?;
+        let old_buf: Box<dyn ClassInstance> = jvm.get_field(this, "buf", "[B").await?;
         let current_capacity = jvm.array_length(&old_buf).await?;
 
         if current_capacity < capacity {
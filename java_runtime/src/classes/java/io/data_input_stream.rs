@@ -1,8 +1,8 @@
-use alloc::{vec, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 
 use java_class_proto::JavaMethodProto;
 use java_constants::MethodAccessFlags;
-use jvm::{Array, ClassInstanceRef, JavaChar, Jvm, Result};
+use jvm::{Array, ClassInstance, ClassInstanceRef, JavaChar, Jvm, Result};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -237,14 +237,14 @@ impl DataInputStream {
     async fn skip_bytes(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, n: i32) -> Result<i32> {
         tracing::debug!("java.io.DataInputStream::skipBytes({this:?}, {n:?})");
 
-        let r#in = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
+        let r#in: Box<dyn ClassInstance> = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
         let skipped: i64 = jvm.invoke_virtual(&r#in, "skip", "(J)J", (n as i64,)).await?;
 
         Ok(skipped as _)
     }
 
     async fn read_required_byte(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> Result<u8> {
-        let r#in = jvm.get_field(this, "in", "Ljava/io/InputStream;").await?;
+        let r#in: Box<dyn ClassInstance> = jvm.get_field(this, "in", "Ljava/io/InputStream;").await?;
         let value: i32 = jvm.invoke_virtual(&r#in, "read", "()I", ()).await?;
         if value == -1 {
             return Err(jvm.exception("java/io/EOFException", "End of stream").await);
@@ -1,9 +1,9 @@
-use alloc::{vec, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 
 use bytemuck::cast_vec;
 
 use java_class_proto::JavaMethodProto;
-use jvm::{Array, ClassInstanceRef, JavaChar, Jvm, Result};
+use jvm::{Array, ClassInstance, ClassInstanceRef, JavaChar, Jvm, Result};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -54,7 +54,7 @@ impl DataOutputStream {
     async fn write(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, b: i32) -> Result<()> {
         tracing::debug!("java.io.DataOutputStream::write({this:?}, {b:?})");
 
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         let _: () = jvm.invoke_virtual(&out, "write", "(I)V", (b,)).await?;
 
         Ok(())
@@ -63,7 +63,7 @@ impl DataOutputStream {
     async fn write_byte(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, v: i32) -> Result<()> {
         tracing::debug!("java.io.DataOutputStream::writeByte({this:?}, {v:?})");
 
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         let _: () = jvm.invoke_virtual(&out, "write", "(I)V", (v,)).await?;
 
         Ok(())
@@ -84,7 +84,7 @@ impl DataOutputStream {
         let mut byte_array = jvm.instantiate_array("B", bytes.len() as _).await?;
         jvm.store_array(&mut byte_array, 0, cast_vec::<u8, i8>(bytes.to_vec())).await?;
 
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         let _: () = jvm.invoke_virtual(&out, "write", "([B)V", (byte_array,)).await?;
 
         Ok(())
@@ -102,7 +102,7 @@ impl DataOutputStream {
         let mut byte_array = jvm.instantiate_array("B", bytes.len() as _).await?;
         jvm.store_array(&mut byte_array, 0, cast_vec::<u8, i8>(bytes.to_vec())).await?;
 
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         let _: () = jvm.invoke_virtual(&out, "write", "([B)V", (byte_array,)).await?;
 
         Ok(())
@@ -115,7 +115,7 @@ impl DataOutputStream {
         let mut byte_array = jvm.instantiate_array("B", bytes.len() as _).await?;
         jvm.store_array(&mut byte_array, 0, cast_vec::<u8, i8>(bytes.to_vec())).await?;
 
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         let _: () = jvm.invoke_virtual(&out, "write", "([B)V", (byte_array,)).await?;
 
         Ok(())
@@ -140,7 +140,7 @@ impl DataOutputStream {
         let mut bytes = jvm.instantiate_array("B", chars.len()).await?;
         jvm.store_array(&mut bytes, 0, chars.into_iter().map(|value| value as i8)).await?;
 
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         jvm.invoke_virtual(&out, "write", "([B)V", (bytes,)).await
     }
 
@@ -159,7 +159,7 @@ impl DataOutputStream {
         let mut bytes = jvm.instantiate_array("B", data.len()).await?;
         jvm.store_array(&mut bytes, 0, data).await?;
 
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         jvm.invoke_virtual(&out, "write", "([B)V", (bytes,)).await
     }
 
@@ -192,14 +192,14 @@ impl DataOutputStream {
         let mut bytes = jvm.instantiate_array("B", data.len()).await?;
         jvm.store_array(&mut bytes, 0, data).await?;
 
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         jvm.invoke_virtual(&out, "write", "([B)V", (bytes,)).await
     }
 
     async fn close(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.io.DataOutputStream::close({this:?})");
 
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         let _: () = jvm.invoke_virtual(&out, "close", "()V", []).await?;
 
         Ok(())
@@ -208,7 +208,7 @@ impl DataOutputStream {
     async fn flush(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.io.DataOutputStream::flush({this:?})");
 
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         let _: () = jvm.invoke_virtual(&out, "flush", "()V", []).await?;
 
         Ok(())
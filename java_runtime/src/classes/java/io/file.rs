@@ -1,8 +1,8 @@
-use alloc::vec;
+use alloc::{boxed::Box, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::{FieldAccessFlags, MethodAccessFlags};
-use jvm::{ClassInstanceRef, JavaChar, Jvm, Result, runtime::JavaLangString};
+use jvm::{ClassInstance, ClassInstanceRef, JavaChar, Jvm, Result, runtime::JavaLangString};
 
 use crate::{FileType, RuntimeClassProto, RuntimeContext, classes::java::lang::String};
 
@@ -101,7 +101,7 @@ impl File {
     async fn exists(jvm: &Jvm, context: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<bool> {
         tracing::debug!("java.io.File::exists({this:?})");
 
-        let path = jvm.invoke_virtual(&this, "getPath", "()Ljava/lang/String;", ()).await?;
+        let path: Box<dyn ClassInstance> = jvm.invoke_virtual(&this, "getPath", "()Ljava/lang/String;", ()).await?;
         let path = JavaLangString::to_rust_string(jvm, &path).await?;
 
         Ok(context.metadata(&path).await.is_ok())
@@ -110,7 +110,7 @@ impl File {
     async fn is_directory(jvm: &Jvm, context: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<bool> {
         tracing::debug!("java.io.File::isDirectory({this:?})");
 
-        let path = jvm.invoke_virtual(&this, "getPath", "()Ljava/lang/String;", ()).await?;
+        let path: Box<dyn ClassInstance> = jvm.invoke_virtual(&this, "getPath", "()Ljava/lang/String;", ()).await?;
         let path = JavaLangString::to_rust_string(jvm, &path).await?;
 
         Ok(context.metadata(&path).await.is_ok_and(|x| x.r#type == FileType::Directory))
@@ -119,7 +119,7 @@ impl File {
     async fn is_file(jvm: &Jvm, context: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<bool> {
         tracing::debug!("java.io.File::isFile({this:?})");
 
-        let path = jvm.invoke_virtual(&this, "getPath", "()Ljava/lang/String;", ()).await?;
+        let path: Box<dyn ClassInstance> = jvm.invoke_virtual(&this, "getPath", "()Ljava/lang/String;", ()).await?;
         let path = JavaLangString::to_rust_string(jvm, &path).await?;
 
         Ok(context.metadata(&path).await.is_ok_and(|x| x.r#type == FileType::File))
@@ -128,7 +128,7 @@ impl File {
     async fn delete(jvm: &Jvm, context: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<bool> {
         tracing::debug!("java.io.File::delete({this:?})");
 
-        let path = jvm.invoke_virtual(&this, "getPath", "()Ljava/lang/String;", ()).await?;
+        let path: Box<dyn ClassInstance> = jvm.invoke_virtual(&this, "getPath", "()Ljava/lang/String;", ()).await?;
         let path = JavaLangString::to_rust_string(jvm, &path).await?;
 
         Ok(context.unlink(&path).await.is_ok())
@@ -137,7 +137,7 @@ impl File {
     async fn length(jvm: &Jvm, context: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i64> {
         tracing::debug!("java.io.File::length({this:?})");
 
-        let path = jvm.invoke_virtual(&this, "getPath", "()Ljava/lang/String;", ()).await?;
+        let path: Box<dyn ClassInstance> = jvm.invoke_virtual(&this, "getPath", "()Ljava/lang/String;", ()).await?;
         let path = JavaLangString::to_rust_string(jvm, &path).await?;
 
         // File.length() is 0 when the file does not exist
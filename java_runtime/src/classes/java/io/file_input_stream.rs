@@ -1,9 +1,9 @@
-use alloc::vec;
+use alloc::{boxed::Box, vec};
 
 use bytemuck::cast_vec;
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{Array, ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
+use jvm::{Array, ClassInstance, ClassInstanceRef, JavaError, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -40,7 +40,7 @@ impl FileInputStream {
     async fn init(jvm: &Jvm, context: &mut RuntimeContext, this: ClassInstanceRef<Self>, file: ClassInstanceRef<File>) -> Result<()> {
         tracing::debug!("java.io.FileInputStream::<init>({this:?}, {file:?})");
 
-        let path = jvm.invoke_virtual(&file, "getPath", "()Ljava/lang/String;", ()).await?;
+        let path: Box<dyn ClassInstance> = jvm.invoke_virtual(&file, "getPath", "()Ljava/lang/String;", ()).await?;
         let path = JavaLangString::to_rust_string(jvm, &path).await?;
 
         let fd = context.open(&path, false).await;
@@ -105,7 +105,10 @@ impl FileInputStream {
         let mut rust_file = FileDescriptor::file(jvm, context, fd).await?;
 
         let mut rust_buf = vec![0; length as _];
-        let Ok(read) = rust_file.read(&mut rust_buf).await else {
+        let Some(read) = jvm.interruptible(rust_file.read(&mut rust_buf)).await else {
+            return Err(Self::interrupted_io_exception(jvm).await);
+        };
+        let Ok(read) = read else {
             return Err(jvm.exception("java/io/IOException", "I/O error").await);
         };
         if read == 0 {
@@ -124,7 +127,10 @@ impl FileInputStream {
         let mut rust_file = FileDescriptor::file(jvm, context, fd).await?;
 
         let mut buf = [0; 1];
-        let Ok(read) = rust_file.read(&mut buf).await else {
+        let Some(read) = jvm.interruptible(rust_file.read(&mut buf)).await else {
+            return Err(Self::interrupted_io_exception(jvm).await);
+        };
+        let Ok(read) = read else {
             return Err(jvm.exception("java/io/IOException", "I/O error").await);
         };
         if read == 0 {
@@ -134,6 +140,16 @@ impl FileInputStream {
         Ok(buf[0] as i32)
     }
 
+    // a blocking read gives up when the reading thread is interrupted, clearing its interrupt status
+    async fn interrupted_io_exception(jvm: &Jvm) -> JavaError {
+        let thread = match jvm.current_java_thread() {
+            Ok(x) => x,
+            Err(err) => return err,
+        };
+        jvm.is_interrupted(&thread, true);
+        jvm.exception("java/io/InterruptedIOException", "read interrupted").await
+    }
+
     async fn close(jvm: &Jvm, context: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.io.FileInputStream::close({this:?})");
 
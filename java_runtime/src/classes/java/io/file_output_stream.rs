@@ -1,9 +1,9 @@
-use alloc::vec;
+use alloc::{boxed::Box, vec};
 
 use bytemuck::cast_slice;
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{Array, ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
+use jvm::{Array, ClassInstance, ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -39,7 +39,7 @@ impl FileOutputStream {
     async fn init(jvm: &Jvm, context: &mut RuntimeContext, this: ClassInstanceRef<Self>, file: ClassInstanceRef<File>) -> Result<()> {
         tracing::debug!("java.io.FileOutputStream::<init>({this:?}, {file:?})");
 
-        let path = jvm.invoke_virtual(&file, "getPath", "()Ljava/lang/String;", ()).await?;
+        let path: Box<dyn ClassInstance> = jvm.invoke_virtual(&file, "getPath", "()Ljava/lang/String;", ()).await?;
         let path = JavaLangString::to_rust_string(jvm, &path).await?;
 
         let fd = context.open(&path, true).await;
@@ -1,8 +1,8 @@
-use alloc::vec;
+use alloc::{boxed::Box, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::FieldAccessFlags;
-use jvm::{Array, ClassInstanceRef, Jvm, Result};
+use jvm::{Array, ClassInstance, ClassInstanceRef, Jvm, Result};
 
 use crate::{RuntimeClassProto, RuntimeContext, classes::java::io::InputStream};
 
@@ -45,7 +45,7 @@ impl FilterInputStream {
     async fn available(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i32> {
         tracing::debug!("java.io.FilterInputStream::available({this:?})");
 
-        let r#in = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
+        let r#in: Box<dyn ClassInstance> = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
         let available: i32 = jvm.invoke_virtual(&r#in, "available", "()I", ()).await?;
 
         Ok(available)
@@ -54,7 +54,7 @@ impl FilterInputStream {
     async fn close(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.io.FilterInputStream::close({this:?})");
 
-        let r#in = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
+        let r#in: Box<dyn ClassInstance> = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
         let _: () = jvm.invoke_virtual(&r#in, "close", "()V", ()).await?;
 
         Ok(())
@@ -63,7 +63,7 @@ impl FilterInputStream {
     async fn reset(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.io.FilterInputStream::reset({this:?})");
 
-        let r#in = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
+        let r#in: Box<dyn ClassInstance> = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
         let _: () = jvm.invoke_virtual(&r#in, "reset", "()V", ()).await?;
 
         Ok(())
@@ -71,26 +71,26 @@ impl FilterInputStream {
 
     async fn skip(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, n: i64) -> Result<i64> {
         tracing::debug!("java.io.FilterInputStream::skip({this:?}, {n})");
-        let r#in = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
+        let r#in: Box<dyn ClassInstance> = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
         jvm.invoke_virtual(&r#in, "skip", "(J)J", (n,)).await
     }
 
     async fn mark(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, readlimit: i32) -> Result<()> {
         tracing::debug!("java.io.FilterInputStream::mark({this:?}, {readlimit})");
-        let r#in = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
+        let r#in: Box<dyn ClassInstance> = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
         jvm.invoke_virtual(&r#in, "mark", "(I)V", (readlimit,)).await
     }
 
     async fn mark_supported(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<bool> {
         tracing::debug!("java.io.FilterInputStream::markSupported({this:?})");
-        let r#in = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
+        let r#in: Box<dyn ClassInstance> = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
         jvm.invoke_virtual(&r#in, "markSupported", "()Z", ()).await
     }
 
     async fn read(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, b: ClassInstanceRef<Array<i8>>) -> Result<i32> {
         tracing::debug!("java.io.FilterInputStream::read({this:?}, {b:?})");
 
-        let r#in = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
+        let r#in: Box<dyn ClassInstance> = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
         let result: i32 = jvm.invoke_virtual(&r#in, "read", "([B)I", (b,)).await?;
 
         Ok(result)
@@ -106,7 +106,7 @@ impl FilterInputStream {
     ) -> Result<i32> {
         tracing::debug!("java.io.FilterInputStream::read({this:?}, {b:?}, {off}, {len})");
 
-        let r#in = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
+        let r#in: Box<dyn ClassInstance> = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
         let result: i32 = jvm.invoke_virtual(&r#in, "read", "([BII)I", (b, off, len)).await?;
 
         Ok(result)
@@ -115,7 +115,7 @@ impl FilterInputStream {
     async fn read_byte_int(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i32> {
         tracing::debug!("java.io.FilterInputStream::read({this:?})");
 
-        let r#in = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
+        let r#in: Box<dyn ClassInstance> = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
         let result: i32 = jvm.invoke_virtual(&r#in, "read", "()I", ()).await?;
 
         Ok(result)
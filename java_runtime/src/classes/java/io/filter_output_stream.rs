@@ -1,8 +1,8 @@
-use alloc::vec;
+use alloc::{boxed::Box, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::FieldAccessFlags;
-use jvm::{Array, ClassInstanceRef, Jvm, Result};
+use jvm::{Array, ClassInstance, ClassInstanceRef, Jvm, Result};
 
 use crate::{RuntimeClassProto, RuntimeContext, classes::java::io::OutputStream};
 
@@ -47,7 +47,7 @@ impl FilterOutputStream {
     ) -> Result<()> {
         tracing::debug!(" java.io.FilterOutputStream::write({this:?}, {bytes:?}, {offset:?}, {length:?})");
 
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         let _: () = jvm.invoke_virtual(&out, "write", "([BII)V", (bytes, offset, length)).await?;
 
         Ok(())
@@ -56,7 +56,7 @@ impl FilterOutputStream {
     async fn write(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, byte: i32) -> Result<()> {
         tracing::debug!("java.io.FilterOutputStream::write({this:?}, {byte:?})");
 
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         let _: () = jvm.invoke_virtual(&out, "write", "(I)V", (byte,)).await?;
 
         Ok(())
@@ -64,13 +64,13 @@ impl FilterOutputStream {
 
     async fn flush(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.io.FilterOutputStream::flush({this:?})");
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         jvm.invoke_virtual(&out, "flush", "()V", ()).await
     }
 
     async fn close(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.io.FilterOutputStream::close({this:?})");
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         jvm.invoke_virtual(&out, "close", "()V", ()).await
     }
 }
@@ -1,12 +1,12 @@
 use core::cmp::min;
 
-use alloc::{vec, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 
 use bytemuck::{cast_slice, cast_vec};
 use encoding_rs::{EUC_KR, UTF_8};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{Array, ClassInstanceRef, JavaChar, Jvm, Result, runtime::JavaLangString};
+use jvm::{Array, ClassInstance, ClassInstanceRef, JavaChar, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -129,7 +129,7 @@ impl InputStreamReader {
             if read_buf_size < (BUF_SIZE / 2) as _ {
                 let bytes_to_read = BUF_SIZE as i32 - read_buf_size;
 
-                let r#in = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
+                let r#in: Box<dyn ClassInstance> = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
 
                 let temp = jvm.instantiate_array("B", bytes_to_read as _).await?;
                 let read: i32 = jvm.invoke_virtual(&r#in, "read", "([BII)I", (temp.clone(), 0, bytes_to_read)).await?;
@@ -155,7 +155,7 @@ impl InputStreamReader {
             let mut read_buf_data = vec![0; read_buf_size as _];
             jvm.array_raw_buffer(&read_buf).await?.read(0, &mut read_buf_data)?;
 
-            let charset_ref = jvm.get_field(&this, "charset", "Ljava/lang/String;").await?;
+            let charset_ref: Box<dyn ClassInstance> = jvm.get_field(&this, "charset", "Ljava/lang/String;").await?;
             let charset = JavaLangString::to_rust_string(jvm, &charset_ref).await?;
             let mut decoder = if charset == "UTF-8" {
                 UTF_8.new_decoder_without_bom_handling()
@@ -201,7 +201,7 @@ impl InputStreamReader {
             jvm.put_field(&mut this, "readBufSize", "I", read_buf_size - read as i32).await?;
 
             // add to writeBuf
-            let mut write_buf = jvm.get_field(&this, "writeBuf", "[C").await?;
+            let mut write_buf: Box<dyn ClassInstance> = jvm.get_field(&this, "writeBuf", "[C").await?;
             let buffered_chars: i32 = jvm.get_field(&this, "writeBufSize", "I").await?;
             jvm.store_array(
                 &mut write_buf,
@@ -247,7 +247,7 @@ impl InputStreamReader {
     async fn close(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.io.InputStreamReader::close({this:?})");
 
-        let r#in = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
+        let r#in: Box<dyn ClassInstance> = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
         let _: () = jvm.invoke_virtual(&r#in, "close", "()V", ()).await?;
 
         Ok(())
@@ -261,7 +261,7 @@ impl InputStreamReader {
             return Ok(true);
         }
 
-        let r#in = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
+        let r#in: Box<dyn ClassInstance> = jvm.get_field(&this, "in", "Ljava/io/InputStream;").await?;
         let available: i32 = jvm.invoke_virtual(&r#in, "available", "()I", ()).await?;
         Ok(available > 0)
     }
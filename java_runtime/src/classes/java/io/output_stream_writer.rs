@@ -1,7 +1,7 @@
-use alloc::{string::String as RustString, vec, vec::Vec};
+use alloc::{boxed::Box, string::String as RustString, vec, vec::Vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{Array, ClassInstanceRef, JavaChar, Jvm, Result, runtime::JavaLangString};
+use jvm::{Array, ClassInstance, ClassInstanceRef, JavaChar, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -131,13 +131,13 @@ impl OutputStreamWriter {
         let mut java_bytes = jvm.instantiate_array("B", bytes.len()).await?;
         jvm.store_array(&mut java_bytes, 0, bytes.into_iter().map(|value| value as i8)).await?;
 
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         jvm.invoke_virtual(&out, "write", "([B)V", (java_bytes,)).await
     }
 
     async fn flush(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.io.OutputStreamWriter::flush({this:?})");
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         jvm.invoke_virtual(&out, "flush", "()V", ()).await
     }
 
@@ -152,7 +152,7 @@ impl OutputStreamWriter {
             let _: () = jvm.invoke_virtual(&this, "write", "([CII)V", (replacement, 0, 1)).await?;
         }
 
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         jvm.invoke_virtual(&out, "close", "()V", ()).await
     }
 }
@@ -1,4 +1,5 @@
 use alloc::{
+    boxed::Box,
     format,
     string::{String as RustString, ToString},
     vec,
@@ -7,7 +8,7 @@ use alloc::{
 use core::char;
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{Array, ClassInstanceRef, JavaChar, Jvm, Result, runtime::JavaLangString};
+use jvm::{Array, ClassInstance, ClassInstanceRef, JavaChar, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -81,7 +82,7 @@ impl PrintStream {
 
     async fn close(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.io.PrintStream::close({this:?})");
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         if jvm.invoke_virtual::<_, ()>(&out, "close", "()V", ()).await.is_err() {
             jvm.put_field(&mut this, "trouble", "Z", true).await?;
         }
@@ -90,7 +91,7 @@ impl PrintStream {
 
     async fn flush(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.io.PrintStream::flush({this:?})");
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         if jvm.invoke_virtual::<_, ()>(&out, "flush", "()V", ()).await.is_err() {
             jvm.put_field(&mut this, "trouble", "Z", true).await?;
         }
@@ -99,7 +100,7 @@ impl PrintStream {
 
     async fn write_byte(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>, value: i32) -> Result<()> {
         tracing::debug!("java.io.PrintStream::write({this:?}, {value})");
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         if jvm.invoke_virtual::<_, ()>(&out, "write", "(I)V", (value,)).await.is_err() {
             jvm.put_field(&mut this, "trouble", "Z", true).await?;
         }
@@ -115,7 +116,7 @@ impl PrintStream {
         len: i32,
     ) -> Result<()> {
         tracing::debug!("java.io.PrintStream::write({this:?}, {bytes:?}, {off}, {len})");
-        let out = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/OutputStream;").await?;
         if jvm.invoke_virtual::<_, ()>(&out, "write", "([BII)V", (bytes, off, len)).await.is_err() {
             jvm.put_field(&mut this, "trouble", "Z", true).await?;
         }
@@ -1,7 +1,7 @@
-use alloc::{format, vec};
+use alloc::{boxed::Box, format, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{Array, ClassInstanceRef, JavaChar, Jvm, Result, runtime::JavaLangString};
+use jvm::{Array, ClassInstance, ClassInstanceRef, JavaChar, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -49,7 +49,7 @@ impl PrintWriter {
     ) -> Result<()> {
         tracing::debug!("java.io.PrintWriter::write({this:?}, {chars:?}, {off:?}, {len:?})");
 
-        let out = jvm.get_field(&this, "out", "Ljava/io/Writer;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/Writer;").await?;
 
         let _: () = jvm.invoke_virtual(&out, "write", "([CII)V", (chars, off, len)).await?;
 
@@ -58,13 +58,13 @@ impl PrintWriter {
 
     async fn flush(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.io.PrintWriter::flush({this:?})");
-        let out = jvm.get_field(&this, "out", "Ljava/io/Writer;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/Writer;").await?;
         jvm.invoke_virtual(&out, "flush", "()V", ()).await
     }
 
     async fn close(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.io.PrintWriter::close({this:?})");
-        let out = jvm.get_field(&this, "out", "Ljava/io/Writer;").await?;
+        let out: Box<dyn ClassInstance> = jvm.get_field(&this, "out", "Ljava/io/Writer;").await?;
         jvm.invoke_virtual(&out, "close", "()V", ()).await
     }
 
@@ -1,7 +1,7 @@
-use alloc::vec;
+use alloc::{boxed::Box, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{Array, ClassInstanceRef, JavaChar, Jvm, Result};
+use jvm::{Array, ClassInstance, ClassInstanceRef, JavaChar, Jvm, Result};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -50,7 +50,7 @@ impl StringWriter {
     ) -> Result<()> {
         tracing::debug!("java.io.StringWriter::write({this:?}, {chars:?}, {off:?}, {len:?})");
 
-        let buf = jvm.get_field(&this, "buf", "Ljava/lang/StringBuffer;").await?;
+        let buf: Box<dyn ClassInstance> = jvm.get_field(&this, "buf", "Ljava/lang/StringBuffer;").await?;
 
         let _: ClassInstanceRef<StringBuffer> = jvm
             .invoke_virtual(&buf, "append", "([CII)Ljava/lang/StringBuffer;", (chars, off, len))
@@ -72,7 +72,7 @@ impl StringWriter {
     async fn to_string(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<String>> {
         tracing::debug!("java.io.StringWriter::toString({this:?})");
 
-        let buf = jvm.get_field(&this, "buf", "Ljava/lang/StringBuffer;").await?;
+        let buf: Box<dyn ClassInstance> = jvm.get_field(&this, "buf", "Ljava/lang/StringBuffer;").await?;
 
         let string = jvm.invoke_virtual(&buf, "toString", "()Ljava/lang/String;", ()).await?;
 
@@ -1,3 +1,6 @@
+pub mod r#ref;
+pub mod reflect;
+
 mod abstract_method_error;
 mod arithmetic_exception;
 mod array_index_out_of_bounds_exception;
@@ -21,9 +24,11 @@ mod float;
 mod illegal_access_exception;
 mod illegal_argument_exception;
 mod illegal_monitor_state_exception;
+mod illegal_state_exception;
 mod illegal_thread_state_exception;
 mod incompatible_class_change_error;
 mod index_out_of_bounds_exception;
+mod inheritable_thread_local;
 mod instantiation_error;
 mod instantiation_exception;
 mod integer;
@@ -34,7 +39,9 @@ mod math;
 mod negative_array_size_exception;
 mod no_class_def_found_error;
 mod no_such_field_error;
+mod no_such_field_exception;
 mod no_such_method_error;
+mod no_such_method_exception;
 mod null_pointer_exception;
 mod number;
 mod number_format_exception;
@@ -50,12 +57,15 @@ mod string_buffer;
 mod string_index_out_of_bounds_exception;
 mod system;
 mod thread;
+mod thread_group;
+mod thread_local;
 mod throwable;
 mod unsatisfied_link_error;
 mod unsupported_class_version_error;
 mod unsupported_operation_exception;
 mod verify_error;
 mod virtual_machine_error;
+mod void;
 
 pub use self::{
     abstract_method_error::AbstractMethodError, arithmetic_exception::ArithmeticException,
@@ -64,15 +74,18 @@ pub use self::{
     class_not_found_exception::ClassNotFoundException, clone_not_supported_exception::CloneNotSupportedException, cloneable::Cloneable,
     comparable::Comparable, double::Double, error::Error, exception::Exception, exception_in_initializer_error::ExceptionInInitializerError,
     float::Float, illegal_access_exception::IllegalAccessException, illegal_argument_exception::IllegalArgumentException,
-    illegal_monitor_state_exception::IllegalMonitorStateException, illegal_thread_state_exception::IllegalThreadStateException,
-    incompatible_class_change_error::IncompatibleClassChangeError, index_out_of_bounds_exception::IndexOutOfBoundsException,
+    illegal_monitor_state_exception::IllegalMonitorStateException, illegal_state_exception::IllegalStateException,
+    illegal_thread_state_exception::IllegalThreadStateException, incompatible_class_change_error::IncompatibleClassChangeError,
+    index_out_of_bounds_exception::IndexOutOfBoundsException, inheritable_thread_local::InheritableThreadLocal,
     instantiation_error::InstantiationError, instantiation_exception::InstantiationException, integer::Integer,
     interrupted_exception::InterruptedException, linkage_error::LinkageError, long::Long, math::Math,
     negative_array_size_exception::NegativeArraySizeException, no_class_def_found_error::NoClassDefFoundError, no_such_field_error::NoSuchFieldError,
-    no_such_method_error::NoSuchMethodError, null_pointer_exception::NullPointerException, number::Number,
-    number_format_exception::NumberFormatException, object::Object, out_of_memory_error::OutOfMemoryError, runnable::Runnable, runtime::Runtime,
-    runtime_exception::RuntimeException, security_exception::SecurityException, short::Short, string::String, string_buffer::StringBuffer,
-    string_index_out_of_bounds_exception::StringIndexOutOfBoundsException, system::System, thread::Thread, throwable::Throwable,
-    unsatisfied_link_error::UnsatisfiedLinkError, unsupported_class_version_error::UnsupportedClassVersionError,
-    unsupported_operation_exception::UnsupportedOperationException, verify_error::VerifyError, virtual_machine_error::VirtualMachineError,
+    no_such_field_exception::NoSuchFieldException, no_such_method_error::NoSuchMethodError, no_such_method_exception::NoSuchMethodException,
+    null_pointer_exception::NullPointerException, number::Number, number_format_exception::NumberFormatException, object::Object,
+    out_of_memory_error::OutOfMemoryError, runnable::Runnable, runtime::Runtime, runtime_exception::RuntimeException,
+    security_exception::SecurityException, short::Short, string::String, string_buffer::StringBuffer,
+    string_index_out_of_bounds_exception::StringIndexOutOfBoundsException, system::System, thread::Thread, thread_group::ThreadGroup,
+    thread_local::ThreadLocal, throwable::Throwable, unsatisfied_link_error::UnsatisfiedLinkError,
+    unsupported_class_version_error::UnsupportedClassVersionError, unsupported_operation_exception::UnsupportedOperationException,
+    verify_error::VerifyError, virtual_machine_error::VirtualMachineError, void::Void,
 };
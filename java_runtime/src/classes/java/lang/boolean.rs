@@ -125,7 +125,7 @@ impl Boolean {
     }
 
     async fn equals(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
-        if other.is_null() || !jvm.is_instance(&**other, "java/lang/Boolean") {
+        if other.is_null() || !jvm.is_instance(&other, "java/lang/Boolean") {
             return Ok(false);
         }
 
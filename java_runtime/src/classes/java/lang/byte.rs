@@ -1,9 +1,9 @@
-use alloc::{format, vec};
+use alloc::{boxed::Box, format, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::{ClassAccessFlags, FieldAccessFlags, MethodAccessFlags};
 use jvm::{
-    ClassInstanceRef, Jvm, Result,
+    ClassInstance, ClassInstanceRef, Jvm, Result,
     runtime::{JavaLangClass, JavaLangString},
 };
 
@@ -156,7 +156,7 @@ impl Byte {
     }
 
     async fn decode(jvm: &Jvm, _: &mut RuntimeContext, value: ClassInstanceRef<String>) -> Result<ClassInstanceRef<Self>> {
-        let integer = jvm
+        let integer: Box<dyn ClassInstance> = jvm
             .invoke_static("java/lang/Integer", "decode", "(Ljava/lang/String;)Ljava/lang/Integer;", (value,))
             .await?;
         let value: i32 = jvm.invoke_virtual(&integer, "intValue", "()I", ()).await?;
@@ -210,7 +210,7 @@ impl Byte {
     }
 
     async fn equals(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
-        if other.is_null() || !jvm.is_instance(&**other, "java/lang/Byte") {
+        if other.is_null() || !jvm.is_instance(&other, "java/lang/Byte") {
             return Ok(false);
         }
         let left: i8 = jvm.invoke_virtual(&this, "byteValue", "()B", ()).await?;
@@ -231,7 +231,7 @@ impl Byte {
         if other.is_null() {
             return Err(jvm.exception("java/lang/NullPointerException", "other").await);
         }
-        if !jvm.is_instance(&**other, "java/lang/Byte") {
+        if !jvm.is_instance(&other, "java/lang/Byte") {
             return Err(jvm.exception("java/lang/ClassCastException", "not Byte").await);
         }
         let other = ClassInstanceRef::<Self>::from(other.instance);
@@ -396,7 +396,7 @@ impl Character {
     }
 
     async fn equals(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
-        if other.is_null() || !jvm.is_instance(&**other, "java/lang/Character") {
+        if other.is_null() || !jvm.is_instance(&other, "java/lang/Character") {
             return Ok(false);
         }
 
@@ -409,7 +409,7 @@ impl Character {
         if other.is_null() {
             return Err(jvm.exception("java/lang/NullPointerException", "other").await);
         }
-        if !jvm.is_instance(&**other, "java/lang/Character") {
+        if !jvm.is_instance(&other, "java/lang/Character") {
             return Err(jvm.exception("java/lang/ClassCastException", "not Character").await);
         }
 
@@ -422,7 +422,7 @@ impl Character {
         if other.is_null() {
             return Err(jvm.exception("java/lang/NullPointerException", "other").await);
         }
-        if !jvm.is_instance(&**other, "java/lang/Character") {
+        if !jvm.is_instance(&other, "java/lang/Character") {
             return Err(jvm.exception("java/lang/ClassCastException", "not Character").await);
         }
 
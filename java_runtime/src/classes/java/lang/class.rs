@@ -1,9 +1,15 @@
-use alloc::vec;
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String as RustString, ToString},
+    vec,
+    vec::Vec,
+};
 
-use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use java_constants::{ClassAccessFlags, MethodAccessFlags};
+use java_class_proto::{JavaFieldProto, JavaMethodProto, java_class};
+use java_constants::{ClassAccessFlags, FieldAccessFlags, MethodAccessFlags};
 use jvm::{
-    ClassInstanceRef, JavaType, Jvm, Result,
+    Array, ClassDefinition, ClassInstanceRef, JavaClassName, JavaType, JavaValue, Jvm, Result,
     runtime::{JavaLangClass, JavaLangClassLoader, JavaLangString},
 };
 
@@ -11,17 +17,24 @@ use crate::{
     RuntimeClassProto, RuntimeContext,
     classes::java::{
         io::InputStream,
-        lang::{ClassLoader, Object, String},
+        lang::{
+            ClassLoader, Object, String,
+            reflect::{Constructor, Field, Method, Modifier},
+        },
     },
 };
 
+// (declaring class, member) pairs found by reflection
+type Members<T> = Vec<(Box<dyn ClassDefinition>, Box<T>)>;
+
 // class java.lang.Class
 pub struct Class;
 
+#[java_class("java/lang/Class")]
 impl Class {
     pub fn as_proto() -> RuntimeClassProto {
         RuntimeClassProto {
-            name: "java/lang/Class",
+            name: <Self as JavaClassName>::NAME,
             parent_class: Some("java/lang/Object"),
             interfaces: vec![],
             methods: vec![
@@ -34,6 +47,12 @@ impl Class {
                 JavaMethodProto::new("isAssignableFrom", "(Ljava/lang/Class;)Z", Self::is_assignable_from, Default::default()),
                 JavaMethodProto::new("newInstance", "()Ljava/lang/Object;", Self::new_instance, MethodAccessFlags::PUBLIC),
                 JavaMethodProto::new("toString", "()Ljava/lang/String;", Self::to_string, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new(
+                    "getClassLoader",
+                    "()Ljava/lang/ClassLoader;",
+                    Self::get_class_loader,
+                    MethodAccessFlags::PUBLIC,
+                ),
                 JavaMethodProto::new(
                     "getResourceAsStream",
                     "(Ljava/lang/String;)Ljava/io/InputStream;",
@@ -46,6 +65,82 @@ impl Class {
                     Self::for_name,
                     MethodAccessFlags::STATIC,
                 ),
+                JavaMethodProto::new("getModifiers", "()I", Self::get_modifiers, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new("getSuperclass", "()Ljava/lang/Class;", Self::get_superclass, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new("getInterfaces", "()[Ljava/lang/Class;", Self::get_interfaces, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new(
+                    "getComponentType",
+                    "()Ljava/lang/Class;",
+                    Self::get_component_type,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new(
+                    "getMethods",
+                    "()[Ljava/lang/reflect/Method;",
+                    Self::get_methods,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new(
+                    "getDeclaredMethods",
+                    "()[Ljava/lang/reflect/Method;",
+                    Self::get_declared_methods,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new(
+                    "getMethod",
+                    "(Ljava/lang/String;[Ljava/lang/Class;)Ljava/lang/reflect/Method;",
+                    Self::get_method,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new(
+                    "getDeclaredMethod",
+                    "(Ljava/lang/String;[Ljava/lang/Class;)Ljava/lang/reflect/Method;",
+                    Self::get_declared_method,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new("getFields", "()[Ljava/lang/reflect/Field;", Self::get_fields, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new(
+                    "getDeclaredFields",
+                    "()[Ljava/lang/reflect/Field;",
+                    Self::get_declared_fields,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new(
+                    "getField",
+                    "(Ljava/lang/String;)Ljava/lang/reflect/Field;",
+                    Self::get_field,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new(
+                    "getDeclaredField",
+                    "(Ljava/lang/String;)Ljava/lang/reflect/Field;",
+                    Self::get_declared_field,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new(
+                    "getConstructors",
+                    "()[Ljava/lang/reflect/Constructor;",
+                    Self::get_constructors,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new(
+                    "getDeclaredConstructors",
+                    "()[Ljava/lang/reflect/Constructor;",
+                    Self::get_declared_constructors,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new(
+                    "getConstructor",
+                    "([Ljava/lang/Class;)Ljava/lang/reflect/Constructor;",
+                    Self::get_constructor,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new(
+                    "getDeclaredConstructor",
+                    "([Ljava/lang/Class;)Ljava/lang/reflect/Constructor;",
+                    Self::get_declared_constructor,
+                    MethodAccessFlags::PUBLIC,
+                ),
             ],
             fields: vec![
                 // Stored as raw bytes instead of java/lang/String to avoid circular dependency:
@@ -76,10 +171,7 @@ impl Class {
 
     async fn is_primitive(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<bool> {
         let name = JavaLangClass::name(jvm, &this).await?;
-        Ok(matches!(
-            name.as_str(),
-            "boolean" | "byte" | "char" | "short" | "int" | "long" | "float" | "double"
-        ))
+        Ok(Self::is_primitive_name(&name))
     }
 
     async fn is_array(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<bool> {
@@ -89,7 +181,7 @@ impl Class {
 
     async fn is_interface(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<bool> {
         let name = JavaLangClass::name(jvm, &this).await?;
-        if name.starts_with('[') || matches!(name.as_str(), "boolean" | "byte" | "char" | "short" | "int" | "long" | "float" | "double") {
+        if name.starts_with('[') || Self::is_primitive_name(&name) {
             return Ok(false);
         }
 
@@ -103,16 +195,16 @@ impl Class {
         }
 
         let name = JavaLangClass::name(jvm, &this).await?;
-        if matches!(name.as_str(), "boolean" | "byte" | "char" | "short" | "int" | "long" | "float" | "double") {
+        if Self::is_primitive_name(&name) {
             return Ok(false);
         }
 
-        Ok(jvm.is_instance(&**object, &name))
+        Ok(jvm.is_instance(&object, &name))
     }
 
     async fn new_instance(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Object>> {
         let name = JavaLangClass::name(jvm, &this).await?;
-        if name.starts_with('[') || matches!(name.as_str(), "boolean" | "byte" | "char" | "short" | "int" | "long" | "float" | "double") {
+        if name.starts_with('[') || Self::is_primitive_name(&name) {
             return Err(jvm.exception("java/lang/InstantiationException", &name).await);
         }
 
@@ -125,7 +217,7 @@ impl Class {
             return Err(jvm.exception("java/lang/InstantiationException", &name).await);
         }
 
-        let instance = jvm.instantiate_class(&name).await?;
+        let instance = jvm.instantiate(&jvm.class_of(&*class).await?).await?;
         let _: () = jvm.invoke_special(&instance, &name, "<init>", "()V", ()).await?;
 
         Ok(instance.into())
@@ -133,7 +225,7 @@ impl Class {
 
     async fn to_string(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<String>> {
         let name = JavaLangClass::name(jvm, &this).await?;
-        let text = if matches!(name.as_str(), "boolean" | "byte" | "char" | "short" | "int" | "long" | "float" | "double") {
+        let text = if Self::is_primitive_name(&name) {
             name
         } else {
             let class = JavaLangClass::to_rust_class(jvm, &this).await?;
@@ -157,20 +249,21 @@ impl Class {
 
         let class_name = JavaLangClass::name(jvm, &this).await?;
         let other_name = JavaLangClass::name(jvm, &other).await?;
-        let class_is_primitive = matches!(
-            class_name.as_str(),
-            "boolean" | "byte" | "char" | "short" | "int" | "long" | "float" | "double"
-        );
-        let other_is_primitive = matches!(
-            other_name.as_str(),
-            "boolean" | "byte" | "char" | "short" | "int" | "long" | "float" | "double"
-        );
+        let class_is_primitive = Self::is_primitive_name(&class_name);
+        let other_is_primitive = Self::is_primitive_name(&other_name);
 
         if class_is_primitive || other_is_primitive {
             return Ok(class_name == other_name);
         }
 
-        Ok(jvm.is_type_assignable(&JavaType::from_class_name(&other_name), &JavaType::from_class_name(&class_name)))
+        Ok(jvm.is_type_assignable(&JavaType::from_class_name(&other_name)?, &JavaType::from_class_name(&class_name)?))
+    }
+
+    async fn get_class_loader(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<ClassLoader>> {
+        tracing::debug!("java.lang.Class::getClassLoader({this:?})");
+
+        // null for classes of the bootstrap loader
+        jvm.get_field(&this, "classLoader", "Ljava/lang/ClassLoader;").await
     }
 
     async fn get_resource_as_stream(
@@ -187,7 +280,7 @@ impl Class {
             // TODO ClassLoader.getSystemResourceAsStream?
             JavaLangClassLoader::get_system_class_loader(jvm).await?
         } else {
-            class_loader.into()
+            class_loader.try_into()?
         };
 
         jvm.invoke_virtual(&class_loader, "getResourceAsStream", "(Ljava/lang/String;)Ljava/io/InputStream;", (name,))
@@ -205,8 +298,500 @@ impl Class {
         let qualified_name = rust_name.replace('.', "/");
 
         match jvm.resolve_class(&qualified_name).await {
-            Ok(class) => Ok(class.java_class().into()),
+            Ok(class) => Ok(class.java_class()?.into()),
             Err(_) => Err(jvm.exception("java/lang/ClassNotFoundException", &rust_name).await),
         }
     }
+
+    async fn get_modifiers(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i32> {
+        tracing::debug!("java.lang.Class::getModifiers({this:?})");
+
+        let name = JavaLangClass::name(jvm, &this).await?;
+        // arrays and primitive types are public final abstract
+        if name.starts_with('[') || Self::is_primitive_name(&name) {
+            return Ok(0x0411);
+        }
+
+        let class = JavaLangClass::to_rust_class(jvm, &this).await?;
+        Ok(Modifier::from_class_flags(class.access_flags()))
+    }
+
+    async fn get_superclass(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Self>> {
+        tracing::debug!("java.lang.Class::getSuperclass({this:?})");
+
+        let Some(class) = Self::definition(jvm, &this).await? else {
+            return Ok(None.into());
+        };
+        if class.access_flags().contains(ClassAccessFlags::INTERFACE) {
+            return Ok(None.into());
+        }
+
+        match class.super_class_name() {
+            Some(x) => Ok(jvm.resolve_referenced_class(&*class, &x).await?.java_class()?.into()),
+            None => Ok(None.into()),
+        }
+    }
+
+    async fn get_interfaces(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Array<Self>>> {
+        tracing::debug!("java.lang.Class::getInterfaces({this:?})");
+
+        let name = JavaLangClass::name(jvm, &this).await?;
+        let interfaces = if name.starts_with('[') {
+            let mut interfaces = Vec::with_capacity(2);
+            for x in ["java/lang/Cloneable", "java/io/Serializable"] {
+                interfaces.push(jvm.resolve_class(x).await?.java_class()?);
+            }
+            interfaces
+        } else if let Some(class) = Self::definition(jvm, &this).await? {
+            let mut interfaces = Vec::new();
+            for x in class.interface_names() {
+                interfaces.push(jvm.resolve_referenced_class(&*class, &x).await?.java_class()?);
+            }
+            interfaces
+        } else {
+            Vec::new()
+        };
+
+        Self::to_array(jvm, "Ljava/lang/Class;", interfaces).await
+    }
+
+    async fn get_component_type(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Self>> {
+        tracing::debug!("java.lang.Class::getComponentType({this:?})");
+
+        let name = JavaLangClass::name(jvm, &this).await?;
+        match name.strip_prefix('[') {
+            Some(x) => Self::from_descriptor(jvm, x).await,
+            None => Ok(None.into()),
+        }
+    }
+
+    async fn get_methods(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Array<Method>>> {
+        tracing::debug!("java.lang.Class::getMethods({this:?})");
+
+        let methods = Self::methods(jvm, &this, false).await?;
+        Self::to_method_array(jvm, methods).await
+    }
+
+    async fn get_declared_methods(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Array<Method>>> {
+        tracing::debug!("java.lang.Class::getDeclaredMethods({this:?})");
+
+        let methods = Self::methods(jvm, &this, true).await?;
+        Self::to_method_array(jvm, methods).await
+    }
+
+    async fn get_method(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        name: ClassInstanceRef<String>,
+        parameter_types: ClassInstanceRef<Array<Self>>,
+    ) -> Result<ClassInstanceRef<Method>> {
+        tracing::debug!("java.lang.Class::getMethod({this:?}, {name:?}, {parameter_types:?})");
+
+        Self::find_method(jvm, &this, name, parameter_types, false).await
+    }
+
+    async fn get_declared_method(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        name: ClassInstanceRef<String>,
+        parameter_types: ClassInstanceRef<Array<Self>>,
+    ) -> Result<ClassInstanceRef<Method>> {
+        tracing::debug!("java.lang.Class::getDeclaredMethod({this:?}, {name:?}, {parameter_types:?})");
+
+        Self::find_method(jvm, &this, name, parameter_types, true).await
+    }
+
+    async fn get_fields(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Array<Field>>> {
+        tracing::debug!("java.lang.Class::getFields({this:?})");
+
+        let fields = Self::fields(jvm, &this, false).await?;
+        Self::to_field_array(jvm, fields).await
+    }
+
+    async fn get_declared_fields(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Array<Field>>> {
+        tracing::debug!("java.lang.Class::getDeclaredFields({this:?})");
+
+        let fields = Self::fields(jvm, &this, true).await?;
+        Self::to_field_array(jvm, fields).await
+    }
+
+    async fn get_field(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        name: ClassInstanceRef<String>,
+    ) -> Result<ClassInstanceRef<Field>> {
+        tracing::debug!("java.lang.Class::getField({this:?}, {name:?})");
+
+        Self::find_field(jvm, &this, name, false).await
+    }
+
+    async fn get_declared_field(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        name: ClassInstanceRef<String>,
+    ) -> Result<ClassInstanceRef<Field>> {
+        tracing::debug!("java.lang.Class::getDeclaredField({this:?}, {name:?})");
+
+        Self::find_field(jvm, &this, name, true).await
+    }
+
+    async fn get_constructors(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Array<Constructor>>> {
+        tracing::debug!("java.lang.Class::getConstructors({this:?})");
+
+        let constructors = Self::constructors(jvm, &this, false).await?;
+        Self::to_constructor_array(jvm, constructors).await
+    }
+
+    async fn get_declared_constructors(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+    ) -> Result<ClassInstanceRef<Array<Constructor>>> {
+        tracing::debug!("java.lang.Class::getDeclaredConstructors({this:?})");
+
+        let constructors = Self::constructors(jvm, &this, true).await?;
+        Self::to_constructor_array(jvm, constructors).await
+    }
+
+    async fn get_constructor(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        parameter_types: ClassInstanceRef<Array<Self>>,
+    ) -> Result<ClassInstanceRef<Constructor>> {
+        tracing::debug!("java.lang.Class::getConstructor({this:?}, {parameter_types:?})");
+
+        Self::find_constructor(jvm, &this, parameter_types, false).await
+    }
+
+    async fn get_declared_constructor(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        parameter_types: ClassInstanceRef<Array<Self>>,
+    ) -> Result<ClassInstanceRef<Constructor>> {
+        tracing::debug!("java.lang.Class::getDeclaredConstructor({this:?}, {parameter_types:?})");
+
+        Self::find_constructor(jvm, &this, parameter_types, true).await
+    }
+
+    async fn find_method(
+        jvm: &Jvm,
+        this: &ClassInstanceRef<Self>,
+        name: ClassInstanceRef<String>,
+        parameter_types: ClassInstanceRef<Array<Self>>,
+        declared: bool,
+    ) -> Result<ClassInstanceRef<Method>> {
+        if name.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "name").await);
+        }
+
+        let name = JavaLangString::to_rust_string(jvm, &name).await?;
+        let parameters = Self::parameter_types(jvm, parameter_types).await?;
+        let prefix = format!("({})", parameters.iter().map(|x| x.descriptor()).collect::<RustString>());
+
+        let methods = Self::methods(jvm, this, declared).await?;
+        if let Some((class, method)) = methods.into_iter().find(|(_, x)| x.name() == name && x.descriptor().starts_with(&prefix)) {
+            return Method::from_rust_method(jvm, &*class, &*method).await;
+        }
+
+        let class_name = JavaLangClass::name(jvm, this).await?;
+        let message = format!("{}.{name}({})", class_name.replace('/', "."), Method::parameter_list(&parameters));
+        Err(jvm.exception("java/lang/NoSuchMethodException", &message).await)
+    }
+
+    async fn find_field(jvm: &Jvm, this: &ClassInstanceRef<Self>, name: ClassInstanceRef<String>, declared: bool) -> Result<ClassInstanceRef<Field>> {
+        if name.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "name").await);
+        }
+
+        let name = JavaLangString::to_rust_string(jvm, &name).await?;
+
+        let fields = Self::fields(jvm, this, declared).await?;
+        if let Some((class, field)) = fields.into_iter().find(|(_, x)| x.name() == name) {
+            return Field::from_rust_field(jvm, &*class, &*field).await;
+        }
+
+        Err(jvm.exception("java/lang/NoSuchFieldException", &name).await)
+    }
+
+    async fn find_constructor(
+        jvm: &Jvm,
+        this: &ClassInstanceRef<Self>,
+        parameter_types: ClassInstanceRef<Array<Self>>,
+        declared: bool,
+    ) -> Result<ClassInstanceRef<Constructor>> {
+        let parameters = Self::parameter_types(jvm, parameter_types).await?;
+        let descriptor = JavaType::Method(parameters.clone(), Box::new(JavaType::Void)).descriptor();
+
+        let constructors = Self::constructors(jvm, this, declared).await?;
+        if let Some((class, constructor)) = constructors.into_iter().find(|(_, x)| x.descriptor() == descriptor) {
+            return Constructor::from_rust_method(jvm, &*class, &*constructor).await;
+        }
+
+        let class_name = JavaLangClass::name(jvm, this).await?;
+        let message = format!("{}.<init>({})", class_name.replace('/', "."), Method::parameter_list(&parameters));
+        Err(jvm.exception("java/lang/NoSuchMethodException", &message).await)
+    }
+
+    // <init> and <clinit> aren't methods to reflection
+    async fn methods(jvm: &Jvm, this: &ClassInstanceRef<Self>, declared: bool) -> Result<Members<dyn jvm::Method>> {
+        let Some(class) = Self::definition(jvm, this).await? else {
+            return Ok(Vec::new());
+        };
+        let classes = if declared { vec![class] } else { Self::hierarchy(jvm, class).await? };
+
+        let mut result: Members<dyn jvm::Method> = Vec::new();
+        for (index, class) in classes.iter().enumerate() {
+            let is_interface = class.access_flags().contains(ClassAccessFlags::INTERFACE);
+            for method in class.methods() {
+                if method.name().starts_with('<') {
+                    continue;
+                }
+
+                if !declared {
+                    // static methods of superinterfaces aren't inherited, and overridden methods are listed once
+                    let flags = MethodAccessFlags::from_bits_truncate(Self::member_flags(jvm, &**class, method.access_flags().bits()).await?);
+                    let inherited_static = index > 0 && is_interface && flags.contains(MethodAccessFlags::STATIC);
+                    let overridden = result
+                        .iter()
+                        .any(|(_, x)| x.name() == method.name() && x.descriptor() == method.descriptor());
+                    if !flags.contains(MethodAccessFlags::PUBLIC) || inherited_static || overridden {
+                        continue;
+                    }
+                }
+
+                result.push((class.clone(), method));
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn fields(jvm: &Jvm, this: &ClassInstanceRef<Self>, declared: bool) -> Result<Members<dyn jvm::Field>> {
+        let Some(class) = Self::definition(jvm, this).await? else {
+            return Ok(Vec::new());
+        };
+        let classes = if declared { vec![class] } else { Self::hierarchy(jvm, class).await? };
+
+        let mut result: Members<dyn jvm::Field> = Vec::new();
+        for class in classes {
+            for field in class.fields() {
+                let flags = FieldAccessFlags::from_bits_truncate(Self::member_flags(jvm, &*class, field.access_flags().bits()).await?);
+                if declared || flags.contains(FieldAccessFlags::PUBLIC) {
+                    result.push((class.clone(), field));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn constructors(jvm: &Jvm, this: &ClassInstanceRef<Self>, declared: bool) -> Result<Members<dyn jvm::Method>> {
+        let Some(class) = Self::definition(jvm, this).await? else {
+            return Ok(Vec::new());
+        };
+
+        let mut result: Members<dyn jvm::Method> = Vec::new();
+        for method in class.methods() {
+            if method.name() != "<init>" {
+                continue;
+            }
+
+            let flags = MethodAccessFlags::from_bits_truncate(Self::member_flags(jvm, &*class, method.access_flags().bits()).await?);
+            if declared || flags.contains(MethodAccessFlags::PUBLIC) {
+                result.push((class.clone(), method));
+            }
+        }
+
+        Ok(result)
+    }
+
+    // Runtime protos leave visibility out on most members, so members of bootstrap classes without any count as public.
+    // Synthetic ones are the runtime's internals. field and method flags share these bits
+    pub(crate) async fn member_flags(jvm: &Jvm, class: &dyn ClassDefinition, flags: u16) -> Result<u16> {
+        let explicit = MethodAccessFlags::PUBLIC | MethodAccessFlags::PRIVATE | MethodAccessFlags::PROTECTED | MethodAccessFlags::SYNTHETIC;
+        if flags & explicit.bits() != 0 {
+            return Ok(flags);
+        }
+
+        let clazz = jvm.class_of(class).await?.java_class()?;
+        let class_loader: ClassInstanceRef<ClassLoader> = jvm.get_field(&clazz, "classLoader", "Ljava/lang/ClassLoader;").await?;
+
+        Ok(if class_loader.is_null() {
+            flags | MethodAccessFlags::PUBLIC.bits()
+        } else {
+            flags
+        })
+    }
+
+    // the class, its superclasses and then all of their superinterfaces, each once
+    async fn hierarchy(jvm: &Jvm, class: Box<dyn ClassDefinition>) -> Result<Vec<Box<dyn ClassDefinition>>> {
+        let is_interface = class.access_flags().contains(ClassAccessFlags::INTERFACE);
+        let mut classes = vec![class];
+
+        // interfaces don't inherit java.lang.Object's members
+        if !is_interface {
+            let mut super_class_name = classes[0].super_class_name();
+            while let Some(x) = super_class_name {
+                let super_class = jvm.resolve_referenced_class(&*classes[classes.len() - 1], &x).await?.definition;
+                super_class_name = super_class.super_class_name();
+                classes.push(super_class);
+            }
+        }
+
+        let mut index = 0;
+        while index < classes.len() {
+            for x in classes[index].interface_names() {
+                if !classes.iter().any(|class| class.name() == x) {
+                    let interface = jvm.resolve_referenced_class(&*classes[index], &x).await?.definition;
+                    classes.push(interface);
+                }
+            }
+            index += 1;
+        }
+
+        Ok(classes)
+    }
+
+    // primitive types have no class definition
+    async fn definition(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> Result<Option<Box<dyn ClassDefinition>>> {
+        let name = JavaLangClass::name(jvm, this).await?;
+        if Self::is_primitive_name(&name) {
+            return Ok(None);
+        }
+
+        Ok(Some(JavaLangClass::to_rust_class(jvm, this).await?))
+    }
+
+    async fn parameter_types(jvm: &Jvm, parameter_types: ClassInstanceRef<Array<Self>>) -> Result<Vec<JavaType>> {
+        if parameter_types.is_null() {
+            return Ok(Vec::new());
+        }
+
+        let length = jvm.array_length(&parameter_types).await?;
+        let classes: Vec<ClassInstanceRef<Self>> = jvm.load_array(&parameter_types, 0, length).await?;
+
+        let mut types = Vec::with_capacity(length);
+        for class in classes {
+            if class.is_null() {
+                return Err(jvm.exception("java/lang/NullPointerException", "parameterTypes").await);
+            }
+            types.push(JavaType::parse(&Self::descriptor(jvm, &class).await?)?);
+        }
+
+        Ok(types)
+    }
+
+    async fn to_method_array(jvm: &Jvm, methods: Members<dyn jvm::Method>) -> Result<ClassInstanceRef<Array<Method>>> {
+        let mut result = Vec::with_capacity(methods.len());
+        for (class, method) in methods {
+            result.push(Method::from_rust_method(jvm, &*class, &*method).await?);
+        }
+
+        Self::to_array(jvm, "Ljava/lang/reflect/Method;", result).await
+    }
+
+    async fn to_field_array(jvm: &Jvm, fields: Members<dyn jvm::Field>) -> Result<ClassInstanceRef<Array<Field>>> {
+        let mut result = Vec::with_capacity(fields.len());
+        for (class, field) in fields {
+            result.push(Field::from_rust_field(jvm, &*class, &*field).await?);
+        }
+
+        Self::to_array(jvm, "Ljava/lang/reflect/Field;", result).await
+    }
+
+    async fn to_constructor_array(jvm: &Jvm, constructors: Members<dyn jvm::Method>) -> Result<ClassInstanceRef<Array<Constructor>>> {
+        let mut result = Vec::with_capacity(constructors.len());
+        for (class, constructor) in constructors {
+            result.push(Constructor::from_rust_method(jvm, &*class, &*constructor).await?);
+        }
+
+        Self::to_array(jvm, "Ljava/lang/reflect/Constructor;", result).await
+    }
+
+    async fn to_array<T, U>(jvm: &Jvm, element_type: &str, values: Vec<U>) -> Result<ClassInstanceRef<Array<T>>>
+    where
+        U: Into<JavaValue> + Send,
+    {
+        let mut array = jvm.instantiate_array(element_type, values.len()).await?;
+        jvm.store_array(&mut array, 0, values).await?;
+
+        Ok(array.into())
+    }
+
+    fn is_primitive_name(name: &str) -> bool {
+        matches!(name, "void" | "boolean" | "byte" | "char" | "short" | "int" | "long" | "float" | "double")
+    }
+
+    // the Class of a field descriptor; primitive types map to the wrappers' TYPE
+    pub(crate) async fn from_descriptor(jvm: &Jvm, descriptor: &str) -> Result<ClassInstanceRef<Self>> {
+        let wrapper = match descriptor {
+            "V" => "java/lang/Void",
+            "Z" => "java/lang/Boolean",
+            "B" => "java/lang/Byte",
+            "C" => "java/lang/Character",
+            "S" => "java/lang/Short",
+            "I" => "java/lang/Integer",
+            "J" => "java/lang/Long",
+            "F" => "java/lang/Float",
+            "D" => "java/lang/Double",
+            _ => {
+                let class_name = descriptor.strip_prefix('L').and_then(|x| x.strip_suffix(';')).unwrap_or(descriptor);
+                return Ok(jvm.resolve_class(class_name).await?.java_class()?.into());
+            }
+        };
+
+        jvm.get_static_field(wrapper, "TYPE", "Ljava/lang/Class;").await
+    }
+
+    pub(crate) async fn descriptor(jvm: &Jvm, class: &ClassInstanceRef<Self>) -> Result<RustString> {
+        let name = JavaLangClass::name(jvm, class).await?;
+        let descriptor = match name.as_str() {
+            "void" => "V",
+            "boolean" => "Z",
+            "byte" => "B",
+            "char" => "C",
+            "short" => "S",
+            "int" => "I",
+            "long" => "J",
+            "float" => "F",
+            "double" => "D",
+            _ if name.starts_with('[') => return Ok(name),
+            _ => return Ok(format!("L{name};")),
+        };
+
+        Ok(descriptor.to_string())
+    }
+
+    // as Class.getTypeName
+    pub(crate) fn type_name(r#type: &JavaType) -> RustString {
+        match r#type {
+            JavaType::Void => "void".to_string(),
+            JavaType::Boolean => "boolean".to_string(),
+            JavaType::Byte => "byte".to_string(),
+            JavaType::Char => "char".to_string(),
+            JavaType::Short => "short".to_string(),
+            JavaType::Int => "int".to_string(),
+            JavaType::Long => "long".to_string(),
+            JavaType::Float => "float".to_string(),
+            JavaType::Double => "double".to_string(),
+            JavaType::Class(x) => x.replace('/', "."),
+            JavaType::Array(x) => format!("{}[]", Self::type_name(x)),
+            JavaType::Method(_, _) => r#type.descriptor(),
+        }
+    }
+
+    pub(crate) async fn to_class_array(jvm: &Jvm, types: &[JavaType]) -> Result<ClassInstanceRef<Array<Self>>> {
+        let mut classes = Vec::with_capacity(types.len());
+        for x in types {
+            classes.push(Self::from_descriptor(jvm, &x.descriptor()).await?);
+        }
+
+        Self::to_array(jvm, "Ljava/lang/Class;", classes).await
+    }
 }
@@ -12,7 +12,7 @@ impl ClassCastException {
     pub fn as_proto() -> RuntimeClassProto {
         RuntimeClassProto {
             name: "java/lang/ClassCastException",
-            parent_class: Some("java/lang/Exception"),
+            parent_class: Some("java/lang/RuntimeException"),
             interfaces: vec![],
             methods: vec![
                 JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
@@ -26,7 +26,7 @@ impl ClassCastException {
     async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.lang.ClassCastException::<init>({this:?})");
 
-        let _: () = jvm.invoke_special(&this, "java/lang/Exception", "<init>", "()V", ()).await?;
+        let _: () = jvm.invoke_special(&this, "java/lang/RuntimeException", "<init>", "()V", ()).await?;
 
         Ok(())
     }
@@ -35,7 +35,7 @@ impl ClassCastException {
         tracing::debug!("java.lang.ClassCastException::<init>({this:?}, {message:?})");
 
         let _: () = jvm
-            .invoke_special(&this, "java/lang/Exception", "<init>", "(Ljava/lang/String;)V", (message,))
+            .invoke_special(&this, "java/lang/RuntimeException", "<init>", "(Ljava/lang/String;)V", (message,))
             .await?;
 
         Ok(())
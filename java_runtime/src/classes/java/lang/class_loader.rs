@@ -2,7 +2,10 @@ use alloc::{format, vec, vec::Vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::{FieldAccessFlags, MethodAccessFlags};
-use jvm::{Array, ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
+use jvm::{
+    Array, ClassInstanceRef, JavaError, Jvm, Result,
+    runtime::{JavaLangClassLoader, JavaLangString},
+};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -22,8 +25,17 @@ impl ClassLoader {
             parent_class: Some("java/lang/Object"),
             interfaces: vec![],
             methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init_system_parent, Default::default()),
                 JavaMethodProto::new("<init>", "(Ljava/lang/ClassLoader;)V", Self::init, Default::default()),
+                JavaMethodProto::new("getParent", "()Ljava/lang/ClassLoader;", Self::get_parent, Default::default()),
                 JavaMethodProto::new("loadClass", "(Ljava/lang/String;)Ljava/lang/Class;", Self::load_class, Default::default()),
+                JavaMethodProto::new(
+                    "loadClass",
+                    "(Ljava/lang/String;Z)Ljava/lang/Class;",
+                    Self::load_class_resolve,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("resolveClass", "(Ljava/lang/Class;)V", Self::resolve_class, Default::default()),
                 JavaMethodProto::new("findClass", "(Ljava/lang/String;)Ljava/lang/Class;", Self::find_class, Default::default()),
                 JavaMethodProto::new(
                     "findLoadedClass",
@@ -61,6 +73,7 @@ impl ClassLoader {
                     Self::define_class,
                     Default::default(),
                 ),
+                JavaMethodProto::new("defineClass", "([BII)Ljava/lang/Class;", Self::define_class_unnamed, Default::default()),
             ],
             fields: vec![
                 JavaFieldProto::new("systemClassLoader", "Ljava/lang/ClassLoader;", FieldAccessFlags::STATIC),
@@ -80,6 +93,21 @@ impl ClassLoader {
         Ok(())
     }
 
+    async fn init_system_parent(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        tracing::debug!("java.lang.ClassLoader::<init>({this:?})");
+
+        let parent = JavaLangClassLoader::get_system_class_loader(jvm).await?;
+
+        jvm.invoke_special(&this, "java/lang/ClassLoader", "<init>", "(Ljava/lang/ClassLoader;)V", (parent,))
+            .await
+    }
+
+    async fn get_parent(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Self>> {
+        tracing::debug!("java.lang.ClassLoader::getParent({this:?})");
+
+        jvm.get_field(&this, "parent", "Ljava/lang/ClassLoader;").await
+    }
+
     async fn get_system_class_loader(jvm: &Jvm, _: &mut RuntimeContext) -> Result<ClassInstanceRef<Self>> {
         tracing::debug!("java.lang.ClassLoader::getSystemClassLoader()");
 
@@ -171,12 +199,27 @@ impl ClassLoader {
 
     async fn load_class(
         jvm: &Jvm,
-        runtime: &mut RuntimeContext,
+        _: &mut RuntimeContext,
         this: ClassInstanceRef<Self>,
         name: ClassInstanceRef<String>,
     ) -> Result<ClassInstanceRef<Class>> {
         tracing::debug!("java.lang.ClassLoader::loadClass({this:?}, {name:?})");
 
+        // through loadClass(String, boolean), which subclasses may override
+        jvm.invoke_virtual(&this, "loadClass", "(Ljava/lang/String;Z)Ljava/lang/Class;", (name, false))
+            .await
+    }
+
+    async fn load_class_resolve(
+        jvm: &Jvm,
+        runtime: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        name: ClassInstanceRef<String>,
+        resolve: bool,
+    ) -> Result<ClassInstanceRef<Class>> {
+        tracing::debug!("java.lang.ClassLoader::loadClass({this:?}, {name:?}, {resolve})");
+
+        // `resolve` needs no work, as classes are linked when they're registered
         let class: ClassInstanceRef<Class> = jvm
             .invoke_virtual(&this, "findLoadedClass", "(Ljava/lang/String;)Ljava/lang/Class;", (name.clone(),))
             .await?;
@@ -190,17 +233,22 @@ impl ClassLoader {
         if let Some(element_type_name) = name_str.strip_prefix('[') {
             // TODO do we need another class loader for array?
             let class = runtime.define_array_class(jvm, element_type_name).await?;
-            let java_class = jvm.register_class(class, Some(this.into())).await?;
+            let java_class = jvm.register_class(class, Some(this.try_into()?)).await?;
 
             return Ok(java_class.into());
         }
 
         let parent: ClassInstanceRef<Self> = jvm.get_field(&this, "parent", "Ljava/lang/ClassLoader;").await?;
         let class: ClassInstanceRef<Class> = if !parent.is_null() {
-            jvm.invoke_virtual(&parent, "loadClass", "(Ljava/lang/String;)Ljava/lang/Class;", (name.clone(),))
-                .await?
+            let result = jvm
+                .invoke_virtual(&parent, "loadClass", "(Ljava/lang/String;)Ljava/lang/Class;", (name.clone(),))
+                .await;
+            match result {
+                Err(JavaError::JavaException(x)) if jvm.is_instance(&*x, "java/lang/ClassNotFoundException") => None.into(),
+                x => x?,
+            }
         } else {
-            jvm.load_bootstrap_class(&name_str).await?.into()
+            jvm.load_bootstrap_class(&name_str.replace('.', "/")).await?.into()
         };
 
         if !class.is_null() {
@@ -222,7 +270,9 @@ impl ClassLoader {
     ) -> Result<ClassInstanceRef<Class>> {
         tracing::debug!("java.lang.ClassLoader::findClass({this:?}, {name:?})");
 
-        Err(jvm.exception("java/lang/ClassNotFoundException", "class not found").await)
+        let name = JavaLangString::to_rust_string(jvm, &name).await?;
+
+        Err(jvm.exception("java/lang/ClassNotFoundException", &name).await)
     }
 
     async fn find_loaded_class(
@@ -233,14 +283,10 @@ impl ClassLoader {
     ) -> Result<ClassInstanceRef<Class>> {
         tracing::debug!("java.lang.ClassLoader::findLoadedClass({this:?}, {name:?})");
 
-        let rust_name = JavaLangString::to_rust_string(jvm, &name).await?;
-        if !jvm.has_class(&rust_name) {
-            return Ok(None.into());
-        }
-
-        let class = jvm.resolve_class(&rust_name).await?;
+        let rust_name = JavaLangString::to_rust_string(jvm, &name).await?.replace('.', "/");
+        let class = jvm.loaded_class(Some(&this.try_into()?), &rust_name);
 
-        Ok(class.java_class().into())
+        Ok(class.map(|x| x.java_class()).transpose()?.into())
     }
 
     async fn get_resource(
@@ -326,8 +372,66 @@ impl ClassLoader {
         jvm.array_raw_buffer(&bytes).await?.read(offset as _, &mut data)?;
 
         let class = runtime.define_class(jvm, &data).await?;
-        let java_class = jvm.register_class(class, Some(this.into())).await?;
+        let class_name = class.name();
+
+        if !name.is_null() {
+            let name = JavaLangString::to_rust_string(jvm, &name).await?.replace('.', "/");
+            if name != class_name {
+                return Err(jvm
+                    .exception("java/lang/NoClassDefFoundError", &format!("{name} (wrong name: {class_name})"))
+                    .await);
+            }
+        }
+
+        let loaded: ClassInstanceRef<Class> = jvm
+            .invoke_virtual(
+                &this,
+                "findLoadedClass",
+                "(Ljava/lang/String;)Ljava/lang/Class;",
+                (JavaLangString::from_rust_string(jvm, &class_name).await?,),
+            )
+            .await?;
+        if !loaded.is_null() {
+            return Err(jvm
+                .exception(
+                    "java/lang/LinkageError",
+                    &format!("attempted duplicate class definition for name: \"{class_name}\""),
+                )
+                .await);
+        }
+
+        let java_class = jvm.register_class(class, Some(this.try_into()?)).await?;
 
         Ok(java_class.into())
     }
+
+    async fn define_class_unnamed(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        bytes: ClassInstanceRef<Array<i8>>,
+        offset: i32,
+        length: i32,
+    ) -> Result<ClassInstanceRef<Class>> {
+        tracing::debug!("java.lang.ClassLoader::defineClass({this:?}, {bytes:?}, {offset:?}, {length:?})");
+
+        jvm.invoke_virtual(
+            &this,
+            "defineClass",
+            "(Ljava/lang/String;[BII)Ljava/lang/Class;",
+            (None, bytes, offset, length),
+        )
+        .await
+    }
+
+    async fn resolve_class(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, class: ClassInstanceRef<Class>) -> Result<()> {
+        tracing::debug!("java.lang.ClassLoader::resolveClass({this:?}, {class:?})");
+
+        if class.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "class").await);
+        }
+
+        // already linked on registration
+        Ok(())
+    }
 }
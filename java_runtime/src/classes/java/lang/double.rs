@@ -329,7 +329,7 @@ impl Double {
     }
 
     async fn equals(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
-        if other.is_null() || !jvm.is_instance(&**other, "java/lang/Double") {
+        if other.is_null() || !jvm.is_instance(&other, "java/lang/Double") {
             return Ok(false);
         }
         let left: f64 = jvm.invoke_virtual(&this, "doubleValue", "()D", ()).await?;
@@ -360,7 +360,7 @@ impl Double {
         if other.is_null() {
             return Err(jvm.exception("java/lang/NullPointerException", "other").await);
         }
-        if !jvm.is_instance(&**other, "java/lang/Double") {
+        if !jvm.is_instance(&other, "java/lang/Double") {
             return Err(jvm.exception("java/lang/ClassCastException", "not Double").await);
         }
         let other = ClassInstanceRef::<Self>::from(other.instance);
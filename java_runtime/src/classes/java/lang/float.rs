@@ -334,7 +334,7 @@ impl Float {
     }
 
     async fn equals(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
-        if other.is_null() || !jvm.is_instance(&**other, "java/lang/Float") {
+        if other.is_null() || !jvm.is_instance(&other, "java/lang/Float") {
             return Ok(false);
         }
         let left: f32 = jvm.invoke_virtual(&this, "floatValue", "()F", ()).await?;
@@ -365,7 +365,7 @@ impl Float {
         if other.is_null() {
             return Err(jvm.exception("java/lang/NullPointerException", "other").await);
         }
-        if !jvm.is_instance(&**other, "java/lang/Float") {
+        if !jvm.is_instance(&other, "java/lang/Float") {
             return Err(jvm.exception("java/lang/ClassCastException", "not Float").await);
         }
         let other = ClassInstanceRef::<Self>::from(other.instance);
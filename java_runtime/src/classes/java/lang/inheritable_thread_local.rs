@@ -0,0 +1,50 @@
+use alloc::vec;
+
+use java_class_proto::JavaMethodProto;
+use jvm::{ClassInstanceRef, Jvm, Result};
+
+use crate::{RuntimeClassProto, RuntimeContext, classes::java::lang::Object};
+
+// class java.lang.InheritableThreadLocal
+// Thread.start copies the starting thread's values into the new thread, through childValue
+pub struct InheritableThreadLocal;
+
+impl InheritableThreadLocal {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/lang/InheritableThreadLocal",
+            parent_class: Some("java/lang/ThreadLocal"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                JavaMethodProto::new(
+                    "childValue",
+                    "(Ljava/lang/Object;)Ljava/lang/Object;",
+                    Self::child_value,
+                    Default::default(),
+                ),
+            ],
+            fields: vec![],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        tracing::debug!("java.lang.InheritableThreadLocal::<init>({this:?})");
+
+        let _: () = jvm.invoke_special(&this, "java/lang/ThreadLocal", "<init>", "()V", ()).await?;
+
+        Ok(())
+    }
+
+    async fn child_value(
+        _: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        parent_value: ClassInstanceRef<Object>,
+    ) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.lang.InheritableThreadLocal::childValue({this:?}, {parent_value:?})");
+
+        Ok(parent_value)
+    }
+}
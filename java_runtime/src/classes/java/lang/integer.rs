@@ -158,11 +158,10 @@ impl Integer {
         jvm.put_field(&mut this, "value", "I", value).await
     }
 
-    async fn init_string(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, value: ClassInstanceRef<String>) -> Result<()> {
-        if value.is_null() {
+    async fn init_string(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, value: Option<RustString>) -> Result<()> {
+        let Some(value) = value else {
             return Err(jvm.exception("java/lang/NumberFormatException", "null").await);
-        }
-        let value = JavaLangString::to_rust_string(jvm, &value).await?;
+        };
         let value = Self::parse_value(jvm, &value, 10).await?;
         let mut this = this;
         let _: () = jvm.invoke_special(&this, "java/lang/Number", "<init>", "()V", ()).await?;
@@ -214,19 +213,17 @@ impl Integer {
         }
     }
 
-    async fn parse_int(jvm: &Jvm, _: &mut RuntimeContext, value: ClassInstanceRef<String>) -> Result<i32> {
-        if value.is_null() {
+    async fn parse_int(jvm: &Jvm, _: &mut RuntimeContext, value: Option<RustString>) -> Result<i32> {
+        let Some(value) = value else {
             return Err(jvm.exception("java/lang/NumberFormatException", "null").await);
-        }
-        let value = JavaLangString::to_rust_string(jvm, &value).await?;
+        };
         Self::parse_value(jvm, &value, 10).await
     }
 
-    async fn parse_int_radix(jvm: &Jvm, _: &mut RuntimeContext, value: ClassInstanceRef<String>, radix: i32) -> Result<i32> {
-        if value.is_null() {
+    async fn parse_int_radix(jvm: &Jvm, _: &mut RuntimeContext, value: Option<RustString>, radix: i32) -> Result<i32> {
+        let Some(value) = value else {
             return Err(jvm.exception("java/lang/NumberFormatException", "null").await);
-        }
-        let value = JavaLangString::to_rust_string(jvm, &value).await?;
+        };
         Self::parse_value(jvm, &value, radix as u32).await
     }
 
@@ -234,29 +231,26 @@ impl Integer {
         Ok(jvm.new_class("java/lang/Integer", "(I)V", (value,)).await?.into())
     }
 
-    async fn value_of_string(jvm: &Jvm, _: &mut RuntimeContext, value: ClassInstanceRef<String>) -> Result<ClassInstanceRef<Self>> {
-        if value.is_null() {
+    async fn value_of_string(jvm: &Jvm, _: &mut RuntimeContext, value: Option<RustString>) -> Result<ClassInstanceRef<Self>> {
+        let Some(value) = value else {
             return Err(jvm.exception("java/lang/NumberFormatException", "null").await);
-        }
-        let value = JavaLangString::to_rust_string(jvm, &value).await?;
+        };
         let value = Self::parse_value(jvm, &value, 10).await?;
         Ok(jvm.new_class("java/lang/Integer", "(I)V", (value,)).await?.into())
     }
 
-    async fn value_of_string_radix(jvm: &Jvm, _: &mut RuntimeContext, value: ClassInstanceRef<String>, radix: i32) -> Result<ClassInstanceRef<Self>> {
-        if value.is_null() {
+    async fn value_of_string_radix(jvm: &Jvm, _: &mut RuntimeContext, value: Option<RustString>, radix: i32) -> Result<ClassInstanceRef<Self>> {
+        let Some(value) = value else {
             return Err(jvm.exception("java/lang/NumberFormatException", "null").await);
-        }
-        let value = JavaLangString::to_rust_string(jvm, &value).await?;
+        };
         let value = Self::parse_value(jvm, &value, radix as u32).await?;
         Ok(jvm.new_class("java/lang/Integer", "(I)V", (value,)).await?.into())
     }
 
-    async fn decode(jvm: &Jvm, _: &mut RuntimeContext, value: ClassInstanceRef<String>) -> Result<ClassInstanceRef<Self>> {
-        if value.is_null() {
+    async fn decode(jvm: &Jvm, _: &mut RuntimeContext, value: Option<RustString>) -> Result<ClassInstanceRef<Self>> {
+        let Some(value) = value else {
             return Err(jvm.exception("java/lang/NumberFormatException", "null").await);
-        }
-        let value = JavaLangString::to_rust_string(jvm, &value).await?;
+        };
         let (sign, body) = if let Some(body) = value.strip_prefix('-') {
             ("-", body)
         } else if let Some(body) = value.strip_prefix('+') {
@@ -421,7 +415,7 @@ impl Integer {
     }
 
     async fn equals(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
-        if other.is_null() || !jvm.is_instance(&**other, "java/lang/Integer") {
+        if other.is_null() || !jvm.is_instance(&other, "java/lang/Integer") {
             return Ok(false);
         }
         let this_value: i32 = jvm.invoke_virtual(&this, "intValue", "()I", ()).await?;
@@ -442,7 +436,7 @@ impl Integer {
         if other.is_null() {
             return Err(jvm.exception("java/lang/NullPointerException", "other").await);
         }
-        if !jvm.is_instance(&**other, "java/lang/Integer") {
+        if !jvm.is_instance(&other, "java/lang/Integer") {
             return Err(jvm.exception("java/lang/ClassCastException", "java/lang/Object is not Integer").await);
         }
         let other = ClassInstanceRef::<Self>::from(other.instance);
@@ -187,11 +187,10 @@ impl Long {
         }
         Some(if negative { result as i64 } else { -result as i64 })
     }
-    async fn parse_long(jvm: &Jvm, _: &mut RuntimeContext, value: ClassInstanceRef<String>) -> Result<i64> {
-        if value.is_null() {
+    async fn parse_long(jvm: &Jvm, _: &mut RuntimeContext, value: Option<RustString>) -> Result<i64> {
+        let Some(value) = value else {
             return Err(jvm.exception("java/lang/NumberFormatException", "null").await);
-        }
-        let value = JavaLangString::to_rust_string(jvm, &value).await?;
+        };
         match Self::parse_raw(&value, 10) {
             Some(value) => Ok(value),
             None => Err(jvm
@@ -199,11 +198,10 @@ impl Long {
                 .await),
         }
     }
-    async fn parse_long_radix(jvm: &Jvm, _: &mut RuntimeContext, value: ClassInstanceRef<String>, radix: i32) -> Result<i64> {
-        if value.is_null() {
+    async fn parse_long_radix(jvm: &Jvm, _: &mut RuntimeContext, value: Option<RustString>, radix: i32) -> Result<i64> {
+        let Some(value) = value else {
             return Err(jvm.exception("java/lang/NumberFormatException", "null").await);
-        }
-        let value = JavaLangString::to_rust_string(jvm, &value).await?;
+        };
         match Self::parse_raw(&value, radix as u32) {
             Some(value) => Ok(value),
             None => Err(jvm
@@ -223,11 +221,10 @@ impl Long {
             .await?;
         Ok(jvm.new_class("java/lang/Long", "(J)V", (value,)).await?.into())
     }
-    async fn decode(jvm: &Jvm, _: &mut RuntimeContext, value: ClassInstanceRef<String>) -> Result<ClassInstanceRef<Self>> {
-        if value.is_null() {
+    async fn decode(jvm: &Jvm, _: &mut RuntimeContext, value: Option<RustString>) -> Result<ClassInstanceRef<Self>> {
+        let Some(value) = value else {
             return Err(jvm.exception("java/lang/NumberFormatException", "null").await);
-        }
-        let value = JavaLangString::to_rust_string(jvm, &value).await?;
+        };
         let (sign, body) = if let Some(body) = value.strip_prefix('-') {
             ("-", body)
         } else if let Some(body) = value.strip_prefix('+') {
@@ -382,7 +379,7 @@ impl Long {
         Ok((value ^ (value >> 32)) as i32)
     }
     async fn equals(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
-        if other.is_null() || !jvm.is_instance(&**other, "java/lang/Long") {
+        if other.is_null() || !jvm.is_instance(&other, "java/lang/Long") {
             return Ok(false);
         }
         Ok(
@@ -402,7 +399,7 @@ impl Long {
         if other.is_null() {
             return Err(jvm.exception("java/lang/NullPointerException", "other").await);
         }
-        if !jvm.is_instance(&**other, "java/lang/Long") {
+        if !jvm.is_instance(&other, "java/lang/Long") {
             return Err(jvm.exception("java/lang/ClassCastException", "not Long").await);
         }
         let other = ClassInstanceRef::<Self>::from(other.instance);
@@ -6,9 +6,9 @@ use core::{
 use alloc::{boxed::Box, format, vec};
 
 use dyn_clone::clone_box;
-use java_class_proto::JavaMethodProto;
-use java_constants::MethodAccessFlags;
-use jvm::{ClassInstance, ClassInstanceRef, Jvm, MonitorWaitTimeout, Result, runtime::JavaLangString};
+use java_class_proto::{JavaMethodProto, java_class};
+use java_constants::{ClassAccessFlags, MethodAccessFlags};
+use jvm::{ClassInstance, ClassInstanceRef, JavaClassName, Jvm, MonitorWaitTimeout, Result, runtime::JavaLangString};
 
 use crate::{Runtime, RuntimeClassProto, RuntimeContext, SpawnCallback, classes::java::lang::String};
 
@@ -36,45 +36,67 @@ impl Hasher for IdentityHasher {
     }
 }
 
+#[java_class("java/lang/Object")]
 impl Object {
     pub fn as_proto() -> RuntimeClassProto {
         RuntimeClassProto {
-            name: "java/lang/Object",
+            name: <Self as JavaClassName>::NAME,
             parent_class: None,
             interfaces: vec![],
             methods: vec![
-                JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
-                JavaMethodProto::new("getClass", "()Ljava/lang/Class;", Self::get_class, Default::default()),
-                JavaMethodProto::new("hashCode", "()I", Self::hash_code, Default::default()),
-                JavaMethodProto::new("equals", "(Ljava/lang/Object;)Z", Self::equals, Default::default()),
-                JavaMethodProto::new("clone", "()Ljava/lang/Object;", Self::clone, MethodAccessFlags::NATIVE),
-                JavaMethodProto::new("toString", "()Ljava/lang/String;", Self::to_string, Default::default()),
-                JavaMethodProto::new("notify", "()V", Self::notify, Default::default()),
-                JavaMethodProto::new("notifyAll", "()V", Self::notify_all, Default::default()),
-                JavaMethodProto::new("wait", "(J)V", Self::wait_long, Default::default()),
-                JavaMethodProto::new("wait", "(JI)V", Self::wait_long_int, Default::default()),
-                JavaMethodProto::new("wait", "()V", Self::wait, Default::default()),
-                JavaMethodProto::new("finalize", "()V", Self::finalize, Default::default()),
+                JavaMethodProto::new("<init>", "()V", Self::init, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new(
+                    "getClass",
+                    "()Ljava/lang/Class;",
+                    Self::get_class,
+                    MethodAccessFlags::PUBLIC | MethodAccessFlags::FINAL,
+                ),
+                JavaMethodProto::new("hashCode", "()I", Self::hash_code, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new("equals", "(Ljava/lang/Object;)Z", Self::equals, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new(
+                    "clone",
+                    "()Ljava/lang/Object;",
+                    Self::clone,
+                    MethodAccessFlags::PROTECTED | MethodAccessFlags::NATIVE,
+                ),
+                JavaMethodProto::new("toString", "()Ljava/lang/String;", Self::to_string, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new("notify", "()V", Self::notify, MethodAccessFlags::PUBLIC | MethodAccessFlags::FINAL),
+                JavaMethodProto::new("notifyAll", "()V", Self::notify_all, MethodAccessFlags::PUBLIC | MethodAccessFlags::FINAL),
+                JavaMethodProto::new("wait", "(J)V", Self::wait_long, MethodAccessFlags::PUBLIC | MethodAccessFlags::FINAL),
+                JavaMethodProto::new("wait", "(JI)V", Self::wait_long_int, MethodAccessFlags::PUBLIC | MethodAccessFlags::FINAL),
+                JavaMethodProto::new("wait", "()V", Self::wait, MethodAccessFlags::PUBLIC | MethodAccessFlags::FINAL),
+                JavaMethodProto::new("finalize", "()V", Self::finalize, MethodAccessFlags::PROTECTED),
             ],
             fields: vec![],
-            access_flags: Default::default(),
+            access_flags: ClassAccessFlags::PUBLIC,
         }
     }
 
-    async fn init(_: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+    async fn init(jvm: &Jvm, context: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.lang.Object::<init>({this:?})");
 
+        if jvm.register_finalizer(&this) && jvm.claim_finalizer_thread() {
+            context.spawn(jvm, Box::new(FinalizerThread { jvm: jvm.clone() }));
+        }
+
         Ok(())
     }
 
+    // runs finalize() on the object, ignoring any exception it throws (JLS 12.6)
+    pub async fn run_finalizer(jvm: &Jvm, object: Box<dyn ClassInstance>) {
+        tracing::trace!("Running finalizer of {}", object.class_definition().name());
+
+        let result: Result<()> = jvm.invoke_virtual(&object, "finalize", "()V", ()).await;
+        if let Err(error) = result {
+            tracing::debug!(?error, "exception in finalizer ignored");
+        }
+    }
+
     async fn get_class(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Self>> {
         tracing::debug!("java.lang.Object::getClass({this:?})");
 
-        // TODO can we get class directly?
-        let this: Box<dyn ClassInstance> = this.into();
-        let class_name = this.class_definition().name();
-
-        let class = jvm.resolve_class(&class_name).await?.java_class();
+        let this: Box<dyn ClassInstance> = this.try_into()?;
+        let class = jvm.class_of(&*this.class_definition()).await?.java_class()?;
 
         Ok(class.into())
     }
@@ -82,7 +104,7 @@ impl Object {
     async fn hash_code(_: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i32> {
         tracing::debug!("java.lang.Object::hashCode({this:?})");
 
-        let rust_this: Box<dyn ClassInstance> = this.into();
+        let rust_this: Box<dyn ClassInstance> = this.try_into()?;
 
         let mut hasher = IdentityHasher::default();
         rust_this.hash(&mut hasher);
@@ -98,8 +120,8 @@ impl Object {
             return Ok(false);
         }
 
-        let rust_this: Box<dyn ClassInstance> = this.into();
-        let rust_other: Box<dyn ClassInstance> = other.into();
+        let rust_this: Box<dyn ClassInstance> = this.try_into()?;
+        let rust_other: Box<dyn ClassInstance> = other.try_into()?;
 
         rust_this.equals(&*rust_other)
     }
@@ -107,7 +129,7 @@ impl Object {
     async fn clone(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Self>> {
         tracing::debug!("java.lang.Object::clone({this:?})");
 
-        if !jvm.is_instance(&**this, "java/lang/Cloneable") {
+        if !jvm.is_instance(&this, "java/lang/Cloneable") {
             return Err(jvm.exception("java/lang/CloneNotSupportedException", "Cannot clone this object").await);
         }
 
@@ -117,8 +139,8 @@ impl Object {
     async fn to_string(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<String>> {
         tracing::debug!("java.lang.Object::toString({this:?})");
 
-        let class = jvm.invoke_virtual(&this, "getClass", "()Ljava/lang/Class;", ()).await?;
-        let class_name = jvm.invoke_virtual(&class, "getName", "()Ljava/lang/String;", ()).await?;
+        let class: Box<dyn ClassInstance> = jvm.invoke_virtual(&this, "getClass", "()Ljava/lang/Class;", ()).await?;
+        let class_name: Box<dyn ClassInstance> = jvm.invoke_virtual(&class, "getName", "()Ljava/lang/String;", ()).await?;
         let class_name_rust = JavaLangString::to_rust_string(jvm, &class_name).await?;
 
         let hash_code: i32 = jvm.invoke_virtual(&this, "hashCode", "()I", ()).await?;
@@ -201,8 +223,26 @@ impl Object {
     }
 
     async fn finalize(_: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
-        tracing::warn!("stub java.lang.Object::finalize({this:?})");
+        tracing::debug!("java.lang.Object::finalize({this:?})");
 
         Ok(())
     }
 }
+
+struct FinalizerThread {
+    jvm: Jvm,
+}
+
+#[async_trait::async_trait]
+impl SpawnCallback for FinalizerThread {
+    #[tracing::instrument(name = "finalizer thread", skip_all)]
+    async fn call(&self) -> Result<()> {
+        self.jvm.attach_thread(None).await?;
+
+        loop {
+            let _frame = self.jvm.push_native_frame()?;
+            let object = self.jvm.next_finalizable().await;
+            Object::run_finalizer(&self.jvm, object).await;
+        }
+    }
+}
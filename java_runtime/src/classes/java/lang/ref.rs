@@ -0,0 +1,10 @@
+mod phantom_reference;
+mod reference;
+mod reference_queue;
+mod soft_reference;
+mod weak_reference;
+
+pub use {
+    phantom_reference::PhantomReference, reference::Reference, reference_queue::ReferenceQueue, soft_reference::SoftReference,
+    weak_reference::WeakReference,
+};
@@ -0,0 +1,67 @@
+use alloc::vec;
+
+use java_class_proto::JavaMethodProto;
+use jvm::{ClassInstanceRef, Jvm, ReferenceKind, Result};
+
+use crate::{
+    RuntimeClassProto, RuntimeContext,
+    classes::java::lang::{
+        Object,
+        r#ref::{Reference, ReferenceQueue},
+    },
+};
+
+// class java.lang.ref.PhantomReference
+pub struct PhantomReference;
+
+impl PhantomReference {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/lang/ref/PhantomReference",
+            parent_class: Some("java/lang/ref/Reference"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/Object;Ljava/lang/ref/ReferenceQueue;)V",
+                    Self::init,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("get", "()Ljava/lang/Object;", Self::get, Default::default()),
+            ],
+            fields: vec![],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        context: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        referent: ClassInstanceRef<Object>,
+        queue: ClassInstanceRef<ReferenceQueue>,
+    ) -> Result<()> {
+        tracing::debug!("java.lang.ref.PhantomReference::<init>({this:?}, {referent:?}, {queue:?})");
+
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "java/lang/ref/Reference",
+                "<init>",
+                "(Ljava/lang/Object;Ljava/lang/ref/ReferenceQueue;)V",
+                (referent, queue),
+            )
+            .await?;
+
+        Reference::register(jvm, context, &this, ReferenceKind::Phantom);
+
+        Ok(())
+    }
+
+    // the referent of a phantom reference is never accessible
+    async fn get(_: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.lang.ref.PhantomReference::get({this:?})");
+
+        Ok(None.into())
+    }
+}
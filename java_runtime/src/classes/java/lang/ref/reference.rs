@@ -0,0 +1,154 @@
+use alloc::{boxed::Box, vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_constants::ClassAccessFlags;
+use jvm::{AsClassInstance, ClassInstance, ClassInstanceRef, Jvm, ReferenceKind, Result};
+
+use crate::{
+    RuntimeClassProto, RuntimeContext, SpawnCallback,
+    classes::java::lang::{Object, r#ref::ReferenceQueue},
+};
+
+// abstract class java.lang.ref.Reference
+pub struct Reference;
+
+impl Reference {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/lang/ref/Reference",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/Object;)V", Self::init, Default::default()),
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/Object;Ljava/lang/ref/ReferenceQueue;)V",
+                    Self::init_with_queue,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("get", "()Ljava/lang/Object;", Self::get, Default::default()),
+                JavaMethodProto::new("clear", "()V", Self::clear, Default::default()),
+                JavaMethodProto::new("isEnqueued", "()Z", Self::is_enqueued, Default::default()),
+                JavaMethodProto::new("enqueue", "()Z", Self::enqueue, Default::default()),
+            ],
+            fields: vec![
+                // cleared by the garbage collector, which doesn't trace it
+                JavaFieldProto::new("referent", "Ljava/lang/Object;", Default::default()),
+                // nulled once the reference is removed from its queue
+                JavaFieldProto::new("queue", "Ljava/lang/ref/ReferenceQueue;", Default::default()),
+                JavaFieldProto::new("next", "Ljava/lang/ref/Reference;", Default::default()),
+                JavaFieldProto::new("enqueued", "Z", Default::default()),
+            ],
+            access_flags: ClassAccessFlags::ABSTRACT,
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, referent: ClassInstanceRef<Object>) -> Result<()> {
+        tracing::debug!("java.lang.ref.Reference::<init>({this:?}, {referent:?})");
+
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "java/lang/ref/Reference",
+                "<init>",
+                "(Ljava/lang/Object;Ljava/lang/ref/ReferenceQueue;)V",
+                (referent, None),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn init_with_queue(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        mut this: ClassInstanceRef<Self>,
+        referent: ClassInstanceRef<Object>,
+        queue: ClassInstanceRef<ReferenceQueue>,
+    ) -> Result<()> {
+        tracing::debug!("java.lang.ref.Reference::<init>({this:?}, {referent:?}, {queue:?})");
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "referent", "Ljava/lang/Object;", referent).await?;
+        jvm.put_field(&mut this, "queue", "Ljava/lang/ref/ReferenceQueue;", queue).await?;
+
+        Ok(())
+    }
+
+    // called by subclass constructors once the fields are set
+    pub(crate) fn register(jvm: &Jvm, context: &mut RuntimeContext, this: &(impl AsClassInstance + ?Sized), kind: ReferenceKind) {
+        jvm.register_reference(this, kind);
+
+        if jvm.claim_reference_handler_thread() {
+            context.spawn(jvm, Box::new(ReferenceHandlerThread { jvm: jvm.clone() }));
+        }
+    }
+
+    // moves a reference cleared by the garbage collector onto its queue
+    pub(crate) async fn enqueue_pending(jvm: &Jvm, reference: Box<dyn ClassInstance>) -> Result<()> {
+        let queue: ClassInstanceRef<ReferenceQueue> = jvm.get_field(&reference, "queue", "Ljava/lang/ref/ReferenceQueue;").await?;
+        if queue.is_null() {
+            return Ok(());
+        }
+
+        let _: bool = jvm
+            .invoke_virtual(&queue, "enqueue", "(Ljava/lang/ref/Reference;)Z", (reference,))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.lang.ref.Reference::get({this:?})");
+
+        jvm.get_field(&this, "referent", "Ljava/lang/Object;").await
+    }
+
+    async fn clear(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>) -> Result<()> {
+        tracing::debug!("java.lang.ref.Reference::clear({this:?})");
+
+        jvm.put_field(&mut this, "referent", "Ljava/lang/Object;", None).await?;
+
+        Ok(())
+    }
+
+    async fn is_enqueued(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<bool> {
+        tracing::debug!("java.lang.ref.Reference::isEnqueued({this:?})");
+
+        jvm.get_field(&this, "enqueued", "Z").await
+    }
+
+    async fn enqueue(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>) -> Result<bool> {
+        tracing::debug!("java.lang.ref.Reference::enqueue({this:?})");
+
+        jvm.put_field(&mut this, "referent", "Ljava/lang/Object;", None).await?;
+
+        let queue: ClassInstanceRef<ReferenceQueue> = jvm.get_field(&this, "queue", "Ljava/lang/ref/ReferenceQueue;").await?;
+        if queue.is_null() {
+            return Ok(false);
+        }
+
+        jvm.invoke_virtual(&queue, "enqueue", "(Ljava/lang/ref/Reference;)Z", (this,)).await
+    }
+}
+
+struct ReferenceHandlerThread {
+    jvm: Jvm,
+}
+
+#[async_trait::async_trait]
+impl SpawnCallback for ReferenceHandlerThread {
+    #[tracing::instrument(name = "reference handler thread", skip_all)]
+    async fn call(&self) -> Result<()> {
+        self.jvm.attach_thread(None).await?;
+
+        loop {
+            let _frame = self.jvm.push_native_frame()?;
+            let reference = self.jvm.next_pending_reference().await;
+            if let Err(error) = Reference::enqueue_pending(&self.jvm, reference).await {
+                tracing::debug!(?error, "failed to enqueue reference");
+            }
+        }
+    }
+}
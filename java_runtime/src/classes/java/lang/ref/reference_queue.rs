@@ -0,0 +1,139 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_constants::MethodAccessFlags;
+use jvm::{ClassInstanceRef, Jvm, Result};
+
+use crate::{RuntimeClassProto, RuntimeContext, classes::java::lang::r#ref::Reference};
+
+// class java.lang.ref.ReferenceQueue
+pub struct ReferenceQueue;
+
+impl ReferenceQueue {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/lang/ref/ReferenceQueue",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                JavaMethodProto::new("poll", "()Ljava/lang/ref/Reference;", Self::poll, Default::default()),
+                JavaMethodProto::new("remove", "()Ljava/lang/ref/Reference;", Self::remove, Default::default()),
+                JavaMethodProto::new("remove", "(J)Ljava/lang/ref/Reference;", Self::remove_with_timeout, Default::default()),
+                JavaMethodProto::new("enqueue", "(Ljava/lang/ref/Reference;)Z", Self::enqueue, MethodAccessFlags::SYNCHRONIZED),
+            ],
+            fields: vec![JavaFieldProto::new("head", "Ljava/lang/ref/Reference;", Default::default())],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        tracing::debug!("java.lang.ref.ReferenceQueue::<init>({this:?})");
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        Ok(())
+    }
+
+    async fn poll(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Reference>> {
+        tracing::debug!("java.lang.ref.ReferenceQueue::poll({this:?})");
+
+        Self::enqueue_pending(jvm).await?;
+
+        jvm.monitor_enter(&this).await?;
+        let result = Self::pop(jvm, &this).await;
+        jvm.monitor_exit(&this).await?;
+
+        result
+    }
+
+    async fn remove(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Reference>> {
+        tracing::debug!("java.lang.ref.ReferenceQueue::remove({this:?})");
+
+        jvm.invoke_virtual(&this, "remove", "(J)Ljava/lang/ref/Reference;", (0i64,)).await
+    }
+
+    // blocks until a reference is enqueued or the timeout (0 meaning none) elapses
+    async fn remove_with_timeout(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        timeout: i64,
+    ) -> Result<ClassInstanceRef<Reference>> {
+        tracing::debug!("java.lang.ref.ReferenceQueue::remove({this:?}, {timeout:?})");
+
+        if timeout < 0 {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Negative timeout value").await);
+        }
+
+        let mut waited = false;
+        loop {
+            Self::enqueue_pending(jvm).await?;
+
+            jvm.monitor_enter(&this).await?;
+            let reference = Self::pop(jvm, &this).await;
+            let result = match reference {
+                Ok(x) if x.is_null() && !(waited && timeout != 0) => jvm.invoke_virtual(&this, "wait", "(J)V", (timeout,)).await.map(|()| None),
+                x => x.map(Some),
+            };
+            jvm.monitor_exit(&this).await?;
+
+            if let Some(x) = result? {
+                return Ok(x);
+            }
+            waited = true;
+        }
+    }
+
+    async fn enqueue(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        mut this: ClassInstanceRef<Self>,
+        mut reference: ClassInstanceRef<Reference>,
+    ) -> Result<bool> {
+        tracing::debug!("java.lang.ref.ReferenceQueue::enqueue({this:?}, {reference:?})");
+
+        let queue: ClassInstanceRef<Self> = jvm.get_field(&reference, "queue", "Ljava/lang/ref/ReferenceQueue;").await?;
+        let enqueued: bool = jvm.get_field(&reference, "enqueued", "Z").await?;
+        if queue.is_null() || queue.instance != this.instance || enqueued {
+            return Ok(false);
+        }
+
+        let head: ClassInstanceRef<Reference> = jvm.get_field(&this, "head", "Ljava/lang/ref/Reference;").await?;
+        jvm.put_field(&mut reference, "next", "Ljava/lang/ref/Reference;", head).await?;
+        jvm.put_field(&mut reference, "enqueued", "Z", true).await?;
+        jvm.put_field(&mut this, "head", "Ljava/lang/ref/Reference;", reference).await?;
+
+        let _: () = jvm.invoke_virtual(&this, "notifyAll", "()V", ()).await?;
+
+        Ok(true)
+    }
+
+    // must hold the queue's monitor
+    async fn pop(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Reference>> {
+        let mut head: ClassInstanceRef<Reference> = jvm.get_field(this, "head", "Ljava/lang/ref/Reference;").await?;
+        if head.is_null() {
+            return Ok(head);
+        }
+
+        let next: ClassInstanceRef<Reference> = jvm.get_field(&head, "next", "Ljava/lang/ref/Reference;").await?;
+        jvm.put_field(&mut this.clone(), "head", "Ljava/lang/ref/Reference;", next).await?;
+
+        // a removed reference can't be enqueued again
+        jvm.put_field(&mut head, "next", "Ljava/lang/ref/Reference;", None).await?;
+        jvm.put_field(&mut head, "enqueued", "Z", false).await?;
+        jvm.put_field(&mut head, "queue", "Ljava/lang/ref/ReferenceQueue;", None).await?;
+
+        Ok(head)
+    }
+
+    // references cleared by the garbage collector are normally enqueued by the reference handler thread;
+    // queue operations also do it themselves so polling never lags behind a collection
+    async fn enqueue_pending(jvm: &Jvm) -> Result<()> {
+        while let Some(reference) = jvm.poll_pending_reference() {
+            Reference::enqueue_pending(jvm, reference).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use alloc::vec;
+
+use java_class_proto::JavaMethodProto;
+use jvm::{ClassInstanceRef, Jvm, ReferenceKind, Result};
+
+use crate::{
+    RuntimeClassProto, RuntimeContext,
+    classes::java::lang::{
+        Object,
+        r#ref::{Reference, ReferenceQueue},
+    },
+};
+
+// class java.lang.ref.SoftReference
+pub struct SoftReference;
+
+impl SoftReference {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/lang/ref/SoftReference",
+            parent_class: Some("java/lang/ref/Reference"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/Object;)V", Self::init, Default::default()),
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/Object;Ljava/lang/ref/ReferenceQueue;)V",
+                    Self::init_with_queue,
+                    Default::default(),
+                ),
+            ],
+            fields: vec![],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, referent: ClassInstanceRef<Object>) -> Result<()> {
+        tracing::debug!("java.lang.ref.SoftReference::<init>({this:?}, {referent:?})");
+
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "java/lang/ref/SoftReference",
+                "<init>",
+                "(Ljava/lang/Object;Ljava/lang/ref/ReferenceQueue;)V",
+                (referent, None),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn init_with_queue(
+        jvm: &Jvm,
+        context: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        referent: ClassInstanceRef<Object>,
+        queue: ClassInstanceRef<ReferenceQueue>,
+    ) -> Result<()> {
+        tracing::debug!("java.lang.ref.SoftReference::<init>({this:?}, {referent:?}, {queue:?})");
+
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "java/lang/ref/Reference",
+                "<init>",
+                "(Ljava/lang/Object;Ljava/lang/ref/ReferenceQueue;)V",
+                (referent, queue),
+            )
+            .await?;
+
+        Reference::register(jvm, context, &this, ReferenceKind::Soft);
+
+        Ok(())
+    }
+}
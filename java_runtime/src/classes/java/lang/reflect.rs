@@ -0,0 +1,12 @@
+mod accessible_object;
+mod array;
+mod constructor;
+mod field;
+mod invocation_target_exception;
+mod method;
+mod modifier;
+
+pub use {
+    accessible_object::AccessibleObject, array::Array, constructor::Constructor, field::Field,
+    invocation_target_exception::InvocationTargetException, method::Method, modifier::Modifier,
+};
@@ -0,0 +1,58 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_constants::MethodAccessFlags;
+use jvm::{ClassInstanceRef, Jvm, Result};
+
+use crate::{RuntimeClassProto, RuntimeContext, classes::java::lang::Object};
+
+// class java.lang.reflect.AccessibleObject
+// Member access isn't checked; the flag only matters for writing final instance fields
+pub struct AccessibleObject;
+
+impl AccessibleObject {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/lang/reflect/AccessibleObject",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init, MethodAccessFlags::PROTECTED),
+                JavaMethodProto::new("isAccessible", "()Z", Self::is_accessible, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new("setAccessible", "(Z)V", Self::set_accessible, MethodAccessFlags::PUBLIC),
+            ],
+            fields: vec![JavaFieldProto::new("accessible", "Z", Default::default())],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        tracing::debug!("java.lang.reflect.AccessibleObject::<init>({this:?})");
+        jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await
+    }
+
+    async fn is_accessible(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<bool> {
+        tracing::debug!("java.lang.reflect.AccessibleObject::isAccessible({this:?})");
+        jvm.get_field(&this, "accessible", "Z").await
+    }
+
+    async fn set_accessible(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>, flag: bool) -> Result<()> {
+        tracing::debug!("java.lang.reflect.AccessibleObject::setAccessible({this:?}, {flag:?})");
+        jvm.put_field(&mut this, "accessible", "Z", flag).await
+    }
+
+    // the receiver of an instance field access or method call
+    pub(crate) async fn check_receiver(jvm: &Jvm, object: &ClassInstanceRef<Object>, class_name: &str) -> Result<()> {
+        if object.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "object").await);
+        }
+
+        if !jvm.is_instance(object, class_name) {
+            return Err(jvm
+                .exception("java/lang/IllegalArgumentException", "object is not an instance of declaring class")
+                .await);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,274 @@
+use alloc::{boxed::Box, format, vec, vec::Vec};
+
+use java_class_proto::JavaMethodProto;
+use java_constants::{ClassAccessFlags, MethodAccessFlags};
+use jvm::{ClassInstance, ClassInstanceRef, JavaType, JavaValue, Jvm, Result};
+
+use crate::{
+    RuntimeClassProto, RuntimeContext,
+    classes::java::lang::{Class, Object},
+};
+
+// public final class java.lang.reflect.Array
+pub struct Array;
+
+impl Array {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/lang/reflect/Array",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new(
+                    "newInstance",
+                    "(Ljava/lang/Class;I)Ljava/lang/Object;",
+                    Self::new_instance,
+                    MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "newInstance",
+                    "(Ljava/lang/Class;[I)Ljava/lang/Object;",
+                    Self::new_instance_multi,
+                    MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "getLength",
+                    "(Ljava/lang/Object;)I",
+                    Self::get_length,
+                    MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "get",
+                    "(Ljava/lang/Object;I)Ljava/lang/Object;",
+                    Self::get,
+                    MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "set",
+                    "(Ljava/lang/Object;ILjava/lang/Object;)V",
+                    Self::set,
+                    MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                ),
+            ],
+            fields: vec![],
+            access_flags: ClassAccessFlags::PUBLIC | ClassAccessFlags::FINAL,
+        }
+    }
+
+    async fn new_instance(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        component_type: ClassInstanceRef<Class>,
+        length: i32,
+    ) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.lang.reflect.Array::newInstance({component_type:?}, {length:?})");
+
+        Self::new_multi_array(jvm, component_type, &[length]).await
+    }
+
+    async fn new_instance_multi(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        component_type: ClassInstanceRef<Class>,
+        dimensions: ClassInstanceRef<jvm::Array<i32>>,
+    ) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.lang.reflect.Array::newInstance({component_type:?}, {dimensions:?})");
+
+        if dimensions.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "dimensions").await);
+        }
+
+        let length = jvm.array_length(&dimensions).await?;
+        let dimensions: Vec<i32> = jvm.load_array(&dimensions, 0, length).await?;
+        if dimensions.is_empty() {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Empty dimensions array").await);
+        }
+
+        Self::new_multi_array(jvm, component_type, &dimensions).await
+    }
+
+    async fn get_length(jvm: &Jvm, _: &mut RuntimeContext, array: ClassInstanceRef<Object>) -> Result<i32> {
+        tracing::debug!("java.lang.reflect.Array::getLength({array:?})");
+
+        Self::check_array(jvm, &array).await?;
+
+        Ok(jvm.array_length(&array).await? as _)
+    }
+
+    async fn get(jvm: &Jvm, _: &mut RuntimeContext, array: ClassInstanceRef<Object>, index: i32) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.lang.reflect.Array::get({array:?}, {index:?})");
+
+        Self::check_index(jvm, &array, index).await?;
+
+        let value: Vec<JavaValue> = jvm.load_array(&array, index as _, 1).await?;
+        Self::wrap(jvm, value.into_iter().next().unwrap_or(JavaValue::Object(None))).await
+    }
+
+    async fn set(jvm: &Jvm, _: &mut RuntimeContext, mut array: ClassInstanceRef<Object>, index: i32, value: ClassInstanceRef<Object>) -> Result<()> {
+        tracing::debug!("java.lang.reflect.Array::set({array:?}, {index:?}, {value:?})");
+
+        Self::check_index(jvm, &array, index).await?;
+
+        let element_type = jvm.array_element_type(&array).await?;
+        let value = Self::unwrap(jvm, value, &element_type).await?;
+        jvm.store_array(&mut array, index as _, [value]).await
+    }
+
+    async fn check_array(jvm: &Jvm, array: &ClassInstanceRef<Object>) -> Result<()> {
+        if array.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "array").await);
+        }
+
+        if array.instance.as_ref().and_then(|x| x.as_array_instance()).is_none() {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Argument is not an array").await);
+        }
+
+        Ok(())
+    }
+
+    async fn check_index(jvm: &Jvm, array: &ClassInstanceRef<Object>, index: i32) -> Result<()> {
+        Self::check_array(jvm, array).await?;
+
+        let length = jvm.array_length(array).await?;
+        if index < 0 || index as usize >= length {
+            return Err(jvm
+                .exception(
+                    "java/lang/ArrayIndexOutOfBoundsException",
+                    &format!("Index {index} out of bounds for length {length}"),
+                )
+                .await);
+        }
+
+        Ok(())
+    }
+
+    async fn new_multi_array(jvm: &Jvm, component_type: ClassInstanceRef<Class>, dimensions: &[i32]) -> Result<ClassInstanceRef<Object>> {
+        if component_type.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "componentType").await);
+        }
+
+        let descriptor = Class::descriptor(jvm, &component_type).await?;
+        if descriptor == "V" {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "void").await);
+        }
+
+        if let Some(x) = dimensions.iter().find(|x| **x < 0) {
+            return Err(jvm.exception("java/lang/NegativeArraySizeException", &format!("{x}")).await);
+        }
+
+        let array_class = format!("{}{descriptor}", "[".repeat(dimensions.len()));
+        Ok(Self::new_array(jvm, &array_class, dimensions).await?.into())
+    }
+
+    #[async_recursion::async_recursion]
+    async fn new_array(jvm: &Jvm, array_class: &str, dimensions: &[i32]) -> Result<Box<dyn ClassInstance>> {
+        let mut array = jvm.instantiate_array(&array_class[1..], dimensions[0] as _).await?;
+
+        if dimensions.len() > 1 {
+            for i in 0..dimensions[0] {
+                let element = Self::new_array(jvm, &array_class[1..], &dimensions[1..]).await?;
+                jvm.store_array(&mut array, i as _, [element]).await?;
+            }
+        }
+
+        Ok(array)
+    }
+
+    // boxes a primitive into its wrapper class, void becomes null
+    pub(crate) async fn wrap(jvm: &Jvm, value: JavaValue) -> Result<ClassInstanceRef<Object>> {
+        let boxed = match value {
+            JavaValue::Void => return Ok(None.into()),
+            JavaValue::Object(x) => return Ok(x.into()),
+            JavaValue::Boolean(x) => jvm.new_class("java/lang/Boolean", "(Z)V", (x,)).await?,
+            JavaValue::Byte(x) => jvm.new_class("java/lang/Byte", "(B)V", (x,)).await?,
+            JavaValue::Char(x) => jvm.new_class("java/lang/Character", "(C)V", (x,)).await?,
+            JavaValue::Short(x) => jvm.new_class("java/lang/Short", "(S)V", (x,)).await?,
+            JavaValue::Int(x) => jvm.new_class("java/lang/Integer", "(I)V", (x,)).await?,
+            JavaValue::Long(x) => jvm.new_class("java/lang/Long", "(J)V", (x,)).await?,
+            JavaValue::Float(x) => jvm.new_class("java/lang/Float", "(F)V", (x,)).await?,
+            JavaValue::Double(x) => jvm.new_class("java/lang/Double", "(D)V", (x,)).await?,
+        };
+
+        Ok(boxed.into())
+    }
+
+    // converts an object to a value of `type`, unboxing and widening primitives like method invocation does
+    pub(crate) async fn unwrap(jvm: &Jvm, value: ClassInstanceRef<Object>, r#type: &JavaType) -> Result<JavaValue> {
+        if let JavaType::Class(_) | JavaType::Array(_) = r#type {
+            let class_name = match r#type {
+                JavaType::Class(x) => x.clone(),
+                _ => r#type.descriptor(),
+            };
+
+            if !value.is_null() && !jvm.is_instance(&value, &class_name) {
+                return Err(jvm.exception("java/lang/IllegalArgumentException", "argument type mismatch").await);
+            }
+
+            return Ok(value.into());
+        }
+
+        let unboxed = match &value.instance {
+            None => None,
+            Some(instance) => match instance.class_definition().name().as_str() {
+                "java/lang/Boolean" => Some(JavaValue::Boolean(jvm.invoke_virtual(&value, "booleanValue", "()Z", ()).await?)),
+                "java/lang/Byte" => Some(JavaValue::Byte(jvm.invoke_virtual(&value, "byteValue", "()B", ()).await?)),
+                "java/lang/Character" => Some(JavaValue::Char(jvm.invoke_virtual(&value, "charValue", "()C", ()).await?)),
+                "java/lang/Short" => Some(JavaValue::Short(jvm.invoke_virtual(&value, "shortValue", "()S", ()).await?)),
+                "java/lang/Integer" => Some(JavaValue::Int(jvm.invoke_virtual(&value, "intValue", "()I", ()).await?)),
+                "java/lang/Long" => Some(JavaValue::Long(jvm.invoke_virtual(&value, "longValue", "()J", ()).await?)),
+                "java/lang/Float" => Some(JavaValue::Float(jvm.invoke_virtual(&value, "floatValue", "()F", ()).await?)),
+                "java/lang/Double" => Some(JavaValue::Double(jvm.invoke_virtual(&value, "doubleValue", "()D", ()).await?)),
+                _ => None,
+            },
+        };
+
+        match unboxed.and_then(|x| Self::widen(x, r#type)) {
+            Some(x) => Ok(x),
+            None => Err(jvm.exception("java/lang/IllegalArgumentException", "argument type mismatch").await),
+        }
+    }
+
+    pub(crate) async fn unwrap_arguments(jvm: &Jvm, args: ClassInstanceRef<jvm::Array<Object>>, types: &[JavaType]) -> Result<Vec<JavaValue>> {
+        let args: Vec<ClassInstanceRef<Object>> = if args.is_null() {
+            Vec::new()
+        } else {
+            let length = jvm.array_length(&args).await?;
+            jvm.load_array(&args, 0, length).await?
+        };
+
+        if args.len() != types.len() {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "wrong number of arguments").await);
+        }
+
+        let mut values = Vec::with_capacity(args.len());
+        for (arg, r#type) in args.into_iter().zip(types) {
+            values.push(Self::unwrap(jvm, arg, r#type).await?);
+        }
+
+        Ok(values)
+    }
+
+    // JLS 5.1.2 widening primitive conversion
+    fn widen(value: JavaValue, r#type: &JavaType) -> Option<JavaValue> {
+        match (value, r#type) {
+            (JavaValue::Boolean(x), JavaType::Boolean) => Some(JavaValue::Boolean(x)),
+            (JavaValue::Char(x), JavaType::Char) => Some(JavaValue::Char(x)),
+            (JavaValue::Byte(x), JavaType::Byte) => Some(JavaValue::Byte(x)),
+            (JavaValue::Byte(x), JavaType::Short) => Some(JavaValue::Short(x as _)),
+            (JavaValue::Short(x), JavaType::Short) => Some(JavaValue::Short(x)),
+            (JavaValue::Byte(x), _) => Self::widen(JavaValue::Int(x as _), r#type),
+            (JavaValue::Short(x), _) => Self::widen(JavaValue::Int(x as _), r#type),
+            (JavaValue::Char(x), _) => Self::widen(JavaValue::Int(x as _), r#type),
+            (JavaValue::Int(x), JavaType::Int) => Some(JavaValue::Int(x)),
+            (JavaValue::Int(x), JavaType::Float) => Some(JavaValue::Float(x as _)),
+            (JavaValue::Int(x), _) => Self::widen(JavaValue::Long(x as _), r#type),
+            (JavaValue::Long(x), JavaType::Long) => Some(JavaValue::Long(x)),
+            (JavaValue::Long(x), JavaType::Float) => Some(JavaValue::Float(x as _)),
+            (JavaValue::Long(x), JavaType::Double) => Some(JavaValue::Double(x as _)),
+            (JavaValue::Float(x), JavaType::Float) => Some(JavaValue::Float(x)),
+            (JavaValue::Float(x), JavaType::Double) => Some(JavaValue::Double(x as _)),
+            (JavaValue::Double(x), JavaType::Double) => Some(JavaValue::Double(x)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use alloc::{format, vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_constants::{ClassAccessFlags, MethodAccessFlags};
+use jvm::{
+    ClassDefinition, ClassInstanceRef, Jvm, Result,
+    runtime::{JavaLangClass, JavaLangString},
+};
+
+use crate::{
+    RuntimeClassProto, RuntimeContext,
+    classes::java::lang::{
+        Class, Object, String,
+        reflect::{Array, InvocationTargetException, Method, Modifier},
+    },
+};
+
+// public final class java.lang.reflect.Constructor
+pub struct Constructor;
+
+impl Constructor {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/lang/reflect/Constructor",
+            parent_class: Some("java/lang/reflect/AccessibleObject"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new(
+                    "getDeclaringClass",
+                    "()Ljava/lang/Class;",
+                    Self::get_declaring_class,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new("getName", "()Ljava/lang/String;", Self::get_name, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new("getModifiers", "()I", Self::get_modifiers, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new(
+                    "getParameterTypes",
+                    "()[Ljava/lang/Class;",
+                    Self::get_parameter_types,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new(
+                    "newInstance",
+                    "([Ljava/lang/Object;)Ljava/lang/Object;",
+                    Self::new_instance,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new("toString", "()Ljava/lang/String;", Self::to_string, MethodAccessFlags::PUBLIC),
+                // rustjava internal
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/Class;Ljava/lang/String;I)V",
+                    Self::init,
+                    MethodAccessFlags::SYNTHETIC,
+                ),
+            ],
+            fields: vec![
+                JavaFieldProto::new("clazz", "Ljava/lang/Class;", Default::default()),
+                JavaFieldProto::new("descriptor", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("modifiers", "I", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        mut this: ClassInstanceRef<Self>,
+        clazz: ClassInstanceRef<Class>,
+        descriptor: ClassInstanceRef<String>,
+        modifiers: i32,
+    ) -> Result<()> {
+        tracing::debug!("java.lang.reflect.Constructor::<init>({this:?}, {clazz:?}, {descriptor:?}, {modifiers:?})");
+
+        let _: () = jvm
+            .invoke_special(&this, "java/lang/reflect/AccessibleObject", "<init>", "()V", ())
+            .await?;
+
+        jvm.put_field(&mut this, "clazz", "Ljava/lang/Class;", clazz).await?;
+        jvm.put_field(&mut this, "descriptor", "Ljava/lang/String;", descriptor).await?;
+        jvm.put_field(&mut this, "modifiers", "I", modifiers).await?;
+
+        Ok(())
+    }
+
+    async fn get_declaring_class(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Class>> {
+        tracing::debug!("java.lang.reflect.Constructor::getDeclaringClass({this:?})");
+        jvm.get_field(&this, "clazz", "Ljava/lang/Class;").await
+    }
+
+    async fn get_name(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<String>> {
+        tracing::debug!("java.lang.reflect.Constructor::getName({this:?})");
+
+        let class_name = Method::class_name(jvm, &this).await?;
+
+        Ok(JavaLangString::from_rust_string(jvm, &class_name.replace('/', ".")).await?.into())
+    }
+
+    async fn get_modifiers(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i32> {
+        tracing::debug!("java.lang.reflect.Constructor::getModifiers({this:?})");
+        jvm.get_field(&this, "modifiers", "I").await
+    }
+
+    async fn get_parameter_types(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<jvm::Array<Class>>> {
+        tracing::debug!("java.lang.reflect.Constructor::getParameterTypes({this:?})");
+
+        let (parameters, _) = Method::method_signature(jvm, &this).await?;
+
+        Class::to_class_array(jvm, &parameters).await
+    }
+
+    async fn new_instance(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        args: ClassInstanceRef<jvm::Array<Object>>,
+    ) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.lang.reflect.Constructor::newInstance({this:?}, {args:?})");
+
+        let clazz: ClassInstanceRef<Class> = jvm.get_field(&this, "clazz", "Ljava/lang/Class;").await?;
+        let class = JavaLangClass::to_rust_class(jvm, &clazz).await?;
+        let class_name = class.name();
+        if class.access_flags().intersects(ClassAccessFlags::INTERFACE | ClassAccessFlags::ABSTRACT) {
+            return Err(jvm.exception("java/lang/InstantiationException", &class_name.replace('/', ".")).await);
+        }
+
+        let descriptor = Method::descriptor(jvm, &this).await?;
+        let (parameters, _) = Method::signature(jvm, &descriptor).await?;
+        let args = Array::unwrap_arguments(jvm, args, &parameters).await?;
+
+        let instance = jvm.instantiate_class(&class_name).await?;
+        let result: Result<()> = jvm.invoke_special(&instance, &class_name, "<init>", &descriptor, args).await;
+
+        match result {
+            Ok(()) => Ok(instance.into()),
+            Err(err) => Err(InvocationTargetException::wrap(jvm, err).await),
+        }
+    }
+
+    async fn to_string(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<String>> {
+        tracing::debug!("java.lang.reflect.Constructor::toString({this:?})");
+
+        let class_name = Method::class_name(jvm, &this).await?;
+        let modifiers: i32 = jvm.get_field(&this, "modifiers", "I").await?;
+
+        let (parameters, _) = Method::method_signature(jvm, &this).await?;
+
+        let text = format!(
+            "{}{}({})",
+            Method::modifiers_prefix(modifiers),
+            class_name.replace('/', "."),
+            Method::parameter_list(&parameters)
+        );
+
+        Ok(JavaLangString::from_rust_string(jvm, &text).await?.into())
+    }
+
+    pub(crate) async fn from_rust_method(jvm: &Jvm, class: &dyn ClassDefinition, method: &dyn jvm::Method) -> Result<ClassInstanceRef<Self>> {
+        let clazz = jvm.class_of(class).await?.java_class()?;
+        let descriptor = JavaLangString::from_rust_string(jvm, &method.descriptor()).await?;
+        let flags = Class::member_flags(jvm, class, method.access_flags().bits()).await?;
+        let modifiers = Modifier::from_method_flags(MethodAccessFlags::from_bits_truncate(flags));
+
+        Ok(jvm
+            .new_class(
+                "java/lang/reflect/Constructor",
+                "(Ljava/lang/Class;Ljava/lang/String;I)V",
+                (clazz, descriptor, modifiers),
+            )
+            .await?
+            .into())
+    }
+}
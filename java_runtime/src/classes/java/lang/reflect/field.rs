@@ -0,0 +1,207 @@
+use alloc::{format, vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_constants::{FieldAccessFlags, MethodAccessFlags};
+use jvm::{ClassDefinition, ClassInstanceRef, JavaType, JavaValue, Jvm, Result, runtime::JavaLangString};
+
+use crate::{
+    RuntimeClassProto, RuntimeContext,
+    classes::java::lang::{
+        Class, Object, String,
+        reflect::{AccessibleObject, Array, Method, Modifier},
+    },
+};
+
+// public final class java.lang.reflect.Field
+pub struct Field;
+
+impl Field {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/lang/reflect/Field",
+            parent_class: Some("java/lang/reflect/AccessibleObject"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new(
+                    "getDeclaringClass",
+                    "()Ljava/lang/Class;",
+                    Self::get_declaring_class,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new("getName", "()Ljava/lang/String;", Self::get_name, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new("getModifiers", "()I", Self::get_modifiers, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new("getType", "()Ljava/lang/Class;", Self::get_type, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new("get", "(Ljava/lang/Object;)Ljava/lang/Object;", Self::get, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new("set", "(Ljava/lang/Object;Ljava/lang/Object;)V", Self::set, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new("toString", "()Ljava/lang/String;", Self::to_string, MethodAccessFlags::PUBLIC),
+                // rustjava internal
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/Class;Ljava/lang/String;Ljava/lang/String;I)V",
+                    Self::init,
+                    MethodAccessFlags::SYNTHETIC,
+                ),
+            ],
+            fields: vec![
+                JavaFieldProto::new("clazz", "Ljava/lang/Class;", Default::default()),
+                JavaFieldProto::new("name", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("descriptor", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("modifiers", "I", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        mut this: ClassInstanceRef<Self>,
+        clazz: ClassInstanceRef<Class>,
+        name: ClassInstanceRef<String>,
+        descriptor: ClassInstanceRef<String>,
+        modifiers: i32,
+    ) -> Result<()> {
+        tracing::debug!("java.lang.reflect.Field::<init>({this:?}, {clazz:?}, {name:?}, {descriptor:?}, {modifiers:?})");
+
+        let _: () = jvm
+            .invoke_special(&this, "java/lang/reflect/AccessibleObject", "<init>", "()V", ())
+            .await?;
+
+        jvm.put_field(&mut this, "clazz", "Ljava/lang/Class;", clazz).await?;
+        jvm.put_field(&mut this, "name", "Ljava/lang/String;", name).await?;
+        jvm.put_field(&mut this, "descriptor", "Ljava/lang/String;", descriptor).await?;
+        jvm.put_field(&mut this, "modifiers", "I", modifiers).await?;
+
+        Ok(())
+    }
+
+    async fn get_declaring_class(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Class>> {
+        tracing::debug!("java.lang.reflect.Field::getDeclaringClass({this:?})");
+        jvm.get_field(&this, "clazz", "Ljava/lang/Class;").await
+    }
+
+    async fn get_name(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<String>> {
+        tracing::debug!("java.lang.reflect.Field::getName({this:?})");
+        jvm.get_field(&this, "name", "Ljava/lang/String;").await
+    }
+
+    async fn get_modifiers(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i32> {
+        tracing::debug!("java.lang.reflect.Field::getModifiers({this:?})");
+        jvm.get_field(&this, "modifiers", "I").await
+    }
+
+    async fn get_type(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Class>> {
+        tracing::debug!("java.lang.reflect.Field::getType({this:?})");
+
+        let descriptor = Method::descriptor(jvm, &this).await?;
+
+        Class::from_descriptor(jvm, &descriptor).await
+    }
+
+    async fn get(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        object: ClassInstanceRef<Object>,
+    ) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.lang.reflect.Field::get({this:?}, {object:?})");
+
+        let class_name = Method::class_name(jvm, &this).await?;
+        let name = Self::rust_name(jvm, &this).await?;
+        let descriptor = Method::descriptor(jvm, &this).await?;
+        let flags = Self::flags(jvm, &this).await?;
+
+        let value: JavaValue = if flags.contains(FieldAccessFlags::STATIC) {
+            jvm.get_static_field(&class_name, &name, &descriptor).await?
+        } else {
+            AccessibleObject::check_receiver(jvm, &object, &class_name).await?;
+            jvm.get_field(&object, &name, &descriptor).await?
+        };
+
+        Array::wrap(jvm, value).await
+    }
+
+    async fn set(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        mut object: ClassInstanceRef<Object>,
+        value: ClassInstanceRef<Object>,
+    ) -> Result<()> {
+        tracing::debug!("java.lang.reflect.Field::set({this:?}, {object:?}, {value:?})");
+
+        let class_name = Method::class_name(jvm, &this).await?;
+        let name = Self::rust_name(jvm, &this).await?;
+        let descriptor = Method::descriptor(jvm, &this).await?;
+        let flags = Self::flags(jvm, &this).await?;
+        let is_static = flags.contains(FieldAccessFlags::STATIC);
+
+        // final instance fields are writable after setAccessible(true), final static fields never are
+        let accessible: bool = jvm.get_field(&this, "accessible", "Z").await?;
+        if flags.contains(FieldAccessFlags::FINAL) && (is_static || !accessible) {
+            return Err(jvm
+                .exception(
+                    "java/lang/IllegalAccessException",
+                    &format!("Can not set final field {}.{name}", class_name.replace('/', ".")),
+                )
+                .await);
+        }
+
+        if !is_static {
+            AccessibleObject::check_receiver(jvm, &object, &class_name).await?;
+        }
+
+        let value = Array::unwrap(jvm, value, &JavaType::parse(&descriptor)?).await?;
+
+        if is_static {
+            jvm.put_static_field(&class_name, &name, &descriptor, value).await
+        } else {
+            jvm.put_field(&mut object, &name, &descriptor, value).await
+        }
+    }
+
+    async fn to_string(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<String>> {
+        tracing::debug!("java.lang.reflect.Field::toString({this:?})");
+
+        let class_name = Method::class_name(jvm, &this).await?;
+        let name = Self::rust_name(jvm, &this).await?;
+        let descriptor = Method::descriptor(jvm, &this).await?;
+        let modifiers: i32 = jvm.get_field(&this, "modifiers", "I").await?;
+
+        let text = format!(
+            "{}{} {}.{name}",
+            Method::modifiers_prefix(modifiers),
+            Class::type_name(&JavaType::parse(&descriptor)?),
+            class_name.replace('/', ".")
+        );
+
+        Ok(JavaLangString::from_rust_string(jvm, &text).await?.into())
+    }
+
+    pub(crate) async fn from_rust_field(jvm: &Jvm, class: &dyn ClassDefinition, field: &dyn jvm::Field) -> Result<ClassInstanceRef<Self>> {
+        let clazz = jvm.class_of(class).await?.java_class()?;
+        let name = JavaLangString::from_rust_string(jvm, &field.name()).await?;
+        let descriptor = JavaLangString::from_rust_string(jvm, &field.descriptor()).await?;
+        let flags = Class::member_flags(jvm, class, field.access_flags().bits()).await?;
+        let modifiers = Modifier::from_field_flags(FieldAccessFlags::from_bits_truncate(flags));
+
+        Ok(jvm
+            .new_class(
+                "java/lang/reflect/Field",
+                "(Ljava/lang/Class;Ljava/lang/String;Ljava/lang/String;I)V",
+                (clazz, name, descriptor, modifiers),
+            )
+            .await?
+            .into())
+    }
+
+    async fn rust_name(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> Result<alloc::string::String> {
+        let name: ClassInstanceRef<String> = jvm.get_field(this, "name", "Ljava/lang/String;").await?;
+        JavaLangString::to_rust_string(jvm, &name).await
+    }
+
+    async fn flags(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> Result<FieldAccessFlags> {
+        let modifiers: i32 = jvm.get_field(this, "modifiers", "I").await?;
+        Ok(FieldAccessFlags::from_bits_truncate(modifiers as _))
+    }
+}
@@ -0,0 +1,96 @@
+use alloc::vec;
+
+use java_class_proto::JavaMethodProto;
+use jvm::{ClassInstanceRef, JavaError, Jvm, Result};
+
+use crate::{
+    RuntimeClassProto, RuntimeContext,
+    classes::java::lang::{String, Throwable},
+};
+
+// class java.lang.reflect.InvocationTargetException
+// The target exception is kept as the cause
+pub struct InvocationTargetException;
+
+impl InvocationTargetException {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/lang/reflect/InvocationTargetException",
+            parent_class: Some("java/lang/Exception"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                JavaMethodProto::new("<init>", "(Ljava/lang/Throwable;)V", Self::init_with_target, Default::default()),
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/Throwable;Ljava/lang/String;)V",
+                    Self::init_with_target_and_message,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "getTargetException",
+                    "()Ljava/lang/Throwable;",
+                    Self::get_target_exception,
+                    Default::default(),
+                ),
+            ],
+            fields: vec![],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        tracing::debug!("java.lang.reflect.InvocationTargetException::<init>({this:?})");
+        jvm.invoke_special(&this, "java/lang/Exception", "<init>", "()V", ()).await
+    }
+
+    async fn init_with_target(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, target: ClassInstanceRef<Throwable>) -> Result<()> {
+        tracing::debug!("java.lang.reflect.InvocationTargetException::<init>({this:?}, {target:?})");
+        jvm.invoke_special(
+            &this,
+            "java/lang/Exception",
+            "<init>",
+            "(Ljava/lang/String;Ljava/lang/Throwable;)V",
+            (None, target),
+        )
+        .await
+    }
+
+    async fn init_with_target_and_message(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        target: ClassInstanceRef<Throwable>,
+        message: ClassInstanceRef<String>,
+    ) -> Result<()> {
+        tracing::debug!("java.lang.reflect.InvocationTargetException::<init>({this:?}, {target:?}, {message:?})");
+        jvm.invoke_special(
+            &this,
+            "java/lang/Exception",
+            "<init>",
+            "(Ljava/lang/String;Ljava/lang/Throwable;)V",
+            (message, target),
+        )
+        .await
+    }
+
+    async fn get_target_exception(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Throwable>> {
+        tracing::debug!("java.lang.reflect.InvocationTargetException::getTargetException({this:?})");
+        jvm.invoke_virtual(&this, "getCause", "()Ljava/lang/Throwable;", ()).await
+    }
+
+    // java exceptions thrown by the invoked code are wrapped, vm errors pass through
+    pub(crate) async fn wrap(jvm: &Jvm, error: JavaError) -> JavaError {
+        let JavaError::JavaException(target) = error else {
+            return error;
+        };
+
+        match jvm
+            .new_class("java/lang/reflect/InvocationTargetException", "(Ljava/lang/Throwable;)V", (target,))
+            .await
+        {
+            Ok(exception) => jvm.throw(exception),
+            Err(err) => err,
+        }
+    }
+}
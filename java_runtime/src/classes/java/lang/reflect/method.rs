@@ -0,0 +1,227 @@
+use alloc::{format, string::String as RustString, vec, vec::Vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_constants::MethodAccessFlags;
+use jvm::{ClassDefinition, ClassInstanceRef, JavaType, JavaValue, Jvm, Result, runtime::JavaLangString};
+
+use crate::{
+    RuntimeClassProto, RuntimeContext,
+    classes::java::lang::{
+        Class, Object, String,
+        reflect::{AccessibleObject, Array, InvocationTargetException, Modifier},
+    },
+};
+
+// public final class java.lang.reflect.Method
+pub struct Method;
+
+impl Method {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/lang/reflect/Method",
+            parent_class: Some("java/lang/reflect/AccessibleObject"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new(
+                    "getDeclaringClass",
+                    "()Ljava/lang/Class;",
+                    Self::get_declaring_class,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new("getName", "()Ljava/lang/String;", Self::get_name, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new("getModifiers", "()I", Self::get_modifiers, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new("getReturnType", "()Ljava/lang/Class;", Self::get_return_type, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new(
+                    "getParameterTypes",
+                    "()[Ljava/lang/Class;",
+                    Self::get_parameter_types,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new(
+                    "invoke",
+                    "(Ljava/lang/Object;[Ljava/lang/Object;)Ljava/lang/Object;",
+                    Self::invoke,
+                    MethodAccessFlags::PUBLIC,
+                ),
+                JavaMethodProto::new("toString", "()Ljava/lang/String;", Self::to_string, MethodAccessFlags::PUBLIC),
+                // rustjava internal
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/Class;Ljava/lang/String;Ljava/lang/String;I)V",
+                    Self::init,
+                    MethodAccessFlags::SYNTHETIC,
+                ),
+            ],
+            fields: vec![
+                JavaFieldProto::new("clazz", "Ljava/lang/Class;", Default::default()),
+                JavaFieldProto::new("name", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("descriptor", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("modifiers", "I", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        mut this: ClassInstanceRef<Self>,
+        clazz: ClassInstanceRef<Class>,
+        name: ClassInstanceRef<String>,
+        descriptor: ClassInstanceRef<String>,
+        modifiers: i32,
+    ) -> Result<()> {
+        tracing::debug!("java.lang.reflect.Method::<init>({this:?}, {clazz:?}, {name:?}, {descriptor:?}, {modifiers:?})");
+
+        let _: () = jvm
+            .invoke_special(&this, "java/lang/reflect/AccessibleObject", "<init>", "()V", ())
+            .await?;
+
+        jvm.put_field(&mut this, "clazz", "Ljava/lang/Class;", clazz).await?;
+        jvm.put_field(&mut this, "name", "Ljava/lang/String;", name).await?;
+        jvm.put_field(&mut this, "descriptor", "Ljava/lang/String;", descriptor).await?;
+        jvm.put_field(&mut this, "modifiers", "I", modifiers).await?;
+
+        Ok(())
+    }
+
+    async fn get_declaring_class(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Class>> {
+        tracing::debug!("java.lang.reflect.Method::getDeclaringClass({this:?})");
+        jvm.get_field(&this, "clazz", "Ljava/lang/Class;").await
+    }
+
+    async fn get_name(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<String>> {
+        tracing::debug!("java.lang.reflect.Method::getName({this:?})");
+        jvm.get_field(&this, "name", "Ljava/lang/String;").await
+    }
+
+    async fn get_modifiers(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i32> {
+        tracing::debug!("java.lang.reflect.Method::getModifiers({this:?})");
+        jvm.get_field(&this, "modifiers", "I").await
+    }
+
+    async fn get_return_type(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Class>> {
+        tracing::debug!("java.lang.reflect.Method::getReturnType({this:?})");
+
+        let (_, return_type) = Self::method_signature(jvm, &this).await?;
+
+        Class::from_descriptor(jvm, &return_type.descriptor()).await
+    }
+
+    async fn get_parameter_types(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<jvm::Array<Class>>> {
+        tracing::debug!("java.lang.reflect.Method::getParameterTypes({this:?})");
+
+        let (parameters, _) = Self::method_signature(jvm, &this).await?;
+
+        Class::to_class_array(jvm, &parameters).await
+    }
+
+    async fn invoke(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        object: ClassInstanceRef<Object>,
+        args: ClassInstanceRef<jvm::Array<Object>>,
+    ) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.lang.reflect.Method::invoke({this:?}, {object:?}, {args:?})");
+
+        let class_name = Self::class_name(jvm, &this).await?;
+        let name: ClassInstanceRef<String> = jvm.get_field(&this, "name", "Ljava/lang/String;").await?;
+        let name = JavaLangString::to_rust_string(jvm, &name).await?;
+        let descriptor = Self::descriptor(jvm, &this).await?;
+        let modifiers: i32 = jvm.get_field(&this, "modifiers", "I").await?;
+        let flags = MethodAccessFlags::from_bits_truncate(modifiers as _);
+
+        let (parameters, _) = Self::signature(jvm, &descriptor).await?;
+        let args = Array::unwrap_arguments(jvm, args, &parameters).await?;
+
+        let result: Result<JavaValue> = if flags.contains(MethodAccessFlags::STATIC) {
+            jvm.invoke_static(&class_name, &name, &descriptor, args).await
+        } else {
+            AccessibleObject::check_receiver(jvm, &object, &class_name).await?;
+
+            // private methods aren't dispatched virtually
+            if flags.contains(MethodAccessFlags::PRIVATE) {
+                jvm.invoke_special(&object, &class_name, &name, &descriptor, args).await
+            } else {
+                jvm.invoke_virtual(&object, &name, &descriptor, args).await
+            }
+        };
+
+        match result {
+            Ok(x) => Array::wrap(jvm, x).await,
+            Err(err) => Err(InvocationTargetException::wrap(jvm, err).await),
+        }
+    }
+
+    async fn to_string(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<String>> {
+        tracing::debug!("java.lang.reflect.Method::toString({this:?})");
+
+        let class_name = Self::class_name(jvm, &this).await?;
+        let name: ClassInstanceRef<String> = jvm.get_field(&this, "name", "Ljava/lang/String;").await?;
+        let name = JavaLangString::to_rust_string(jvm, &name).await?;
+        let modifiers: i32 = jvm.get_field(&this, "modifiers", "I").await?;
+
+        let (parameters, return_type) = Self::method_signature(jvm, &this).await?;
+
+        let text = format!(
+            "{}{} {}.{name}({})",
+            Self::modifiers_prefix(modifiers),
+            Class::type_name(&return_type),
+            class_name.replace('/', "."),
+            Self::parameter_list(&parameters)
+        );
+
+        Ok(JavaLangString::from_rust_string(jvm, &text).await?.into())
+    }
+
+    pub(crate) async fn from_rust_method(jvm: &Jvm, class: &dyn ClassDefinition, method: &dyn jvm::Method) -> Result<ClassInstanceRef<Self>> {
+        let clazz = jvm.class_of(class).await?.java_class()?;
+        let name = JavaLangString::from_rust_string(jvm, &method.name()).await?;
+        let descriptor = JavaLangString::from_rust_string(jvm, &method.descriptor()).await?;
+        let flags = Class::member_flags(jvm, class, method.access_flags().bits()).await?;
+        let modifiers = Modifier::from_method_flags(MethodAccessFlags::from_bits_truncate(flags));
+
+        Ok(jvm
+            .new_class(
+                "java/lang/reflect/Method",
+                "(Ljava/lang/Class;Ljava/lang/String;Ljava/lang/String;I)V",
+                (clazz, name, descriptor, modifiers),
+            )
+            .await?
+            .into())
+    }
+
+    // shared with Constructor, which has the same fields
+    pub(crate) async fn class_name<T>(jvm: &Jvm, this: &ClassInstanceRef<T>) -> Result<RustString> {
+        let clazz: ClassInstanceRef<Class> = jvm.get_field(this, "clazz", "Ljava/lang/Class;").await?;
+        jvm::runtime::JavaLangClass::name(jvm, &clazz).await
+    }
+
+    pub(crate) async fn descriptor<T>(jvm: &Jvm, this: &ClassInstanceRef<T>) -> Result<RustString> {
+        let descriptor: ClassInstanceRef<String> = jvm.get_field(this, "descriptor", "Ljava/lang/String;").await?;
+        JavaLangString::to_rust_string(jvm, &descriptor).await
+    }
+
+    pub(crate) async fn method_signature<T>(jvm: &Jvm, this: &ClassInstanceRef<T>) -> Result<(Vec<JavaType>, JavaType)> {
+        let descriptor = Self::descriptor(jvm, this).await?;
+        Self::signature(jvm, &descriptor).await
+    }
+
+    // parameter and return types of a method descriptor
+    pub(crate) async fn signature(jvm: &Jvm, descriptor: &str) -> Result<(Vec<JavaType>, JavaType)> {
+        match JavaType::try_parse(descriptor) {
+            Some(JavaType::Method(parameters, return_type)) => Ok((parameters, *return_type)),
+            _ => Err(jvm.exception("java/lang/ClassFormatError", descriptor).await),
+        }
+    }
+
+    pub(crate) fn modifiers_prefix(modifiers: i32) -> RustString {
+        let modifiers = Modifier::to_rust_string(modifiers);
+        if modifiers.is_empty() { modifiers } else { format!("{modifiers} ") }
+    }
+
+    pub(crate) fn parameter_list(parameters: &[JavaType]) -> RustString {
+        parameters.iter().map(Class::type_name).collect::<Vec<_>>().join(",")
+    }
+}
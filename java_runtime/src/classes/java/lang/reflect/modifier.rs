@@ -0,0 +1,202 @@
+use alloc::{string::String as RustString, vec, vec::Vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_constants::{ClassAccessFlags, FieldAccessFlags, MethodAccessFlags};
+use jvm::{ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
+
+use crate::{RuntimeClassProto, RuntimeContext, classes::java::lang::String};
+
+const MODIFIERS: [(&str, i32); 12] = [
+    ("PUBLIC", 0x0001),
+    ("PRIVATE", 0x0002),
+    ("PROTECTED", 0x0004),
+    ("STATIC", 0x0008),
+    ("FINAL", 0x0010),
+    ("SYNCHRONIZED", 0x0020),
+    ("VOLATILE", 0x0040),
+    ("TRANSIENT", 0x0080),
+    ("NATIVE", 0x0100),
+    ("INTERFACE", 0x0200),
+    ("ABSTRACT", 0x0400),
+    ("STRICT", 0x0800),
+];
+
+// in the order Modifier.toString prints them
+const MODIFIER_NAMES: [(&str, i32); 12] = [
+    ("public", 0x0001),
+    ("protected", 0x0004),
+    ("private", 0x0002),
+    ("abstract", 0x0400),
+    ("static", 0x0008),
+    ("final", 0x0010),
+    ("transient", 0x0080),
+    ("volatile", 0x0040),
+    ("synchronized", 0x0020),
+    ("native", 0x0100),
+    ("strictfp", 0x0800),
+    ("interface", 0x0200),
+];
+
+// public class java.lang.reflect.Modifier
+pub struct Modifier;
+
+impl Modifier {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/lang/reflect/Modifier",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<clinit>", "()V", Self::clinit, MethodAccessFlags::STATIC),
+                JavaMethodProto::new("<init>", "()V", Self::init, MethodAccessFlags::PUBLIC),
+                JavaMethodProto::new("isPublic", "(I)Z", Self::is_public, MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC),
+                JavaMethodProto::new(
+                    "isPrivate",
+                    "(I)Z",
+                    Self::is_private,
+                    MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "isProtected",
+                    "(I)Z",
+                    Self::is_protected,
+                    MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new("isStatic", "(I)Z", Self::is_static, MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC),
+                JavaMethodProto::new("isFinal", "(I)Z", Self::is_final, MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC),
+                JavaMethodProto::new(
+                    "isSynchronized",
+                    "(I)Z",
+                    Self::is_synchronized,
+                    MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "isVolatile",
+                    "(I)Z",
+                    Self::is_volatile,
+                    MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "isTransient",
+                    "(I)Z",
+                    Self::is_transient,
+                    MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new("isNative", "(I)Z", Self::is_native, MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC),
+                JavaMethodProto::new(
+                    "isInterface",
+                    "(I)Z",
+                    Self::is_interface,
+                    MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "isAbstract",
+                    "(I)Z",
+                    Self::is_abstract,
+                    MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new("isStrict", "(I)Z", Self::is_strict, MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC),
+                JavaMethodProto::new(
+                    "toString",
+                    "(I)Ljava/lang/String;",
+                    Self::to_string,
+                    MethodAccessFlags::PUBLIC | MethodAccessFlags::STATIC,
+                ),
+            ],
+            fields: MODIFIERS
+                .iter()
+                .map(|(name, _)| JavaFieldProto::new(name, "I", FieldAccessFlags::PUBLIC | FieldAccessFlags::STATIC | FieldAccessFlags::FINAL))
+                .collect(),
+            access_flags: ClassAccessFlags::PUBLIC,
+        }
+    }
+
+    async fn clinit(jvm: &Jvm, _: &mut RuntimeContext) -> Result<()> {
+        for (name, value) in MODIFIERS {
+            jvm.put_static_field("java/lang/reflect/Modifier", name, "I", value).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        tracing::debug!("java.lang.reflect.Modifier::<init>({this:?})");
+        jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await
+    }
+
+    async fn is_public(_: &Jvm, _: &mut RuntimeContext, modifiers: i32) -> Result<bool> {
+        Ok(modifiers & 0x0001 != 0)
+    }
+
+    async fn is_private(_: &Jvm, _: &mut RuntimeContext, modifiers: i32) -> Result<bool> {
+        Ok(modifiers & 0x0002 != 0)
+    }
+
+    async fn is_protected(_: &Jvm, _: &mut RuntimeContext, modifiers: i32) -> Result<bool> {
+        Ok(modifiers & 0x0004 != 0)
+    }
+
+    async fn is_static(_: &Jvm, _: &mut RuntimeContext, modifiers: i32) -> Result<bool> {
+        Ok(modifiers & 0x0008 != 0)
+    }
+
+    async fn is_final(_: &Jvm, _: &mut RuntimeContext, modifiers: i32) -> Result<bool> {
+        Ok(modifiers & 0x0010 != 0)
+    }
+
+    async fn is_synchronized(_: &Jvm, _: &mut RuntimeContext, modifiers: i32) -> Result<bool> {
+        Ok(modifiers & 0x0020 != 0)
+    }
+
+    async fn is_volatile(_: &Jvm, _: &mut RuntimeContext, modifiers: i32) -> Result<bool> {
+        Ok(modifiers & 0x0040 != 0)
+    }
+
+    async fn is_transient(_: &Jvm, _: &mut RuntimeContext, modifiers: i32) -> Result<bool> {
+        Ok(modifiers & 0x0080 != 0)
+    }
+
+    async fn is_native(_: &Jvm, _: &mut RuntimeContext, modifiers: i32) -> Result<bool> {
+        Ok(modifiers & 0x0100 != 0)
+    }
+
+    async fn is_interface(_: &Jvm, _: &mut RuntimeContext, modifiers: i32) -> Result<bool> {
+        Ok(modifiers & 0x0200 != 0)
+    }
+
+    async fn is_abstract(_: &Jvm, _: &mut RuntimeContext, modifiers: i32) -> Result<bool> {
+        Ok(modifiers & 0x0400 != 0)
+    }
+
+    async fn is_strict(_: &Jvm, _: &mut RuntimeContext, modifiers: i32) -> Result<bool> {
+        Ok(modifiers & 0x0800 != 0)
+    }
+
+    async fn to_string(jvm: &Jvm, _: &mut RuntimeContext, modifiers: i32) -> Result<ClassInstanceRef<String>> {
+        tracing::debug!("java.lang.reflect.Modifier::toString({modifiers:?})");
+
+        Ok(JavaLangString::from_rust_string(jvm, &Self::to_rust_string(modifiers)).await?.into())
+    }
+
+    pub(crate) fn to_rust_string(modifiers: i32) -> RustString {
+        MODIFIER_NAMES
+            .iter()
+            .filter(|(_, flag)| modifiers & flag != 0)
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    // the bits of the class file access flags that are java language modifiers
+    pub(crate) fn from_class_flags(flags: ClassAccessFlags) -> i32 {
+        (flags.bits() & 0x0611) as _
+    }
+
+    pub(crate) fn from_method_flags(flags: MethodAccessFlags) -> i32 {
+        (flags.bits() & 0x0d3f) as _
+    }
+
+    pub(crate) fn from_field_flags(flags: FieldAccessFlags) -> i32 {
+        (flags.bits() & 0x00df) as _
+    }
+}
@@ -1,12 +1,19 @@
-use alloc::vec;
+use alloc::{vec, vec::Vec};
 
-use java_class_proto::JavaMethodProto;
-use java_constants::MethodAccessFlags;
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_constants::{FieldAccessFlags, MethodAccessFlags};
 use jvm::{ClassInstanceRef, Jvm, Result};
 
-use crate::{RuntimeClassProto, RuntimeContext};
+use crate::{
+    RuntimeClassProto, RuntimeContext,
+    classes::java::{
+        lang::{Object, Thread},
+        util::ArrayList,
+    },
+};
 
 // class java.lang.Runtime
+// Shutdown hooks are kept in a static list, so they stay reachable until the vm exits
 pub struct Runtime;
 
 impl Runtime {
@@ -16,17 +23,49 @@ impl Runtime {
             parent_class: Some("java/lang/Object"),
             interfaces: vec![],
             methods: vec![
+                JavaMethodProto::new("<clinit>", "()V", Self::cl_init, MethodAccessFlags::STATIC),
                 JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
                 JavaMethodProto::new("getRuntime", "()Ljava/lang/Runtime;", Self::get_runtime, MethodAccessFlags::STATIC),
                 JavaMethodProto::new("totalMemory", "()J", Self::total_memory, Default::default()),
                 JavaMethodProto::new("freeMemory", "()J", Self::free_memory, Default::default()),
+                JavaMethodProto::new("maxMemory", "()J", Self::max_memory, Default::default()),
                 JavaMethodProto::new("gc", "()V", Self::gc, Default::default()),
+                JavaMethodProto::new("runFinalization", "()V", Self::run_finalization, Default::default()),
+                JavaMethodProto::new("addShutdownHook", "(Ljava/lang/Thread;)V", Self::add_shutdown_hook, Default::default()),
+                JavaMethodProto::new(
+                    "removeShutdownHook",
+                    "(Ljava/lang/Thread;)Z",
+                    Self::remove_shutdown_hook,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("exit", "(I)V", Self::exit, Default::default()),
+                JavaMethodProto::new("halt", "(I)V", Self::halt, Default::default()),
+                // rustjava internal
+                JavaMethodProto::new(
+                    "runShutdownHooks",
+                    "()V",
+                    Self::run_shutdown_hooks,
+                    MethodAccessFlags::STATIC | MethodAccessFlags::SYNTHETIC,
+                ),
+            ],
+            fields: vec![
+                JavaFieldProto::new("shutdownHooks", "Ljava/util/ArrayList;", FieldAccessFlags::STATIC),
+                JavaFieldProto::new("shuttingDown", "Z", FieldAccessFlags::STATIC),
+                JavaFieldProto::new("hooksStarted", "Z", FieldAccessFlags::STATIC),
             ],
-            fields: vec![],
             access_flags: Default::default(),
         }
     }
 
+    async fn cl_init(jvm: &Jvm, _: &mut RuntimeContext) -> Result<()> {
+        tracing::debug!("java.lang.Runtime::<clinit>");
+
+        let hooks = jvm.new_class("java/util/ArrayList", "()V", ()).await?;
+        jvm.put_static_field("java/lang/Runtime", "shutdownHooks", "Ljava/util/ArrayList;", hooks)
+            .await?;
+        jvm.put_static_field("java/lang/Runtime", "shuttingDown", "Z", false).await
+    }
+
     async fn init(_: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Runtime>) -> Result<()> {
         tracing::warn!("stub java.lang.Runtime::<init>({this:?})");
 
@@ -41,16 +80,27 @@ impl Runtime {
         Ok(instance.into())
     }
 
-    async fn total_memory(_: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Runtime>) -> Result<i64> {
-        tracing::warn!("stub java.lang.Runtime::totalMemory({this:?})");
+    async fn total_memory(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Runtime>) -> Result<i64> {
+        tracing::debug!("java.lang.Runtime::totalMemory({this:?})");
 
-        Ok(0x100000) // TODO: hardcoded
+        Ok(Self::total(jvm) as _)
     }
 
-    async fn free_memory(_: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Runtime>) -> Result<i64> {
-        tracing::warn!("stub java.lang.Runtime::freeMemory({this:?})");
+    async fn free_memory(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Runtime>) -> Result<i64> {
+        tracing::debug!("java.lang.Runtime::freeMemory({this:?})");
+
+        Ok(Self::total(jvm).saturating_sub(jvm.heap_used()) as _)
+    }
+
+    async fn max_memory(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Runtime>) -> Result<i64> {
+        tracing::debug!("java.lang.Runtime::maxMemory({this:?})");
+
+        Ok(jvm.max_heap_size().map(|x| x as i64).unwrap_or(i64::MAX))
+    }
 
-        Ok(0x100000) // TODO: hardcoded
+    // the whole configured heap counts as committed; without a limit, the heap is exactly as large as what's in use
+    fn total(jvm: &Jvm) -> usize {
+        jvm.max_heap_size().unwrap_or_else(|| jvm.heap_used())
     }
 
     async fn gc(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Runtime>) -> Result<()> {
@@ -60,4 +110,164 @@ impl Runtime {
 
         Ok(())
     }
+
+    async fn run_finalization(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Runtime>) -> Result<()> {
+        tracing::debug!("java.lang.Runtime::runFinalization({this:?})");
+
+        while let Some(object) = jvm.poll_finalizable() {
+            Object::run_finalizer(jvm, object).await;
+        }
+
+        Ok(())
+    }
+
+    async fn add_shutdown_hook(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Runtime>, hook: ClassInstanceRef<Thread>) -> Result<()> {
+        tracing::debug!("java.lang.Runtime::addShutdownHook({this:?}, {hook:?})");
+
+        if hook.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "hook").await);
+        }
+        let shutting_down: bool = jvm.get_static_field("java/lang/Runtime", "shuttingDown", "Z").await?;
+        if shutting_down {
+            return Err(jvm.exception("java/lang/IllegalStateException", "Shutdown in progress").await);
+        }
+        let started: bool = jvm.get_field(&hook, "started", "Z").await?;
+        if started {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Hook already running").await);
+        }
+
+        let hooks: ClassInstanceRef<ArrayList> = jvm
+            .get_static_field("java/lang/Runtime", "shutdownHooks", "Ljava/util/ArrayList;")
+            .await?;
+        let registered: bool = jvm.invoke_virtual(&hooks, "contains", "(Ljava/lang/Object;)Z", (hook.clone(),)).await?;
+        if registered {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Hook previously registered").await);
+        }
+        let _: bool = jvm.invoke_virtual(&hooks, "add", "(Ljava/lang/Object;)Z", (hook,)).await?;
+
+        Ok(())
+    }
+
+    async fn remove_shutdown_hook(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Runtime>,
+        hook: ClassInstanceRef<Thread>,
+    ) -> Result<bool> {
+        tracing::debug!("java.lang.Runtime::removeShutdownHook({this:?}, {hook:?})");
+
+        if hook.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "hook").await);
+        }
+        let shutting_down: bool = jvm.get_static_field("java/lang/Runtime", "shuttingDown", "Z").await?;
+        if shutting_down {
+            return Err(jvm.exception("java/lang/IllegalStateException", "Shutdown in progress").await);
+        }
+
+        let hooks: ClassInstanceRef<ArrayList> = jvm
+            .get_static_field("java/lang/Runtime", "shutdownHooks", "Ljava/util/ArrayList;")
+            .await?;
+        jvm.invoke_virtual(&hooks, "remove", "(Ljava/lang/Object;)Z", (hook,)).await
+    }
+
+    async fn exit(jvm: &Jvm, context: &mut RuntimeContext, this: ClassInstanceRef<Runtime>, status: i32) -> Result<()> {
+        tracing::debug!("java.lang.Runtime::exit({this:?}, {status})");
+
+        let _: () = jvm.invoke_static("java/lang/Runtime", "runShutdownHooks", "()V", ()).await?;
+        context.exit(status);
+
+        Ok(())
+    }
+
+    async fn halt(_: &Jvm, context: &mut RuntimeContext, this: ClassInstanceRef<Runtime>, status: i32) -> Result<()> {
+        tracing::debug!("java.lang.Runtime::halt({this:?}, {status})");
+
+        context.exit(status);
+
+        Ok(())
+    }
+
+    // starts every registered hook and waits for all of them, then reports the first failure. only the first call runs
+    // them; a re-entrant exit, from a hook or another thread, waits for them instead, as the jdk blocks it
+    async fn run_shutdown_hooks(jvm: &Jvm, _: &mut RuntimeContext) -> Result<()> {
+        tracing::debug!("java.lang.Runtime::runShutdownHooks");
+
+        let shutting_down: bool = jvm.get_static_field("java/lang/Runtime", "shuttingDown", "Z").await?;
+        if shutting_down {
+            return Self::wait_for_shutdown_hooks(jvm).await;
+        }
+        jvm.put_static_field("java/lang/Runtime", "shuttingDown", "Z", true).await?;
+
+        let hooks: ClassInstanceRef<ArrayList> = jvm
+            .get_static_field("java/lang/Runtime", "shutdownHooks", "Ljava/util/ArrayList;")
+            .await?;
+        let count: i32 = jvm.invoke_virtual(&hooks, "size", "()I", ()).await?;
+        let mut registered = Vec::with_capacity(count as _);
+        for i in 0..count {
+            let hook: ClassInstanceRef<Thread> = jvm.invoke_virtual(&hooks, "get", "(I)Ljava/lang/Object;", (i,)).await?;
+            registered.push(hook);
+        }
+
+        let mut first_error = None;
+        let mut started = Vec::new();
+        for hook in registered {
+            match jvm.invoke_virtual(&hook, "start", "()V", ()).await {
+                Ok(()) => started.push(hook),
+                Err(err) => {
+                    first_error.get_or_insert(err);
+                }
+            }
+        }
+        if let Err(err) = Self::set_hooks_started(jvm, &hooks).await {
+            first_error.get_or_insert(err);
+        }
+        for hook in started {
+            if let Err(err) = jvm.invoke_virtual::<_, ()>(&hook, "join", "()V", ()).await {
+                first_error.get_or_insert(err);
+            }
+        }
+
+        match first_error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    async fn set_hooks_started(jvm: &Jvm, hooks: &ClassInstanceRef<ArrayList>) -> Result<()> {
+        jvm.monitor_enter(hooks).await?;
+        jvm.put_static_field("java/lang/Runtime", "hooksStarted", "Z", true).await?;
+        let result: Result<()> = jvm.invoke_virtual(hooks, "notifyAll", "()V", ()).await;
+        jvm.monitor_exit(hooks).await?;
+
+        result
+    }
+
+    // joins every hook but the calling one, which would never end, once the first exit has started them all
+    async fn wait_for_shutdown_hooks(jvm: &Jvm) -> Result<()> {
+        let hooks: ClassInstanceRef<ArrayList> = jvm
+            .get_static_field("java/lang/Runtime", "shutdownHooks", "Ljava/util/ArrayList;")
+            .await?;
+
+        jvm.monitor_enter(&hooks).await?;
+        let waited: Result<()> = async {
+            while !jvm.get_static_field::<bool>("java/lang/Runtime", "hooksStarted", "Z").await? {
+                let _: () = jvm.invoke_virtual(&hooks, "wait", "()V", ()).await?;
+            }
+            Ok(())
+        }
+        .await;
+        jvm.monitor_exit(&hooks).await?;
+        waited?;
+
+        let current = jvm.current_java_thread()?;
+        let count: i32 = jvm.invoke_virtual(&hooks, "size", "()I", ()).await?;
+        for i in 0..count {
+            let hook: ClassInstanceRef<Thread> = jvm.invoke_virtual(&hooks, "get", "(I)Ljava/lang/Object;", (i,)).await?;
+            if hook.instance.as_ref() != Some(&current) {
+                let _: () = jvm.invoke_virtual(&hook, "join", "()V", ()).await?;
+            }
+        }
+
+        Ok(())
+    }
 }
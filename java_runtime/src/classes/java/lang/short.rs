@@ -1,9 +1,9 @@
-use alloc::{format, vec};
+use alloc::{boxed::Box, format, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::{ClassAccessFlags, FieldAccessFlags, MethodAccessFlags};
 use jvm::{
-    ClassInstanceRef, Jvm, Result,
+    ClassInstance, ClassInstanceRef, Jvm, Result,
     runtime::{JavaLangClass, JavaLangString},
 };
 
@@ -156,7 +156,7 @@ impl Short {
     }
 
     async fn decode(jvm: &Jvm, _: &mut RuntimeContext, value: ClassInstanceRef<String>) -> Result<ClassInstanceRef<Self>> {
-        let integer = jvm
+        let integer: Box<dyn ClassInstance> = jvm
             .invoke_static("java/lang/Integer", "decode", "(Ljava/lang/String;)Ljava/lang/Integer;", (value,))
             .await?;
         let value: i32 = jvm.invoke_virtual(&integer, "intValue", "()I", ()).await?;
@@ -196,7 +196,7 @@ impl Short {
         Ok(jvm.get_field::<i16>(&this, "value", "S").await? as i32)
     }
     async fn equals(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
-        if other.is_null() || !jvm.is_instance(&**other, "java/lang/Short") {
+        if other.is_null() || !jvm.is_instance(&other, "java/lang/Short") {
             return Ok(false);
         }
         Ok(jvm.invoke_virtual::<_, i16>(&this, "shortValue", "()S", ()).await?
@@ -214,7 +214,7 @@ impl Short {
         if other.is_null() {
             return Err(jvm.exception("java/lang/NullPointerException", "other").await);
         }
-        if !jvm.is_instance(&**other, "java/lang/Short") {
+        if !jvm.is_instance(&other, "java/lang/Short") {
             return Err(jvm.exception("java/lang/ClassCastException", "not Short").await);
         }
         let other = ClassInstanceRef::<Self>::from(other.instance);
@@ -1,6 +1,7 @@
 use core::cmp::Ordering;
 
 use alloc::{
+    boxed::Box,
     format,
     string::{String as RustString, ToString},
     vec,
@@ -9,9 +10,9 @@ use alloc::{
 
 use bytemuck::{cast_slice, cast_vec};
 
-use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_class_proto::{JavaFieldProto, JavaMethodProto, java_class};
 use java_constants::MethodAccessFlags;
-use jvm::{Array, ClassInstanceRef, JavaChar, Jvm, Result, runtime::JavaLangString};
+use jvm::{Array, ClassInstance, ClassInstanceRef, JavaChar, JavaClassName, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -23,10 +24,11 @@ use super::StringBuffer;
 // class java.lang.String
 pub struct String;
 
+#[java_class("java/lang/String")]
 impl String {
     pub fn as_proto() -> RuntimeClassProto {
         RuntimeClassProto {
-            name: "java/lang/String",
+            name: <Self as JavaClassName>::NAME,
             parent_class: Some("java/lang/Object"),
             interfaces: vec![],
             methods: vec![
@@ -236,7 +238,7 @@ impl String {
     async fn hash_code(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i32> {
         tracing::debug!("java.lang.String::hashCode({this:?})");
 
-        let chars = jvm.get_field(&this, "value", "[C").await?;
+        let chars: Box<dyn ClassInstance> = jvm.get_field(&this, "value", "[C").await?;
         let chars: Vec<JavaChar> = jvm.load_array(&chars, 0, jvm.array_length(&chars).await? as _).await?;
 
         let hash = chars.iter().fold(0i32, |acc, &c| acc.wrapping_mul(31).wrapping_add(c as i32));
@@ -253,7 +255,7 @@ impl String {
     async fn char_at(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, index: i32) -> Result<u16> {
         tracing::debug!("java.lang.String::charAt({this:?}, {index})");
 
-        let value = jvm.get_field(&this, "value", "[C").await?;
+        let value: Box<dyn ClassInstance> = jvm.get_field(&this, "value", "[C").await?;
 
         Ok(jvm.load_array(&value, index as _, 1).await?[0])
     }
@@ -299,7 +301,7 @@ impl String {
     ) -> Result<()> {
         tracing::debug!("java.lang.String::getChars({this:?}, {src_begin}, {src_end}, {dst:?}, {dst_begin})");
 
-        let value = jvm.get_field(&this, "value", "[C").await?;
+        let value: Box<dyn ClassInstance> = jvm.get_field(&this, "value", "[C").await?;
 
         let count = src_end - src_begin;
         let chars: Vec<JavaChar> = jvm.load_array(&value, src_begin as _, count as _).await?;
@@ -319,7 +321,7 @@ impl String {
     async fn length(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i32> {
         tracing::debug!("java.lang.String::length({this:?})");
 
-        let value = jvm.get_field(&this, "value", "[C").await?;
+        let value: Box<dyn ClassInstance> = jvm.get_field(&this, "value", "[C").await?;
 
         Ok(jvm.array_length(&value).await? as _)
     }
@@ -417,7 +419,7 @@ impl String {
             return Ok(-1);
         }
 
-        let value = jvm.get_field(&this, "value", "[C").await?;
+        let value: Box<dyn ClassInstance> = jvm.get_field(&this, "value", "[C").await?;
         let length = jvm.array_length(&value).await?;
         let chars: Vec<JavaChar> = jvm.load_array(&value, 0, length).await?;
         let from_index = from_index.max(0) as usize;
@@ -448,10 +450,10 @@ impl String {
             return Err(jvm.exception("java/lang/NullPointerException", "str is null").await);
         }
 
-        let value = jvm.get_field(&this, "value", "[C").await?;
+        let value: Box<dyn ClassInstance> = jvm.get_field(&this, "value", "[C").await?;
         let length = jvm.array_length(&value).await?;
         let chars: Vec<JavaChar> = jvm.load_array(&value, 0, length).await?;
-        let pattern = jvm.get_field(&str, "value", "[C").await?;
+        let pattern: Box<dyn ClassInstance> = jvm.get_field(&str, "value", "[C").await?;
         let pattern_length = jvm.array_length(&pattern).await?;
         let pattern: Vec<JavaChar> = jvm.load_array(&pattern, 0, pattern_length).await?;
         let from_index = (from_index.max(0) as usize).min(chars.len());
@@ -475,7 +477,7 @@ impl String {
             return Ok(-1);
         }
 
-        let value = jvm.get_field(&this, "value", "[C").await?;
+        let value: Box<dyn ClassInstance> = jvm.get_field(&this, "value", "[C").await?;
         let length = jvm.array_length(&value).await?;
         let chars: Vec<JavaChar> = jvm.load_array(&value, 0, length).await?;
         let index = chars.iter().rposition(|&value| value == ch as u16).map(|index| index as i32);
@@ -486,7 +488,7 @@ impl String {
     async fn trim(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Self>> {
         tracing::debug!("java.lang.String::trim({this:?})");
 
-        let value = jvm.get_field(&this, "value", "[C").await?;
+        let value: Box<dyn ClassInstance> = jvm.get_field(&this, "value", "[C").await?;
         let length = jvm.array_length(&value).await?;
         let chars: Vec<JavaChar> = jvm.load_array(&value, 0, length).await?;
         let start = chars.iter().position(|&value| value > 0x20).unwrap_or(chars.len());
@@ -532,10 +534,10 @@ impl String {
             return Ok(false);
         }
 
-        let value = jvm.get_field(&this, "value", "[C").await?;
+        let value: Box<dyn ClassInstance> = jvm.get_field(&this, "value", "[C").await?;
         let length = jvm.array_length(&value).await?;
         let chars: Vec<JavaChar> = jvm.load_array(&value, 0, length).await?;
-        let prefix_value = jvm.get_field(&prefix, "value", "[C").await?;
+        let prefix_value: Box<dyn ClassInstance> = jvm.get_field(&prefix, "value", "[C").await?;
         let prefix_length = jvm.array_length(&prefix_value).await?;
         let prefix: Vec<JavaChar> = jvm.load_array(&prefix_value, 0, prefix_length).await?;
 
@@ -666,7 +668,7 @@ impl String {
     ) -> Result<ClassInstanceRef<Self>> {
         tracing::debug!("java.lang.String::replace({this:?}, {old_char}, {new_char})");
 
-        let value = jvm.get_field(&this, "value", "[C").await?;
+        let value: Box<dyn ClassInstance> = jvm.get_field(&this, "value", "[C").await?;
         let length = jvm.array_length(&value).await?;
         let chars: Vec<JavaChar> = jvm.load_array(&value, 0, length).await?;
 
@@ -739,7 +741,7 @@ impl String {
             return Ok(-1);
         }
 
-        let value = jvm.get_field(&this, "value", "[C").await?;
+        let value: Box<dyn ClassInstance> = jvm.get_field(&this, "value", "[C").await?;
         let length = jvm.array_length(&value).await?;
         let chars: Vec<JavaChar> = jvm.load_array(&value, 0, length).await?;
         let end = (from_index as usize + 1).min(chars.len());
@@ -765,7 +767,7 @@ impl String {
     async fn intern(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Self>> {
         tracing::debug!("java.lang.String::intern({this:?})");
 
-        let chars = jvm.get_field(&this, "value", "[C").await?;
+        let chars: Box<dyn ClassInstance> = jvm.get_field(&this, "value", "[C").await?;
         let length = jvm.array_length(&chars).await?;
         let utf16: Vec<JavaChar> = jvm.load_array(&chars, 0, length).await?;
 
@@ -1,7 +1,7 @@
-use alloc::{string::ToString, vec, vec::Vec};
+use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{Array, ClassInstanceRef, JavaChar, Jvm, Result, runtime::JavaLangString};
+use jvm::{Array, ClassInstance, ClassInstanceRef, JavaChar, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -77,7 +77,7 @@ impl StringBuffer {
 
         let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
 
-        let value_array = jvm.invoke_virtual(&string, "toCharArray", "()[C", ()).await?;
+        let value_array: Box<dyn ClassInstance> = jvm.invoke_virtual(&string, "toCharArray", "()[C", ()).await?;
         let length = jvm.array_length(&value_array).await? as i32;
 
         jvm.put_field(&mut this, "value", "[C", value_array).await?;
@@ -116,7 +116,7 @@ impl StringBuffer {
         let string = if object.is_null() {
             "null".into()
         } else {
-            let string = jvm.invoke_virtual(&object, "toString", "()Ljava/lang/String;", ()).await?;
+            let string: Box<dyn ClassInstance> = jvm.invoke_virtual(&object, "toString", "()Ljava/lang/String;", ()).await?;
             JavaLangString::to_rust_string(jvm, &string).await?
         };
 
@@ -214,7 +214,7 @@ impl StringBuffer {
     }
 
     async fn ensure_capacity(jvm: &Jvm, this: &mut ClassInstanceRef<Self>, capacity: usize) -> Result<()> {
-        let java_value_array = jvm.get_field(this, "value", "[C").await?;
+        let java_value_array: Box<dyn ClassInstance> = jvm.get_field(this, "value", "[C").await?;
         let current_capacity = jvm.array_length(&java_value_array).await?;
 
         if current_capacity < capacity {
@@ -240,7 +240,7 @@ impl StringBuffer {
 
         StringBuffer::ensure_capacity(jvm, this, (current_count + count_to_add) as _).await?;
 
-        let mut java_value_array = jvm.get_field(this, "value", "[C").await?;
+        let mut java_value_array: Box<dyn ClassInstance> = jvm.get_field(this, "value", "[C").await?;
         jvm.store_array(&mut java_value_array, current_count as _, value_to_add).await?;
         jvm.put_field(this, "count", "I", current_count + count_to_add).await?;
 
@@ -1,6 +1,6 @@
-use alloc::{string::String as RustString, vec, vec::Vec};
+use alloc::{boxed::Box, format, string::String as RustString, vec, vec::Vec};
 
-use jvm::{JavaValue, runtime::JavaLangString};
+use jvm::{ClassInstance, JavaValue, runtime::JavaLangString};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::{FieldAccessFlags, MethodAccessFlags};
@@ -29,6 +29,7 @@ impl System {
                     MethodAccessFlags::NATIVE | MethodAccessFlags::STATIC,
                 ),
                 JavaMethodProto::new("gc", "()V", Self::gc, MethodAccessFlags::STATIC),
+                JavaMethodProto::new("runFinalization", "()V", Self::run_finalization, MethodAccessFlags::STATIC),
                 JavaMethodProto::new(
                     "arraycopy",
                     "(Ljava/lang/Object;ILjava/lang/Object;II)V",
@@ -48,6 +49,7 @@ impl System {
                     MethodAccessFlags::STATIC,
                 ),
                 JavaMethodProto::new("exit", "(I)V", Self::exit, MethodAccessFlags::STATIC),
+                JavaMethodProto::new("loadLibrary", "(Ljava/lang/String;)V", Self::load_library, MethodAccessFlags::STATIC),
             ],
             fields: vec![
                 JavaFieldProto::new("out", "Ljava/io/PrintStream;", FieldAccessFlags::STATIC),
@@ -102,6 +104,13 @@ impl System {
         Ok(())
     }
 
+    async fn run_finalization(jvm: &Jvm, _: &mut RuntimeContext) -> Result<()> {
+        tracing::debug!("java.lang.System::runFinalization()");
+
+        let runtime: Box<dyn ClassInstance> = jvm.invoke_static("java/lang/Runtime", "getRuntime", "()Ljava/lang/Runtime;", ()).await?;
+        jvm.invoke_virtual(&runtime, "runFinalization", "()V", ()).await
+    }
+
     async fn arraycopy(
         jvm: &Jvm,
         _: &mut RuntimeContext,
@@ -123,7 +132,7 @@ impl System {
     async fn get_property(jvm: &Jvm, _: &mut RuntimeContext, key: ClassInstanceRef<String>) -> Result<ClassInstanceRef<String>> {
         tracing::debug!("java.lang.System::getProperty({key:?})");
 
-        let props = jvm.get_static_field("java/lang/System", "props", "Ljava/util/Properties;").await?;
+        let props: Box<dyn ClassInstance> = jvm.get_static_field("java/lang/System", "props", "Ljava/util/Properties;").await?;
         let value = jvm
             .invoke_virtual(&props, "getProperty", "(Ljava/lang/String;)Ljava/lang/String;", (key,))
             .await?;
@@ -139,7 +148,7 @@ impl System {
     ) -> Result<ClassInstanceRef<String>> {
         tracing::debug!("java.lang.System::setProperty({key:?}, {value:?})");
 
-        let props = jvm.get_static_field("java/lang/System", "props", "Ljava/util/Properties;").await?;
+        let props: Box<dyn ClassInstance> = jvm.get_static_field("java/lang/System", "props", "Ljava/util/Properties;").await?;
         let value = jvm
             .invoke_virtual(
                 &props,
@@ -152,10 +161,23 @@ impl System {
         Ok(value)
     }
 
-    async fn exit(_jvm: &Jvm, context: &mut RuntimeContext, status: i32) -> Result<()> {
+    async fn exit(jvm: &Jvm, _: &mut RuntimeContext, status: i32) -> Result<()> {
         tracing::debug!("java.lang.System::exit({status})");
 
-        context.exit(status);
+        let runtime: Box<dyn ClassInstance> = jvm.invoke_static("java/lang/Runtime", "getRuntime", "()Ljava/lang/Runtime;", ()).await?;
+        jvm.invoke_virtual(&runtime, "exit", "(I)V", (status,)).await
+    }
+
+    async fn load_library(jvm: &Jvm, _: &mut RuntimeContext, name: RustString) -> Result<()> {
+        tracing::debug!("java.lang.System::loadLibrary({name:?})");
+
+        // libraries are bundles of natives the embedder registered with Jvm::register_native_library
+        if !jvm.load_native_library(&name)? {
+            return Err(jvm
+                .exception("java/lang/UnsatisfiedLinkError", &format!("no {name} in java.library.path"))
+                .await);
+        }
+
         Ok(())
     }
 
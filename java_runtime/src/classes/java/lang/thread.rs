@@ -3,11 +3,14 @@ use core::time::Duration;
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::{FieldAccessFlags, MethodAccessFlags};
-use jvm::{ClassInstanceRef, GlobalRef, Jvm, Result, runtime::JavaLangString};
+use jvm::{ClassInstance, ClassInstanceRef, GlobalRef, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext, SpawnCallback,
-    classes::java::lang::{Runnable, String},
+    classes::java::{
+        lang::{Object, Runnable, String, ThreadGroup},
+        util::HashMap,
+    },
 };
 
 // class java.lang.Thread
@@ -30,18 +33,41 @@ impl Thread {
                     Self::init_with_runnable_and_name,
                     Default::default(),
                 ),
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/ThreadGroup;Ljava/lang/Runnable;)V",
+                    Self::init_with_group_and_runnable,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/ThreadGroup;Ljava/lang/String;)V",
+                    Self::init_with_group_and_name,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/ThreadGroup;Ljava/lang/Runnable;Ljava/lang/String;)V",
+                    Self::init_with_group_runnable_and_name,
+                    Default::default(),
+                ),
                 JavaMethodProto::new("start", "()V", Self::start, MethodAccessFlags::SYNCHRONIZED),
                 JavaMethodProto::new("join", "()V", Self::join, MethodAccessFlags::SYNCHRONIZED),
                 JavaMethodProto::new("run", "()V", Self::run, Default::default()),
                 JavaMethodProto::new("isAlive", "()Z", Self::is_alive, Default::default()),
                 JavaMethodProto::new("getName", "()Ljava/lang/String;", Self::get_name, Default::default()),
                 JavaMethodProto::new("getPriority", "()I", Self::get_priority, Default::default()),
+                JavaMethodProto::new("getThreadGroup", "()Ljava/lang/ThreadGroup;", Self::get_thread_group, Default::default()),
                 JavaMethodProto::new("interrupt", "()V", Self::interrupt, Default::default()),
+                JavaMethodProto::new("interrupted", "()Z", Self::interrupted, MethodAccessFlags::STATIC),
+                JavaMethodProto::new("isInterrupted", "()Z", Self::is_interrupted, Default::default()),
                 JavaMethodProto::new("activeCount", "()I", Self::active_count, MethodAccessFlags::STATIC),
                 JavaMethodProto::new("toString", "()Ljava/lang/String;", Self::to_string, Default::default()),
                 JavaMethodProto::new("sleep", "(J)V", Self::sleep, MethodAccessFlags::NATIVE | MethodAccessFlags::STATIC),
                 JavaMethodProto::new("yield", "()V", Self::r#yield, MethodAccessFlags::NATIVE | MethodAccessFlags::STATIC),
                 JavaMethodProto::new("setPriority", "(I)V", Self::set_priority, Default::default()),
+                JavaMethodProto::new("setDaemon", "(Z)V", Self::set_daemon, Default::default()),
+                JavaMethodProto::new("isDaemon", "()Z", Self::is_daemon, Default::default()),
                 JavaMethodProto::new(
                     "currentThread",
                     "()Ljava/lang/Thread;",
@@ -49,7 +75,7 @@ impl Thread {
                     MethodAccessFlags::NATIVE | MethodAccessFlags::STATIC,
                 ),
                 // rustjava internal
-                JavaMethodProto::new("<init>", "(Z)V", Self::init_internal, Default::default()),
+                JavaMethodProto::new("<init>", "(Z)V", Self::init_internal, MethodAccessFlags::SYNTHETIC),
             ],
             fields: vec![
                 JavaFieldProto::new(
@@ -75,6 +101,11 @@ impl Thread {
                 JavaFieldProto::new("interrupted", "Z", Default::default()),
                 JavaFieldProto::new("started", "Z", Default::default()),
                 JavaFieldProto::new("alive", "Z", Default::default()),
+                JavaFieldProto::new("daemon", "Z", Default::default()),
+                // null for threads attached from rust, which belong to the main group
+                JavaFieldProto::new("group", "Ljava/lang/ThreadGroup;", Default::default()),
+                JavaFieldProto::new("threadLocals", "Ljava/util/HashMap;", Default::default()),
+                JavaFieldProto::new("inheritableThreadLocals", "Ljava/util/HashMap;", Default::default()),
             ],
             access_flags: Default::default(),
         }
@@ -90,9 +121,7 @@ impl Thread {
     async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.lang.Thread::<init>({this:?})");
 
-        let number: i32 = jvm.get_static_field("java/lang/Thread", "threadInitNumber", "I").await?;
-        jvm.put_static_field("java/lang/Thread", "threadInitNumber", "I", number + 1).await?;
-        let name = JavaLangString::from_rust_string(jvm, &format!("Thread-{number}")).await?;
+        let name = Self::next_thread_name(jvm).await?;
         let target = ClassInstanceRef::<Runnable>::new(None);
         let _: () = jvm
             .invoke_special(
@@ -110,9 +139,7 @@ impl Thread {
     async fn init_with_runnable(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, target: ClassInstanceRef<Runnable>) -> Result<()> {
         tracing::debug!("java.lang.Thread::<init>({this:?}, {target:?})");
 
-        let number: i32 = jvm.get_static_field("java/lang/Thread", "threadInitNumber", "I").await?;
-        jvm.put_static_field("java/lang/Thread", "threadInitNumber", "I", number + 1).await?;
-        let name = JavaLangString::from_rust_string(jvm, &format!("Thread-{number}")).await?;
+        let name = Self::next_thread_name(jvm).await?;
         let _: () = jvm
             .invoke_special(
                 &this,
@@ -126,6 +153,13 @@ impl Thread {
         Ok(())
     }
 
+    async fn next_thread_name(jvm: &Jvm) -> Result<ClassInstanceRef<String>> {
+        let number: i32 = jvm.get_static_field("java/lang/Thread", "threadInitNumber", "I").await?;
+        jvm.put_static_field("java/lang/Thread", "threadInitNumber", "I", number + 1).await?;
+
+        Ok(JavaLangString::from_rust_string(jvm, &format!("Thread-{number}")).await?.into())
+    }
+
     async fn init_with_name(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, name: ClassInstanceRef<String>) -> Result<()> {
         tracing::debug!("java.lang.Thread::<init>({this:?}, {name:?})");
 
@@ -146,12 +180,83 @@ impl Thread {
     async fn init_with_runnable_and_name(
         jvm: &Jvm,
         _: &mut RuntimeContext,
-        mut this: ClassInstanceRef<Self>,
+        this: ClassInstanceRef<Self>,
         target: ClassInstanceRef<Runnable>,
         name: ClassInstanceRef<String>,
     ) -> Result<()> {
         tracing::debug!("java.lang.Thread::<init>({this:?}, {target:?}, {name:?})");
 
+        let group = ClassInstanceRef::<ThreadGroup>::new(None);
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "java/lang/Thread",
+                "<init>",
+                "(Ljava/lang/ThreadGroup;Ljava/lang/Runnable;Ljava/lang/String;)V",
+                (group, target, name),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn init_with_group_and_runnable(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        group: ClassInstanceRef<ThreadGroup>,
+        target: ClassInstanceRef<Runnable>,
+    ) -> Result<()> {
+        tracing::debug!("java.lang.Thread::<init>({this:?}, {group:?}, {target:?})");
+
+        let name = Self::next_thread_name(jvm).await?;
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "java/lang/Thread",
+                "<init>",
+                "(Ljava/lang/ThreadGroup;Ljava/lang/Runnable;Ljava/lang/String;)V",
+                (group, target, name),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn init_with_group_and_name(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        group: ClassInstanceRef<ThreadGroup>,
+        name: ClassInstanceRef<String>,
+    ) -> Result<()> {
+        tracing::debug!("java.lang.Thread::<init>({this:?}, {group:?}, {name:?})");
+
+        let target = ClassInstanceRef::<Runnable>::new(None);
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "java/lang/Thread",
+                "<init>",
+                "(Ljava/lang/ThreadGroup;Ljava/lang/Runnable;Ljava/lang/String;)V",
+                (group, target, name),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    // a null group means the group of the thread creating this one, like the jdk
+    async fn init_with_group_runnable_and_name(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        mut this: ClassInstanceRef<Self>,
+        group: ClassInstanceRef<ThreadGroup>,
+        target: ClassInstanceRef<Runnable>,
+        name: ClassInstanceRef<String>,
+    ) -> Result<()> {
+        tracing::debug!("java.lang.Thread::<init>({this:?}, {group:?}, {target:?}, {name:?})");
+
         if name.is_null() {
             return Err(jvm.exception("java/lang/NullPointerException", "name").await);
         }
@@ -164,6 +269,18 @@ impl Thread {
         jvm.put_field(&mut this, "started", "Z", false).await?;
         jvm.put_field(&mut this, "alive", "Z", false).await?;
 
+        // like the jdk, new threads inherit daemon status from the thread creating them
+        let daemon: bool = jvm.get_field(&jvm.current_java_thread()?, "daemon", "Z").await?;
+        jvm.put_field(&mut this, "daemon", "Z", daemon).await?;
+
+        let group = if group.is_null() {
+            jvm.invoke_virtual(&jvm.current_java_thread()?, "getThreadGroup", "()Ljava/lang/ThreadGroup;", ())
+                .await?
+        } else {
+            group
+        };
+        jvm.put_field(&mut this, "group", "Ljava/lang/ThreadGroup;", group).await?;
+
         Ok(())
     }
 
@@ -188,10 +305,22 @@ impl Thread {
             return Err(jvm.exception("java/lang/IllegalThreadStateException", "thread already started").await);
         }
 
+        // keeps the vm alive until the thread's task is gone, however it ends
+        struct NonDaemonThread {
+            jvm: Jvm,
+        }
+
+        impl Drop for NonDaemonThread {
+            fn drop(&mut self) {
+                self.jvm.non_daemon_thread_ended();
+            }
+        }
+
         struct ThreadStartProxy {
             jvm: Jvm,
             thread_id: i32,
             this: GlobalRef<Thread>,
+            _non_daemon: Option<NonDaemonThread>,
         }
 
         #[async_trait::async_trait]
@@ -205,24 +334,25 @@ impl Thread {
                 let result: Result<()> = self.jvm.invoke_virtual(&self.this, "run", "()V", []).await;
 
                 if let Err(jvm::JavaError::JavaException(exception)) = &result {
-                    let trace = async {
-                        let string_writer = self.jvm.new_class("java/io/StringWriter", "()V", ()).await?;
-                        let print_writer = self
-                            .jvm
-                            .new_class("java/io/PrintWriter", "(Ljava/io/Writer;)V", (string_writer.clone(),))
-                            .await?;
-                        let _: () = self
+                    self.jvm.uncaught_exception(&**exception);
+
+                    let handled: Result<()> = async {
+                        let group: Box<dyn ClassInstance> = self
                             .jvm
-                            .invoke_virtual(exception, "printStackTrace", "(Ljava/io/PrintWriter;)V", (print_writer,))
+                            .invoke_virtual(&self.this, "getThreadGroup", "()Ljava/lang/ThreadGroup;", ())
                             .await?;
-                        let trace = self.jvm.invoke_virtual(&string_writer, "toString", "()Ljava/lang/String;", []).await?;
-                        JavaLangString::to_rust_string(&self.jvm, &trace).await
+                        self.jvm
+                            .invoke_virtual(
+                                &group,
+                                "uncaughtException",
+                                "(Ljava/lang/Thread;Ljava/lang/Throwable;)V",
+                                (self.this.instance.clone(), exception.clone()),
+                            )
+                            .await
                     }
                     .await;
-
-                    match trace {
-                        Ok(trace) => tracing::error!("Uncaught exception in thread {}:\n{}", self.thread_id, trace),
-                        Err(error) => tracing::error!(?error, "failed to format uncaught exception in thread {}", self.thread_id),
+                    if let Err(error) = handled {
+                        tracing::error!(?error, "uncaught exception handler failed in thread {}", self.thread_id);
                     }
                 }
 
@@ -250,25 +380,71 @@ impl Thread {
 
         jvm.put_field(&mut this, "started", "Z", true).await?;
         jvm.put_field(&mut this, "alive", "Z", true).await?;
+        Self::inherit_thread_locals(jvm, &mut this).await?;
 
         let id: i32 = jvm.invoke_virtual(&this, "hashCode", "()I", ()).await?;
+        let daemon: bool = jvm.get_field(&this, "daemon", "Z").await?;
 
         let this = match jvm.new_global_ref(&this) {
             Some(this) => this,
             None => return Err(jvm.exception("java/lang/NullPointerException", "thread is null").await),
         };
+        let non_daemon = (!daemon).then(|| {
+            jvm.non_daemon_thread_started();
+            NonDaemonThread { jvm: jvm.clone() }
+        });
         context.spawn(
             jvm,
             Box::new(ThreadStartProxy {
                 jvm: jvm.clone(),
                 thread_id: id,
                 this,
+                _non_daemon: non_daemon,
             }),
         );
 
         Ok(())
     }
 
+    pub(crate) async fn thread_local_map(jvm: &Jvm, this: &mut ClassInstanceRef<Self>, field: &str) -> Result<ClassInstanceRef<HashMap>> {
+        let map: ClassInstanceRef<HashMap> = jvm.get_field(this, field, "Ljava/util/HashMap;").await?;
+        if !map.is_null() {
+            return Ok(map);
+        }
+
+        let map = jvm.new_class("java/util/HashMap", "()V", ()).await?;
+        jvm.put_field(this, field, "Ljava/util/HashMap;", map.clone()).await?;
+
+        Ok(map.into())
+    }
+
+    // copies the starting thread's inheritable thread locals into the new thread, passing each value through childValue
+    async fn inherit_thread_locals(jvm: &Jvm, this: &mut ClassInstanceRef<Self>) -> Result<()> {
+        let parent = jvm.current_java_thread()?;
+        let parent_map: ClassInstanceRef<HashMap> = jvm.get_field(&parent, "inheritableThreadLocals", "Ljava/util/HashMap;").await?;
+        if parent_map.is_null() {
+            return Ok(());
+        }
+
+        let map = Self::thread_local_map(jvm, this, "inheritableThreadLocals").await?;
+        let entries: Box<dyn ClassInstance> = jvm.invoke_virtual(&parent_map, "entrySet", "()Ljava/util/Set;", ()).await?;
+        let iterator: Box<dyn ClassInstance> = jvm.invoke_virtual(&entries, "iterator", "()Ljava/util/Iterator;", ()).await?;
+        while jvm.invoke_virtual(&iterator, "hasNext", "()Z", ()).await? {
+            let entry: Box<dyn ClassInstance> = jvm.invoke_virtual(&iterator, "next", "()Ljava/lang/Object;", ()).await?;
+            let key: ClassInstanceRef<Object> = jvm.invoke_virtual(&entry, "getKey", "()Ljava/lang/Object;", ()).await?;
+            let value: ClassInstanceRef<Object> = jvm.invoke_virtual(&entry, "getValue", "()Ljava/lang/Object;", ()).await?;
+
+            let value: ClassInstanceRef<Object> = jvm
+                .invoke_virtual(&key, "childValue", "(Ljava/lang/Object;)Ljava/lang/Object;", (value,))
+                .await?;
+            let _: ClassInstanceRef<Object> = jvm
+                .invoke_virtual(&map, "put", "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;", (key, value))
+                .await?;
+        }
+
+        Ok(())
+    }
+
     async fn run(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.lang.Thread::run({this:?})");
 
@@ -309,14 +485,38 @@ impl Thread {
         Ok(name)
     }
 
+    async fn get_thread_group(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<ThreadGroup>> {
+        tracing::debug!("java.lang.Thread::getThreadGroup({this:?})");
+
+        let group: ClassInstanceRef<ThreadGroup> = jvm.get_field(&this, "group", "Ljava/lang/ThreadGroup;").await?;
+        if group.is_null() {
+            return ThreadGroup::main_group(jvm).await;
+        }
+
+        Ok(group)
+    }
+
     async fn get_priority(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i32> {
         tracing::debug!("java.lang.Thread::getPriority({this:?})");
         jvm.get_field(&this, "priority", "I").await
     }
 
-    async fn interrupt(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>) -> Result<()> {
+    async fn interrupt(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.lang.Thread::interrupt({this:?})");
-        jvm.put_field(&mut this, "interrupted", "Z", true).await
+
+        jvm.interrupt(&this);
+
+        Ok(())
+    }
+
+    async fn interrupted(jvm: &Jvm, _: &mut RuntimeContext) -> Result<bool> {
+        tracing::debug!("java.lang.Thread::interrupted()");
+        Ok(jvm.is_interrupted(&jvm.current_java_thread()?, true))
+    }
+
+    async fn is_interrupted(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<bool> {
+        tracing::debug!("java.lang.Thread::isInterrupted({this:?})");
+        jvm.get_field(&this, "interrupted", "Z").await
     }
 
     async fn active_count(jvm: &Jvm, _: &mut RuntimeContext) -> Result<i32> {
@@ -339,7 +539,14 @@ impl Thread {
             return Err(jvm.exception("java/lang/IllegalArgumentException", "timeout value is negative").await);
         }
 
-        context.sleep(Duration::from_millis(duration as _)).await;
+        jvm.set_sleeping(true);
+        let slept = jvm.interruptible(context.sleep(Duration::from_millis(duration as _))).await;
+        jvm.set_sleeping(false);
+
+        if slept.is_none() {
+            jvm.is_interrupted(&jvm.current_java_thread()?, true);
+            return Err(jvm.exception("java/lang/InterruptedException", "sleep interrupted").await);
+        }
 
         Ok(())
     }
@@ -363,9 +570,27 @@ impl Thread {
         Ok(())
     }
 
+    async fn set_daemon(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Thread>, on: bool) -> Result<()> {
+        tracing::debug!("java.lang.Thread::setDaemon({this:?}, {on:?})");
+
+        let alive: bool = jvm.get_field(&this, "alive", "Z").await?;
+        if alive {
+            return Err(jvm.exception("java/lang/IllegalThreadStateException", "thread is alive").await);
+        }
+
+        jvm.put_field(&mut this, "daemon", "Z", on).await?;
+
+        Ok(())
+    }
+
+    async fn is_daemon(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Thread>) -> Result<bool> {
+        tracing::debug!("java.lang.Thread::isDaemon({this:?})");
+        jvm.get_field(&this, "daemon", "Z").await
+    }
+
     async fn current_thread(jvm: &Jvm, _: &mut RuntimeContext) -> Result<ClassInstanceRef<Self>> {
         tracing::debug!("java.lang.Thread::currentThread()");
 
-        Ok(jvm.current_java_thread().into())
+        Ok(jvm.current_java_thread()?.into())
     }
 }
@@ -0,0 +1,249 @@
+use alloc::{format, vec, vec::Vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_constants::{FieldAccessFlags, MethodAccessFlags};
+use jvm::{Array, ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
+
+use crate::{
+    RuntimeClassProto, RuntimeContext,
+    classes::java::{
+        io::PrintStream,
+        lang::{String, Thread, Throwable},
+    },
+};
+
+// class java.lang.ThreadGroup
+// Groups don't keep member lists; membership is computed from the vm's live threads and their `group` fields
+pub struct ThreadGroup;
+
+impl ThreadGroup {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/lang/ThreadGroup",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<clinit>", "()V", Self::cl_init, MethodAccessFlags::STATIC),
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;)V", Self::init, Default::default()),
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/ThreadGroup;Ljava/lang/String;)V",
+                    Self::init_with_parent,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("getName", "()Ljava/lang/String;", Self::get_name, Default::default()),
+                JavaMethodProto::new("getParent", "()Ljava/lang/ThreadGroup;", Self::get_parent, Default::default()),
+                JavaMethodProto::new("parentOf", "(Ljava/lang/ThreadGroup;)Z", Self::parent_of, Default::default()),
+                JavaMethodProto::new("activeCount", "()I", Self::active_count, Default::default()),
+                JavaMethodProto::new("enumerate", "([Ljava/lang/Thread;)I", Self::enumerate, Default::default()),
+                JavaMethodProto::new("enumerate", "([Ljava/lang/Thread;Z)I", Self::enumerate_recurse, Default::default()),
+                JavaMethodProto::new(
+                    "uncaughtException",
+                    "(Ljava/lang/Thread;Ljava/lang/Throwable;)V",
+                    Self::uncaught_exception,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("toString", "()Ljava/lang/String;", Self::to_string, Default::default()),
+                // rustjava internal
+                JavaMethodProto::new("<init>", "()V", Self::init_main, MethodAccessFlags::SYNTHETIC),
+            ],
+            fields: vec![
+                JavaFieldProto::new("mainGroup", "Ljava/lang/ThreadGroup;", FieldAccessFlags::STATIC),
+                JavaFieldProto::new("parent", "Ljava/lang/ThreadGroup;", Default::default()),
+                JavaFieldProto::new("name", "Ljava/lang/String;", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn cl_init(jvm: &Jvm, _: &mut RuntimeContext) -> Result<()> {
+        tracing::debug!("java.lang.ThreadGroup::<clinit>");
+
+        let main = jvm.new_class("java/lang/ThreadGroup", "()V", ()).await?;
+        jvm.put_static_field("java/lang/ThreadGroup", "mainGroup", "Ljava/lang/ThreadGroup;", main)
+            .await
+    }
+
+    // the root group, which threads attached from rust belong to
+    async fn init_main(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>) -> Result<()> {
+        tracing::debug!("java.lang.ThreadGroup::<init>({this:?})");
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        let name = JavaLangString::from_rust_string(jvm, "main").await?;
+        jvm.put_field(&mut this, "name", "Ljava/lang/String;", name).await?;
+
+        Ok(())
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, name: ClassInstanceRef<String>) -> Result<()> {
+        tracing::debug!("java.lang.ThreadGroup::<init>({this:?}, {name:?})");
+
+        let current = jvm.current_java_thread()?;
+        let parent: ClassInstanceRef<Self> = jvm.invoke_virtual(&current, "getThreadGroup", "()Ljava/lang/ThreadGroup;", ()).await?;
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "java/lang/ThreadGroup",
+                "<init>",
+                "(Ljava/lang/ThreadGroup;Ljava/lang/String;)V",
+                (parent, name),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn init_with_parent(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        mut this: ClassInstanceRef<Self>,
+        parent: ClassInstanceRef<Self>,
+        name: ClassInstanceRef<String>,
+    ) -> Result<()> {
+        tracing::debug!("java.lang.ThreadGroup::<init>({this:?}, {parent:?}, {name:?})");
+
+        if parent.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "parent").await);
+        }
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+        jvm.put_field(&mut this, "parent", "Ljava/lang/ThreadGroup;", parent).await?;
+        jvm.put_field(&mut this, "name", "Ljava/lang/String;", name).await?;
+
+        Ok(())
+    }
+
+    async fn get_name(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<String>> {
+        tracing::debug!("java.lang.ThreadGroup::getName({this:?})");
+        jvm.get_field(&this, "name", "Ljava/lang/String;").await
+    }
+
+    async fn get_parent(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Self>> {
+        tracing::debug!("java.lang.ThreadGroup::getParent({this:?})");
+        jvm.get_field(&this, "parent", "Ljava/lang/ThreadGroup;").await
+    }
+
+    async fn parent_of(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, group: ClassInstanceRef<Self>) -> Result<bool> {
+        tracing::debug!("java.lang.ThreadGroup::parentOf({this:?}, {group:?})");
+        Self::contains_group(jvm, &this, group).await
+    }
+
+    async fn active_count(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i32> {
+        tracing::debug!("java.lang.ThreadGroup::activeCount({this:?})");
+
+        Ok(Self::threads(jvm, &this, true).await?.len() as _)
+    }
+
+    async fn enumerate(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, list: ClassInstanceRef<Array<Thread>>) -> Result<i32> {
+        tracing::debug!("java.lang.ThreadGroup::enumerate({this:?}, {list:?})");
+
+        jvm.invoke_virtual(&this, "enumerate", "([Ljava/lang/Thread;Z)I", (list, true)).await
+    }
+
+    async fn enumerate_recurse(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        mut list: ClassInstanceRef<Array<Thread>>,
+        recurse: bool,
+    ) -> Result<i32> {
+        tracing::debug!("java.lang.ThreadGroup::enumerate({this:?}, {list:?}, {recurse:?})");
+
+        if list.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "list").await);
+        }
+
+        let threads = Self::threads(jvm, &this, recurse).await?;
+        let count = threads.len().min(jvm.array_length(&list).await?);
+        jvm.store_array(&mut list, 0, threads.into_iter().take(count)).await?;
+
+        Ok(count as _)
+    }
+
+    // the jdk's default: defer to the parent group, and print the stack trace to System.err at the root
+    async fn uncaught_exception(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        thread: ClassInstanceRef<Thread>,
+        exception: ClassInstanceRef<Throwable>,
+    ) -> Result<()> {
+        tracing::debug!("java.lang.ThreadGroup::uncaughtException({this:?}, {thread:?}, {exception:?})");
+
+        let parent: ClassInstanceRef<Self> = jvm.get_field(&this, "parent", "Ljava/lang/ThreadGroup;").await?;
+        if !parent.is_null() {
+            return jvm
+                .invoke_virtual(
+                    &parent,
+                    "uncaughtException",
+                    "(Ljava/lang/Thread;Ljava/lang/Throwable;)V",
+                    (thread, exception),
+                )
+                .await;
+        }
+
+        let name: ClassInstanceRef<String> = jvm.invoke_virtual(&thread, "getName", "()Ljava/lang/String;", ()).await?;
+        let name = JavaLangString::to_rust_string(jvm, &name).await?;
+
+        let err: ClassInstanceRef<PrintStream> = jvm.get_static_field("java/lang/System", "err", "Ljava/io/PrintStream;").await?;
+        if err.is_null() {
+            tracing::error!("Exception in thread \"{name}\" {exception:?}");
+            return Ok(());
+        }
+
+        let header = JavaLangString::from_rust_string(jvm, &format!("Exception in thread \"{name}\" ")).await?;
+        let _: () = jvm.invoke_virtual(&err, "print", "(Ljava/lang/String;)V", (header,)).await?;
+        let _: () = jvm
+            .invoke_virtual(&exception, "printStackTrace", "(Ljava/io/PrintStream;)V", (err,))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn to_string(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<String>> {
+        tracing::debug!("java.lang.ThreadGroup::toString({this:?})");
+
+        let name: ClassInstanceRef<String> = jvm.get_field(&this, "name", "Ljava/lang/String;").await?;
+        let name = JavaLangString::to_rust_string(jvm, &name).await?;
+
+        Ok(JavaLangString::from_rust_string(jvm, &format!("java.lang.ThreadGroup[name={name}]"))
+            .await?
+            .into())
+    }
+
+    pub(crate) async fn main_group(jvm: &Jvm) -> Result<ClassInstanceRef<Self>> {
+        jvm.get_static_field("java/lang/ThreadGroup", "mainGroup", "Ljava/lang/ThreadGroup;")
+            .await
+    }
+
+    // whether `group` is `this` or one of its descendants
+    async fn contains_group(jvm: &Jvm, this: &ClassInstanceRef<Self>, mut group: ClassInstanceRef<Self>) -> Result<bool> {
+        while !group.is_null() {
+            if group.instance == this.instance {
+                return Ok(true);
+            }
+            group = jvm.get_field(&group, "parent", "Ljava/lang/ThreadGroup;").await?;
+        }
+
+        Ok(false)
+    }
+
+    // live threads in this group, and in its subgroups if `recurse`
+    async fn threads(jvm: &Jvm, this: &ClassInstanceRef<Self>, recurse: bool) -> Result<Vec<ClassInstanceRef<Thread>>> {
+        let mut result = Vec::new();
+        for thread in jvm.java_threads() {
+            let group: ClassInstanceRef<Self> = jvm.invoke_virtual(&thread, "getThreadGroup", "()Ljava/lang/ThreadGroup;", ()).await?;
+            let member = if recurse {
+                Self::contains_group(jvm, this, group).await?
+            } else {
+                group.instance == this.instance
+            };
+            if member {
+                result.push(thread.into());
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,107 @@
+use alloc::vec;
+
+use java_class_proto::JavaMethodProto;
+use jvm::{ClassInstanceRef, Jvm, Result};
+
+use crate::{
+    RuntimeClassProto, RuntimeContext,
+    classes::java::{
+        lang::{Object, Thread},
+        util::HashMap,
+    },
+};
+
+// class java.lang.ThreadLocal
+// Values live in a HashMap keyed by the ThreadLocal, stored in the current Thread's `threadLocals` field
+// (`inheritableThreadLocals` for InheritableThreadLocal), so they're reachable exactly as long as the thread is
+pub struct ThreadLocal;
+
+impl ThreadLocal {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/lang/ThreadLocal",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                JavaMethodProto::new("initialValue", "()Ljava/lang/Object;", Self::initial_value, Default::default()),
+                JavaMethodProto::new("get", "()Ljava/lang/Object;", Self::get, Default::default()),
+                JavaMethodProto::new("set", "(Ljava/lang/Object;)V", Self::set, Default::default()),
+                JavaMethodProto::new("remove", "()V", Self::remove, Default::default()),
+            ],
+            fields: vec![],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        tracing::debug!("java.lang.ThreadLocal::<init>({this:?})");
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        Ok(())
+    }
+
+    async fn initial_value(_: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.lang.ThreadLocal::initialValue({this:?})");
+
+        Ok(None.into())
+    }
+
+    async fn get(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.lang.ThreadLocal::get({this:?})");
+
+        let map = Self::map(jvm, &this).await?;
+        // values may be null, so containsKey tells an unset value apart from a null one
+        let contains: bool = jvm.invoke_virtual(&map, "containsKey", "(Ljava/lang/Object;)Z", (this.clone(),)).await?;
+        if contains {
+            return jvm.invoke_virtual(&map, "get", "(Ljava/lang/Object;)Ljava/lang/Object;", (this,)).await;
+        }
+
+        let value: ClassInstanceRef<Object> = jvm.invoke_virtual(&this, "initialValue", "()Ljava/lang/Object;", ()).await?;
+        let _: ClassInstanceRef<Object> = jvm
+            .invoke_virtual(
+                &map,
+                "put",
+                "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+                (this, value.clone()),
+            )
+            .await?;
+
+        Ok(value)
+    }
+
+    async fn set(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, value: ClassInstanceRef<Object>) -> Result<()> {
+        tracing::debug!("java.lang.ThreadLocal::set({this:?}, {value:?})");
+
+        let map = Self::map(jvm, &this).await?;
+        let _: ClassInstanceRef<Object> = jvm
+            .invoke_virtual(&map, "put", "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;", (this, value))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn remove(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        tracing::debug!("java.lang.ThreadLocal::remove({this:?})");
+
+        let map = Self::map(jvm, &this).await?;
+        let _: ClassInstanceRef<Object> = jvm
+            .invoke_virtual(&map, "remove", "(Ljava/lang/Object;)Ljava/lang/Object;", (this,))
+            .await?;
+
+        Ok(())
+    }
+
+    // the current thread's map for this kind of thread local, created on first use
+    async fn map(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> Result<ClassInstanceRef<HashMap>> {
+        let field = if jvm.is_instance(this, "java/lang/InheritableThreadLocal") {
+            "inheritableThreadLocals"
+        } else {
+            "threadLocals"
+        };
+
+        let mut thread: ClassInstanceRef<Thread> = jvm.current_java_thread()?.into();
+        Thread::thread_local_map(jvm, &mut thread, field).await
+    }
+}
@@ -184,7 +184,7 @@ impl Throwable {
     ) -> Result<()> {
         tracing::debug!("java.lang.Throwable::printStackTrace({this:?}, {stream:?})");
 
-        Self::do_print_stack_trace(jvm, this, stream.into()).await?;
+        Self::do_print_stack_trace(jvm, this, stream.try_into()?).await?;
 
         Ok(())
     }
@@ -197,7 +197,7 @@ impl Throwable {
     ) -> Result<()> {
         tracing::debug!("java.lang.Throwable::printStackTrace({this:?}, {writer:?})");
 
-        Self::do_print_stack_trace(jvm, this, writer.into()).await?;
+        Self::do_print_stack_trace(jvm, this, writer.try_into()?).await?;
 
         Ok(())
     }
@@ -205,8 +205,8 @@ impl Throwable {
     async fn to_string(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<String>> {
         tracing::debug!("java.lang.Throwable::toString({this:?})");
 
-        let class = jvm.invoke_virtual(&this, "getClass", "()Ljava/lang/Class;", ()).await?;
-        let class_name = jvm.invoke_virtual(&class, "getName", "()Ljava/lang/String;", ()).await?;
+        let class: Box<dyn ClassInstance> = jvm.invoke_virtual(&this, "getClass", "()Ljava/lang/Class;", ()).await?;
+        let class_name: Box<dyn ClassInstance> = jvm.invoke_virtual(&class, "getName", "()Ljava/lang/String;", ()).await?;
 
         let message: ClassInstanceRef<String> = jvm.get_field(&this, "detailMessage", "Ljava/lang/String;").await?;
 
@@ -0,0 +1,26 @@
+use java_class_proto::java_class;
+use java_constants::{ClassAccessFlags, FieldAccessFlags};
+use jvm::{ClassInstanceRef, Jvm, Result, runtime::JavaLangClass};
+
+use crate::{RuntimeContext, classes::java::lang::Class};
+
+// public final class java.lang.Void
+pub struct Void;
+
+#[java_class(
+    "java/lang/Void",
+    flags = ClassAccessFlags::PUBLIC | ClassAccessFlags::FINAL,
+    fields(TYPE: ClassInstanceRef<Class> = FieldAccessFlags::PUBLIC | FieldAccessFlags::STATIC | FieldAccessFlags::FINAL)
+)]
+impl Void {
+    #[java_method]
+    async fn clinit(jvm: &Jvm, _: &mut RuntimeContext) -> Result<()> {
+        jvm.put_static_field(
+            "java/lang/Void",
+            "TYPE",
+            "Ljava/lang/Class;",
+            JavaLangClass::from_rust_primitive(jvm, "void").await?,
+        )
+        .await
+    }
+}
@@ -1,8 +1,8 @@
-use alloc::{vec, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::ClassAccessFlags;
-use jvm::{ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
+use jvm::{ClassInstance, ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -46,7 +46,7 @@ impl JarURLConnection {
             .invoke_special(&this, "java/net/URLConnection", "<init>", "(Ljava/net/URL;)V", (url.clone(),))
             .await?;
 
-        let file = jvm.invoke_virtual(&url, "getFile", "()Ljava/lang/String;", ()).await?;
+        let file: Box<dyn ClassInstance> = jvm.invoke_virtual(&url, "getFile", "()Ljava/lang/String;", ()).await?;
         let file = JavaLangString::to_rust_string(jvm, &file).await?;
         let split = file.splitn(2, "!/").collect::<Vec<_>>();
 
@@ -79,7 +79,7 @@ impl JarURLConnection {
     async fn get_jar_entry(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<URL>> {
         tracing::debug!("java.net.JarURLConnection::getJarEntry({this:?})");
 
-        let jar_file = jvm.invoke_virtual(&this, "getJarFile", "()Ljava/util/jar/JarFile;", ()).await?;
+        let jar_file: Box<dyn ClassInstance> = jvm.invoke_virtual(&this, "getJarFile", "()Ljava/util/jar/JarFile;", ()).await?;
         let entry_name: ClassInstanceRef<String> = jvm.invoke_virtual(&this, "getEntryName", "()Ljava/lang/String;", ()).await?;
 
         let entry = jvm
@@ -92,8 +92,8 @@ impl JarURLConnection {
     async fn get_main_attributes(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Attributes>> {
         tracing::debug!("java.net.JarURLConnection::getMainAttributes({this:?})");
 
-        let jar_file = jvm.invoke_virtual(&this, "getJarFile", "()Ljava/util/jar/JarFile;", ()).await?;
-        let manifest = jvm.invoke_virtual(&jar_file, "getManifest", "()Ljava/util/jar/Manifest;", ()).await?;
+        let jar_file: Box<dyn ClassInstance> = jvm.invoke_virtual(&this, "getJarFile", "()Ljava/util/jar/JarFile;", ()).await?;
+        let manifest: Box<dyn ClassInstance> = jvm.invoke_virtual(&jar_file, "getManifest", "()Ljava/util/jar/Manifest;", ()).await?;
         let main_attributes = jvm
             .invoke_virtual(&manifest, "getMainAttributes", "()Ljava/util/jar/Attributes;", ())
             .await?;
@@ -1,8 +1,8 @@
-use alloc::{format, vec, vec::Vec};
+use alloc::{boxed::Box, format, vec, vec::Vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use jvm::{
-    Array, ClassInstanceRef, Jvm, Result,
+    Array, ClassInstance, ClassInstanceRef, Jvm, Result,
     runtime::{JavaIoInputStream, JavaLangString},
 };
 
@@ -77,7 +77,7 @@ impl URLClassLoader {
             return Ok(None.into());
         }
 
-        let stream = jvm.invoke_virtual(&resource, "openStream", "()Ljava/io/InputStream;", ()).await?;
+        let stream: Box<dyn ClassInstance> = jvm.invoke_virtual(&resource, "openStream", "()Ljava/io/InputStream;", ()).await?;
         let bytes = JavaIoInputStream::read_until_end(jvm, &stream).await?;
 
         let length = bytes.len() as i32;
@@ -106,11 +106,11 @@ impl URLClassLoader {
 
         let name_str = JavaLangString::to_rust_string(jvm, &name).await?;
 
-        let urls = jvm.get_field(&this, "urls", "[Ljava/net/URL;").await?;
+        let urls: Box<dyn ClassInstance> = jvm.get_field(&this, "urls", "[Ljava/net/URL;").await?;
         let urls: Vec<ClassInstanceRef<URL>> = jvm.load_array(&urls, 0, jvm.array_length(&urls).await? as _).await?;
 
         for url in urls {
-            let file = jvm.invoke_virtual(&url, "getFile", "()Ljava/lang/String;", ()).await?;
+            let file: Box<dyn ClassInstance> = jvm.invoke_virtual(&url, "getFile", "()Ljava/lang/String;", ()).await?;
             let file = JavaLangString::to_rust_string(jvm, &file).await?;
 
             let metadata = runtime.metadata(&file).await;
@@ -2,7 +2,7 @@ use alloc::{format, string::ToString, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::{ClassAccessFlags, FieldAccessFlags, MethodAccessFlags};
-use jvm::{Array, ClassInstanceRef, JavaError, Jvm, Result, runtime::JavaLangString};
+use jvm::{Array, ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -306,7 +306,7 @@ impl DateFormat {
         buffer: ClassInstanceRef<StringBuffer>,
         position: ClassInstanceRef<FieldPosition>,
     ) -> Result<ClassInstanceRef<StringBuffer>> {
-        if object.is_null() || !jvm.is_instance(&**object, "java/util/Date") {
+        if object.is_null() || !jvm.is_instance(&object, "java/util/Date") {
             return Err(jvm
                 .exception("java/lang/IllegalArgumentException", "Cannot format given Object as a Date")
                 .await);
@@ -369,7 +369,7 @@ impl DateFormat {
                 .new_class("java/text/ParseException", "(Ljava/lang/String;I)V", (message, error_index))
                 .await?
                 .into();
-            return Err(JavaError::JavaException(exception.into()));
+            return Err(jvm.throw(exception.try_into()?));
         }
         Ok(date)
     }
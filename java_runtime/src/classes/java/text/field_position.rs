@@ -66,7 +66,7 @@ impl FieldPosition {
     }
 
     async fn equals(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
-        if other.is_null() || !jvm.is_instance(&**other, "java/text/FieldPosition") {
+        if other.is_null() || !jvm.is_instance(&other, "java/text/FieldPosition") {
             return Ok(false);
         }
 
@@ -2,7 +2,7 @@ use alloc::vec;
 
 use java_class_proto::JavaMethodProto;
 use java_constants::{ClassAccessFlags, MethodAccessFlags};
-use jvm::{ClassInstanceRef, JavaError, Jvm, Result, runtime::JavaLangString};
+use jvm::{ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -102,7 +102,7 @@ impl Format {
                 .new_class("java/text/ParseException", "(Ljava/lang/String;I)V", (message, error_index))
                 .await?
                 .into();
-            return Err(JavaError::JavaException(exception.into()));
+            return Err(jvm.throw(exception.try_into()?));
         }
         Ok(result)
     }
@@ -2,7 +2,7 @@ use alloc::vec;
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::{ClassAccessFlags, FieldAccessFlags, MethodAccessFlags};
-use jvm::{Array, ClassInstanceRef, JavaError, Jvm, Result, runtime::JavaLangString};
+use jvm::{Array, ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -231,7 +231,7 @@ impl NumberFormat {
         buffer: ClassInstanceRef<StringBuffer>,
         position: ClassInstanceRef<FieldPosition>,
     ) -> Result<ClassInstanceRef<StringBuffer>> {
-        if object.is_null() || !jvm.is_instance(&**object, "java/lang/Number") {
+        if object.is_null() || !jvm.is_instance(&object, "java/lang/Number") {
             return Err(jvm
                 .exception("java/lang/IllegalArgumentException", "Cannot format given Object as a Number")
                 .await);
@@ -240,10 +240,10 @@ impl NumberFormat {
             return Err(jvm.exception("java/lang/NullPointerException", "buffer or position").await);
         }
 
-        if jvm.is_instance(&**object, "java/lang/Byte")
-            || jvm.is_instance(&**object, "java/lang/Short")
-            || jvm.is_instance(&**object, "java/lang/Integer")
-            || jvm.is_instance(&**object, "java/lang/Long")
+        if jvm.is_instance(&object, "java/lang/Byte")
+            || jvm.is_instance(&object, "java/lang/Short")
+            || jvm.is_instance(&object, "java/lang/Integer")
+            || jvm.is_instance(&object, "java/lang/Long")
         {
             let value: i64 = jvm.invoke_virtual(&object, "longValue", "()J", ()).await?;
             return jvm
@@ -320,7 +320,7 @@ impl NumberFormat {
                 .new_class("java/text/ParseException", "(Ljava/lang/String;I)V", (message, error_index))
                 .await?
                 .into();
-            return Err(JavaError::JavaException(exception.into()));
+            return Err(jvm.throw(exception.try_into()?));
         }
         Ok(result)
     }
@@ -530,7 +530,7 @@ impl NumberFormat {
     }
 
     async fn equals(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
-        if other.is_null() || !jvm.is_instance(&**other, "java/text/NumberFormat") {
+        if other.is_null() || !jvm.is_instance(&other, "java/text/NumberFormat") {
             return Ok(false);
         }
         let other: ClassInstanceRef<Self> = ClassInstanceRef::new(other.instance);
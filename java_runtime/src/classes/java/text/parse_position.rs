@@ -59,7 +59,7 @@ impl ParsePosition {
     }
 
     async fn equals(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
-        if other.is_null() || !jvm.is_instance(&**other, "java/text/ParsePosition") {
+        if other.is_null() || !jvm.is_instance(&other, "java/text/ParsePosition") {
             return Ok(false);
         }
 
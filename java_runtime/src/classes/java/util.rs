@@ -47,6 +47,13 @@ mod timer_task;
 mod timer_thread;
 mod vector;
 mod vector_itr;
+mod weak_hash_map;
+mod weak_hash_map_entry;
+mod weak_hash_map_entry_set;
+mod weak_hash_map_hash_iterator;
+mod weak_hash_map_key_set;
+mod weak_hash_map_values;
+mod weak_hash_map_weak_key;
 
 pub use self::{
     abstract_collection::AbstractCollection, abstract_list::AbstractList, abstract_map::AbstractMap, abstract_set::AbstractSet,
@@ -59,5 +66,7 @@ pub use self::{
     hashtable_key_set::HashtableKeySet, hashtable_values::HashtableValues, iterator::Iterator, list::List, locale::Locale, map::Map,
     map_entry::MapEntry, no_such_element_exception::NoSuchElementException, properties::Properties, random::Random, set::Set,
     simple_time_zone::SimpleTimeZone, stack::Stack, time_zone::TimeZone, timer::Timer, timer_task::TimerTask, timer_thread::TimerThread,
-    vector::Vector, vector_itr::VectorItr,
+    vector::Vector, vector_itr::VectorItr, weak_hash_map::WeakHashMap, weak_hash_map_entry::WeakHashMapEntry,
+    weak_hash_map_entry_set::WeakHashMapEntrySet, weak_hash_map_hash_iterator::WeakHashMapHashIterator, weak_hash_map_key_set::WeakHashMapKeySet,
+    weak_hash_map_values::WeakHashMapValues, weak_hash_map_weak_key::WeakHashMapWeakKey,
 };
@@ -1,7 +1,7 @@
-use alloc::{format, vec, vec::Vec};
+use alloc::{boxed::Box, format, vec, vec::Vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{Array, ClassInstanceRef, Jvm, Result};
+use jvm::{Array, ClassInstance, ClassInstanceRef, Jvm, Result};
 
 use crate::{RuntimeClassProto, RuntimeContext, classes::java::lang::Object};
 
@@ -71,7 +71,7 @@ impl ArrayList {
         let size: i32 = jvm.get_field(&this, "size", "I").await?;
         Self::ensure_capacity(jvm, &mut this, (size + 1) as usize).await?;
 
-        let mut element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let mut element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
         jvm.store_array(&mut element_data, size as usize, core::iter::once(element)).await?;
         jvm.put_field(&mut this, "size", "I", size + 1).await?;
 
@@ -94,7 +94,7 @@ impl ArrayList {
 
         Self::ensure_capacity(jvm, &mut this, (size + 1) as usize).await?;
 
-        let mut element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let mut element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
         let num_to_move = size - index;
         if num_to_move > 0 {
             let to_shift: Vec<ClassInstanceRef<Object>> = jvm.load_array(&element_data, index as usize, num_to_move as usize).await?;
@@ -115,7 +115,7 @@ impl ArrayList {
             return Err(Self::index_out_of_bounds(jvm, index, size).await);
         }
 
-        let element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
         let element: ClassInstanceRef<Object> = jvm.load_array(&element_data, index as usize, 1).await?.into_iter().next().unwrap();
 
         Ok(element)
@@ -135,7 +135,7 @@ impl ArrayList {
             return Err(Self::index_out_of_bounds(jvm, index, size).await);
         }
 
-        let mut element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let mut element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
         let old_element: ClassInstanceRef<Object> = jvm.load_array(&element_data, index as usize, 1).await?.into_iter().next().unwrap();
         jvm.store_array(&mut element_data, index as usize, core::iter::once(element)).await?;
 
@@ -150,7 +150,7 @@ impl ArrayList {
             return Err(Self::index_out_of_bounds(jvm, index, size).await);
         }
 
-        let mut element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let mut element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
         let removed: ClassInstanceRef<Object> = jvm.load_array(&element_data, index as usize, 1).await?.into_iter().next().unwrap();
 
         let num_to_move = size - index - 1;
@@ -206,7 +206,7 @@ impl ArrayList {
         tracing::debug!("java.util.ArrayList::indexOf({this:?}, {element:?})");
 
         let size: i32 = jvm.get_field(&this, "size", "I").await?;
-        let element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
 
         for index in 0..size {
             let item: ClassInstanceRef<Object> = jvm.load_array(&element_data, index as usize, 1).await?.into_iter().next().unwrap();
@@ -223,7 +223,7 @@ impl ArrayList {
 
         let size: i32 = jvm.get_field(&this, "size", "I").await?;
         if size > 0 {
-            let mut element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+            let mut element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
             let nulls: Vec<ClassInstanceRef<Object>> = (0..size).map(|_| None.into()).collect();
             jvm.store_array(&mut element_data, 0, nulls).await?;
         }
@@ -252,7 +252,7 @@ impl ArrayList {
     }
 
     async fn ensure_capacity(jvm: &Jvm, this: &mut ClassInstanceRef<Self>, min_capacity: usize) -> Result<()> {
-        let element_data = jvm.get_field(this, "elementData", "[Ljava/lang/Object;").await?;
+        let element_data: Box<dyn ClassInstance> = jvm.get_field(this, "elementData", "[Ljava/lang/Object;").await?;
         let current_capacity = jvm.array_length(&element_data).await?;
 
         if min_capacity <= current_capacity {
@@ -1,8 +1,8 @@
-use alloc::vec;
+use alloc::{boxed::Box, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::{ClassAccessFlags, MethodAccessFlags};
-use jvm::{ClassInstanceRef, Jvm, Result};
+use jvm::{ClassInstance, ClassInstanceRef, Jvm, Result};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -163,7 +163,7 @@ impl Calendar {
     async fn equals(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
         tracing::debug!("java.util.Calendar::equals({this:?}, {other:?})");
 
-        if other.is_null() || !jvm.is_instance(&**other, "java/util/Calendar") {
+        if other.is_null() || !jvm.is_instance(&other, "java/util/Calendar") {
             return Ok(false);
         }
 
@@ -208,7 +208,7 @@ impl Calendar {
     async fn before(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
         tracing::debug!("java.util.Calendar::before({this:?}, {other:?})");
 
-        if other.is_null() || !jvm.is_instance(&**other, "java/util/Calendar") {
+        if other.is_null() || !jvm.is_instance(&other, "java/util/Calendar") {
             return Ok(false);
         }
 
@@ -221,7 +221,7 @@ impl Calendar {
     async fn after(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
         tracing::debug!("java.util.Calendar::after({this:?}, {other:?})");
 
-        if other.is_null() || !jvm.is_instance(&**other, "java/util/Calendar") {
+        if other.is_null() || !jvm.is_instance(&other, "java/util/Calendar") {
             return Ok(false);
         }
 
@@ -238,7 +238,7 @@ impl Calendar {
             return Err(jvm.exception("java/lang/ArrayIndexOutOfBoundsException", "calendar field").await);
         }
 
-        let mut fields = jvm.get_field(&this, "fields", "[I").await?;
+        let mut fields: Box<dyn ClassInstance> = jvm.get_field(&this, "fields", "[I").await?;
         jvm.store_array(&mut fields, field as usize, vec![value]).await?;
 
         let _: () = jvm.invoke_virtual(&this, "computeTime", "()V", ()).await?;
@@ -254,7 +254,7 @@ impl Calendar {
             return Err(jvm.exception("java/lang/ArrayIndexOutOfBoundsException", "calendar field").await);
         }
 
-        let fields = jvm.get_field(&this, "fields", "[I").await?;
+        let fields: Box<dyn ClassInstance> = jvm.get_field(&this, "fields", "[I").await?;
         let value = jvm.load_array(&fields, field as usize, 1).await?[0];
 
         Ok(value)
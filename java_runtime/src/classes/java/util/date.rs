@@ -72,7 +72,7 @@ impl Date {
     async fn equals(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
         tracing::debug!("java.util.Date::equals({this:?}, {other:?})");
 
-        if other.is_null() || !jvm.is_instance(&**other, "java/util/Date") {
+        if other.is_null() || !jvm.is_instance(&other, "java/util/Date") {
             return Ok(false);
         }
 
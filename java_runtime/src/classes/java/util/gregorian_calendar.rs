@@ -1,9 +1,9 @@
-use alloc::{vec, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 
 use chrono::{DateTime, Datelike, TimeZone as ChronoTimeZone, Timelike, Utc};
 
 use java_class_proto::JavaMethodProto;
-use jvm::{ClassInstanceRef, Jvm, Result};
+use jvm::{ClassInstance, ClassInstanceRef, Jvm, Result};
 
 use crate::{RuntimeClassProto, RuntimeContext, classes::java::util::TimeZone};
 
@@ -58,7 +58,7 @@ impl GregorianCalendar {
 
         // fields -> time
 
-        let fields = jvm.get_field(&this, "fields", "[I").await?;
+        let fields: Box<dyn ClassInstance> = jvm.get_field(&this, "fields", "[I").await?;
 
         // TODO constant
         let fields: Vec<i32> = jvm.load_array(&fields, 0, 17).await?;
@@ -135,7 +135,7 @@ impl GregorianCalendar {
             0,
         ];
 
-        let mut fields = jvm.get_field(&this, "fields", "[I").await?;
+        let mut fields: Box<dyn ClassInstance> = jvm.get_field(&this, "fields", "[I").await?;
         jvm.store_array(&mut fields, 0, calculated_fields).await?;
 
         Ok(())
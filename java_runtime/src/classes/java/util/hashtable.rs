@@ -1,8 +1,8 @@
-use alloc::{string::String as RustString, vec, vec::Vec};
+use alloc::{boxed::Box, string::String as RustString, vec, vec::Vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::MethodAccessFlags;
-use jvm::{Array, ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
+use jvm::{Array, ClassInstance, ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -126,7 +126,7 @@ impl Hashtable {
         tracing::debug!("java.util.Hashtable::containsKey({this:?}, {key:?})");
 
         let key_hash = Self::key_hash(jvm, &key).await?;
-        let table = jvm.get_field(&this, "table", "[Ljava/util/Hashtable$Entry;").await?;
+        let table: Box<dyn ClassInstance> = jvm.get_field(&this, "table", "[Ljava/util/Hashtable$Entry;").await?;
         let table_len = jvm.array_length(&table).await? as i32;
         let bucket_index = ((key_hash & 0x7FFFFFFF) % table_len) as usize;
 
@@ -174,7 +174,7 @@ impl Hashtable {
         tracing::debug!("java.util.Hashtable::get({this:?}, {key:?})");
 
         let key_hash = Self::key_hash(jvm, &key).await?;
-        let table = jvm.get_field(&this, "table", "[Ljava/util/Hashtable$Entry;").await?;
+        let table: Box<dyn ClassInstance> = jvm.get_field(&this, "table", "[Ljava/util/Hashtable$Entry;").await?;
         let table_len = jvm.array_length(&table).await? as i32;
         let bucket_index = ((key_hash & 0x7FFFFFFF) % table_len) as usize;
 
@@ -203,7 +203,7 @@ impl Hashtable {
         tracing::debug!("java.util.Hashtable::remove({this:?}, {key:?})");
 
         let key_hash = Self::key_hash(jvm, &key).await?;
-        let mut table = jvm.get_field(&this, "table", "[Ljava/util/Hashtable$Entry;").await?;
+        let mut table: Box<dyn ClassInstance> = jvm.get_field(&this, "table", "[Ljava/util/Hashtable$Entry;").await?;
         let table_len = jvm.array_length(&table).await? as i32;
         let bucket_index = ((key_hash & 0x7FFFFFFF) % table_len) as usize;
 
@@ -250,7 +250,7 @@ impl Hashtable {
         }
 
         let key_hash = Self::key_hash(jvm, &key).await?;
-        let mut table = jvm.get_field(&this, "table", "[Ljava/util/Hashtable$Entry;").await?;
+        let mut table: Box<dyn ClassInstance> = jvm.get_field(&this, "table", "[Ljava/util/Hashtable$Entry;").await?;
         let table_len = jvm.array_length(&table).await? as i32;
         let bucket_index = ((key_hash & 0x7FFFFFFF) % table_len) as usize;
 
@@ -387,7 +387,7 @@ impl Hashtable {
     }
 
     async fn rehash_table(jvm: &Jvm, this: &mut ClassInstanceRef<Self>) -> Result<()> {
-        let old_table = jvm.get_field(this, "table", "[Ljava/util/Hashtable$Entry;").await?;
+        let old_table: Box<dyn ClassInstance> = jvm.get_field(this, "table", "[Ljava/util/Hashtable$Entry;").await?;
         let old_capacity = jvm.array_length(&old_table).await?;
         let new_capacity = old_capacity * 2 + 1;
 
@@ -429,7 +429,7 @@ impl Hashtable {
 
             let entry: ClassInstanceRef<HashtableEntry> = ClassInstanceRef::new(entry.instance);
             let key: ClassInstanceRef<Object> = jvm.get_field(&entry, "key", "Ljava/lang/Object;").await?;
-            if key.instance.as_ref().unwrap().equals(&**this)? {
+            if key.instance == this.instance {
                 result.push_str("(this Map)");
             } else {
                 let text: ClassInstanceRef<String> = jvm.invoke_virtual(&key, "toString", "()Ljava/lang/String;", ()).await?;
@@ -438,7 +438,7 @@ impl Hashtable {
             result.push('=');
 
             let value: ClassInstanceRef<Object> = jvm.get_field(&entry, "value", "Ljava/lang/Object;").await?;
-            if value.instance.as_ref().unwrap().equals(&**this)? {
+            if value.instance == this.instance {
                 result.push_str("(this Map)");
             } else {
                 let text: ClassInstanceRef<String> = jvm.invoke_virtual(&value, "toString", "()Ljava/lang/String;", ()).await?;
@@ -57,7 +57,7 @@ impl HashtableEntrySet {
     async fn contains(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, candidate: ClassInstanceRef<Object>) -> Result<bool> {
         tracing::debug!("java.util.Hashtable$EntrySet::contains({this:?}, {candidate:?})");
 
-        if candidate.is_null() || !jvm.is_instance(&**candidate, "java/util/Map$Entry") {
+        if candidate.is_null() || !jvm.is_instance(&candidate, "java/util/Map$Entry") {
             return Ok(false);
         }
 
@@ -1,7 +1,7 @@
-use alloc::vec;
+use alloc::{boxed::Box, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{ClassInstanceRef, Jvm, Result};
+use jvm::{ClassInstance, ClassInstanceRef, Jvm, Result};
 
 use crate::{RuntimeClassProto, RuntimeContext, classes::java::lang::String};
 
@@ -50,7 +50,7 @@ impl Attributes {
         tracing::debug!("java.util.jar.Attributes::putValue({this:?}, {name:?}, {value:?})");
 
         // TODO we should store key in Attributes.Name type
-        let map = jvm.get_field(&this, "map", "Ljava/util/Map;").await?;
+        let map: Box<dyn ClassInstance> = jvm.get_field(&this, "map", "Ljava/util/Map;").await?;
         let old = jvm
             .invoke_virtual(&map, "put", "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;", (name, value))
             .await?;
@@ -66,7 +66,7 @@ impl Attributes {
     ) -> Result<ClassInstanceRef<String>> {
         tracing::debug!("java.util.jar.Attributes::getValue({this:?}, {name:?})");
 
-        let map = jvm.get_field(&this, "map", "Ljava/util/Map;").await?;
+        let map: Box<dyn ClassInstance> = jvm.get_field(&this, "map", "Ljava/util/Map;").await?;
         let value = jvm.invoke_virtual(&map, "get", "(Ljava/lang/Object;)Ljava/lang/Object;", (name,)).await?;
 
         Ok(value)
@@ -1,7 +1,7 @@
-use alloc::vec;
+use alloc::{boxed::Box, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{ClassInstanceRef, Jvm, Result};
+use jvm::{ClassInstance, ClassInstanceRef, Jvm, Result};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -43,7 +43,7 @@ impl JarFileEntries {
     async fn has_more_elements(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<bool> {
         tracing::debug!("java.util.jar.JarFile$Entries::hasMoreElements({this:?})");
 
-        let entries = jvm.get_field(&this, "entries", "Ljava/util/zip/ZipFile$Entries;").await?;
+        let entries: Box<dyn ClassInstance> = jvm.get_field(&this, "entries", "Ljava/util/zip/ZipFile$Entries;").await?;
 
         jvm.invoke_virtual(&entries, "hasMoreElements", "()Z", ()).await
     }
@@ -51,7 +51,7 @@ impl JarFileEntries {
     async fn next_element(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Object>> {
         tracing::debug!("java.util.jar.JarFile$Entries::nextElement({this:?})");
 
-        let entries = jvm.get_field(&this, "entries", "Ljava/util/zip/ZipFile$Entries;").await?;
+        let entries: Box<dyn ClassInstance> = jvm.get_field(&this, "entries", "Ljava/util/zip/ZipFile$Entries;").await?;
 
         let element: ClassInstanceRef<ZipEntry> = jvm.invoke_virtual(&entries, "nextElement", "()Ljava/lang/Object;", ()).await?;
 
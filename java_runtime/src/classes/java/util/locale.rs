@@ -841,7 +841,7 @@ impl Locale {
     async fn equals(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
         tracing::debug!("java.util.Locale::equals({this:?}, {other:?})");
 
-        if other.is_null() || !jvm.is_instance(&**other, "java/util/Locale") {
+        if other.is_null() || !jvm.is_instance(&other, "java/util/Locale") {
             return Ok(false);
         }
 
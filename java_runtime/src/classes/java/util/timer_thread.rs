@@ -1,8 +1,8 @@
-use alloc::{vec, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 use core::time::Duration;
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{ClassInstanceRef, Jvm, Result};
+use jvm::{ClassInstance, ClassInstanceRef, Jvm, Result};
 
 use crate::{RuntimeClassProto, RuntimeContext, classes::java::util::Vector};
 
@@ -37,7 +37,7 @@ impl TimerThread {
     async fn run(jvm: &Jvm, context: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
         tracing::debug!("java.util.Timer$TimerThread::run({this:?})");
 
-        let java_tasks = jvm.get_field(&this, "tasks", "Ljava/util/Vector;").await?;
+        let java_tasks: Box<dyn ClassInstance> = jvm.get_field(&this, "tasks", "Ljava/util/Vector;").await?;
 
         loop {
             // TODO: we need to wait for new tasks to arrive
@@ -51,7 +51,7 @@ impl TimerThread {
             // get all tasks. removing from tasks vector to avoid some concurrency issue
             let mut tasks = Vec::with_capacity(tasks_size as _);
             for _ in 0..tasks_size {
-                let task = jvm.invoke_virtual(&java_tasks, "remove", "(I)Ljava/lang/Object;", (0,)).await?;
+                let task: Box<dyn ClassInstance> = jvm.invoke_virtual(&java_tasks, "remove", "(I)Ljava/lang/Object;", (0,)).await?;
                 tasks.push(task);
             }
 
@@ -1,4 +1,5 @@
 use alloc::{
+    boxed::Box,
     format,
     string::{String as RustString, ToString},
     vec,
@@ -7,7 +8,7 @@ use alloc::{
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::FieldAccessFlags;
-use jvm::{Array, ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
+use jvm::{Array, ClassInstance, ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -111,7 +112,7 @@ impl Vector {
     }
 
     async fn capacity(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i32> {
-        let element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
         Ok(jvm.array_length(&element_data).await? as i32)
     }
 
@@ -128,7 +129,7 @@ impl Vector {
         }
 
         let element_count: i32 = jvm.get_field(&this, "elementCount", "I").await?;
-        let element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
         let elements: Vec<ClassInstanceRef<Object>> = jvm.load_array(&element_data, 0, element_count as usize).await?;
         jvm.store_array(&mut destination, 0, elements).await
     }
@@ -159,7 +160,7 @@ impl Vector {
         let element_count: i32 = jvm.get_field(&this, "elementCount", "I").await?;
         Self::ensure_capacity(jvm, &mut this, (element_count + 1) as _).await?;
 
-        let mut element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let mut element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
         jvm.store_array(&mut element_data, element_count as _, core::iter::once(element)).await?;
         jvm.put_field(&mut this, "elementCount", "I", element_count + 1).await?;
 
@@ -186,7 +187,7 @@ impl Vector {
         let element_count: i32 = jvm.get_field(&this, "elementCount", "I").await?;
         Self::ensure_capacity(jvm, &mut this, (element_count + 1) as _).await?;
 
-        let mut element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let mut element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
         jvm.store_array(&mut element_data, element_count as _, core::iter::once(element)).await?;
         jvm.put_field(&mut this, "elementCount", "I", element_count + 1).await?;
 
@@ -210,7 +211,7 @@ impl Vector {
         }
         Self::ensure_capacity(jvm, &mut this, (element_count + 1) as _).await?;
 
-        let mut element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let mut element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
 
         let num_to_move = element_count - index;
         if num_to_move > 0 {
@@ -234,7 +235,7 @@ impl Vector {
                 .await);
         }
 
-        let element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
         let element: ClassInstanceRef<Object> = jvm.load_array(&element_data, index as _, 1).await?.into_iter().next().unwrap();
 
         Ok(element)
@@ -262,7 +263,7 @@ impl Vector {
                 .await);
         }
 
-        let mut element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let mut element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
         let old_element: ClassInstanceRef<Object> = jvm.load_array(&element_data, index as _, 1).await?.into_iter().next().unwrap();
         jvm.store_array(&mut element_data, index as _, core::iter::once(element)).await?;
 
@@ -293,7 +294,7 @@ impl Vector {
                 .await);
         }
 
-        let mut element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let mut element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
         let removed: ClassInstanceRef<Object> = jvm.load_array(&element_data, index as _, 1).await?.into_iter().next().unwrap();
 
         let num_to_move = element_count - index - 1;
@@ -320,7 +321,7 @@ impl Vector {
         tracing::debug!("java.util.Vector::removeAllElements({this:?})");
 
         let element_count: i32 = jvm.get_field(&this, "elementCount", "I").await?;
-        let mut element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let mut element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
 
         let nulls: Vec<ClassInstanceRef<Object>> = (0..element_count).map(|_| None.into()).collect();
         if !nulls.is_empty() {
@@ -342,7 +343,7 @@ impl Vector {
                 .await);
         }
 
-        let mut element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let mut element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
 
         let num_to_move = element_count - index - 1;
         if num_to_move > 0 {
@@ -378,7 +379,7 @@ impl Vector {
         }
 
         let element_count: i32 = jvm.get_field(&this, "elementCount", "I").await?;
-        let element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
 
         for i in index..element_count {
             let item: ClassInstanceRef<Object> = jvm.load_array(&element_data, i as _, 1).await?.into_iter().next().unwrap();
@@ -436,7 +437,7 @@ impl Vector {
                 .await);
         }
 
-        let element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
 
         for i in (0..=index).rev() {
             let item: ClassInstanceRef<Object> = jvm.load_array(&element_data, i as _, 1).await?.into_iter().next().unwrap();
@@ -466,7 +467,7 @@ impl Vector {
             return Err(jvm.exception("java/util/NoSuchElementException", "Vector is empty").await);
         }
 
-        let element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
         let element: ClassInstanceRef<Object> = jvm.load_array(&element_data, 0, 1).await?.into_iter().next().unwrap();
 
         Ok(element)
@@ -480,7 +481,7 @@ impl Vector {
             return Err(jvm.exception("java/util/NoSuchElementException", "Vector is empty").await);
         }
 
-        let element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
         Ok(jvm
             .load_array::<ClassInstanceRef<Object>>(&element_data, (element_count - 1) as usize, 1)
             .await?
@@ -513,7 +514,7 @@ impl Vector {
         if new_size > element_count {
             Self::ensure_capacity(jvm, &mut this, new_size as usize).await?;
         } else if new_size < element_count {
-            let mut element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+            let mut element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
             let nulls: Vec<ClassInstanceRef<Object>> = (new_size..element_count).map(|_| None.into()).collect();
             jvm.store_array(&mut element_data, new_size as usize, nulls).await?;
         }
@@ -561,7 +562,7 @@ impl Vector {
         tracing::debug!("java.util.Vector::trimToSize({this:?})");
 
         let element_count: i32 = jvm.get_field(&this, "elementCount", "I").await?;
-        let element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
         let current_capacity = jvm.array_length(&element_data).await?;
 
         if (element_count as usize) < current_capacity {
@@ -578,7 +579,7 @@ impl Vector {
         tracing::debug!("java.util.Vector::toString({this:?})");
 
         let element_count: i32 = jvm.get_field(&this, "elementCount", "I").await?;
-        let element_data = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
+        let element_data: Box<dyn ClassInstance> = jvm.get_field(&this, "elementData", "[Ljava/lang/Object;").await?;
         let elements: Vec<ClassInstanceRef<Object>> = jvm.load_array(&element_data, 0, element_count as usize).await?;
         let mut result = RustString::from("[");
         for (index, element) in elements.into_iter().enumerate() {
@@ -588,7 +589,7 @@ impl Vector {
 
             if element.is_null() {
                 result.push_str("null");
-            } else if element.instance.as_ref().unwrap().equals(&**this)? {
+            } else if element.instance == this.instance {
                 result.push_str("(this Collection)");
             } else {
                 let text: ClassInstanceRef<String> = jvm.invoke_virtual(&element, "toString", "()Ljava/lang/String;", ()).await?;
@@ -601,7 +602,7 @@ impl Vector {
     }
 
     async fn ensure_capacity(jvm: &Jvm, this: &mut ClassInstanceRef<Self>, min_capacity: usize) -> Result<()> {
-        let element_data = jvm.get_field(this, "elementData", "[Ljava/lang/Object;").await?;
+        let element_data: Box<dyn ClassInstance> = jvm.get_field(this, "elementData", "[Ljava/lang/Object;").await?;
         let current_capacity = jvm.array_length(&element_data).await?;
 
         if min_capacity > current_capacity {
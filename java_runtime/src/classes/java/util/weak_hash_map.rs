@@ -0,0 +1,309 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use jvm::{Array, ClassInstance, ClassInstanceRef, Jvm, Result};
+
+use crate::{
+    RuntimeClassProto, RuntimeContext,
+    classes::java::{
+        lang::{Object, r#ref::ReferenceQueue},
+        util::{Collection, HashMap, Set, WeakHashMapWeakKey},
+    },
+};
+
+// class java.util.WeakHashMap
+// Entries live in a HashMap keyed by WeakKey. Keys cleared by the garbage collector land on the queue,
+// and their entries are expunged at the start of every operation
+pub struct WeakHashMap;
+
+impl WeakHashMap {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/util/WeakHashMap",
+            parent_class: Some("java/util/AbstractMap"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                JavaMethodProto::new("<init>", "(I)V", Self::init_with_capacity, Default::default()),
+                JavaMethodProto::new("size", "()I", Self::size, Default::default()),
+                JavaMethodProto::new("containsKey", "(Ljava/lang/Object;)Z", Self::contains_key, Default::default()),
+                JavaMethodProto::new("get", "(Ljava/lang/Object;)Ljava/lang/Object;", Self::get, Default::default()),
+                JavaMethodProto::new(
+                    "put",
+                    "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+                    Self::put,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("remove", "(Ljava/lang/Object;)Ljava/lang/Object;", Self::remove, Default::default()),
+                JavaMethodProto::new("clear", "()V", Self::clear, Default::default()),
+                JavaMethodProto::new("containsValue", "(Ljava/lang/Object;)Z", Self::contains_value, Default::default()),
+                JavaMethodProto::new("keySet", "()Ljava/util/Set;", Self::key_set, Default::default()),
+                JavaMethodProto::new("values", "()Ljava/util/Collection;", Self::values, Default::default()),
+                JavaMethodProto::new("entrySet", "()Ljava/util/Set;", Self::entry_set, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("map", "Ljava/util/HashMap;", Default::default()),
+                JavaFieldProto::new("queue", "Ljava/lang/ref/ReferenceQueue;", Default::default()),
+                // stands in for the null key, which can't be weakly referenced
+                JavaFieldProto::new("nullKey", "Ljava/lang/Object;", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        tracing::debug!("java.util.WeakHashMap::<init>({this:?})");
+
+        let _: () = jvm.invoke_special(&this, "java/util/WeakHashMap", "<init>", "(I)V", (16,)).await?;
+
+        Ok(())
+    }
+
+    async fn init_with_capacity(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>, capacity: i32) -> Result<()> {
+        tracing::debug!("java.util.WeakHashMap::<init>({this:?}, {capacity:?})");
+
+        let _: () = jvm.invoke_special(&this, "java/util/AbstractMap", "<init>", "()V", ()).await?;
+
+        let map = jvm.new_class("java/util/HashMap", "(I)V", (capacity,)).await?;
+        let queue = jvm.new_class("java/lang/ref/ReferenceQueue", "()V", ()).await?;
+        let null_key = jvm.new_class("java/lang/Object", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "map", "Ljava/util/HashMap;", map).await?;
+        jvm.put_field(&mut this, "queue", "Ljava/lang/ref/ReferenceQueue;", queue).await?;
+        jvm.put_field(&mut this, "nullKey", "Ljava/lang/Object;", null_key).await?;
+
+        Ok(())
+    }
+
+    async fn size(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i32> {
+        tracing::debug!("java.util.WeakHashMap::size({this:?})");
+
+        let map = Self::expunged_map(jvm, &this).await?;
+
+        jvm.invoke_virtual(&map, "size", "()I", ()).await
+    }
+
+    async fn contains_key(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, key: ClassInstanceRef<Object>) -> Result<bool> {
+        tracing::debug!("java.util.WeakHashMap::containsKey({this:?}, {key:?})");
+
+        let map = Self::expunged_map(jvm, &this).await?;
+        let key = Self::weak_key(jvm, &this, key, false).await?;
+
+        jvm.invoke_virtual(&map, "containsKey", "(Ljava/lang/Object;)Z", (key,)).await
+    }
+
+    async fn get(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, key: ClassInstanceRef<Object>) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.util.WeakHashMap::get({this:?}, {key:?})");
+
+        let map = Self::expunged_map(jvm, &this).await?;
+        let key = Self::weak_key(jvm, &this, key, false).await?;
+
+        jvm.invoke_virtual(&map, "get", "(Ljava/lang/Object;)Ljava/lang/Object;", (key,)).await
+    }
+
+    async fn put(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        key: ClassInstanceRef<Object>,
+        value: ClassInstanceRef<Object>,
+    ) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.util.WeakHashMap::put({this:?}, {key:?}, {value:?})");
+
+        let map = Self::expunged_map(jvm, &this).await?;
+        let key = Self::weak_key(jvm, &this, key, true).await?;
+
+        jvm.invoke_virtual(&map, "put", "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;", (key, value))
+            .await
+    }
+
+    async fn remove(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        this: ClassInstanceRef<Self>,
+        key: ClassInstanceRef<Object>,
+    ) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.util.WeakHashMap::remove({this:?}, {key:?})");
+
+        let map = Self::expunged_map(jvm, &this).await?;
+        let key = Self::weak_key(jvm, &this, key, false).await?;
+
+        jvm.invoke_virtual(&map, "remove", "(Ljava/lang/Object;)Ljava/lang/Object;", (key,)).await
+    }
+
+    async fn clear(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        tracing::debug!("java.util.WeakHashMap::clear({this:?})");
+
+        let map = Self::expunged_map(jvm, &this).await?;
+
+        jvm.invoke_virtual(&map, "clear", "()V", ()).await
+    }
+
+    async fn contains_value(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, value: ClassInstanceRef<Object>) -> Result<bool> {
+        tracing::debug!("java.util.WeakHashMap::containsValue({this:?}, {value:?})");
+
+        let map = Self::expunged_map(jvm, &this).await?;
+
+        jvm.invoke_virtual(&map, "containsValue", "(Ljava/lang/Object;)Z", (value,)).await
+    }
+
+    async fn key_set(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Set>> {
+        tracing::debug!("java.util.WeakHashMap::keySet({this:?})");
+
+        let key_set = jvm
+            .new_class("java/util/WeakHashMap$KeySet", "(Ljava/util/WeakHashMap;)V", (this,))
+            .await?;
+
+        Ok(key_set.into())
+    }
+
+    async fn values(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Collection>> {
+        tracing::debug!("java.util.WeakHashMap::values({this:?})");
+
+        let values = jvm
+            .new_class("java/util/WeakHashMap$Values", "(Ljava/util/WeakHashMap;)V", (this,))
+            .await?;
+
+        Ok(values.into())
+    }
+
+    async fn entry_set(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Set>> {
+        tracing::debug!("java.util.WeakHashMap::entrySet({this:?})");
+
+        let entry_set = jvm
+            .new_class("java/util/WeakHashMap$EntrySet", "(Ljava/util/WeakHashMap;)V", (this,))
+            .await?;
+
+        Ok(entry_set.into())
+    }
+
+    // iterates over the entries live at the time of the call. removal goes through the map
+    pub(super) async fn iterator(jvm: &Jvm, this: &ClassInstanceRef<Self>, kind: IteratorKind) -> Result<ClassInstanceRef<Object>> {
+        let entries = Self::live_entries(jvm, this).await?;
+
+        let mut keys = Vec::with_capacity(entries.len());
+        let mut elements = Vec::with_capacity(entries.len());
+        for (key, value) in entries {
+            let element = match kind {
+                IteratorKind::Keys => key.clone(),
+                IteratorKind::Values => value,
+                IteratorKind::Entries => jvm
+                    .new_class(
+                        "java/util/WeakHashMap$Entry",
+                        "(Ljava/util/WeakHashMap;Ljava/lang/Object;Ljava/lang/Object;)V",
+                        (this.clone(), key.clone(), value),
+                    )
+                    .await?
+                    .into(),
+            };
+            keys.push(key);
+            elements.push(element);
+        }
+
+        let keys = Self::to_array(jvm, keys).await?;
+        let elements = Self::to_array(jvm, elements).await?;
+        let iterator = jvm
+            .new_class(
+                "java/util/WeakHashMap$HashIterator",
+                "(Ljava/util/WeakHashMap;[Ljava/lang/Object;[Ljava/lang/Object;)V",
+                (this.clone(), keys, elements),
+            )
+            .await?;
+
+        Ok(iterator.into())
+    }
+
+    // the live entries with their keys unwrapped
+    async fn live_entries(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> Result<Vec<(ClassInstanceRef<Object>, ClassInstanceRef<Object>)>> {
+        let map = Self::expunged_map(jvm, this).await?;
+        let null_key: ClassInstanceRef<Object> = jvm.get_field(this, "nullKey", "Ljava/lang/Object;").await?;
+        let mut result = Vec::new();
+
+        let entries: Box<dyn ClassInstance> = jvm.invoke_virtual(&map, "entrySet", "()Ljava/util/Set;", ()).await?;
+        let iterator: Box<dyn ClassInstance> = jvm.invoke_virtual(&entries, "iterator", "()Ljava/util/Iterator;", ()).await?;
+        while jvm.invoke_virtual(&iterator, "hasNext", "()Z", ()).await? {
+            let entry: Box<dyn ClassInstance> = jvm.invoke_virtual(&iterator, "next", "()Ljava/lang/Object;", ()).await?;
+            let weak_key: Box<dyn ClassInstance> = jvm.invoke_virtual(&entry, "getKey", "()Ljava/lang/Object;", ()).await?;
+            let key: ClassInstanceRef<Object> = jvm.invoke_virtual(&weak_key, "get", "()Ljava/lang/Object;", ()).await?;
+            // cleared, but not expunged yet
+            if key.is_null() {
+                continue;
+            }
+            let key = if key.instance.as_ref().map(|x| x.identity()) == null_key.instance.as_ref().map(|x| x.identity()) {
+                None.into()
+            } else {
+                key
+            };
+            let value: ClassInstanceRef<Object> = jvm.invoke_virtual(&entry, "getValue", "()Ljava/lang/Object;", ()).await?;
+
+            result.push((key, value));
+        }
+
+        Ok(result)
+    }
+
+    async fn to_array(jvm: &Jvm, elements: Vec<ClassInstanceRef<Object>>) -> Result<ClassInstanceRef<Array<Object>>> {
+        let mut array: ClassInstanceRef<Array<Object>> = jvm.instantiate_array("Ljava/lang/Object;", elements.len()).await?.into();
+        if !elements.is_empty() {
+            jvm.store_array(&mut array, 0, elements).await?;
+        }
+
+        Ok(array)
+    }
+
+    // removes the entries whose keys have been collected, and returns the backing map
+    async fn expunged_map(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> Result<ClassInstanceRef<HashMap>> {
+        let map: ClassInstanceRef<HashMap> = jvm.get_field(this, "map", "Ljava/util/HashMap;").await?;
+        let queue: ClassInstanceRef<ReferenceQueue> = jvm.get_field(this, "queue", "Ljava/lang/ref/ReferenceQueue;").await?;
+
+        loop {
+            let key: ClassInstanceRef<WeakHashMapWeakKey> = jvm.invoke_virtual(&queue, "poll", "()Ljava/lang/ref/Reference;", ()).await?;
+            if key.is_null() {
+                break;
+            }
+
+            let _: ClassInstanceRef<Object> = jvm
+                .invoke_virtual(&map, "remove", "(Ljava/lang/Object;)Ljava/lang/Object;", (key,))
+                .await?;
+        }
+
+        Ok(map)
+    }
+
+    // keys stored in the map are registered with the queue, lookup keys aren't
+    async fn weak_key(
+        jvm: &Jvm,
+        this: &ClassInstanceRef<Self>,
+        key: ClassInstanceRef<Object>,
+        stored: bool,
+    ) -> Result<ClassInstanceRef<WeakHashMapWeakKey>> {
+        let key: ClassInstanceRef<Object> = if key.is_null() {
+            jvm.get_field(this, "nullKey", "Ljava/lang/Object;").await?
+        } else {
+            key
+        };
+        let hash: i32 = jvm.invoke_virtual(&key, "hashCode", "()I", ()).await?;
+        let queue: ClassInstanceRef<ReferenceQueue> = if stored {
+            jvm.get_field(this, "queue", "Ljava/lang/ref/ReferenceQueue;").await?
+        } else {
+            None.into()
+        };
+
+        let weak_key = jvm
+            .new_class(
+                "java/util/WeakHashMap$WeakKey",
+                "(Ljava/lang/Object;Ljava/lang/ref/ReferenceQueue;I)V",
+                (key, queue, hash),
+            )
+            .await?;
+
+        Ok(weak_key.into())
+    }
+}
+
+#[derive(Copy, Clone)]
+pub(super) enum IteratorKind {
+    Keys,
+    Values,
+    Entries,
+}
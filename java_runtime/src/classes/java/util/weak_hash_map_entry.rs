@@ -0,0 +1,95 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use jvm::{ClassInstanceRef, Jvm, Result};
+
+use crate::{RuntimeClassProto, RuntimeContext, classes::java::lang::Object};
+
+use super::WeakHashMap;
+
+// class java.util.WeakHashMap$Entry
+// Handed out by the entry set iterator. It holds the key strongly, and setValue writes through to the map
+pub struct WeakHashMapEntry;
+
+impl WeakHashMapEntry {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/util/WeakHashMap$Entry",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec!["java/util/Map$Entry"],
+            methods: vec![
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/util/WeakHashMap;Ljava/lang/Object;Ljava/lang/Object;)V",
+                    Self::init,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("getKey", "()Ljava/lang/Object;", Self::get_key, Default::default()),
+                JavaMethodProto::new("getValue", "()Ljava/lang/Object;", Self::get_value, Default::default()),
+                JavaMethodProto::new("setValue", "(Ljava/lang/Object;)Ljava/lang/Object;", Self::set_value, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("map", "Ljava/util/WeakHashMap;", Default::default()),
+                JavaFieldProto::new("key", "Ljava/lang/Object;", Default::default()),
+                JavaFieldProto::new("value", "Ljava/lang/Object;", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        mut this: ClassInstanceRef<Self>,
+        map: ClassInstanceRef<WeakHashMap>,
+        key: ClassInstanceRef<Object>,
+        value: ClassInstanceRef<Object>,
+    ) -> Result<()> {
+        tracing::debug!("java.util.WeakHashMap$Entry::<init>({this:?}, {map:?}, {key:?}, {value:?})");
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "map", "Ljava/util/WeakHashMap;", map).await?;
+        jvm.put_field(&mut this, "key", "Ljava/lang/Object;", key).await?;
+        jvm.put_field(&mut this, "value", "Ljava/lang/Object;", value).await?;
+
+        Ok(())
+    }
+
+    async fn get_key(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.util.WeakHashMap$Entry::getKey({this:?})");
+
+        jvm.get_field(&this, "key", "Ljava/lang/Object;").await
+    }
+
+    async fn get_value(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.util.WeakHashMap$Entry::getValue({this:?})");
+
+        jvm.get_field(&this, "value", "Ljava/lang/Object;").await
+    }
+
+    async fn set_value(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        mut this: ClassInstanceRef<Self>,
+        value: ClassInstanceRef<Object>,
+    ) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.util.WeakHashMap$Entry::setValue({this:?}, {value:?})");
+
+        let map: ClassInstanceRef<WeakHashMap> = jvm.get_field(&this, "map", "Ljava/util/WeakHashMap;").await?;
+        let key: ClassInstanceRef<Object> = jvm.get_field(&this, "key", "Ljava/lang/Object;").await?;
+        let _: ClassInstanceRef<Object> = jvm
+            .invoke_virtual(
+                &map,
+                "put",
+                "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+                (key, value.clone()),
+            )
+            .await?;
+
+        let old_value = jvm.get_field(&this, "value", "Ljava/lang/Object;").await?;
+        jvm.put_field(&mut this, "value", "Ljava/lang/Object;", value).await?;
+
+        Ok(old_value)
+    }
+}
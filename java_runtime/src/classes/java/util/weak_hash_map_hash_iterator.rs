@@ -0,0 +1,108 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use jvm::{Array, ClassInstanceRef, Jvm, Result};
+
+use crate::{RuntimeClassProto, RuntimeContext, classes::java::lang::Object};
+
+use super::WeakHashMap;
+
+// class java.util.WeakHashMap$HashIterator
+// Walks the keys, values or entries live when it was created, holding their keys strongly until it's done.
+// remove() takes the last returned key out of the map
+pub struct WeakHashMapHashIterator;
+
+impl WeakHashMapHashIterator {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/util/WeakHashMap$HashIterator",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec!["java/util/Iterator"],
+            methods: vec![
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/util/WeakHashMap;[Ljava/lang/Object;[Ljava/lang/Object;)V",
+                    Self::init,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("hasNext", "()Z", Self::has_next, Default::default()),
+                JavaMethodProto::new("next", "()Ljava/lang/Object;", Self::next, Default::default()),
+                JavaMethodProto::new("remove", "()V", Self::remove, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("map", "Ljava/util/WeakHashMap;", Default::default()),
+                JavaFieldProto::new("keys", "[Ljava/lang/Object;", Default::default()),
+                JavaFieldProto::new("elements", "[Ljava/lang/Object;", Default::default()),
+                JavaFieldProto::new("index", "I", Default::default()),
+                // index of the element returned by the last next(), -1 if there's none to remove
+                JavaFieldProto::new("lastReturned", "I", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        mut this: ClassInstanceRef<Self>,
+        map: ClassInstanceRef<WeakHashMap>,
+        keys: ClassInstanceRef<Array<Object>>,
+        elements: ClassInstanceRef<Array<Object>>,
+    ) -> Result<()> {
+        tracing::debug!("java.util.WeakHashMap$HashIterator::<init>({this:?}, {map:?}, {keys:?}, {elements:?})");
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "map", "Ljava/util/WeakHashMap;", map).await?;
+        jvm.put_field(&mut this, "keys", "[Ljava/lang/Object;", keys).await?;
+        jvm.put_field(&mut this, "elements", "[Ljava/lang/Object;", elements).await?;
+        jvm.put_field(&mut this, "index", "I", 0).await?;
+        jvm.put_field(&mut this, "lastReturned", "I", -1).await?;
+
+        Ok(())
+    }
+
+    async fn has_next(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<bool> {
+        tracing::debug!("java.util.WeakHashMap$HashIterator::hasNext({this:?})");
+
+        let elements: ClassInstanceRef<Array<Object>> = jvm.get_field(&this, "elements", "[Ljava/lang/Object;").await?;
+        let index: i32 = jvm.get_field(&this, "index", "I").await?;
+
+        Ok((index as usize) < jvm.array_length(&elements).await?)
+    }
+
+    async fn next(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.util.WeakHashMap$HashIterator::next({this:?})");
+
+        let elements: ClassInstanceRef<Array<Object>> = jvm.get_field(&this, "elements", "[Ljava/lang/Object;").await?;
+        let index: i32 = jvm.get_field(&this, "index", "I").await?;
+
+        let mut values = jvm.load_array(&elements, index as usize, 1).await?;
+        let Some(element) = values.pop() else {
+            return Err(jvm.exception("java/util/NoSuchElementException", "WeakHashMap iterator exhausted").await);
+        };
+        jvm.put_field(&mut this, "index", "I", index + 1).await?;
+        jvm.put_field(&mut this, "lastReturned", "I", index).await?;
+
+        Ok(element)
+    }
+
+    async fn remove(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>) -> Result<()> {
+        tracing::debug!("java.util.WeakHashMap$HashIterator::remove({this:?})");
+
+        let last_returned: i32 = jvm.get_field(&this, "lastReturned", "I").await?;
+        if last_returned < 0 {
+            return Err(jvm.exception("java/lang/IllegalStateException", "WeakHashMap iterator").await);
+        }
+
+        let map: ClassInstanceRef<WeakHashMap> = jvm.get_field(&this, "map", "Ljava/util/WeakHashMap;").await?;
+        let keys: ClassInstanceRef<Array<Object>> = jvm.get_field(&this, "keys", "[Ljava/lang/Object;").await?;
+        let key: ClassInstanceRef<Object> = jvm.load_array(&keys, last_returned as usize, 1).await?.pop().unwrap_or(None.into());
+        let _: ClassInstanceRef<Object> = jvm
+            .invoke_virtual(&map, "remove", "(Ljava/lang/Object;)Ljava/lang/Object;", (key,))
+            .await?;
+        jvm.put_field(&mut this, "lastReturned", "I", -1).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use jvm::{ClassInstanceRef, Jvm, Result};
+
+use crate::{RuntimeClassProto, RuntimeContext, classes::java::lang::Object};
+
+use super::{WeakHashMap, weak_hash_map::IteratorKind};
+
+// class java.util.WeakHashMap$KeySet
+pub struct WeakHashMapKeySet;
+
+impl WeakHashMapKeySet {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/util/WeakHashMap$KeySet",
+            parent_class: Some("java/util/AbstractSet"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/util/WeakHashMap;)V", Self::init, Default::default()),
+                JavaMethodProto::new("size", "()I", Self::size, Default::default()),
+                JavaMethodProto::new("contains", "(Ljava/lang/Object;)Z", Self::contains, Default::default()),
+                JavaMethodProto::new("remove", "(Ljava/lang/Object;)Z", Self::remove, Default::default()),
+                JavaMethodProto::new("clear", "()V", Self::clear, Default::default()),
+                JavaMethodProto::new("iterator", "()Ljava/util/Iterator;", Self::iterator, Default::default()),
+            ],
+            fields: vec![JavaFieldProto::new("map", "Ljava/util/WeakHashMap;", Default::default())],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>, map: ClassInstanceRef<WeakHashMap>) -> Result<()> {
+        tracing::debug!("java.util.WeakHashMap$KeySet::<init>({this:?}, {map:?})");
+
+        let _: () = jvm.invoke_special(&this, "java/util/AbstractSet", "<init>", "()V", ()).await?;
+        jvm.put_field(&mut this, "map", "Ljava/util/WeakHashMap;", map).await?;
+
+        Ok(())
+    }
+
+    async fn size(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i32> {
+        tracing::debug!("java.util.WeakHashMap$KeySet::size({this:?})");
+
+        let map: ClassInstanceRef<WeakHashMap> = jvm.get_field(&this, "map", "Ljava/util/WeakHashMap;").await?;
+
+        jvm.invoke_virtual(&map, "size", "()I", ()).await
+    }
+
+    async fn contains(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, key: ClassInstanceRef<Object>) -> Result<bool> {
+        tracing::debug!("java.util.WeakHashMap$KeySet::contains({this:?}, {key:?})");
+
+        let map: ClassInstanceRef<WeakHashMap> = jvm.get_field(&this, "map", "Ljava/util/WeakHashMap;").await?;
+
+        jvm.invoke_virtual(&map, "containsKey", "(Ljava/lang/Object;)Z", (key,)).await
+    }
+
+    async fn remove(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, key: ClassInstanceRef<Object>) -> Result<bool> {
+        tracing::debug!("java.util.WeakHashMap$KeySet::remove({this:?}, {key:?})");
+
+        let map: ClassInstanceRef<WeakHashMap> = jvm.get_field(&this, "map", "Ljava/util/WeakHashMap;").await?;
+        let contains: bool = jvm.invoke_virtual(&map, "containsKey", "(Ljava/lang/Object;)Z", (key.clone(),)).await?;
+        if !contains {
+            return Ok(false);
+        }
+
+        let _: ClassInstanceRef<Object> = jvm
+            .invoke_virtual(&map, "remove", "(Ljava/lang/Object;)Ljava/lang/Object;", (key,))
+            .await?;
+
+        Ok(true)
+    }
+
+    async fn clear(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        tracing::debug!("java.util.WeakHashMap$KeySet::clear({this:?})");
+
+        let map: ClassInstanceRef<WeakHashMap> = jvm.get_field(&this, "map", "Ljava/util/WeakHashMap;").await?;
+
+        jvm.invoke_virtual(&map, "clear", "()V", ()).await
+    }
+
+    async fn iterator(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.util.WeakHashMap$KeySet::iterator({this:?})");
+
+        let map: ClassInstanceRef<WeakHashMap> = jvm.get_field(&this, "map", "Ljava/util/WeakHashMap;").await?;
+
+        WeakHashMap::iterator(jvm, &map, IteratorKind::Keys).await
+    }
+}
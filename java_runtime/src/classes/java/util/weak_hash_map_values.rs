@@ -0,0 +1,94 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use jvm::{ClassInstanceRef, Jvm, Result};
+
+use crate::{RuntimeClassProto, RuntimeContext, classes::java::lang::Object};
+
+use super::{WeakHashMap, weak_hash_map::IteratorKind};
+
+// class java.util.WeakHashMap$Values
+pub struct WeakHashMapValues;
+
+impl WeakHashMapValues {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/util/WeakHashMap$Values",
+            parent_class: Some("java/util/AbstractCollection"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/util/WeakHashMap;)V", Self::init, Default::default()),
+                JavaMethodProto::new("size", "()I", Self::size, Default::default()),
+                JavaMethodProto::new("contains", "(Ljava/lang/Object;)Z", Self::contains, Default::default()),
+                JavaMethodProto::new("remove", "(Ljava/lang/Object;)Z", Self::remove, Default::default()),
+                JavaMethodProto::new("clear", "()V", Self::clear, Default::default()),
+                JavaMethodProto::new("iterator", "()Ljava/util/Iterator;", Self::iterator, Default::default()),
+            ],
+            fields: vec![JavaFieldProto::new("map", "Ljava/util/WeakHashMap;", Default::default())],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>, map: ClassInstanceRef<WeakHashMap>) -> Result<()> {
+        tracing::debug!("java.util.WeakHashMap$Values::<init>({this:?}, {map:?})");
+
+        let _: () = jvm.invoke_special(&this, "java/util/AbstractCollection", "<init>", "()V", ()).await?;
+        jvm.put_field(&mut this, "map", "Ljava/util/WeakHashMap;", map).await?;
+
+        Ok(())
+    }
+
+    async fn size(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i32> {
+        tracing::debug!("java.util.WeakHashMap$Values::size({this:?})");
+
+        let map: ClassInstanceRef<WeakHashMap> = jvm.get_field(&this, "map", "Ljava/util/WeakHashMap;").await?;
+
+        jvm.invoke_virtual(&map, "size", "()I", ()).await
+    }
+
+    async fn contains(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, value: ClassInstanceRef<Object>) -> Result<bool> {
+        tracing::debug!("java.util.WeakHashMap$Values::contains({this:?}, {value:?})");
+
+        let map: ClassInstanceRef<WeakHashMap> = jvm.get_field(&this, "map", "Ljava/util/WeakHashMap;").await?;
+
+        jvm.invoke_virtual(&map, "containsValue", "(Ljava/lang/Object;)Z", (value,)).await
+    }
+
+    // removes the first entry holding the value
+    async fn remove(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, value: ClassInstanceRef<Object>) -> Result<bool> {
+        tracing::debug!("java.util.WeakHashMap$Values::remove({this:?}, {value:?})");
+
+        let iterator: ClassInstanceRef<Object> = jvm.invoke_virtual(&this, "iterator", "()Ljava/util/Iterator;", ()).await?;
+        while jvm.invoke_virtual(&iterator, "hasNext", "()Z", ()).await? {
+            let current: ClassInstanceRef<Object> = jvm.invoke_virtual(&iterator, "next", "()Ljava/lang/Object;", ()).await?;
+            let equal = if value.is_null() {
+                current.is_null()
+            } else {
+                jvm.invoke_virtual(&value, "equals", "(Ljava/lang/Object;)Z", (current,)).await?
+            };
+            if equal {
+                let _: () = jvm.invoke_virtual(&iterator, "remove", "()V", ()).await?;
+
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    async fn clear(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        tracing::debug!("java.util.WeakHashMap$Values::clear({this:?})");
+
+        let map: ClassInstanceRef<WeakHashMap> = jvm.get_field(&this, "map", "Ljava/util/WeakHashMap;").await?;
+
+        jvm.invoke_virtual(&map, "clear", "()V", ()).await
+    }
+
+    async fn iterator(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Object>> {
+        tracing::debug!("java.util.WeakHashMap$Values::iterator({this:?})");
+
+        let map: ClassInstanceRef<WeakHashMap> = jvm.get_field(&this, "map", "Ljava/util/WeakHashMap;").await?;
+
+        WeakHashMap::iterator(jvm, &map, IteratorKind::Values).await
+    }
+}
@@ -0,0 +1,89 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use jvm::{ClassInstanceRef, Jvm, Result};
+
+use crate::{
+    RuntimeClassProto, RuntimeContext,
+    classes::java::lang::{Object, r#ref::ReferenceQueue},
+};
+
+// class java.util.WeakHashMap$WeakKey
+// The hash is captured up front so the entry can still be found and removed after the key is cleared
+pub struct WeakHashMapWeakKey;
+
+impl WeakHashMapWeakKey {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "java/util/WeakHashMap$WeakKey",
+            parent_class: Some("java/lang/ref/WeakReference"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/Object;Ljava/lang/ref/ReferenceQueue;I)V",
+                    Self::init,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("hashCode", "()I", Self::hash_code, Default::default()),
+                JavaMethodProto::new("equals", "(Ljava/lang/Object;)Z", Self::equals, Default::default()),
+            ],
+            fields: vec![JavaFieldProto::new("hash", "I", Default::default())],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        mut this: ClassInstanceRef<Self>,
+        key: ClassInstanceRef<Object>,
+        queue: ClassInstanceRef<ReferenceQueue>,
+        hash: i32,
+    ) -> Result<()> {
+        tracing::debug!("java.util.WeakHashMap$WeakKey::<init>({this:?}, {key:?}, {queue:?}, {hash:?})");
+
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "java/lang/ref/WeakReference",
+                "<init>",
+                "(Ljava/lang/Object;Ljava/lang/ref/ReferenceQueue;)V",
+                (key, queue),
+            )
+            .await?;
+
+        jvm.put_field(&mut this, "hash", "I", hash).await?;
+
+        Ok(())
+    }
+
+    async fn hash_code(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<i32> {
+        tracing::debug!("java.util.WeakHashMap$WeakKey::hashCode({this:?})");
+
+        jvm.get_field(&this, "hash", "I").await
+    }
+
+    // same key object, or two live keys that are equal. a cleared key only equals itself
+    async fn equals(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
+        tracing::debug!("java.util.WeakHashMap$WeakKey::equals({this:?}, {other:?})");
+
+        if other.is_null() {
+            return Ok(false);
+        }
+        if this.instance == other.instance {
+            return Ok(true);
+        }
+        if !jvm.is_instance(&other, "java/util/WeakHashMap$WeakKey") {
+            return Ok(false);
+        }
+
+        let key: ClassInstanceRef<Object> = jvm.invoke_virtual(&this, "get", "()Ljava/lang/Object;", ()).await?;
+        let other_key: ClassInstanceRef<Object> = jvm.invoke_virtual(&other, "get", "()Ljava/lang/Object;", ()).await?;
+        if key.is_null() || other_key.is_null() {
+            return Ok(false);
+        }
+
+        jvm.invoke_virtual(&key, "equals", "(Ljava/lang/Object;)Z", (other_key,)).await
+    }
+}
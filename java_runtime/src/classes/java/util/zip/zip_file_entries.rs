@@ -1,7 +1,7 @@
-use alloc::{vec, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{Array, ClassInstanceRef, Jvm, Result};
+use jvm::{Array, ClassInstance, ClassInstanceRef, Jvm, Result};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -69,7 +69,7 @@ impl ZipFileEntries {
         let names: ClassInstanceRef<Array<String>> = jvm.get_field(&this, "names", "[Ljava/lang/String;").await?;
         let name: Vec<ClassInstanceRef<String>> = jvm.load_array(&names, i as _, 1).await?;
 
-        let zip_file = jvm.get_field(&this, "zipFile", "Ljava/util/zip/ZipFile;").await?;
+        let zip_file: Box<dyn ClassInstance> = jvm.get_field(&this, "zipFile", "Ljava/util/zip/ZipFile;").await?;
         let entry = jvm
             .invoke_virtual(&zip_file, "getEntry", "(Ljava/lang/String;)Ljava/util/zip/ZipEntry;", (name[0].clone(),))
             .await?;
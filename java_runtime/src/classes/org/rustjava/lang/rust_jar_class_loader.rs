@@ -1,7 +1,7 @@
-use alloc::{vec, vec::Vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use jvm::{Array, ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
+use jvm::{Array, ClassInstance, ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -53,7 +53,7 @@ impl RustJarClassLoader {
         tracing::debug!("org.rustjava.lang.RustJarClassLoader::findClass({this:?}, {name:?})");
 
         let name = JavaLangString::to_rust_string(jvm, &name).await?;
-        let class_paths = jvm.get_field(&this, "classPaths", "[Ljava/lang/String;").await?;
+        let class_paths: Box<dyn ClassInstance> = jvm.get_field(&this, "classPaths", "[Ljava/lang/String;").await?;
         let class_paths: Vec<ClassInstanceRef<String>> = jvm.load_array(&class_paths, 0, jvm.array_length(&class_paths).await? as usize).await?;
 
         for class_path in class_paths {
@@ -63,7 +63,7 @@ impl RustJarClassLoader {
             }
 
             if let Some(class) = runtime.find_rustjar_class(jvm, &class_path, &name).await? {
-                let class = jvm.register_class(class, Some(this.clone().into())).await?;
+                let class = jvm.register_class(class, Some(this.clone().try_into()?)).await?;
                 return Ok(class.into());
             }
         }
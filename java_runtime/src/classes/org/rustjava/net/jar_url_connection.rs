@@ -1,8 +1,8 @@
-use alloc::vec;
+use alloc::{boxed::Box, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::{FieldAccessFlags, MethodAccessFlags};
-use jvm::{ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
+use jvm::{ClassInstance, ClassInstanceRef, Jvm, Result, runtime::JavaLangString};
 
 use crate::{
     RuntimeClassProto, RuntimeContext,
@@ -57,14 +57,14 @@ impl JarURLConnection {
     async fn get_jar_file(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<JarFile>> {
         tracing::debug!("org.rustjava.net.JarURLConnection::getJarFile({this:?})");
 
-        let url = jvm.invoke_virtual(&this, "getJarFileURL", "()Ljava/net/URL;", ()).await?;
-        let protocol = jvm.invoke_virtual(&url, "getProtocol", "()Ljava/lang/String;", ()).await?;
+        let url: Box<dyn ClassInstance> = jvm.invoke_virtual(&this, "getJarFileURL", "()Ljava/net/URL;", ()).await?;
+        let protocol: Box<dyn ClassInstance> = jvm.invoke_virtual(&url, "getProtocol", "()Ljava/lang/String;", ()).await?;
         let protocol = JavaLangString::to_rust_string(jvm, &protocol).await?;
 
         if protocol == "file" {
             let name: ClassInstanceRef<String> = jvm.invoke_virtual(&url, "getFile", "()Ljava/lang/String;", ()).await?;
 
-            let opened_files = jvm
+            let opened_files: Box<dyn ClassInstance> = jvm
                 .get_static_field("org/rustjava/net/JarURLConnection", "openedFiles", "Ljava/util/Hashtable;")
                 .await?;
             let cache: ClassInstanceRef<JarFile> = jvm
@@ -97,7 +97,7 @@ impl JarURLConnection {
         tracing::debug!("org.rustjava.net.JarURLConnection::getInputStream({this:?})");
 
         let entry: ClassInstanceRef<String> = jvm.invoke_virtual(&this, "getEntryName", "()Ljava/lang/String;", ()).await?;
-        let jar_file = jvm.invoke_virtual(&this, "getJarFile", "()Ljava/util/jar/JarFile;", ()).await?;
+        let jar_file: Box<dyn ClassInstance> = jvm.invoke_virtual(&this, "getJarFile", "()Ljava/util/jar/JarFile;", ()).await?;
 
         let jar_entry: ClassInstanceRef<JarFile> = jvm
             .invoke_virtual(&jar_file, "getJarEntry", "(Ljava/lang/String;)Ljava/util/jar/JarEntry;", (entry,))
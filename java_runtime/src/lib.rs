@@ -6,7 +6,7 @@ mod loader;
 mod runtime;
 
 pub use self::{
-    loader::{get_bootstrap_class_loader, get_runtime_class_proto},
+    loader::{get_bootstrap_class_loader, get_runtime_class_proto, get_runtime_class_protos},
     runtime::{File, FileDescriptorId, FileSize, FileStat, FileType, IOError, IOResult, Runtime, SpawnCallback},
 };
 
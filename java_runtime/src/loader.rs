@@ -1,11 +1,15 @@
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec, vec::Vec};
 
 use jvm::{BootstrapClassLoader, ClassDefinition, Jvm, Result};
 
 use crate::{RT_RUSTJAR, Runtime, RuntimeClassProto};
 
 pub fn get_runtime_class_proto(name: &str) -> Option<RuntimeClassProto> {
-    let protos = [
+    get_runtime_class_protos().into_iter().find(|proto| proto.name == name)
+}
+
+pub fn get_runtime_class_protos() -> Vec<RuntimeClassProto> {
+    vec![
         crate::classes::java::io::BufferedReader::as_proto(),
         crate::classes::java::io::ByteArrayInputStream::as_proto(),
         crate::classes::java::io::ByteArrayOutputStream::as_proto(),
@@ -59,11 +63,13 @@ pub fn get_runtime_class_proto(name: &str) -> Option<RuntimeClassProto> {
         crate::classes::java::lang::IllegalAccessException::as_proto(),
         crate::classes::java::lang::IllegalArgumentException::as_proto(),
         crate::classes::java::lang::IllegalMonitorStateException::as_proto(),
+        crate::classes::java::lang::IllegalStateException::as_proto(),
         crate::classes::java::lang::IllegalThreadStateException::as_proto(),
         crate::classes::java::lang::InstantiationError::as_proto(),
         crate::classes::java::lang::InstantiationException::as_proto(),
         crate::classes::java::lang::IncompatibleClassChangeError::as_proto(),
         crate::classes::java::lang::IndexOutOfBoundsException::as_proto(),
+        crate::classes::java::lang::InheritableThreadLocal::as_proto(),
         crate::classes::java::lang::Integer::as_proto(),
         crate::classes::java::lang::InterruptedException::as_proto(),
         crate::classes::java::lang::LinkageError::as_proto(),
@@ -72,7 +78,9 @@ pub fn get_runtime_class_proto(name: &str) -> Option<RuntimeClassProto> {
         crate::classes::java::lang::NegativeArraySizeException::as_proto(),
         crate::classes::java::lang::NoClassDefFoundError::as_proto(),
         crate::classes::java::lang::NoSuchFieldError::as_proto(),
+        crate::classes::java::lang::NoSuchFieldException::as_proto(),
         crate::classes::java::lang::NoSuchMethodError::as_proto(),
+        crate::classes::java::lang::NoSuchMethodException::as_proto(),
         crate::classes::java::lang::NullPointerException::as_proto(),
         crate::classes::java::lang::NumberFormatException::as_proto(),
         crate::classes::java::lang::Number::as_proto(),
@@ -88,12 +96,27 @@ pub fn get_runtime_class_proto(name: &str) -> Option<RuntimeClassProto> {
         crate::classes::java::lang::StringIndexOutOfBoundsException::as_proto(),
         crate::classes::java::lang::System::as_proto(),
         crate::classes::java::lang::Thread::as_proto(),
+        crate::classes::java::lang::ThreadGroup::as_proto(),
+        crate::classes::java::lang::ThreadLocal::as_proto(),
         crate::classes::java::lang::Throwable::as_proto(),
         crate::classes::java::lang::UnsupportedOperationException::as_proto(),
         crate::classes::java::lang::UnsupportedClassVersionError::as_proto(),
         crate::classes::java::lang::UnsatisfiedLinkError::as_proto(),
         crate::classes::java::lang::VerifyError::as_proto(),
         crate::classes::java::lang::VirtualMachineError::as_proto(),
+        crate::classes::java::lang::Void::as_proto(),
+        crate::classes::java::lang::r#ref::PhantomReference::as_proto(),
+        crate::classes::java::lang::r#ref::Reference::as_proto(),
+        crate::classes::java::lang::r#ref::ReferenceQueue::as_proto(),
+        crate::classes::java::lang::r#ref::SoftReference::as_proto(),
+        crate::classes::java::lang::r#ref::WeakReference::as_proto(),
+        crate::classes::java::lang::reflect::AccessibleObject::as_proto(),
+        crate::classes::java::lang::reflect::Array::as_proto(),
+        crate::classes::java::lang::reflect::Constructor::as_proto(),
+        crate::classes::java::lang::reflect::Field::as_proto(),
+        crate::classes::java::lang::reflect::InvocationTargetException::as_proto(),
+        crate::classes::java::lang::reflect::Method::as_proto(),
+        crate::classes::java::lang::reflect::Modifier::as_proto(),
         crate::classes::java::net::JarURLConnection::as_proto(),
         crate::classes::java::net::MalformedURLException::as_proto(),
         crate::classes::java::net::UnknownServiceException::as_proto(),
@@ -155,6 +178,13 @@ pub fn get_runtime_class_proto(name: &str) -> Option<RuntimeClassProto> {
         crate::classes::java::util::TimeZone::as_proto(),
         crate::classes::java::util::Vector::as_proto(),
         crate::classes::java::util::VectorItr::as_proto(),
+        crate::classes::java::util::WeakHashMap::as_proto(),
+        crate::classes::java::util::WeakHashMapEntry::as_proto(),
+        crate::classes::java::util::WeakHashMapEntrySet::as_proto(),
+        crate::classes::java::util::WeakHashMapHashIterator::as_proto(),
+        crate::classes::java::util::WeakHashMapKeySet::as_proto(),
+        crate::classes::java::util::WeakHashMapValues::as_proto(),
+        crate::classes::java::util::WeakHashMapWeakKey::as_proto(),
         crate::classes::java::util::jar::Attributes::as_proto(),
         crate::classes::java::util::jar::JarEntry::as_proto(),
         crate::classes::java::util::jar::JarFile::as_proto(),
@@ -169,9 +199,7 @@ pub fn get_runtime_class_proto(name: &str) -> Option<RuntimeClassProto> {
         crate::classes::org::rustjava::net::JarURLConnection::as_proto(),
         crate::classes::org::rustjava::net::JarURLHandler::as_proto(),
         crate::classes::org::rustjava::lang::RustJarClassLoader::as_proto(),
-    ];
-
-    protos.into_iter().find(|proto| proto.name == name)
+    ]
 }
 
 struct JavaRuntimeBootstrapClassLoader {
@@ -1,5 +1,5 @@
 use java_runtime::classes::java::lang::String;
-use jvm::{ClassInstanceRef, Result, runtime::JavaLangString};
+use jvm::{ClassInstance, ClassInstanceRef, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm;
 
@@ -14,11 +14,11 @@ async fn test_buffered_reader() -> Result<()> {
     let isr = jvm.new_class("java/io/InputStreamReader", "(Ljava/io/InputStream;)V", (is,)).await?;
     let reader = jvm.new_class("java/io/BufferedReader", "(Ljava/io/Reader;)V", (isr,)).await?;
 
-    let line = jvm.invoke_virtual(&reader, "readLine", "()Ljava/lang/String;", ()).await?;
+    let line: Box<dyn ClassInstance> = jvm.invoke_virtual(&reader, "readLine", "()Ljava/lang/String;", ()).await?;
     let line = JavaLangString::to_rust_string(&jvm, &line).await?;
     assert_eq!(line, "Hello");
 
-    let line = jvm.invoke_virtual(&reader, "readLine", "()Ljava/lang/String;", ()).await?;
+    let line: Box<dyn ClassInstance> = jvm.invoke_virtual(&reader, "readLine", "()Ljava/lang/String;", ()).await?;
     let line = JavaLangString::to_rust_string(&jvm, &line).await?;
     assert_eq!(line, "World");
 
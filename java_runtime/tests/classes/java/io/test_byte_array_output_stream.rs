@@ -2,7 +2,7 @@ use alloc::vec;
 
 use bytemuck::cast_vec;
 
-use jvm::Result;
+use jvm::{ClassInstance, Result};
 
 use test_utils::test_jvm;
 
@@ -17,7 +17,7 @@ async fn test_byte_array_output_stream() -> Result<()> {
     let _: () = jvm.invoke_virtual(&stream, "write", "(I)V", (b'l' as i32,)).await?;
     let _: () = jvm.invoke_virtual(&stream, "write", "(I)V", (b'o' as i32,)).await?;
 
-    let buf = jvm.invoke_virtual(&stream, "toByteArray", "()[B", ()).await?;
+    let buf: Box<dyn ClassInstance> = jvm.invoke_virtual(&stream, "toByteArray", "()[B", ()).await?;
 
     let mut bytes = vec![0; 5];
     jvm.array_raw_buffer(&buf).await?.read(0, &mut bytes)?;
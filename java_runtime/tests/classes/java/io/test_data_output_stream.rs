@@ -1,6 +1,6 @@
 use alloc::vec;
 
-use jvm::{Array, ClassInstanceRef, JavaError, Result, runtime::JavaLangString};
+use jvm::{Array, ClassInstance, ClassInstanceRef, JavaError, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm;
 
@@ -21,7 +21,7 @@ async fn test_data_output_stream() -> Result<()> {
     let _: () = jvm.invoke_virtual(&data_output_stream, "writeInt", "(I)V", (12341234,)).await?;
     let _: () = jvm.invoke_virtual(&data_output_stream, "writeLong", "(J)V", (123412341324i64,)).await?;
 
-    let bytes = jvm.invoke_virtual(&stream, "toByteArray", "()[B", ()).await?;
+    let bytes: Box<dyn ClassInstance> = jvm.invoke_virtual(&stream, "toByteArray", "()[B", ()).await?;
 
     let length = jvm.array_length(&bytes).await?;
     let mut buf = vec![0; length];
@@ -52,7 +52,7 @@ async fn test_data_output_stream_utf() -> Result<()> {
         .invoke_virtual(&data_output_stream, "writeUTF", "(Ljava/lang/String;)V", (string,))
         .await?;
 
-    let bytes = jvm.invoke_virtual(&stream, "toByteArray", "()[B", ()).await?;
+    let bytes: Box<dyn ClassInstance> = jvm.invoke_virtual(&stream, "toByteArray", "()[B", ()).await?;
 
     let length = jvm.array_length(&bytes).await?;
     let mut buf = vec![0; length];
@@ -1,6 +1,6 @@
 use alloc::vec;
 
-use jvm::{Result, runtime::JavaLangString};
+use jvm::{ClassInstance, JavaError, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm_filesystem;
 
@@ -99,3 +99,28 @@ async fn test_file_input_stream_skip_past_eof() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_file_input_stream_read_interrupted() -> Result<()> {
+    let filesystem = [("test.txt".into(), b"hello world".to_vec())];
+    let jvm = test_jvm_filesystem(filesystem.into_iter().collect()).await?;
+
+    let file = JavaLangString::from_rust_string(&jvm, "test.txt").await?;
+    let java_file = jvm.new_class("java/io/File", "(Ljava/lang/String;)V", (file,)).await?;
+    let fis = jvm.new_class("java/io/FileInputStream", "(Ljava/io/File;)V", (java_file,)).await?;
+
+    let current: Box<dyn ClassInstance> = jvm.invoke_static("java/lang/Thread", "currentThread", "()Ljava/lang/Thread;", ()).await?;
+    let _: () = jvm.invoke_virtual(&current, "interrupt", "()V", ()).await?;
+
+    let result: Result<i32> = jvm.invoke_virtual(&fis, "read", "()I", ()).await;
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("Expected JavaException, got {:?}", result);
+    };
+    assert!(jvm.is_instance(&*exception, "java/io/InterruptedIOException"));
+    assert!(!jvm.invoke_virtual::<_, bool>(&current, "isInterrupted", "()Z", ()).await?);
+
+    let read: i32 = jvm.invoke_virtual(&fis, "read", "()I", ()).await?;
+    assert_eq!(read, 104);
+
+    Ok(())
+}
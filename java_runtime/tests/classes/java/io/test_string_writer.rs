@@ -1,7 +1,7 @@
 use alloc::vec;
 
 use java_runtime::classes::java::lang::Object;
-use jvm::{ClassInstanceRef, JavaChar, JavaError, Result, runtime::JavaLangString};
+use jvm::{ClassInstance, ClassInstanceRef, JavaChar, JavaError, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm;
 
@@ -32,7 +32,7 @@ async fn test_string_writer() -> Result<()> {
     let _: () = jvm.invoke_virtual(&string_writer, "flush", "()V", ()).await?;
     let _: () = jvm.invoke_virtual(&string_writer, "close", "()V", ()).await?;
 
-    let string = jvm.invoke_virtual(&string_writer, "toString", "()Ljava/lang/String;", ()).await.unwrap();
+    let string: Box<dyn ClassInstance> = jvm.invoke_virtual(&string_writer, "toString", "()Ljava/lang/String;", ()).await.unwrap();
 
     let string = JavaLangString::to_rust_string(&jvm, &string).await?;
 
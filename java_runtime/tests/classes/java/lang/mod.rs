@@ -1,3 +1,5 @@
+mod r#ref;
+mod reflect;
 mod test_boolean;
 mod test_byte;
 mod test_character;
@@ -10,9 +12,12 @@ mod test_long;
 mod test_math;
 mod test_number;
 mod test_object;
+mod test_runtime;
 mod test_short;
 mod test_string;
 mod test_string_buffer;
 mod test_system;
 mod test_thread;
+mod test_thread_group;
+mod test_thread_local;
 mod test_throwable;
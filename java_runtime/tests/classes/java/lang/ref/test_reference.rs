@@ -0,0 +1,222 @@
+use alloc::{boxed::Box, collections::BTreeMap, vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::{
+    RuntimeClassProto, RuntimeContext,
+    classes::java::lang::{
+        Object,
+        r#ref::{Reference, ReferenceQueue},
+    },
+};
+use jvm::{ClassInstanceRef, Jvm, Result};
+use jvm_rust::ClassDefinitionImpl;
+
+use test_utils::{TestRuntime, create_test_jvm};
+
+// calls get() in its own frame, so the result doesn't keep the referent alive
+async fn is_cleared(jvm: &Jvm, reference: &ClassInstanceRef<Reference>) -> Result<bool> {
+    let frame = jvm.push_native_frame()?;
+    let value: ClassInstanceRef<Object> = jvm.invoke_virtual(reference, "get", "()Ljava/lang/Object;", ()).await?;
+    drop(frame);
+
+    Ok(value.is_null())
+}
+
+#[tokio::test]
+async fn test_weak_reference() -> Result<()> {
+    let runtime = TestRuntime::new_with_queued_spawns(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    let frame = jvm.push_native_frame()?;
+    let queue: ClassInstanceRef<ReferenceQueue> = jvm.new_class("java/lang/ref/ReferenceQueue", "()V", ()).await?.into();
+    let referent: ClassInstanceRef<Object> = jvm.new_class("java/lang/Object", "()V", ()).await?.into();
+    let reference: ClassInstanceRef<Reference> = jvm
+        .new_class(
+            "java/lang/ref/WeakReference",
+            "(Ljava/lang/Object;Ljava/lang/ref/ReferenceQueue;)V",
+            (referent.clone(), queue.clone()),
+        )
+        .await?
+        .into();
+    let queue = jvm.new_global_ref(&queue).unwrap();
+    let referent = jvm.new_global_ref(&referent).unwrap();
+    let reference = jvm.new_global_ref(&reference).unwrap();
+    drop(frame);
+
+    // the first reference starts the reference handler thread
+    assert!(runtime.take_spawn_callback().is_some());
+
+    jvm.collect_garbage()?;
+    assert!(!is_cleared(&jvm, &reference).await?);
+
+    let polled: ClassInstanceRef<Reference> = jvm.invoke_virtual(&queue, "poll", "()Ljava/lang/ref/Reference;", ()).await?;
+    assert!(polled.is_null());
+
+    drop(referent);
+    assert_eq!(jvm.collect_garbage()?, 1);
+
+    assert!(is_cleared(&jvm, &reference).await?);
+
+    let polled: ClassInstanceRef<Reference> = jvm.invoke_virtual(&queue, "poll", "()Ljava/lang/ref/Reference;", ()).await?;
+    assert!(polled.instance == reference.instance);
+    let enqueued: bool = jvm.invoke_virtual(&reference, "isEnqueued", "()Z", ()).await?;
+    assert!(!enqueued);
+
+    let polled: ClassInstanceRef<Reference> = jvm.invoke_virtual(&queue, "poll", "()Ljava/lang/ref/Reference;", ()).await?;
+    assert!(polled.is_null());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_soft_reference_cleared_under_heap_pressure() -> Result<()> {
+    let runtime = TestRuntime::new_with_queued_spawns(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    let frame = jvm.push_native_frame()?;
+    let referent: ClassInstanceRef<Object> = jvm.new_class("java/lang/Object", "()V", ()).await?.into();
+    let reference: ClassInstanceRef<Reference> = jvm
+        .new_class("java/lang/ref/SoftReference", "(Ljava/lang/Object;)V", (referent,))
+        .await?
+        .into();
+    let reference = jvm.new_global_ref(&reference).unwrap();
+    drop(frame);
+
+    // softly reachable objects survive while there's room
+    jvm.collect_garbage()?;
+    assert!(!is_cleared(&jvm, &reference).await?);
+
+    jvm.set_max_heap_size(Some(jvm.heap_used()));
+    assert_eq!(jvm.collect_garbage()?, 1);
+    jvm.set_max_heap_size(None);
+
+    assert!(is_cleared(&jvm, &reference).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_phantom_reference() -> Result<()> {
+    let runtime = TestRuntime::new_with_queued_spawns(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    let frame = jvm.push_native_frame()?;
+    let queue: ClassInstanceRef<ReferenceQueue> = jvm.new_class("java/lang/ref/ReferenceQueue", "()V", ()).await?.into();
+    let referent: ClassInstanceRef<Object> = jvm.new_class("java/lang/Object", "()V", ()).await?.into();
+    let reference: ClassInstanceRef<Reference> = jvm
+        .new_class(
+            "java/lang/ref/PhantomReference",
+            "(Ljava/lang/Object;Ljava/lang/ref/ReferenceQueue;)V",
+            (referent, queue.clone()),
+        )
+        .await?
+        .into();
+    let queue = jvm.new_global_ref(&queue).unwrap();
+    let reference = jvm.new_global_ref(&reference).unwrap();
+    drop(frame);
+
+    // get() never returns the referent of a phantom reference
+    assert!(is_cleared(&jvm, &reference).await?);
+
+    jvm.collect_garbage()?;
+
+    let polled: ClassInstanceRef<Reference> = jvm.invoke_virtual(&queue, "remove", "(J)Ljava/lang/ref/Reference;", (1000i64,)).await?;
+    assert!(polled.instance == reference.instance);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reference_handler_thread() -> Result<()> {
+    let runtime = TestRuntime::new(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    let frame = jvm.push_native_frame()?;
+    let queue: ClassInstanceRef<ReferenceQueue> = jvm.new_class("java/lang/ref/ReferenceQueue", "()V", ()).await?.into();
+    let referent: ClassInstanceRef<Object> = jvm.new_class("java/lang/Object", "()V", ()).await?.into();
+    let reference: ClassInstanceRef<Reference> = jvm
+        .new_class(
+            "java/lang/ref/WeakReference",
+            "(Ljava/lang/Object;Ljava/lang/ref/ReferenceQueue;)V",
+            (referent, queue.clone()),
+        )
+        .await?
+        .into();
+    let queue = jvm.new_global_ref(&queue).unwrap();
+    let reference = jvm.new_global_ref(&reference).unwrap();
+    drop(frame);
+
+    jvm.collect_garbage()?;
+
+    // blocks until the handler thread enqueues the cleared reference
+    let removed: ClassInstanceRef<Reference> = jvm.invoke_virtual(&queue, "remove", "()Ljava/lang/ref/Reference;", ()).await?;
+    assert!(removed.instance == reference.instance);
+
+    Ok(())
+}
+
+// a weak reference that also holds a strong reference in a field of its own named `referent`
+struct TaggedReference;
+impl TaggedReference {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "TaggedReference",
+            parent_class: Some("java/lang/ref/WeakReference"),
+            interfaces: vec![],
+            methods: vec![JavaMethodProto::new(
+                "<init>",
+                "(Ljava/lang/Object;Ljava/lang/Object;)V",
+                Self::init,
+                Default::default(),
+            )],
+            fields: vec![JavaFieldProto::new("referent", "Ljava/lang/Object;", Default::default())],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        mut this: ClassInstanceRef<Self>,
+        weak: ClassInstanceRef<Object>,
+        strong: ClassInstanceRef<Object>,
+    ) -> Result<()> {
+        let _: () = jvm
+            .invoke_special(&this, "java/lang/ref/WeakReference", "<init>", "(Ljava/lang/Object;)V", (weak,))
+            .await?;
+        jvm.put_field(&mut this, "referent", "Ljava/lang/Object;", strong).await?;
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_subclass_field_named_referent_is_traced() -> Result<()> {
+    let runtime = TestRuntime::new_with_queued_spawns(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    let class = Box::new(ClassDefinitionImpl::from_class_proto(
+        TaggedReference::as_proto(),
+        Box::new(runtime.clone()) as Box<_>,
+    ));
+    jvm.register_class(class, None).await?;
+    jvm.collect_garbage()?;
+
+    let frame = jvm.push_native_frame()?;
+    let weak: ClassInstanceRef<Object> = jvm.new_class("java/lang/Object", "()V", ()).await?.into();
+    let strong: ClassInstanceRef<Object> = jvm.new_class("java/lang/Object", "()V", ()).await?.into();
+    let reference: ClassInstanceRef<Reference> = jvm
+        .new_class("TaggedReference", "(Ljava/lang/Object;Ljava/lang/Object;)V", (weak, strong))
+        .await?
+        .into();
+    let reference = jvm.new_global_ref(&reference).unwrap();
+    drop(frame);
+
+    // only the referent of java.lang.ref.Reference is weak, so just that one is collected
+    assert_eq!(jvm.collect_garbage()?, 1);
+
+    let strong: ClassInstanceRef<Object> = jvm.get_field(&reference, "referent", "Ljava/lang/Object;").await?;
+    assert!(!strong.is_null());
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+mod test_array;
+mod test_method;
+mod test_modifier;
@@ -0,0 +1,74 @@
+use java_runtime::classes::java::lang::{Class, Object};
+use jvm::{ClassInstanceRef, JavaError, Result, runtime::JavaLangClass};
+
+use test_utils::test_jvm;
+
+#[tokio::test]
+async fn test_array_new_instance() -> Result<()> {
+    let jvm = test_jvm().await?;
+
+    let int_class: ClassInstanceRef<Class> = jvm.get_static_field("java/lang/Integer", "TYPE", "Ljava/lang/Class;").await?;
+    let array: ClassInstanceRef<Object> = jvm
+        .invoke_static(
+            "java/lang/reflect/Array",
+            "newInstance",
+            "(Ljava/lang/Class;I)Ljava/lang/Object;",
+            (int_class, 4),
+        )
+        .await?;
+
+    let length: i32 = jvm
+        .invoke_static("java/lang/reflect/Array", "getLength", "(Ljava/lang/Object;)I", (array.clone(),))
+        .await?;
+    assert_eq!(length, 4);
+
+    let array_class: ClassInstanceRef<Class> = jvm.invoke_virtual(&array, "getClass", "()Ljava/lang/Class;", ()).await?;
+    assert_eq!(JavaLangClass::name(&jvm, &array_class).await?, "[I");
+
+    let boxed: ClassInstanceRef<Object> = jvm.new_class("java/lang/Integer", "(I)V", (42,)).await?.into();
+    let _: () = jvm
+        .invoke_static(
+            "java/lang/reflect/Array",
+            "set",
+            "(Ljava/lang/Object;ILjava/lang/Object;)V",
+            (array.clone(), 2, boxed),
+        )
+        .await?;
+
+    let value: ClassInstanceRef<Object> = jvm
+        .invoke_static(
+            "java/lang/reflect/Array",
+            "get",
+            "(Ljava/lang/Object;I)Ljava/lang/Object;",
+            (array.clone(), 2),
+        )
+        .await?;
+    let value: i32 = jvm.invoke_virtual(&value, "intValue", "()I", ()).await?;
+    assert_eq!(value, 42);
+
+    let result: Result<ClassInstanceRef<Object>> = jvm
+        .invoke_static("java/lang/reflect/Array", "get", "(Ljava/lang/Object;I)Ljava/lang/Object;", (array, 4))
+        .await;
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("expected ArrayIndexOutOfBoundsException");
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/ArrayIndexOutOfBoundsException"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_array_not_an_array() -> Result<()> {
+    let jvm = test_jvm().await?;
+
+    let object: ClassInstanceRef<Object> = jvm.new_class("java/lang/Object", "()V", ()).await?.into();
+    let result: Result<i32> = jvm
+        .invoke_static("java/lang/reflect/Array", "getLength", "(Ljava/lang/Object;)I", (object,))
+        .await;
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("expected IllegalArgumentException");
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/IllegalArgumentException"));
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use java_constants::MethodAccessFlags;
+use java_runtime::classes::java::lang::{Class, Object, String, reflect::Method};
+use jvm::{Array, ClassInstanceRef, Result, runtime::JavaLangString};
+
+use test_utils::test_jvm;
+
+#[tokio::test]
+async fn test_get_method_invoke() -> Result<()> {
+    let jvm = test_jvm().await?;
+
+    let string_class = jvm.resolve_class("java/lang/String").await?.java_class()?;
+    let name = JavaLangString::from_rust_string(&jvm, "length").await?;
+    let parameters: ClassInstanceRef<Array<Class>> = jvm.instantiate_array("Ljava/lang/Class;", 0).await?.into();
+
+    let method: ClassInstanceRef<Method> = jvm
+        .invoke_virtual(
+            &string_class,
+            "getDeclaredMethod",
+            "(Ljava/lang/String;[Ljava/lang/Class;)Ljava/lang/reflect/Method;",
+            (name, parameters),
+        )
+        .await?;
+
+    let receiver = JavaLangString::from_rust_string(&jvm, "hello").await?;
+    let args: ClassInstanceRef<Array<Object>> = jvm.instantiate_array("Ljava/lang/Object;", 0).await?.into();
+    let result: ClassInstanceRef<Object> = jvm
+        .invoke_virtual(
+            &method,
+            "invoke",
+            "(Ljava/lang/Object;[Ljava/lang/Object;)Ljava/lang/Object;",
+            (receiver, args),
+        )
+        .await?;
+    let length: i32 = jvm.invoke_virtual(&result, "intValue", "()I", ()).await?;
+    assert_eq!(length, 5);
+
+    let return_type: ClassInstanceRef<Class> = jvm.invoke_virtual(&method, "getReturnType", "()Ljava/lang/Class;", ()).await?;
+    let int_class: ClassInstanceRef<Class> = jvm.get_static_field("java/lang/Integer", "TYPE", "Ljava/lang/Class;").await?;
+    assert!(return_type.instance == int_class.instance);
+
+    let name: ClassInstanceRef<String> = jvm.invoke_virtual(&method, "getName", "()Ljava/lang/String;", ()).await?;
+    assert_eq!(JavaLangString::to_rust_string(&jvm, &name).await?, "length");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_abstract_runtime_method_modifiers() -> Result<()> {
+    let jvm = test_jvm().await?;
+
+    // new_abstract protos report ACC_ABSTRACT at runtime, like the stub jar does
+    let iterator_class = jvm.resolve_class("java/util/Iterator").await?.java_class()?;
+    let name = JavaLangString::from_rust_string(&jvm, "hasNext").await?;
+    let parameters: ClassInstanceRef<Array<Class>> = jvm.instantiate_array("Ljava/lang/Class;", 0).await?.into();
+    let method: ClassInstanceRef<Method> = jvm
+        .invoke_virtual(
+            &iterator_class,
+            "getDeclaredMethod",
+            "(Ljava/lang/String;[Ljava/lang/Class;)Ljava/lang/reflect/Method;",
+            (name, parameters),
+        )
+        .await?;
+
+    let modifiers: i32 = jvm.invoke_virtual(&method, "getModifiers", "()I", ()).await?;
+    assert_ne!(modifiers & MethodAccessFlags::ABSTRACT.bits() as i32, 0);
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use jvm::{ClassInstanceRef, Result, runtime::JavaLangString};
+
+use java_runtime::classes::java::lang::String;
+
+use test_utils::test_jvm;
+
+#[tokio::test]
+async fn test_modifier_to_string() -> Result<()> {
+    let jvm = test_jvm().await?;
+
+    let modifiers: i32 = jvm.get_static_field("java/lang/reflect/Modifier", "PUBLIC", "I").await?;
+    assert_eq!(modifiers, 0x0001);
+
+    // public abstract static final synchronized, in Modifier.toString order
+    let string: ClassInstanceRef<String> = jvm
+        .invoke_static("java/lang/reflect/Modifier", "toString", "(I)Ljava/lang/String;", (0x0439,))
+        .await?;
+    assert_eq!(
+        JavaLangString::to_rust_string(&jvm, &string).await?,
+        "public abstract static final synchronized"
+    );
+
+    let is_static: bool = jvm.invoke_static("java/lang/reflect/Modifier", "isStatic", "(I)Z", (0x0008,)).await?;
+    assert!(is_static);
+    let is_final: bool = jvm.invoke_static("java/lang/reflect/Modifier", "isFinal", "(I)Z", (0x0008,)).await?;
+    assert!(!is_final);
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use java_runtime::classes::java::lang::{Boolean, Object, String};
-use jvm::{ClassInstanceRef, JavaError, Result, runtime::JavaLangString};
+use jvm::{ClassInstance, ClassInstanceRef, JavaError, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm;
 
@@ -58,10 +58,10 @@ async fn test_boolean_string_constants_and_type() -> Result<()> {
         !jvm.invoke_virtual::<_, bool>(&false_constant, "equals", "(Ljava/lang/Object;)Z", (object,))
             .await?
     );
-    assert!(!jvm.is_instance(&**true_constant, "java/lang/Comparable"));
-    assert!(jvm.is_instance(&**true_constant, "java/io/Serializable"));
+    assert!(!jvm.is_instance(&true_constant, "java/lang/Comparable"));
+    assert!(jvm.is_instance(&true_constant, "java/io/Serializable"));
 
-    let typ = jvm.get_static_field("java/lang/Boolean", "TYPE", "Ljava/lang/Class;").await?;
+    let typ: Box<dyn ClassInstance> = jvm.get_static_field("java/lang/Boolean", "TYPE", "Ljava/lang/Class;").await?;
     let name: ClassInstanceRef<String> = jvm.invoke_virtual(&typ, "getName", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &name).await?, "boolean");
     assert!(jvm.invoke_virtual::<_, bool>(&typ, "isPrimitive", "()Z", ()).await?);
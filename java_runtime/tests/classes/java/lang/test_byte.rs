@@ -1,5 +1,5 @@
 use java_runtime::classes::java::lang::{Byte, String};
-use jvm::{ClassInstanceRef, JavaError, Result, runtime::JavaLangString};
+use jvm::{ClassInstance, ClassInstanceRef, JavaError, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm;
 
@@ -20,14 +20,14 @@ async fn test_byte_api() -> Result<()> {
     let min = jvm.get_static_field::<i8>("java/lang/Byte", "MIN_VALUE", "B").await?;
     let max = jvm.get_static_field::<i8>("java/lang/Byte", "MAX_VALUE", "B").await?;
     assert_eq!((min, max), (i8::MIN, i8::MAX));
-    let typ = jvm.get_static_field("java/lang/Byte", "TYPE", "Ljava/lang/Class;").await?;
+    let typ: Box<dyn ClassInstance> = jvm.get_static_field("java/lang/Byte", "TYPE", "Ljava/lang/Class;").await?;
     let name: jvm::ClassInstanceRef<java_runtime::classes::java::lang::String> =
         jvm.invoke_virtual(&typ, "getName", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &name).await?, "byte");
     assert!(jvm.invoke_virtual::<_, bool>(&typ, "isPrimitive", "()Z", ()).await?);
 
     let string = JavaLangString::from_rust_string(&jvm, "0x7f").await?;
-    let decoded = jvm
+    let decoded: Box<dyn ClassInstance> = jvm
         .invoke_static("java/lang/Byte", "decode", "(Ljava/lang/String;)Ljava/lang/Byte;", (string,))
         .await?;
     assert_eq!(jvm.invoke_virtual::<_, i8>(&decoded, "byteValue", "()B", ()).await?, 127);
@@ -103,7 +103,7 @@ async fn test_byte_api() -> Result<()> {
         assert_eq!(jvm.invoke_virtual::<_, i8>(&decoded, "byteValue", "()B", ()).await?, expected);
     }
 
-    let typ = jvm.get_static_field("java/lang/Byte", "TYPE", "Ljava/lang/Class;").await?;
+    let typ: Box<dyn ClassInstance> = jvm.get_static_field("java/lang/Byte", "TYPE", "Ljava/lang/Class;").await?;
     let name: ClassInstanceRef<String> = jvm.invoke_virtual(&typ, "getName", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &name).await?, "byte");
     assert!(jvm.invoke_virtual::<_, bool>(&typ, "isPrimitive", "()Z", ()).await?);
@@ -1,5 +1,5 @@
 use java_runtime::classes::java::lang::{Character, String};
-use jvm::{Array, ClassInstanceRef, JavaChar, JavaError, Result, runtime::JavaLangString};
+use jvm::{Array, ClassInstance, ClassInstanceRef, JavaChar, JavaError, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm;
 
@@ -52,12 +52,12 @@ async fn test_character_value_constants_and_type() -> Result<()> {
         assert_eq!(jvm.get_static_field::<i8>("java/lang/Character", name, "B").await?, expected);
     }
 
-    let typ = jvm.get_static_field("java/lang/Character", "TYPE", "Ljava/lang/Class;").await?;
+    let typ: Box<dyn ClassInstance> = jvm.get_static_field("java/lang/Character", "TYPE", "Ljava/lang/Class;").await?;
     let name: ClassInstanceRef<String> = jvm.invoke_virtual(&typ, "getName", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &name).await?, "char");
     assert!(jvm.invoke_virtual::<_, bool>(&typ, "isPrimitive", "()Z", ()).await?);
-    assert!(jvm.is_instance(&**value, "java/lang/Comparable"));
-    assert!(jvm.is_instance(&**value, "java/io/Serializable"));
+    assert!(jvm.is_instance(&value, "java/lang/Comparable"));
+    assert!(jvm.is_instance(&value, "java/io/Serializable"));
 
     let result: Result<ClassInstanceRef<Character>> = jvm
         .invoke_static("java/lang/Character", "valueOf", "(C)Ljava/lang/Character;", ('A' as JavaChar,))
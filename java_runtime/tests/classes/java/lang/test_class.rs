@@ -7,7 +7,7 @@ use java_runtime::{
     get_bootstrap_class_loader,
 };
 use jvm::{
-    Array, ClassInstanceRef, JavaError, Jvm, Result,
+    Array, ClassInstance, ClassInstanceRef, JavaError, Jvm, Result,
     runtime::{JavaLangClass, JavaLangString},
 };
 
@@ -17,7 +17,7 @@ use test_utils::{TestRuntime, test_jvm};
 async fn test_class() -> Result<()> {
     let jvm = test_jvm().await?;
 
-    let java_class = jvm.resolve_class("java/lang/String").await?.java_class();
+    let java_class = jvm.resolve_class("java/lang/String").await?.java_class()?;
 
     let rust_class = JavaLangClass::to_rust_class(&jvm, &java_class).await?;
     assert_eq!(rust_class.name(), "java/lang/String");
@@ -33,25 +33,25 @@ async fn test_class() -> Result<()> {
 async fn test_is_assignable_from() -> Result<()> {
     let jvm = test_jvm().await?;
 
-    let string_class = jvm.resolve_class("java/lang/String").await?.java_class();
-    let object_class = jvm.resolve_class("java/lang/Object").await?.java_class();
+    let string_class = jvm.resolve_class("java/lang/String").await?.java_class()?;
+    let object_class = jvm.resolve_class("java/lang/Object").await?.java_class()?;
 
     let result: bool = jvm
         .invoke_virtual(&object_class, "isAssignableFrom", "(Ljava/lang/Class;)Z", (string_class.clone(),))
         .await?;
     assert!(result);
 
-    let thread_class = jvm.resolve_class("java/lang/Thread").await?.java_class();
+    let thread_class = jvm.resolve_class("java/lang/Thread").await?.java_class()?;
 
     let result: bool = jvm
         .invoke_virtual(&string_class, "isAssignableFrom", "(Ljava/lang/Class;)Z", (thread_class,))
         .await?;
     assert!(!result);
 
-    let string_array_class = jvm.resolve_class("[Ljava/lang/String;").await?.java_class();
-    let object_array_class = jvm.resolve_class("[Ljava/lang/Object;").await?.java_class();
-    let cloneable_class = jvm.resolve_class("java/lang/Cloneable").await?.java_class();
-    let serializable_class = jvm.resolve_class("java/io/Serializable").await?.java_class();
+    let string_array_class = jvm.resolve_class("[Ljava/lang/String;").await?.java_class()?;
+    let object_array_class = jvm.resolve_class("[Ljava/lang/Object;").await?.java_class()?;
+    let cloneable_class = jvm.resolve_class("java/lang/Cloneable").await?.java_class()?;
+    let serializable_class = jvm.resolve_class("java/io/Serializable").await?.java_class()?;
 
     assert!(
         jvm.invoke_virtual::<_, bool>(
@@ -135,8 +135,8 @@ async fn test_primitive_class_api() -> Result<()> {
         assert!(jvm.is_instance(&*exception, "java/lang/ClassNotFoundException"));
     }
 
-    let object_class = jvm.resolve_class("java/lang/Object").await?.java_class();
-    let string_class = jvm.resolve_class("java/lang/String").await?.java_class();
+    let object_class = jvm.resolve_class("java/lang/Object").await?.java_class()?;
+    let string_class = jvm.resolve_class("java/lang/String").await?.java_class()?;
     let primitive = JavaLangClass::from_rust_primitive(&jvm, "int").await?;
     let other_primitive = JavaLangClass::from_rust_primitive(&jvm, "long").await?;
 
@@ -185,7 +185,7 @@ async fn test_wrapper_type_fields_survive_gc() -> Result<()> {
         ("java/lang/Float", "float"),
         ("java/lang/Double", "double"),
     ] {
-        let typ = jvm.get_static_field(wrapper, "TYPE", "Ljava/lang/Class;").await?;
+        let typ: Box<dyn ClassInstance> = jvm.get_static_field(wrapper, "TYPE", "Ljava/lang/Class;").await?;
         let name: ClassInstanceRef<String> = jvm.invoke_virtual(&typ, "getName", "()Ljava/lang/String;", ()).await?;
         assert_eq!(JavaLangString::to_rust_string(&jvm, &name).await?, primitive_name);
         assert!(jvm.invoke_virtual::<_, bool>(&typ, "isPrimitive", "()Z", ()).await?);
@@ -203,7 +203,7 @@ async fn test_wrapper_type_fields_survive_gc() -> Result<()> {
         "java/lang/Float",
         "java/lang/Double",
     ] {
-        let typ = jvm.get_static_field(wrapper, "TYPE", "Ljava/lang/Class;").await?;
+        let typ: Box<dyn ClassInstance> = jvm.get_static_field(wrapper, "TYPE", "Ljava/lang/Class;").await?;
         assert!(jvm.invoke_virtual::<_, bool>(&typ, "isPrimitive", "()Z", ()).await?);
     }
 
@@ -214,9 +214,9 @@ async fn test_wrapper_type_fields_survive_gc() -> Result<()> {
 async fn test_cldc_class_queries_and_new_instance() -> Result<()> {
     let jvm = test_jvm().await?;
 
-    let string_class = jvm.resolve_class("java/lang/String").await?.java_class();
-    let runnable_class = jvm.resolve_class("java/lang/Runnable").await?.java_class();
-    let array_class = jvm.resolve_class("[Ljava/lang/String;").await?.java_class();
+    let string_class = jvm.resolve_class("java/lang/String").await?.java_class()?;
+    let runnable_class = jvm.resolve_class("java/lang/Runnable").await?.java_class()?;
+    let array_class = jvm.resolve_class("[Ljava/lang/String;").await?.java_class()?;
 
     assert!(!jvm.invoke_virtual::<_, bool>(&string_class, "isArray", "()Z", ()).await?);
     assert!(jvm.invoke_virtual::<_, bool>(&array_class, "isArray", "()Z", ()).await?);
@@ -232,10 +232,10 @@ async fn test_cldc_class_queries_and_new_instance() -> Result<()> {
             .await?
     );
 
-    let object_class = jvm.resolve_class("java/lang/Object").await?.java_class();
+    let object_class = jvm.resolve_class("java/lang/Object").await?.java_class()?;
     let instance: ClassInstanceRef<java_runtime::classes::java::lang::Object> =
         jvm.invoke_virtual(&object_class, "newInstance", "()Ljava/lang/Object;", ()).await?;
-    assert!(jvm.is_instance(&**instance, "java/lang/Object"));
+    assert!(jvm.is_instance(&instance, "java/lang/Object"));
 
     let result: Result<ClassInstanceRef<java_runtime::classes::java::lang::Object>> =
         jvm.invoke_virtual(&runnable_class, "newInstance", "()Ljava/lang/Object;", ()).await;
@@ -289,7 +289,7 @@ async fn test_system_class_loader_uses_rustjar_parent() -> Result<()> {
         .await?;
     let rustjar_class_loader: ClassInstanceRef<ClassLoader> = jvm.get_field(&system_class_loader, "parent", "Ljava/lang/ClassLoader;").await?;
 
-    assert!(jvm.is_instance(&**rustjar_class_loader, "org/rustjava/lang/RustJarClassLoader"));
+    assert!(jvm.is_instance(&rustjar_class_loader, "org/rustjava/lang/RustJarClassLoader"));
 
     let class_paths: ClassInstanceRef<Array<String>> = jvm.get_field(&rustjar_class_loader, "classPaths", "[Ljava/lang/String;").await?;
     assert_eq!(jvm.array_length(&class_paths).await?, 2);
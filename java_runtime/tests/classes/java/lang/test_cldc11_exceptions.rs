@@ -7,6 +7,7 @@ async fn test_cldc11_exception_and_error_hierarchy() -> Result<()> {
     let jvm = test_jvm().await?;
 
     for (class_name, parent_name) in [
+        ("java/lang/ClassCastException", "java/lang/RuntimeException"),
         ("java/lang/IllegalAccessException", "java/lang/Exception"),
         ("java/lang/IllegalMonitorStateException", "java/lang/RuntimeException"),
         ("java/lang/IllegalThreadStateException", "java/lang/IllegalArgumentException"),
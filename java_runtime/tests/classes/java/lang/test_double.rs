@@ -1,5 +1,5 @@
 use java_runtime::classes::java::lang::{Double, String};
-use jvm::{ClassInstanceRef, JavaError, Result, runtime::JavaLangString};
+use jvm::{ClassInstance, ClassInstanceRef, JavaError, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm;
 
@@ -234,7 +234,7 @@ async fn test_double_constants_type_and_predicates() -> Result<()> {
             .await?
     );
 
-    let typ = jvm.get_static_field("java/lang/Double", "TYPE", "Ljava/lang/Class;").await?;
+    let typ: Box<dyn ClassInstance> = jvm.get_static_field("java/lang/Double", "TYPE", "Ljava/lang/Class;").await?;
     let name: ClassInstanceRef<String> = jvm.invoke_virtual(&typ, "getName", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &name).await?, "double");
     assert!(jvm.invoke_virtual::<_, bool>(&typ, "isPrimitive", "()Z", ()).await?);
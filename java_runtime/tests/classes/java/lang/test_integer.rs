@@ -1,5 +1,5 @@
 use java_runtime::classes::java::lang::{Integer, Object, String};
-use jvm::{ClassInstanceRef, JavaError, Result, runtime::JavaLangString};
+use jvm::{ClassInstance, ClassInstanceRef, JavaError, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm;
 
@@ -124,12 +124,12 @@ async fn test_integer_strict_api() -> Result<()> {
     let min = jvm.get_static_field::<i32>("java/lang/Integer", "MIN_VALUE", "I").await?;
     let max = jvm.get_static_field::<i32>("java/lang/Integer", "MAX_VALUE", "I").await?;
     assert_eq!((min, max), (i32::MIN, i32::MAX));
-    let typ = jvm.get_static_field("java/lang/Integer", "TYPE", "Ljava/lang/Class;").await?;
+    let typ: Box<dyn ClassInstance> = jvm.get_static_field("java/lang/Integer", "TYPE", "Ljava/lang/Class;").await?;
     let name: ClassInstanceRef<String> = jvm.invoke_virtual(&typ, "getName", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &name).await?, "int");
     assert!(jvm.invoke_virtual::<_, bool>(&typ, "isPrimitive", "()Z", ()).await?);
     let _: () = jvm.invoke_static("java/lang/System", "gc", "()V", ()).await?;
-    let typ = jvm.get_static_field("java/lang/Integer", "TYPE", "Ljava/lang/Class;").await?;
+    let typ: Box<dyn ClassInstance> = jvm.get_static_field("java/lang/Integer", "TYPE", "Ljava/lang/Class;").await?;
     assert!(jvm.invoke_virtual::<_, bool>(&typ, "isPrimitive", "()Z", ()).await?);
     Ok(())
 }
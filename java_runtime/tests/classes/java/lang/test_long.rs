@@ -1,5 +1,5 @@
 use java_runtime::classes::java::lang::{Long, Object, String};
-use jvm::{ClassInstanceRef, JavaError, Result, runtime::JavaLangString};
+use jvm::{ClassInstance, ClassInstanceRef, JavaError, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm;
 
@@ -160,7 +160,7 @@ async fn test_long_api() -> Result<()> {
 
     assert_eq!(jvm.get_static_field::<i64>("java/lang/Long", "MIN_VALUE", "J").await?, i64::MIN);
     assert_eq!(jvm.get_static_field::<i64>("java/lang/Long", "MAX_VALUE", "J").await?, i64::MAX);
-    let typ = jvm.get_static_field("java/lang/Long", "TYPE", "Ljava/lang/Class;").await?;
+    let typ: Box<dyn ClassInstance> = jvm.get_static_field("java/lang/Long", "TYPE", "Ljava/lang/Class;").await?;
     let type_name: ClassInstanceRef<String> = jvm.invoke_virtual(&typ, "getName", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &type_name).await?, "long");
     assert!(jvm.invoke_virtual::<_, bool>(&typ, "isPrimitive", "()Z", ()).await?);
@@ -29,13 +29,13 @@ async fn test_number_is_abstract() -> Result<()> {
             .contains(MethodAccessFlags::PUBLIC | MethodAccessFlags::ABSTRACT)
     );
 
-    let number_class = jvm.resolve_class("java/lang/Number").await?.java_class();
-    let serializable_class = jvm.resolve_class("java/io/Serializable").await?.java_class();
+    let number_class = jvm.resolve_class("java/lang/Number").await?.java_class()?;
+    let serializable_class = jvm.resolve_class("java/io/Serializable").await?.java_class()?;
     let is_serializable: bool = jvm
         .invoke_virtual(&serializable_class, "isAssignableFrom", "(Ljava/lang/Class;)Z", (number_class.clone(),))
         .await?;
     assert!(is_serializable);
-    let comparable_class = jvm.resolve_class("java/lang/Comparable").await?.java_class();
+    let comparable_class = jvm.resolve_class("java/lang/Comparable").await?.java_class()?;
 
     let integer = jvm.new_class("java/lang/Integer", "(I)V", (257,)).await?;
     assert_eq!(jvm.invoke_virtual::<_, i8>(&integer, "byteValue", "()B", ()).await?, 1);
@@ -51,7 +51,7 @@ async fn test_number_is_abstract() -> Result<()> {
         "java/lang/Float",
         "java/lang/Double",
     ] {
-        let wrapper_class = jvm.resolve_class(class_name).await?.java_class();
+        let wrapper_class = jvm.resolve_class(class_name).await?.java_class()?;
         let is_number: bool = jvm
             .invoke_virtual(&number_class, "isAssignableFrom", "(Ljava/lang/Class;)Z", (wrapper_class.clone(),))
             .await?;
@@ -4,14 +4,23 @@ use core::{
     time::Duration,
 };
 
-use alloc::{boxed::Box, collections::btree_map::BTreeMap, sync::Arc, vec};
+use alloc::{
+    boxed::Box,
+    collections::btree_map::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use std::sync::Mutex;
 
-use java_class_proto::JavaFieldProto;
-use java_runtime::{Runtime, RuntimeClassProto, SpawnCallback, classes::java::lang::Object};
-use jvm::{Array, ClassInstanceRef, JavaError, Jvm, Result};
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_constants::FieldAccessFlags;
+use java_runtime::{Runtime, RuntimeClassProto, RuntimeContext, SpawnCallback, classes::java::lang::Object};
+use jvm::{Array, AsClassInstance, ClassInstance, ClassInstanceRef, JavaError, Jvm, JvmEvent, Result, runtime::JavaLangString};
 use jvm_rust::ClassDefinitionImpl;
 
-use test_utils::{TestRuntime, create_test_jvm};
+use test_utils::{TestRuntime, create_test_jvm, test_jvm};
 
 struct CloneableObject;
 
@@ -82,6 +91,164 @@ async fn test_wait() -> Result<()> {
 
     Ok(())
 }
+#[tokio::test]
+async fn test_deadlock_detection() -> Result<()> {
+    let runtime = TestRuntime::new(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+    jvm.set_throw_on_deadlock(true);
+
+    let reports = Arc::new(Mutex::new(Vec::<String>::new()));
+    jvm.add_event_listener({
+        let reports = reports.clone();
+        move |_: &Jvm, event: &JvmEvent<'_>| {
+            if let JvmEvent::DeadlockDetected { deadlock } = event {
+                reports.lock().unwrap().push(deadlock.to_string());
+            }
+        }
+    });
+
+    let first = jvm.new_class("java/lang/Object", "()V", ()).await?;
+    let second = jvm.new_class("java/lang/Object", "()V", ()).await?;
+
+    struct Locker {
+        jvm: Jvm,
+        first: ClassInstanceRef<Object>,
+        second: ClassInstanceRef<Object>,
+        locked: Arc<AtomicBool>,
+        java_thread: Box<dyn ClassInstance>,
+    }
+
+    #[async_trait::async_trait]
+    impl SpawnCallback for Locker {
+        async fn call(&self) -> Result<()> {
+            self.jvm.attach_thread(Some(self.java_thread.clone())).await?;
+
+            self.jvm.monitor_enter(&self.second).await?;
+            self.locked.store(true, Ordering::Relaxed);
+            self.jvm.monitor_enter(&self.first).await?;
+            self.jvm.monitor_exit(&self.first).await?;
+            self.jvm.monitor_exit(&self.second).await?;
+
+            self.jvm.detach_thread()?;
+
+            Ok(())
+        }
+    }
+
+    let locked = Arc::new(AtomicBool::new(false));
+    let name = JavaLangString::from_rust_string(&jvm, "locker").await?;
+    let java_thread = jvm.new_class("java/lang/Thread", "(Ljava/lang/String;)V", (name,)).await?;
+    jvm.monitor_enter(&first).await?;
+    runtime.spawn(
+        &jvm,
+        Box::new(Locker {
+            jvm: jvm.clone(),
+            first: first.clone().into(),
+            second: second.clone().into(),
+            locked: locked.clone(),
+            java_thread,
+        }),
+    );
+
+    // wait until the other thread holds the second monitor and blocks on the first
+    loop {
+        let blocked = jvm
+            .monitors()
+            .into_iter()
+            .any(|x| x.object_identity == first.identity() && !x.entering_thread_ids.is_empty());
+        if locked.load(Ordering::Relaxed) && blocked {
+            break;
+        }
+        runtime.sleep(Duration::from_millis(10)).await;
+    }
+    assert!(jvm.find_deadlock().is_none());
+
+    let info = jvm.monitors().into_iter().find(|x| x.object_identity == first.identity()).unwrap();
+    assert_eq!(info.owner_thread_id, Some(runtime.current_task_id()));
+    assert_eq!(info.depth, 1);
+    assert_eq!(info.class_name, "java/lang/Object");
+
+    let Err(JavaError::JavaException(exception)) = jvm.monitor_enter(&second).await else {
+        panic!("expected deadlock error");
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/Error"));
+
+    // threads are reported by their java names
+    let report = reports.lock().unwrap().pop().unwrap();
+    assert!(report.contains("\"locker\":"));
+    assert!(report.contains("which is held by \"locker\""));
+
+    // releasing the first monitor breaks the cycle
+    jvm.monitor_exit(&first).await?;
+    loop {
+        let info = jvm.monitors().into_iter().find(|x| x.object_identity == second.identity()).unwrap();
+        if info.owner_thread_id.is_none() {
+            break;
+        }
+        runtime.sleep(Duration::from_millis(10)).await;
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_wait_reacquire_is_blocked_on_the_monitor() -> Result<()> {
+    let runtime = TestRuntime::new(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    let object = jvm.new_class("java/lang/Object", "()V", ()).await?;
+
+    struct Notifier {
+        jvm: Jvm,
+        runtime: TestRuntime,
+        target: ClassInstanceRef<Object>,
+        waiter_thread_id: u64,
+    }
+
+    #[async_trait::async_trait]
+    impl SpawnCallback for Notifier {
+        async fn call(&self) -> Result<()> {
+            self.jvm.attach_thread(None).await?;
+
+            self.jvm.monitor_enter(&self.target).await?;
+            let _: () = self.jvm.invoke_virtual(&self.target, "notify", "()V", ()).await?;
+            // the notified waiter contends for the monitor we still hold
+            loop {
+                let info = self
+                    .jvm
+                    .monitors()
+                    .into_iter()
+                    .find(|x| Some(x.object_identity) == self.target.as_class_instance().map(|x| x.identity()))
+                    .unwrap();
+                if info.entering_thread_ids.contains(&self.waiter_thread_id) {
+                    break;
+                }
+                self.runtime.sleep(Duration::from_millis(10)).await;
+            }
+            self.jvm.monitor_exit(&self.target).await?;
+
+            self.jvm.detach_thread()?;
+
+            Ok(())
+        }
+    }
+
+    jvm.monitor_enter(&object).await?;
+    runtime.spawn(
+        &jvm,
+        Box::new(Notifier {
+            jvm: jvm.clone(),
+            runtime: runtime.clone(),
+            target: object.clone().into(),
+            waiter_thread_id: runtime.current_task_id(),
+        }),
+    );
+    let _: () = jvm.invoke_virtual(&object, "wait", "()V", ()).await?;
+    jvm.monitor_exit(&object).await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_wait_timeout() -> Result<()> {
     let runtime = TestRuntime::new(BTreeMap::new());
@@ -151,12 +318,18 @@ async fn test_clone_creates_shallow_object_and_array_copies() -> Result<()> {
     jvm.put_field(&mut original, "reference", "Ljava/lang/Object;", reference.clone()).await?;
 
     let mut cloned: ClassInstanceRef<CloneableObject> = jvm.invoke_virtual(&original, "clone", "()Ljava/lang/Object;", ()).await?;
-    assert_ne!(original.identity(), cloned.identity());
+    assert_ne!(
+        original.as_class_instance().unwrap().identity(),
+        cloned.as_class_instance().unwrap().identity()
+    );
     assert_eq!(jvm.get_field::<i32>(&cloned, "value", "I").await?, 7);
     let cloned_reference = jvm
         .get_field::<ClassInstanceRef<Object>>(&cloned, "reference", "Ljava/lang/Object;")
         .await?;
-    assert_eq!(reference.identity(), cloned_reference.identity());
+    assert_eq!(
+        reference.as_class_instance().unwrap().identity(),
+        cloned_reference.as_class_instance().unwrap().identity()
+    );
 
     jvm.put_field(&mut cloned, "value", "I", 9i32).await?;
     assert_eq!(jvm.get_field::<i32>(&original, "value", "I").await?, 7);
@@ -164,7 +337,10 @@ async fn test_clone_creates_shallow_object_and_array_copies() -> Result<()> {
     let mut array = jvm.instantiate_array("I", 2).await?;
     jvm.store_array(&mut array, 0, [1i32, 2i32]).await?;
     let mut cloned_array: ClassInstanceRef<Array<i32>> = jvm.invoke_virtual(&array, "clone", "()Ljava/lang/Object;", ()).await?;
-    assert_ne!(array.identity(), cloned_array.identity());
+    assert_ne!(
+        array.as_class_instance().unwrap().identity(),
+        cloned_array.as_class_instance().unwrap().identity()
+    );
     jvm.store_array(&mut cloned_array, 0, [9i32]).await?;
     assert_eq!(jvm.load_array::<i32>(&array, 0, 2).await?, [1, 2]);
     assert_eq!(jvm.load_array::<i32>(&cloned_array, 0, 2).await?, [9, 2]);
@@ -174,11 +350,17 @@ async fn test_clone_creates_shallow_object_and_array_copies() -> Result<()> {
     let mut cloned_reference_array: ClassInstanceRef<Array<Object>> =
         jvm.invoke_virtual(&reference_array, "clone", "()Ljava/lang/Object;", ()).await?;
     let cloned_element = jvm.load_array::<ClassInstanceRef<Object>>(&cloned_reference_array, 0, 1).await?;
-    assert_eq!(cloned_element[0].identity(), reference.identity());
+    assert_eq!(
+        cloned_element[0].as_class_instance().unwrap().identity(),
+        reference.as_class_instance().unwrap().identity()
+    );
     let replacement: ClassInstanceRef<Object> = jvm.new_class("java/lang/Object", "()V", ()).await?.into();
     jvm.store_array(&mut cloned_reference_array, 0, [replacement]).await?;
     let original_element = jvm.load_array::<ClassInstanceRef<Object>>(&reference_array, 0, 1).await?;
-    assert_eq!(original_element[0].identity(), reference.identity());
+    assert_eq!(
+        original_element[0].as_class_instance().unwrap().identity(),
+        reference.as_class_instance().unwrap().identity()
+    );
 
     Ok(())
 }
@@ -215,3 +397,140 @@ async fn test_hash_code_is_not_constant_across_objects() -> Result<()> {
 
     Ok(())
 }
+
+struct FinalizableObject;
+
+impl FinalizableObject {
+    fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "FinalizableObject",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                JavaMethodProto::new("finalize", "()V", Self::finalize, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("resource", "Ljava/lang/Object;", Default::default()),
+                JavaFieldProto::new("finalized", "I", FieldAccessFlags::STATIC),
+                JavaFieldProto::new("resourceAlive", "Z", FieldAccessFlags::STATIC),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>) -> Result<()> {
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        let resource = jvm.new_class("java/lang/Object", "()V", ()).await?;
+        jvm.put_field(&mut this, "resource", "Ljava/lang/Object;", resource).await?;
+
+        Ok(())
+    }
+
+    async fn finalize(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        let finalized: i32 = jvm.get_static_field("FinalizableObject", "finalized", "I").await?;
+        jvm.put_static_field("FinalizableObject", "finalized", "I", finalized + 1).await?;
+
+        let resource: ClassInstanceRef<Object> = jvm.get_field(&this, "resource", "Ljava/lang/Object;").await?;
+        jvm.put_static_field("FinalizableObject", "resourceAlive", "Z", !resource.is_null())
+            .await?;
+
+        Ok(())
+    }
+}
+
+async fn finalizable_object_jvm(runtime: TestRuntime) -> Result<Jvm> {
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    let class = Box::new(ClassDefinitionImpl::from_class_proto(
+        FinalizableObject::as_proto(),
+        Box::new(runtime) as Box<_>,
+    ));
+    jvm.register_class(class, None).await?;
+
+    Ok(jvm)
+}
+
+#[tokio::test]
+async fn test_finalize_runs_once_before_reclaim() -> Result<()> {
+    let runtime = TestRuntime::new_with_queued_spawns(BTreeMap::new());
+    let jvm = finalizable_object_jvm(runtime.clone()).await?;
+
+    let object = jvm.new_class("java/lang/Object", "()V", ()).await?;
+    assert!(!jvm.register_finalizer(&object));
+
+    let frame = jvm.push_native_frame()?;
+    let _ = jvm.new_class("FinalizableObject", "()V", ()).await?;
+    drop(frame);
+
+    // the first finalizable object starts the finalizer thread
+    assert!(runtime.take_spawn_callback().is_some());
+    let frame = jvm.push_native_frame()?;
+    let _ = jvm.new_class("FinalizableObject", "()V", ()).await?;
+    drop(frame);
+    assert!(runtime.take_spawn_callback().is_none());
+
+    jvm.collect_garbage()?;
+    let finalized: i32 = jvm.get_static_field("FinalizableObject", "finalized", "I").await?;
+    assert_eq!(finalized, 0);
+
+    let _: () = jvm.invoke_static("java/lang/System", "runFinalization", "()V", ()).await?;
+    let finalized: i32 = jvm.get_static_field("FinalizableObject", "finalized", "I").await?;
+    let resource_alive: bool = jvm.get_static_field("FinalizableObject", "resourceAlive", "Z").await?;
+    assert_eq!(finalized, 2);
+    assert!(resource_alive);
+
+    // finalized objects and their resources are reclaimed by the next collection, without running finalize() again
+    assert!(jvm.collect_garbage()? >= 4);
+    let _: () = jvm.invoke_static("java/lang/System", "runFinalization", "()V", ()).await?;
+    let finalized: i32 = jvm.get_static_field("FinalizableObject", "finalized", "I").await?;
+    assert_eq!(finalized, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_finalizer_thread() -> Result<()> {
+    let runtime = TestRuntime::new(BTreeMap::new());
+    let jvm = finalizable_object_jvm(runtime.clone()).await?;
+
+    let frame = jvm.push_native_frame()?;
+    let _ = jvm.new_class("FinalizableObject", "()V", ()).await?;
+    drop(frame);
+
+    jvm.collect_garbage()?;
+
+    for _ in 0..100 {
+        let finalized: i32 = jvm.get_static_field("FinalizableObject", "finalized", "I").await?;
+        if finalized == 1 {
+            return Ok(());
+        }
+        runtime.sleep(Duration::from_millis(10)).await;
+    }
+
+    panic!("finalizer thread did not run");
+}
+
+#[tokio::test]
+async fn test_null_reference_throws_null_pointer_exception() -> Result<()> {
+    let jvm = test_jvm().await?;
+
+    let mut null = ClassInstanceRef::<Object>::new(None);
+
+    let result: Result<i32> = jvm.invoke_virtual(&null, "hashCode", "()I", ()).await;
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("expected NullPointerException");
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/NullPointerException"));
+
+    let result = jvm.put_field(&mut null, "value", "I", 1).await;
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("expected NullPointerException");
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/NullPointerException"));
+
+    assert!(!jvm.is_instance(&null, "java/lang/Object"));
+
+    Ok(())
+}
@@ -0,0 +1,216 @@
+use alloc::{boxed::Box, collections::BTreeMap, vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::{RuntimeClassProto, RuntimeContext};
+use jvm::{ClassInstance, ClassInstanceRef, JavaError, Jvm, Result};
+use jvm_rust::ClassDefinitionImpl;
+
+use test_utils::{TestRuntime, create_test_jvm, test_jvm};
+
+#[tokio::test]
+async fn test_runtime_memory() -> Result<()> {
+    let jvm = test_jvm().await?;
+
+    let runtime: Box<dyn ClassInstance> = jvm.invoke_static("java/lang/Runtime", "getRuntime", "()Ljava/lang/Runtime;", ()).await?;
+
+    let max: i64 = jvm.invoke_virtual(&runtime, "maxMemory", "()J", ()).await?;
+    assert_eq!(max, i64::MAX);
+
+    jvm.set_max_heap_size(Some(0x200000));
+
+    let max: i64 = jvm.invoke_virtual(&runtime, "maxMemory", "()J", ()).await?;
+    let total: i64 = jvm.invoke_virtual(&runtime, "totalMemory", "()J", ()).await?;
+    let free: i64 = jvm.invoke_virtual(&runtime, "freeMemory", "()J", ()).await?;
+    assert_eq!(max, 0x200000);
+    assert_eq!(total, 0x200000);
+    assert!(free > 0 && free < total);
+
+    let _array = jvm.instantiate_array("B", 0x10000).await?;
+    let free_after: i64 = jvm.invoke_virtual(&runtime, "freeMemory", "()J", ()).await?;
+    assert!(free_after <= free - 0x10000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shutdown_hooks_run_on_exit() -> Result<()> {
+    let runtime = TestRuntime::new(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    let java_runtime: Box<dyn ClassInstance> = jvm.invoke_static("java/lang/Runtime", "getRuntime", "()Ljava/lang/Runtime;", ()).await?;
+
+    let hook = jvm.new_class("java/lang/Thread", "()V", ()).await?;
+    let removed = jvm.new_class("java/lang/Thread", "()V", ()).await?;
+    let _: () = jvm
+        .invoke_virtual(&java_runtime, "addShutdownHook", "(Ljava/lang/Thread;)V", (hook.clone(),))
+        .await?;
+    let _: () = jvm
+        .invoke_virtual(&java_runtime, "addShutdownHook", "(Ljava/lang/Thread;)V", (removed.clone(),))
+        .await?;
+    let result: bool = jvm
+        .invoke_virtual(&java_runtime, "removeShutdownHook", "(Ljava/lang/Thread;)Z", (removed.clone(),))
+        .await?;
+    assert!(result);
+
+    let result: Result<()> = jvm
+        .invoke_virtual(&java_runtime, "addShutdownHook", "(Ljava/lang/Thread;)V", (hook.clone(),))
+        .await;
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("Expected JavaException, got {:?}", result);
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/IllegalArgumentException"));
+
+    let _: () = jvm.invoke_static("java/lang/System", "exit", "(I)V", (3,)).await?;
+    assert_eq!(runtime.exit_status(), Some(3));
+
+    // exit returns only after the hook has run to completion
+    let started: bool = jvm.get_field(&hook, "started", "Z").await?;
+    let alive: bool = jvm.invoke_virtual(&hook, "isAlive", "()Z", ()).await?;
+    assert!(started && !alive);
+    let started: bool = jvm.get_field(&removed, "started", "Z").await?;
+    assert!(!started);
+
+    let result: Result<()> = jvm
+        .invoke_virtual(&java_runtime, "addShutdownHook", "(Ljava/lang/Thread;)V", (removed,))
+        .await;
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("Expected JavaException, got {:?}", result);
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/IllegalStateException"));
+
+    Ok(())
+}
+
+// sleeps for a while, then records that it ran to completion
+struct Sleeper;
+impl Sleeper {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "Sleeper",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec!["java/lang/Runnable"],
+            methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                JavaMethodProto::new("run", "()V", Self::run, Default::default()),
+            ],
+            fields: vec![JavaFieldProto::new("done", "Z", Default::default())],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(_: &Jvm, _: &mut RuntimeContext, _: ClassInstanceRef<Self>) -> Result<()> {
+        Ok(())
+    }
+
+    async fn run(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>) -> Result<()> {
+        let _: () = jvm.invoke_static("java/lang/Thread", "sleep", "(J)V", (100i64,)).await?;
+        jvm.put_field(&mut this, "done", "Z", true).await?;
+
+        Ok(())
+    }
+}
+
+// calls System.exit from a shutdown hook, then records whether the sleeper had finished by the time it returned
+struct Exiter;
+impl Exiter {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "Exiter",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec!["java/lang/Runnable"],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(LSleeper;)V", Self::init, Default::default()),
+                JavaMethodProto::new("run", "()V", Self::run, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("sleeper", "LSleeper;", Default::default()),
+                JavaFieldProto::new("sawDone", "Z", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>, sleeper: ClassInstanceRef<Sleeper>) -> Result<()> {
+        jvm.put_field(&mut this, "sleeper", "LSleeper;", sleeper).await?;
+
+        Ok(())
+    }
+
+    async fn run(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>) -> Result<()> {
+        let _: () = jvm.invoke_static("java/lang/System", "exit", "(I)V", (7,)).await?;
+
+        let sleeper: ClassInstanceRef<Sleeper> = jvm.get_field(&this, "sleeper", "LSleeper;").await?;
+        let done: bool = jvm.get_field(&sleeper, "done", "Z").await?;
+        jvm.put_field(&mut this, "sawDone", "Z", done).await?;
+
+        Ok(())
+    }
+}
+
+async fn register_hook_classes(jvm: &Jvm, runtime: &TestRuntime) -> Result<()> {
+    for proto in [Sleeper::as_proto(), Exiter::as_proto()] {
+        let class = Box::new(ClassDefinitionImpl::from_class_proto(proto, Box::new(runtime.clone()) as Box<_>));
+        jvm.register_class(class, None).await?;
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_shutdown_hook_start_failure_joins_other_hooks() -> Result<()> {
+    let runtime = TestRuntime::new(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+    register_hook_classes(&jvm, &runtime).await?;
+
+    let java_runtime: Box<dyn ClassInstance> = jvm.invoke_static("java/lang/Runtime", "getRuntime", "()Ljava/lang/Runtime;", ()).await?;
+
+    // started behind the runtime's back, so starting it again at exit fails
+    let failing = jvm.new_class("java/lang/Thread", "()V", ()).await?;
+    let sleeper = jvm.new_class("Sleeper", "()V", ()).await?;
+    let hook = jvm.new_class("java/lang/Thread", "(Ljava/lang/Runnable;)V", (sleeper.clone(),)).await?;
+    for thread in [&failing, &hook] {
+        let _: () = jvm
+            .invoke_virtual(&java_runtime, "addShutdownHook", "(Ljava/lang/Thread;)V", (thread.clone(),))
+            .await?;
+    }
+    let _: () = jvm.invoke_virtual(&failing, "start", "()V", ()).await?;
+    let _: () = jvm.invoke_virtual(&failing, "join", "()V", ()).await?;
+
+    let result: Result<()> = jvm.invoke_static("java/lang/System", "exit", "(I)V", (3,)).await;
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("Expected JavaException, got {:?}", result);
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/IllegalThreadStateException"));
+
+    // the failure is reported only after the other hook has run to completion
+    let done: bool = jvm.get_field(&sleeper, "done", "Z").await?;
+    assert!(done);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_exit_from_shutdown_hook_waits_for_other_hooks() -> Result<()> {
+    let runtime = TestRuntime::new(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+    register_hook_classes(&jvm, &runtime).await?;
+
+    let java_runtime: Box<dyn ClassInstance> = jvm.invoke_static("java/lang/Runtime", "getRuntime", "()Ljava/lang/Runtime;", ()).await?;
+
+    let sleeper = jvm.new_class("Sleeper", "()V", ()).await?;
+    let exiter = jvm.new_class("Exiter", "(LSleeper;)V", (sleeper.clone(),)).await?;
+    for runnable in [exiter.clone(), sleeper] {
+        let hook = jvm.new_class("java/lang/Thread", "(Ljava/lang/Runnable;)V", (runnable,)).await?;
+        let _: () = jvm
+            .invoke_virtual(&java_runtime, "addShutdownHook", "(Ljava/lang/Thread;)V", (hook,))
+            .await?;
+    }
+
+    let _: () = jvm.invoke_static("java/lang/System", "exit", "(I)V", (3,)).await?;
+    assert_eq!(runtime.exit_status(), Some(3));
+
+    let saw_done: bool = jvm.get_field(&exiter, "sawDone", "Z").await?;
+    assert!(saw_done);
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use java_runtime::classes::java::lang::String as JavaString;
-use jvm::{ClassInstanceRef, JavaError, Result, runtime::JavaLangString};
+use jvm::{AsClassInstance, ClassInstance, ClassInstanceRef, JavaError, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm;
 
@@ -23,7 +23,7 @@ async fn test_string_concat() -> Result<()> {
     let string1 = JavaLangString::from_rust_string(&jvm, "test1").await?;
     let string2 = JavaLangString::from_rust_string(&jvm, "test2").await?;
 
-    let result = jvm
+    let result: Box<dyn ClassInstance> = jvm
         .invoke_virtual(&string1, "concat", "(Ljava/lang/String;)Ljava/lang/String;", (string2,))
         .await?;
 
@@ -216,7 +216,7 @@ async fn test_to_lower_case() -> Result<()> {
     let jvm = test_jvm().await?;
 
     let string = JavaLangString::from_rust_string(&jvm, "HELLO 테스트").await?;
-    let result = jvm.invoke_virtual(&string, "toLowerCase", "()Ljava/lang/String;", ()).await?;
+    let result: Box<dyn ClassInstance> = jvm.invoke_virtual(&string, "toLowerCase", "()Ljava/lang/String;", ()).await?;
     let result_string = JavaLangString::to_rust_string(&jvm, &result).await?;
     assert_eq!(result_string, "hello 테스트");
 
@@ -228,7 +228,7 @@ async fn test_replace() -> Result<()> {
     let jvm = test_jvm().await?;
 
     let string = JavaLangString::from_rust_string(&jvm, "a.b.c.d").await?;
-    let result = jvm
+    let result: Box<dyn ClassInstance> = jvm
         .invoke_virtual(&string, "replace", "(CC)Ljava/lang/String;", (b'.' as u16, b'/' as u16))
         .await?;
     let result_string = JavaLangString::to_rust_string(&jvm, &result).await?;
@@ -284,10 +284,10 @@ async fn test_last_index_of_from() -> Result<()> {
 async fn test_value_of_overloads() -> Result<()> {
     let jvm = test_jvm().await?;
 
-    let result = jvm.invoke_static("java/lang/String", "valueOf", "(Z)Ljava/lang/String;", (true,)).await?;
+    let result: Box<dyn ClassInstance> = jvm.invoke_static("java/lang/String", "valueOf", "(Z)Ljava/lang/String;", (true,)).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &result).await?, "true");
 
-    let result = jvm
+    let result: Box<dyn ClassInstance> = jvm
         .invoke_static("java/lang/String", "valueOf", "(J)Ljava/lang/String;", (12345i64,))
         .await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &result).await?, "12345");
@@ -295,17 +295,17 @@ async fn test_value_of_overloads() -> Result<()> {
     let chars = jvm.instantiate_array("C", 3).await?;
     jvm.store_array(&mut chars.clone(), 0, vec![b'a' as u16, b'b' as u16, b'c' as u16])
         .await?;
-    let result = jvm
+    let result: Box<dyn ClassInstance> = jvm
         .invoke_static("java/lang/String", "valueOf", "([C)Ljava/lang/String;", (chars,))
         .await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &result).await?, "abc");
 
-    let result = jvm
+    let result: Box<dyn ClassInstance> = jvm
         .invoke_static("java/lang/String", "valueOf", "(F)Ljava/lang/String;", (1.5f32,))
         .await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &result).await?, "1.5");
 
-    let result = jvm
+    let result: Box<dyn ClassInstance> = jvm
         .invoke_static("java/lang/String", "valueOf", "(D)Ljava/lang/String;", (3.15f64,))
         .await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &result).await?, "3.15");
@@ -313,7 +313,7 @@ async fn test_value_of_overloads() -> Result<()> {
     let mut chars = jvm.instantiate_array("C", 5).await?;
     jvm.store_array(&mut chars, 0, vec![b'h' as u16, b'e' as u16, b'l' as u16, b'l' as u16, b'o' as u16])
         .await?;
-    let result = jvm
+    let result: Box<dyn ClassInstance> = jvm
         .invoke_static("java/lang/String", "valueOf", "([CII)Ljava/lang/String;", (chars, 1i32, 3i32))
         .await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &result).await?, "ell");
@@ -372,7 +372,7 @@ async fn test_get_bytes_unmappable_charset() -> Result<()> {
     let string = JavaLangString::from_rust_string(&jvm, "a한b").await?;
     let charset = JavaLangString::from_rust_string(&jvm, "ISO-8859-1").await?;
 
-    let bytes = jvm.invoke_virtual(&string, "getBytes", "(Ljava/lang/String;)[B", (charset,)).await?;
+    let bytes: Box<dyn ClassInstance> = jvm.invoke_virtual(&string, "getBytes", "(Ljava/lang/String;)[B", (charset,)).await?;
     let bytes = jvm.load_array::<i8>(&bytes, 0, 3).await?;
 
     assert_eq!(bytes, [0x61, 0x3f, 0x62]);
@@ -406,7 +406,7 @@ async fn test_get_bytes_ascii_replaces_non_ascii() -> Result<()> {
     let string = JavaLangString::from_rust_string(&jvm, "aé한").await?;
     let charset = JavaLangString::from_rust_string(&jvm, "US-ASCII").await?;
 
-    let bytes = jvm.invoke_virtual(&string, "getBytes", "(Ljava/lang/String;)[B", (charset,)).await?;
+    let bytes: Box<dyn ClassInstance> = jvm.invoke_virtual(&string, "getBytes", "(Ljava/lang/String;)[B", (charset,)).await?;
     let bytes = jvm.load_array::<i8>(&bytes, 0, 3).await?;
 
     assert_eq!(bytes, [0x61, 0x3f, 0x3f]);
@@ -482,7 +482,10 @@ async fn test_trim_uses_java_control_character_boundary() -> Result<()> {
 
     let unchanged = JavaLangString::from_rust_string(&jvm, "value").await?;
     let same: ClassInstanceRef<JavaString> = jvm.invoke_virtual(&unchanged, "trim", "()Ljava/lang/String;", ()).await?;
-    assert_eq!(unchanged.identity(), same.identity());
+    assert_eq!(
+        unchanged.as_class_instance().unwrap().identity(),
+        same.as_class_instance().unwrap().identity()
+    );
 
     Ok(())
 }
@@ -1,5 +1,5 @@
 use java_runtime::classes::java::lang::StringBuffer;
-use jvm::{ClassInstanceRef, JavaChar, Result, runtime::JavaLangString};
+use jvm::{ClassInstance, ClassInstanceRef, JavaChar, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm;
 
@@ -30,7 +30,7 @@ async fn test_string_buffer() -> Result<()> {
     let char: JavaChar = jvm.invoke_virtual(&string_buffer, "charAt", "(I)C", (7,)).await?;
     assert_eq!(char, '4' as JavaChar);
 
-    let result = jvm.invoke_virtual(&string_buffer, "toString", "()Ljava/lang/String;", ()).await?;
+    let result: Box<dyn ClassInstance> = jvm.invoke_virtual(&string_buffer, "toString", "()Ljava/lang/String;", ()).await?;
     let result = JavaLangString::to_rust_string(&jvm, &result).await?;
 
     assert_eq!("Hello, 42trueH42", result);
@@ -38,7 +38,7 @@ async fn test_string_buffer() -> Result<()> {
     let _: ClassInstanceRef<StringBuffer> = jvm
         .invoke_virtual(&string_buffer, "delete", "(II)Ljava/lang/StringBuffer;", (5, 7))
         .await?;
-    let result = jvm.invoke_virtual(&string_buffer, "toString", "()Ljava/lang/String;", ()).await?;
+    let result: Box<dyn ClassInstance> = jvm.invoke_virtual(&string_buffer, "toString", "()Ljava/lang/String;", ()).await?;
     let result = JavaLangString::to_rust_string(&jvm, &result).await?;
     assert_eq!("Hello42trueH42", result);
 
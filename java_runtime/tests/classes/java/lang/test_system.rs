@@ -1,8 +1,9 @@
-use alloc::collections::BTreeMap;
+use alloc::{boxed::Box, collections::BTreeMap, vec};
 
 use java_runtime::Runtime;
-use jvm::Result;
-use test_utils::{TestRuntime, create_test_jvm};
+use jvm::{ClassInstance, ClassInstanceRef, JavaError, JavaValue, Jvm, NativeMethod, Result, runtime::JavaLangString};
+use jvm_rust::ClassDefinitionImpl;
+use test_utils::{TestRuntime, create_test_jvm, test_jvm};
 
 #[tokio::test]
 async fn test_system_time_yield_and_exit_runtime_contract() -> Result<()> {
@@ -21,3 +22,119 @@ async fn test_system_time_yield_and_exit_runtime_contract() -> Result<()> {
 
     Ok(())
 }
+
+struct Add;
+
+#[async_trait::async_trait]
+impl jvm::JvmCallback for Add {
+    async fn call(&self, _: &Jvm, args: Box<[JavaValue]>) -> Result<JavaValue> {
+        let a: i32 = args[0].clone().try_into()?;
+        let b: i32 = args[1].clone().try_into()?;
+
+        Ok((a + b).into())
+    }
+}
+
+struct Scale;
+
+#[async_trait::async_trait]
+impl jvm::JvmCallback for Scale {
+    async fn call(&self, _: &Jvm, args: Box<[JavaValue]>) -> Result<JavaValue> {
+        // `this` comes first
+        let this: ClassInstanceRef<()> = args[0].clone().try_into()?;
+        assert!(!this.is_null());
+        let value: i32 = args[1].clone().try_into()?;
+
+        Ok((value * 10).into())
+    }
+}
+
+async fn register_native_library_class(jvm: &Jvm) -> Result<()> {
+    let class = ClassDefinitionImpl::from_classfile(include_bytes!("../../../../../test_data/NativeLibrary.class")).unwrap();
+    jvm.register_class(Box::new(class), None).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_register_natives() -> Result<()> {
+    let jvm = test_jvm().await?;
+    register_native_library_class(&jvm).await?;
+
+    let result: Result<i32> = jvm.invoke_static("NativeLibrary", "add", "(II)I", (1, 2)).await;
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("expected UnsatisfiedLinkError");
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/UnsatisfiedLinkError"));
+
+    jvm.register_natives(
+        None,
+        "NativeLibrary",
+        vec![NativeMethod::new("add", "(II)I", Add), NativeMethod::new("scale", "(I)I", Scale)],
+    );
+
+    let result: i32 = jvm.invoke_static("NativeLibrary", "add", "(II)I", (1, 2)).await?;
+    assert_eq!(result, 3);
+
+    let instance = jvm.new_class("NativeLibrary", "()V", ()).await?;
+    let result: i32 = jvm.invoke_virtual(&instance, "scale", "(I)I", (2,)).await?;
+    assert_eq!(result, 20);
+
+    jvm.unregister_natives(None, "NativeLibrary");
+    let result: Result<i32> = jvm.invoke_static("NativeLibrary", "add", "(II)I", (1, 2)).await;
+    assert!(matches!(result, Err(JavaError::JavaException(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_register_natives_binds_per_class_loader() -> Result<()> {
+    let jvm = test_jvm().await?;
+    let system_class_loader: Box<dyn ClassInstance> = jvm
+        .invoke_static("java/lang/ClassLoader", "getSystemClassLoader", "()Ljava/lang/ClassLoader;", ())
+        .await?;
+    let class = ClassDefinitionImpl::from_classfile(include_bytes!("../../../../../test_data/NativeLibrary.class")).unwrap();
+    jvm.register_class(Box::new(class), Some(system_class_loader.clone())).await?;
+
+    // a same-named class of another loader doesn't pick up the binding
+    jvm.register_natives(None, "NativeLibrary", vec![NativeMethod::new("add", "(II)I", Add)]);
+    let result: Result<i32> = jvm.invoke_static("NativeLibrary", "add", "(II)I", (1, 2)).await;
+    assert!(matches!(result, Err(JavaError::JavaException(_))));
+
+    jvm.register_natives(Some(&system_class_loader), "NativeLibrary", vec![NativeMethod::new("add", "(II)I", Add)]);
+    let result: i32 = jvm.invoke_static("NativeLibrary", "add", "(II)I", (1, 2)).await?;
+    assert_eq!(result, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_load_library() -> Result<()> {
+    let jvm = test_jvm().await?;
+    register_native_library_class(&jvm).await?;
+
+    jvm.register_native_library("adder", vec![("NativeLibrary", NativeMethod::new("add", "(II)I", Add))]);
+
+    // not bound until loaded
+    let result: Result<i32> = jvm.invoke_static("NativeLibrary", "add", "(II)I", (1, 2)).await;
+    assert!(matches!(result, Err(JavaError::JavaException(_))));
+
+    let name = JavaLangString::from_rust_string(&jvm, "adder").await?;
+    let _: () = jvm
+        .invoke_static("java/lang/System", "loadLibrary", "(Ljava/lang/String;)V", (name,))
+        .await?;
+
+    let result: i32 = jvm.invoke_static("NativeLibrary", "add", "(II)I", (1, 2)).await?;
+    assert_eq!(result, 3);
+
+    let name = JavaLangString::from_rust_string(&jvm, "missing").await?;
+    let result: Result<()> = jvm
+        .invoke_static("java/lang/System", "loadLibrary", "(Ljava/lang/String;)V", (name,))
+        .await;
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("expected UnsatisfiedLinkError");
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/UnsatisfiedLinkError"));
+
+    Ok(())
+}
@@ -1,8 +1,9 @@
-use alloc::{boxed::Box, collections::BTreeMap, vec};
+use alloc::{boxed::Box, collections::BTreeMap, string::ToString, vec};
+use core::time::Duration;
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use java_runtime::{RuntimeClassProto, RuntimeContext};
-use jvm::{ClassInstanceRef, JavaError, Jvm, Result, runtime::JavaLangString};
+use java_runtime::{Runtime, RuntimeClassProto, RuntimeContext, SpawnCallback};
+use jvm::{ClassInstance, ClassInstanceRef, JavaError, Jvm, Result, ThreadState, runtime::JavaLangString};
 use jvm_rust::ClassDefinitionImpl;
 
 use test_utils::{TestRuntime, create_test_jvm};
@@ -79,7 +80,7 @@ async fn test_thread_cldc_metadata_and_state() -> Result<()> {
         .await?;
 
     assert!(jvm.is_instance(&*thread, "java/lang/Runnable"));
-    let name = jvm.invoke_virtual(&thread, "getName", "()Ljava/lang/String;", ()).await?;
+    let name: Box<dyn ClassInstance> = jvm.invoke_virtual(&thread, "getName", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &name).await?, "worker");
     assert_eq!(jvm.invoke_virtual::<_, i32>(&thread, "getPriority", "()I", ()).await?, 5);
     assert_eq!(jvm.get_static_field::<i32>("java/lang/Thread", "MIN_PRIORITY", "I").await?, 1);
@@ -88,7 +89,7 @@ async fn test_thread_cldc_metadata_and_state() -> Result<()> {
 
     let _: () = jvm.invoke_virtual(&thread, "setPriority", "(I)V", (7,)).await?;
     assert_eq!(jvm.invoke_virtual::<_, i32>(&thread, "getPriority", "()I", ()).await?, 7);
-    let text = jvm.invoke_virtual(&thread, "toString", "()Ljava/lang/String;", ()).await?;
+    let text: Box<dyn ClassInstance> = jvm.invoke_virtual(&thread, "toString", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &text).await?, "Thread[worker,7]");
 
     let _: () = jvm.invoke_virtual(&thread, "interrupt", "()V", ()).await?;
@@ -103,3 +104,299 @@ async fn test_thread_cldc_metadata_and_state() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_thread_dump() -> Result<()> {
+    let runtime = TestRuntime::new(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    struct Sleeper {
+        jvm: Jvm,
+    }
+
+    #[async_trait::async_trait]
+    impl SpawnCallback for Sleeper {
+        async fn call(&self) -> Result<()> {
+            self.jvm.attach_thread(None).await?;
+            let _: () = self.jvm.invoke_static("java/lang/Thread", "sleep", "(J)V", (500i64,)).await?;
+            self.jvm.detach_thread()?;
+
+            Ok(())
+        }
+    }
+
+    let object = jvm.new_class("java/lang/Object", "()V", ()).await?;
+    jvm.monitor_enter(&object).await?;
+    runtime.spawn(&jvm, Box::new(Sleeper { jvm: jvm.clone() }));
+
+    let dump = loop {
+        let dump = jvm.thread_dump();
+        if dump.threads.iter().any(|x| x.state == ThreadState::Sleeping) {
+            break dump;
+        }
+        runtime.sleep(Duration::from_millis(10)).await;
+    };
+
+    let main = dump.threads.iter().find(|x| x.thread_id == runtime.current_task_id()).unwrap();
+    assert_eq!(main.state, ThreadState::Running);
+    assert_eq!(main.priority, 5);
+    assert!(!main.daemon);
+    assert_eq!(main.held_monitors.len(), 1);
+    assert_eq!(main.held_monitors[0].object_identity, object.identity());
+
+    let sleeper = dump.threads.iter().find(|x| x.state == ThreadState::Sleeping).unwrap();
+    assert!(sleeper.stack_trace.iter().any(|x| x == "java/lang/Thread.sleep(J)V"));
+    assert!(dump.deadlock.is_none());
+
+    let text = dump.to_string();
+    assert!(text.contains("java.lang.Thread.State: TIMED_WAITING (sleeping)"));
+    assert!(text.contains("\tat java/lang/Thread.sleep(J)V"));
+    assert!(text.contains("\t- locked <"));
+
+    jvm.monitor_exit(&object).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_daemon_threads() -> Result<()> {
+    let runtime = TestRuntime::new_with_queued_spawns(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    let daemon = jvm.new_class("java/lang/Thread", "()V", ()).await?;
+    let is_daemon: bool = jvm.invoke_virtual(&daemon, "isDaemon", "()Z", ()).await?;
+    assert!(!is_daemon);
+    let _: () = jvm.invoke_virtual(&daemon, "setDaemon", "(Z)V", (true,)).await?;
+    let is_daemon: bool = jvm.invoke_virtual(&daemon, "isDaemon", "()Z", ()).await?;
+    assert!(is_daemon);
+
+    let _: () = jvm.invoke_virtual(&daemon, "start", "()V", ()).await?;
+    assert_eq!(jvm.non_daemon_thread_count(), 0);
+
+    let result: Result<()> = jvm.invoke_virtual(&daemon, "setDaemon", "(Z)V", (false,)).await;
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("Expected JavaException, got {:?}", result);
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/IllegalThreadStateException"));
+
+    let worker = jvm.new_class("java/lang/Thread", "()V", ()).await?;
+    let _: () = jvm.invoke_virtual(&worker, "start", "()V", ()).await?;
+    assert_eq!(jvm.non_daemon_thread_count(), 1);
+
+    let waiter = tokio::spawn({
+        let jvm = jvm.clone();
+        async move { jvm.wait_for_non_daemon_threads().await }
+    });
+
+    let callback = runtime.take_spawn_callback().unwrap();
+    tokio::spawn(async move { callback.call().await }).await.unwrap()?;
+    waiter.await.unwrap();
+    assert_eq!(jvm.non_daemon_thread_count(), 0);
+
+    Ok(())
+}
+
+// blocks in Thread.sleep or Object.wait, recording whether it was interrupted out of it
+struct Blocker;
+impl Blocker {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "Blocker",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec!["java/lang/Runnable"],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Z)V", Self::init, Default::default()),
+                JavaMethodProto::new("run", "()V", Self::run, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("wait", "Z", Default::default()),
+                JavaFieldProto::new("threw", "Z", Default::default()),
+                JavaFieldProto::new("stillInterrupted", "Z", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>, wait: bool) -> Result<()> {
+        jvm.put_field(&mut this, "wait", "Z", wait).await?;
+
+        Ok(())
+    }
+
+    async fn run(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>) -> Result<()> {
+        let wait: bool = jvm.get_field(&this, "wait", "Z").await?;
+        let result: Result<()> = if wait {
+            jvm.monitor_enter(&this).await?;
+            let result = jvm.invoke_virtual(&this, "wait", "()V", ()).await;
+            jvm.monitor_exit(&this).await?;
+            result
+        } else {
+            jvm.invoke_static("java/lang/Thread", "sleep", "(J)V", (60_000i64,)).await
+        };
+
+        let threw = match result {
+            Err(JavaError::JavaException(exception)) => jvm.is_instance(&*exception, "java/lang/InterruptedException"),
+            _ => false,
+        };
+        let still_interrupted: bool = jvm.invoke_static("java/lang/Thread", "interrupted", "()Z", ()).await?;
+        jvm.put_field(&mut this, "threw", "Z", threw).await?;
+        jvm.put_field(&mut this, "stillInterrupted", "Z", still_interrupted).await?;
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_interrupt_wakes_blocked_threads() -> Result<()> {
+    let runtime = TestRuntime::new(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    let class = Box::new(ClassDefinitionImpl::from_class_proto(
+        Blocker::as_proto(),
+        Box::new(runtime.clone()) as Box<_>,
+    ));
+    jvm.register_class(class, None).await?;
+
+    for wait in [false, true] {
+        let blocker = jvm.new_class("Blocker", "(Z)V", (wait,)).await?;
+        let thread = jvm.new_class("java/lang/Thread", "(Ljava/lang/Runnable;)V", (blocker.clone(),)).await?;
+        let _: () = jvm.invoke_virtual(&thread, "start", "()V", ()).await?;
+
+        // the thread dump tells us once the thread is actually blocked
+        loop {
+            let blocked = jvm.thread_dump().threads.iter().any(|x| match &x.state {
+                ThreadState::Sleeping => !wait,
+                ThreadState::Waiting { object_identity, .. } => wait && *object_identity == blocker.identity(),
+                _ => false,
+            });
+            if blocked {
+                break;
+            }
+            runtime.sleep(Duration::from_millis(10)).await;
+        }
+
+        let _: () = jvm.invoke_virtual(&thread, "interrupt", "()V", ()).await?;
+        let _: () = jvm.invoke_virtual(&thread, "join", "()V", ()).await?;
+
+        assert!(jvm.get_field::<bool>(&blocker, "threw", "Z").await?);
+        assert!(!jvm.get_field::<bool>(&blocker, "stillInterrupted", "Z").await?);
+        assert!(!jvm.invoke_virtual::<_, bool>(&thread, "isInterrupted", "()Z", ()).await?);
+    }
+
+    Ok(())
+}
+
+// joins another thread, recording whether it was interrupted out of the join
+struct Joiner;
+impl Joiner {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "Joiner",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec!["java/lang/Runnable"],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/Thread;)V", Self::init, Default::default()),
+                JavaMethodProto::new("run", "()V", Self::run, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("target", "Ljava/lang/Thread;", Default::default()),
+                JavaFieldProto::new("threw", "Z", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>, target: ClassInstanceRef<()>) -> Result<()> {
+        jvm.put_field(&mut this, "target", "Ljava/lang/Thread;", target).await?;
+
+        Ok(())
+    }
+
+    async fn run(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>) -> Result<()> {
+        let target: ClassInstanceRef<()> = jvm.get_field(&this, "target", "Ljava/lang/Thread;").await?;
+        let result: Result<()> = jvm.invoke_virtual(&target, "join", "()V", ()).await;
+
+        let threw = match result {
+            Err(JavaError::JavaException(exception)) => jvm.is_instance(&*exception, "java/lang/InterruptedException"),
+            _ => false,
+        };
+        jvm.put_field(&mut this, "threw", "Z", threw).await?;
+
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_interrupt_wakes_joining_thread() -> Result<()> {
+    let runtime = TestRuntime::new(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    for proto in [Blocker::as_proto(), Joiner::as_proto()] {
+        let class = Box::new(ClassDefinitionImpl::from_class_proto(proto, Box::new(runtime.clone()) as Box<_>));
+        jvm.register_class(class, None).await?;
+    }
+
+    // a thread that doesn't end on its own
+    let blocker = jvm.new_class("Blocker", "(Z)V", (false,)).await?;
+    let target = jvm.new_class("java/lang/Thread", "(Ljava/lang/Runnable;)V", (blocker,)).await?;
+    let _: () = jvm.invoke_virtual(&target, "start", "()V", ()).await?;
+
+    let joiner = jvm.new_class("Joiner", "(Ljava/lang/Thread;)V", (target.clone(),)).await?;
+    let thread = jvm.new_class("java/lang/Thread", "(Ljava/lang/Runnable;)V", (joiner.clone(),)).await?;
+    let _: () = jvm.invoke_virtual(&thread, "start", "()V", ()).await?;
+
+    // join waits on the target thread's monitor
+    loop {
+        let joining = jvm.thread_dump().threads.iter().any(|x| match &x.state {
+            ThreadState::Waiting { object_identity, .. } => *object_identity == target.identity(),
+            _ => false,
+        });
+        if joining {
+            break;
+        }
+        runtime.sleep(Duration::from_millis(10)).await;
+    }
+
+    let _: () = jvm.invoke_virtual(&thread, "interrupt", "()V", ()).await?;
+    let _: () = jvm.invoke_virtual(&thread, "join", "()V", ()).await?;
+    assert!(jvm.get_field::<bool>(&joiner, "threw", "Z").await?);
+
+    let _: () = jvm.invoke_virtual(&target, "interrupt", "()V", ()).await?;
+    let _: () = jvm.invoke_virtual(&target, "join", "()V", ()).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_interrupted_status() -> Result<()> {
+    let jvm = create_test_jvm(TestRuntime::new(BTreeMap::new())).await?;
+
+    let current: Box<dyn ClassInstance> = jvm.invoke_static("java/lang/Thread", "currentThread", "()Ljava/lang/Thread;", ()).await?;
+    let _: () = jvm.invoke_virtual(&current, "interrupt", "()V", ()).await?;
+    assert!(jvm.invoke_virtual::<_, bool>(&current, "isInterrupted", "()Z", ()).await?);
+    assert!(jvm.invoke_static::<_, bool>("java/lang/Thread", "interrupted", "()Z", ()).await?);
+    assert!(!jvm.invoke_static::<_, bool>("java/lang/Thread", "interrupted", "()Z", ()).await?);
+
+    // a pending interrupt makes the next blocking call throw right away
+    let _: () = jvm.invoke_virtual(&current, "interrupt", "()V", ()).await?;
+    let result: Result<()> = jvm.invoke_static("java/lang/Thread", "sleep", "(J)V", (60_000i64,)).await;
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("Expected JavaException, got {:?}", result);
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/InterruptedException"));
+    assert!(!jvm.invoke_virtual::<_, bool>(&current, "isInterrupted", "()Z", ()).await?);
+
+    let object = jvm.new_class("java/lang/Object", "()V", ()).await?;
+    let _: () = jvm.invoke_virtual(&current, "interrupt", "()V", ()).await?;
+    jvm.monitor_enter(&object).await?;
+    let result: Result<()> = jvm.invoke_virtual(&object, "wait", "()V", ()).await;
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("Expected JavaException, got {:?}", result);
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/InterruptedException"));
+    // the monitor is held again once wait throws
+    let _: () = jvm.invoke_virtual(&object, "notify", "()V", ()).await?;
+    jvm.monitor_exit(&object).await?;
+
+    Ok(())
+}
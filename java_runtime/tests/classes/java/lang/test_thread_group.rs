@@ -0,0 +1,221 @@
+use alloc::{boxed::Box, collections::BTreeMap, string::String, sync::Arc, vec, vec::Vec};
+use core::time::Duration;
+use std::sync::Mutex;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::{
+    Runtime, RuntimeClassProto, RuntimeContext,
+    classes::java::lang::{Object, Thread, ThreadGroup, Throwable},
+};
+use jvm::{ClassInstance, ClassInstanceRef, Jvm, JvmEvent, Result, runtime::JavaLangString};
+use jvm_rust::ClassDefinitionImpl;
+
+use test_utils::{TestRuntime, create_test_jvm};
+
+struct Thrower;
+impl Thrower {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "Thrower",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec!["java/lang/Runnable"],
+            methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                JavaMethodProto::new("run", "()V", Self::run, Default::default()),
+            ],
+            fields: vec![],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        Ok(())
+    }
+
+    async fn run(jvm: &Jvm, _: &mut RuntimeContext, _: ClassInstanceRef<Self>) -> Result<()> {
+        Err(jvm.exception("java/lang/RuntimeException", "boom").await)
+    }
+}
+
+// sleeps until interrupted
+struct Sleeper;
+impl Sleeper {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "Sleeper",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec!["java/lang/Runnable"],
+            methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                JavaMethodProto::new("run", "()V", Self::run, Default::default()),
+            ],
+            fields: vec![],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        Ok(())
+    }
+
+    async fn run(jvm: &Jvm, _: &mut RuntimeContext, _: ClassInstanceRef<Self>) -> Result<()> {
+        let _: Result<()> = jvm.invoke_static("java/lang/Thread", "sleep", "(J)V", (60_000i64,)).await;
+
+        Ok(())
+    }
+}
+
+// records the last uncaught exception instead of printing it
+struct RecordingGroup;
+impl RecordingGroup {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "RecordingGroup",
+            parent_class: Some("java/lang/ThreadGroup"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;)V", Self::init, Default::default()),
+                JavaMethodProto::new(
+                    "uncaughtException",
+                    "(Ljava/lang/Thread;Ljava/lang/Throwable;)V",
+                    Self::uncaught_exception,
+                    Default::default(),
+                ),
+            ],
+            fields: vec![
+                JavaFieldProto::new("thread", "Ljava/lang/Thread;", Default::default()),
+                JavaFieldProto::new("exception", "Ljava/lang/Throwable;", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, name: ClassInstanceRef<Object>) -> Result<()> {
+        let _: () = jvm
+            .invoke_special(&this, "java/lang/ThreadGroup", "<init>", "(Ljava/lang/String;)V", (name,))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn uncaught_exception(
+        jvm: &Jvm,
+        _: &mut RuntimeContext,
+        mut this: ClassInstanceRef<Self>,
+        thread: ClassInstanceRef<Thread>,
+        exception: ClassInstanceRef<Throwable>,
+    ) -> Result<()> {
+        jvm.put_field(&mut this, "thread", "Ljava/lang/Thread;", thread).await?;
+        jvm.put_field(&mut this, "exception", "Ljava/lang/Throwable;", exception).await?;
+
+        Ok(())
+    }
+}
+
+async fn setup(runtime: &TestRuntime) -> Result<Jvm> {
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    for proto in [Thrower::as_proto(), Sleeper::as_proto(), RecordingGroup::as_proto()] {
+        let class = Box::new(ClassDefinitionImpl::from_class_proto(proto, Box::new(runtime.clone()) as Box<_>));
+        jvm.register_class(class, None).await?;
+    }
+
+    Ok(jvm)
+}
+
+#[tokio::test]
+async fn test_thread_group_membership() -> Result<()> {
+    let runtime = TestRuntime::new(BTreeMap::new());
+    let jvm = setup(&runtime).await?;
+
+    let current: Box<dyn ClassInstance> = jvm.invoke_static("java/lang/Thread", "currentThread", "()Ljava/lang/Thread;", ()).await?;
+    let main: ClassInstanceRef<ThreadGroup> = jvm.invoke_virtual(&current, "getThreadGroup", "()Ljava/lang/ThreadGroup;", ()).await?;
+    let name: Box<dyn ClassInstance> = jvm.invoke_virtual(&main, "getName", "()Ljava/lang/String;", ()).await?;
+    assert_eq!(JavaLangString::to_rust_string(&jvm, &name).await?, "main");
+
+    let name = JavaLangString::from_rust_string(&jvm, "workers").await?;
+    let workers = jvm.new_class("java/lang/ThreadGroup", "(Ljava/lang/String;)V", (name,)).await?;
+    let parent: Box<dyn ClassInstance> = jvm.invoke_virtual(&workers, "getParent", "()Ljava/lang/ThreadGroup;", ()).await?;
+    assert!(parent.equals(&**main.instance.as_ref().unwrap())?);
+    assert!(
+        jvm.invoke_virtual::<_, bool>(&main, "parentOf", "(Ljava/lang/ThreadGroup;)Z", (workers.clone(),))
+            .await?
+    );
+    assert_eq!(jvm.invoke_virtual::<_, i32>(&workers, "activeCount", "()I", ()).await?, 0);
+
+    let sleeper = jvm.new_class("Sleeper", "()V", ()).await?;
+    let thread = jvm
+        .new_class(
+            "java/lang/Thread",
+            "(Ljava/lang/ThreadGroup;Ljava/lang/Runnable;)V",
+            (workers.clone(), sleeper),
+        )
+        .await?;
+    let group: Box<dyn ClassInstance> = jvm.invoke_virtual(&thread, "getThreadGroup", "()Ljava/lang/ThreadGroup;", ()).await?;
+    assert!(group.equals(&*workers)?);
+
+    let _: () = jvm.invoke_virtual(&thread, "start", "()V", ()).await?;
+    while jvm.invoke_virtual::<_, i32>(&workers, "activeCount", "()I", ()).await? == 0 {
+        runtime.sleep(Duration::from_millis(10)).await;
+    }
+
+    let list = jvm.instantiate_array("Ljava/lang/Thread;", 4).await?;
+    let count: i32 = jvm
+        .invoke_virtual(&workers, "enumerate", "([Ljava/lang/Thread;)I", (list.clone(),))
+        .await?;
+    assert_eq!(count, 1);
+    let listed: Vec<ClassInstanceRef<Thread>> = jvm.load_array(&list, 0, 1).await?;
+    assert!(listed[0].instance.as_ref().unwrap().equals(&*thread)?);
+
+    // the main group counts threads in subgroups too
+    let total: i32 = jvm.invoke_virtual(&main, "activeCount", "()I", ()).await?;
+    let count: i32 = jvm.invoke_virtual(&main, "enumerate", "([Ljava/lang/Thread;Z)I", (list, false)).await?;
+    assert!(total > count);
+
+    let _: () = jvm.invoke_virtual(&thread, "interrupt", "()V", ()).await?;
+    let _: () = jvm.invoke_virtual(&thread, "join", "()V", ()).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_uncaught_exception_goes_to_the_thread_group() -> Result<()> {
+    let runtime = TestRuntime::new(BTreeMap::new());
+    let jvm = setup(&runtime).await?;
+
+    let uncaught = Arc::new(Mutex::new(Vec::<String>::new()));
+    jvm.add_event_listener({
+        let uncaught = uncaught.clone();
+        move |_: &Jvm, event: &JvmEvent<'_>| {
+            if let JvmEvent::UncaughtException { exception, .. } = event {
+                uncaught.lock().unwrap().push(exception.class_definition().name());
+            }
+        }
+    });
+
+    let name = JavaLangString::from_rust_string(&jvm, "recording").await?;
+    let group = jvm.new_class("RecordingGroup", "(Ljava/lang/String;)V", (name,)).await?;
+    let thrower = jvm.new_class("Thrower", "()V", ()).await?;
+    let thread = jvm
+        .new_class(
+            "java/lang/Thread",
+            "(Ljava/lang/ThreadGroup;Ljava/lang/Runnable;)V",
+            (group.clone(), thrower),
+        )
+        .await?;
+    let _: () = jvm.invoke_virtual(&thread, "start", "()V", ()).await?;
+    let _: () = jvm.invoke_virtual(&thread, "join", "()V", ()).await?;
+
+    let recorded_thread: Box<dyn ClassInstance> = jvm.get_field(&group, "thread", "Ljava/lang/Thread;").await?;
+    assert!(recorded_thread.equals(&*thread)?);
+    let exception: ClassInstanceRef<Throwable> = jvm.get_field(&group, "exception", "Ljava/lang/Throwable;").await?;
+    assert!(jvm.is_instance(&**exception.instance.as_ref().unwrap(), "java/lang/RuntimeException"));
+
+    assert_eq!(*uncaught.lock().unwrap(), ["java/lang/RuntimeException"]);
+
+    Ok(())
+}
@@ -0,0 +1,172 @@
+use alloc::{boxed::Box, collections::BTreeMap, vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::{
+    RuntimeClassProto, RuntimeContext,
+    classes::java::lang::{Object, ThreadLocal},
+};
+use jvm::{ClassInstance, ClassInstanceRef, Jvm, Result};
+use jvm_rust::ClassDefinitionImpl;
+
+use test_utils::{TestRuntime, create_test_jvm};
+
+// reads a thread local from the thread running it
+struct Reader;
+impl Reader {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "Reader",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec!["java/lang/Runnable"],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/ThreadLocal;)V", Self::init, Default::default()),
+                JavaMethodProto::new("run", "()V", Self::run, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("local", "Ljava/lang/ThreadLocal;", Default::default()),
+                JavaFieldProto::new("value", "Ljava/lang/Object;", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>, local: ClassInstanceRef<Object>) -> Result<()> {
+        jvm.put_field(&mut this, "local", "Ljava/lang/ThreadLocal;", local).await?;
+
+        Ok(())
+    }
+
+    async fn run(jvm: &Jvm, _: &mut RuntimeContext, mut this: ClassInstanceRef<Self>) -> Result<()> {
+        let local: ClassInstanceRef<Object> = jvm.get_field(&this, "local", "Ljava/lang/ThreadLocal;").await?;
+        let value: ClassInstanceRef<Object> = jvm.invoke_virtual(&local, "get", "()Ljava/lang/Object;", ()).await?;
+        jvm.put_field(&mut this, "value", "Ljava/lang/Object;", value).await?;
+
+        Ok(())
+    }
+}
+
+// a thread local whose initial value is a fresh object per thread
+struct FreshThreadLocal;
+impl FreshThreadLocal {
+    pub fn as_proto() -> RuntimeClassProto {
+        RuntimeClassProto {
+            name: "FreshThreadLocal",
+            parent_class: Some("java/lang/ThreadLocal"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                JavaMethodProto::new("initialValue", "()Ljava/lang/Object;", Self::initial_value, Default::default()),
+            ],
+            fields: vec![],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        let _: () = jvm.invoke_special(&this, "java/lang/ThreadLocal", "<init>", "()V", ()).await?;
+
+        Ok(())
+    }
+
+    async fn initial_value(jvm: &Jvm, _: &mut RuntimeContext, _: ClassInstanceRef<Self>) -> Result<ClassInstanceRef<Object>> {
+        Ok(jvm.new_class("java/lang/Object", "()V", ()).await?.into())
+    }
+}
+
+async fn setup() -> Result<Jvm> {
+    let runtime = TestRuntime::new(BTreeMap::new());
+    let jvm = create_test_jvm(runtime.clone()).await?;
+
+    for proto in [Reader::as_proto(), FreshThreadLocal::as_proto()] {
+        let class = Box::new(ClassDefinitionImpl::from_class_proto(proto, Box::new(runtime.clone()) as Box<_>));
+        jvm.register_class(class, None).await?;
+    }
+
+    Ok(jvm)
+}
+
+// runs a Reader for `local` on a new thread, returning what it saw
+async fn read_on_new_thread(jvm: &Jvm, local: ClassInstanceRef<ThreadLocal>) -> Result<Option<Box<dyn ClassInstance>>> {
+    let reader = jvm.new_class("Reader", "(Ljava/lang/ThreadLocal;)V", (local,)).await?;
+    let thread = jvm.new_class("java/lang/Thread", "(Ljava/lang/Runnable;)V", (reader.clone(),)).await?;
+    let _: () = jvm.invoke_virtual(&thread, "start", "()V", ()).await?;
+    let _: () = jvm.invoke_virtual(&thread, "join", "()V", ()).await?;
+
+    let value: ClassInstanceRef<Object> = jvm.get_field(&reader, "value", "Ljava/lang/Object;").await?;
+    Ok(value.instance)
+}
+
+#[tokio::test]
+async fn test_thread_local() -> Result<()> {
+    let jvm = setup().await?;
+
+    let local = jvm.new_class("java/lang/ThreadLocal", "()V", ()).await?;
+    let value: ClassInstanceRef<Object> = jvm.invoke_virtual(&local, "get", "()Ljava/lang/Object;", ()).await?;
+    assert!(value.is_null());
+
+    let object = jvm.new_class("java/lang/Object", "()V", ()).await?;
+    let _: () = jvm.invoke_virtual(&local, "set", "(Ljava/lang/Object;)V", (object.clone(),)).await?;
+    let value: Box<dyn ClassInstance> = jvm.invoke_virtual(&local, "get", "()Ljava/lang/Object;", ()).await?;
+    assert!(value.equals(&*object)?);
+
+    // other threads have their own value
+    assert!(read_on_new_thread(&jvm, local.clone().into()).await?.is_none());
+
+    let _: () = jvm.invoke_virtual(&local, "remove", "()V", ()).await?;
+    let value: ClassInstanceRef<Object> = jvm.invoke_virtual(&local, "get", "()Ljava/lang/Object;", ()).await?;
+    assert!(value.is_null());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_thread_local_initial_value() -> Result<()> {
+    let jvm = setup().await?;
+
+    let local = jvm.new_class("FreshThreadLocal", "()V", ()).await?;
+    let first: Box<dyn ClassInstance> = jvm.invoke_virtual(&local, "get", "()Ljava/lang/Object;", ()).await?;
+    let second: Box<dyn ClassInstance> = jvm.invoke_virtual(&local, "get", "()Ljava/lang/Object;", ()).await?;
+    assert!(first.equals(&*second)?);
+
+    let other = read_on_new_thread(&jvm, local.clone().into()).await?.unwrap();
+    assert!(!other.equals(&*first)?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_inheritable_thread_local() -> Result<()> {
+    let jvm = setup().await?;
+
+    let inheritable = jvm.new_class("java/lang/InheritableThreadLocal", "()V", ()).await?;
+    let object = jvm.new_class("java/lang/Object", "()V", ()).await?;
+    let _: () = jvm
+        .invoke_virtual(&inheritable, "set", "(Ljava/lang/Object;)V", (object.clone(),))
+        .await?;
+
+    let inherited = read_on_new_thread(&jvm, inheritable.into()).await?.unwrap();
+    assert!(inherited.equals(&*object)?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_thread_local_values_are_reachable_from_the_thread() -> Result<()> {
+    let jvm = setup().await?;
+
+    let local: ClassInstanceRef<ThreadLocal> = jvm.new_class("java/lang/ThreadLocal", "()V", ()).await?.into();
+    let local = jvm.new_global_ref(&local).unwrap();
+
+    let frame = jvm.push_native_frame()?;
+    let object = jvm.new_class("java/lang/Object", "()V", ()).await?;
+    let identity = object.identity();
+    let _: () = jvm.invoke_virtual(&local, "set", "(Ljava/lang/Object;)V", (object,)).await?;
+    drop(frame);
+
+    jvm.collect_garbage()?;
+
+    let value: Box<dyn ClassInstance> = jvm.invoke_virtual(&local, "get", "()Ljava/lang/Object;", ()).await?;
+    assert_eq!(value.identity(), identity);
+
+    Ok(())
+}
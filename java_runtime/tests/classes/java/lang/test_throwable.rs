@@ -12,7 +12,7 @@ async fn test_to_string() -> Result<()> {
     let message = JavaLangString::from_rust_string(&jvm, "test message").await?;
 
     let throwable = jvm.new_class("java/lang/Throwable", "(Ljava/lang/String;)V", (message,)).await?;
-    let to_string = jvm.invoke_virtual(&throwable, "toString", "()Ljava/lang/String;", ()).await?;
+    let to_string: Box<dyn ClassInstance> = jvm.invoke_virtual(&throwable, "toString", "()Ljava/lang/String;", ()).await?;
 
     let result = JavaLangString::to_rust_string(&jvm, &to_string).await?;
 
@@ -32,7 +32,9 @@ async fn test_stacktrace() -> Result<()> {
     let url_string = JavaLangString::from_rust_string(&jvm, "invalid://invalid").await?;
     let url: Result<Box<dyn ClassInstance>> = jvm.new_class("java/net/URL", "(Ljava/lang/String;)V", (url_string,)).await;
 
-    let JavaError::JavaException(exception) = url.err().unwrap();
+    let Err(JavaError::JavaException(exception)) = url else {
+        panic!("Expected JavaException, got {:?}", url);
+    };
 
     let string_writer = jvm.new_class("java/io/StringWriter", "()V", ()).await?;
     let print_writer = jvm
@@ -1,7 +1,7 @@
 use alloc::vec;
 
 use java_runtime::classes::java::{lang::Class, net::URL};
-use jvm::{ClassInstanceRef, Result, runtime::JavaLangString};
+use jvm::{ClassInstance, ClassInstanceRef, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm_filesystem;
 
@@ -21,11 +21,11 @@ async fn test_jar_loading() -> Result<()> {
         .await?;
 
     let resource_name = JavaLangString::from_rust_string(&jvm, "test.txt").await?;
-    let resource = jvm
+    let resource: Box<dyn ClassInstance> = jvm
         .invoke_virtual(&class_loader, "findResource", "(Ljava/lang/String;)Ljava/net/URL;", (resource_name,))
         .await?;
 
-    let stream = jvm.invoke_virtual(&resource, "openStream", "()Ljava/io/InputStream;", ()).await?;
+    let stream: Box<dyn ClassInstance> = jvm.invoke_virtual(&resource, "openStream", "()Ljava/io/InputStream;", ()).await?;
 
     let buf = jvm.instantiate_array("B", 17).await?;
     let len: i32 = jvm.invoke_virtual(&stream, "read", "([B)I", (buf.clone(),)).await?;
@@ -54,7 +54,7 @@ async fn test_jar_loading_with_slash() -> Result<()> {
         .await?;
 
     let resource_name = JavaLangString::from_rust_string(&jvm, "/test.txt").await?;
-    let stream = jvm
+    let stream: Box<dyn ClassInstance> = jvm
         .invoke_virtual(
             &class_loader,
             "getResourceAsStream",
@@ -89,11 +89,11 @@ async fn test_load_from_dir() -> Result<()> {
         .await?;
 
     let resource_name = JavaLangString::from_rust_string(&jvm, "test.txt").await?;
-    let resource = jvm
+    let resource: Box<dyn ClassInstance> = jvm
         .invoke_virtual(&class_loader, "findResource", "(Ljava/lang/String;)Ljava/net/URL;", (resource_name,))
         .await?;
 
-    let stream = jvm.invoke_virtual(&resource, "openStream", "()Ljava/io/InputStream;", ()).await?;
+    let stream: Box<dyn ClassInstance> = jvm.invoke_virtual(&resource, "openStream", "()Ljava/io/InputStream;", ()).await?;
 
     let buf = jvm.instantiate_array("B", 17).await?;
     let len: i32 = jvm.invoke_virtual(&stream, "read", "([B)I", (buf.clone(),)).await?;
@@ -104,7 +104,7 @@ async fn test_format_polymorphic_format_and_parse_object() -> Result<()> {
     let parsed: ClassInstanceRef<Object> = jvm
         .invoke_virtual(&format, "parseObject", "(Ljava/lang/String;)Ljava/lang/Object;", (source,))
         .await?;
-    assert!(jvm.is_instance(&**parsed, "java/lang/Long"));
+    assert!(jvm.is_instance(&parsed, "java/lang/Long"));
 
     Ok(())
 }
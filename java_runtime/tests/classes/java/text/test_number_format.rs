@@ -204,7 +204,7 @@ async fn test_decimal_format_quoted_affixes_and_integer_boundaries() -> Result<(
     let parsed: ClassInstanceRef<Number> = jvm
         .invoke_virtual(&format, "parse", "(Ljava/lang/String;)Ljava/lang/Number;", (overflow,))
         .await?;
-    assert!(jvm.is_instance(&**parsed, "java/lang/Double"));
+    assert!(jvm.is_instance(&parsed, "java/lang/Double"));
 
     Ok(())
 }
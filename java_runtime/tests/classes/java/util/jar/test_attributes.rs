@@ -1,5 +1,5 @@
 use java_runtime::classes::java::lang::{Object, String};
-use jvm::{ClassInstanceRef, Result, runtime::JavaLangString};
+use jvm::{ClassInstance, ClassInstanceRef, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm;
 
@@ -10,8 +10,8 @@ async fn test_attribute_get_set() -> Result<()> {
     let attributes = jvm.new_class("java/util/jar/Attributes", "()V", ()).await?;
     let map: ClassInstanceRef<Object> = jvm.get_field(&attributes, "map", "Ljava/util/Map;").await?;
     assert!(!map.is_null());
-    assert!(jvm.is_instance(&**map, "java/util/HashMap"));
-    assert!(jvm.is_instance(&**map, "java/util/Map"));
+    assert!(jvm.is_instance(&map, "java/util/HashMap"));
+    assert!(jvm.is_instance(&map, "java/util/Map"));
 
     let name = JavaLangString::from_rust_string(&jvm, "Name").await?;
     let value = JavaLangString::from_rust_string(&jvm, "Value").await?;
@@ -26,7 +26,7 @@ async fn test_attribute_get_set() -> Result<()> {
         .await?;
     assert!(old.is_null());
 
-    let value = jvm
+    let value: Box<dyn ClassInstance> = jvm
         .invoke_virtual(&attributes, "getValue", "(Ljava/lang/String;)Ljava/lang/String;", (name,))
         .await?;
 
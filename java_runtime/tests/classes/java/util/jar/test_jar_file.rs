@@ -1,5 +1,5 @@
 use java_runtime::classes::java::util::jar::JarEntry;
-use jvm::{ClassInstanceRef, Result, runtime::JavaLangString};
+use jvm::{ClassInstance, ClassInstanceRef, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm_filesystem;
 
@@ -13,14 +13,14 @@ async fn test_jar_manifest() -> Result<()> {
     let file = jvm.new_class("java/io/File", "(Ljava/lang/String;)V", (name,)).await?;
     let jar = jvm.new_class("java/util/jar/JarFile", "(Ljava/io/File;)V", (file,)).await?;
 
-    let manifest = jvm.invoke_virtual(&jar, "getManifest", "()Ljava/util/jar/Manifest;", ()).await?;
+    let manifest: Box<dyn ClassInstance> = jvm.invoke_virtual(&jar, "getManifest", "()Ljava/util/jar/Manifest;", ()).await?;
 
-    let main_attributes = jvm
+    let main_attributes: Box<dyn ClassInstance> = jvm
         .invoke_virtual(&manifest, "getMainAttributes", "()Ljava/util/jar/Attributes;", ())
         .await?;
 
     let key = JavaLangString::from_rust_string(&jvm, "Main-Class").await?;
-    let value = jvm
+    let value: Box<dyn ClassInstance> = jvm
         .invoke_virtual(&main_attributes, "getValue", "(Ljava/lang/String;)Ljava/lang/String;", (key,))
         .await?;
 
@@ -38,33 +38,33 @@ async fn test_entries() -> Result<()> {
     let name = JavaLangString::from_rust_string(&jvm, "test.jar").await?;
     let jar = jvm.new_class("java/util/jar/JarFile", "(Ljava/lang/String;)V", (name,)).await?;
 
-    let entries = jvm.invoke_virtual(&jar, "entries", "()Ljava/util/Enumeration;", ()).await?;
+    let entries: Box<dyn ClassInstance> = jvm.invoke_virtual(&jar, "entries", "()Ljava/util/Enumeration;", ()).await?;
 
     let has_more_elements: bool = jvm.invoke_virtual(&entries, "hasMoreElements", "()Z", ()).await?;
 
     assert!(has_more_elements);
     let next_element: ClassInstanceRef<JarEntry> = jvm.invoke_virtual(&entries, "nextElement", "()Ljava/lang/Object;", ()).await?;
-    let name = jvm.get_field(&next_element, "name", "Ljava/lang/String;").await?;
+    let name: Box<dyn ClassInstance> = jvm.get_field(&next_element, "name", "Ljava/lang/String;").await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &name).await?, "META-INF/");
 
     let has_more_elements: bool = jvm.invoke_virtual(&entries, "hasMoreElements", "()Z", ()).await?;
     assert!(has_more_elements);
     let next_element: ClassInstanceRef<JarEntry> = jvm.invoke_virtual(&entries, "nextElement", "()Ljava/lang/Object;", ()).await?;
-    let name = jvm.get_field(&next_element, "name", "Ljava/lang/String;").await?;
+    let name: Box<dyn ClassInstance> = jvm.get_field(&next_element, "name", "Ljava/lang/String;").await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &name).await?, "META-INF/MANIFEST.MF");
 
     let has_more_elements: bool = jvm.invoke_virtual(&entries, "hasMoreElements", "()Z", ()).await?;
     assert!(has_more_elements);
 
     let next_element: ClassInstanceRef<JarEntry> = jvm.invoke_virtual(&entries, "nextElement", "()Ljava/lang/Object;", ()).await?;
-    let name = jvm.get_field(&next_element, "name", "Ljava/lang/String;").await?;
+    let name: Box<dyn ClassInstance> = jvm.get_field(&next_element, "name", "Ljava/lang/String;").await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &name).await?, "JarTest.class");
 
     let has_more_elements: bool = jvm.invoke_virtual(&entries, "hasMoreElements", "()Z", ()).await?;
     assert!(has_more_elements);
 
     let next_element: ClassInstanceRef<JarEntry> = jvm.invoke_virtual(&entries, "nextElement", "()Ljava/lang/Object;", ()).await?;
-    let name = jvm.get_field(&next_element, "name", "Ljava/lang/String;").await?;
+    let name: Box<dyn ClassInstance> = jvm.get_field(&next_element, "name", "Ljava/lang/String;").await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &name).await?, "test.txt");
 
     let has_more_elements: bool = jvm.invoke_virtual(&entries, "hasMoreElements", "()Z", ()).await?;
@@ -1,4 +1,4 @@
-use jvm::{Result, runtime::JavaLangString};
+use jvm::{ClassInstance, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm;
 
@@ -15,12 +15,12 @@ async fn test_manifest_parsing() -> Result<()> {
         .new_class("java/util/jar/Manifest", "(Ljava/io/InputStream;)V", (byte_array_stream,))
         .await?;
 
-    let main_attributes = jvm
+    let main_attributes: Box<dyn ClassInstance> = jvm
         .invoke_virtual(&manifest, "getMainAttributes", "()Ljava/util/jar/Attributes;", ())
         .await?;
 
     let key = JavaLangString::from_rust_string(&jvm, "Main-Class").await?;
-    let value = jvm
+    let value: Box<dyn ClassInstance> = jvm
         .invoke_virtual(&main_attributes, "getValue", "(Ljava/lang/String;)Ljava/lang/String;", (key,))
         .await?;
 
@@ -13,4 +13,5 @@ mod test_timer;
 mod test_timezone;
 mod test_url;
 mod test_vector;
+mod test_weak_hash_map;
 mod zip;
@@ -186,7 +186,7 @@ async fn test_array_list_itr_snapshot_exhaustion_and_remove() -> Result<()> {
     let _: bool = jvm.invoke_virtual(&array_list, "add", "(Ljava/lang/Object;)Z", (second,)).await?;
 
     let iterator: ClassInstanceRef<Object> = jvm.invoke_virtual(&array_list, "iterator", "()Ljava/util/Iterator;", ()).await?;
-    assert!(jvm.is_instance(&**iterator, "java/util/Iterator"));
+    assert!(jvm.is_instance(&iterator, "java/util/Iterator"));
 
     let third = JavaLangString::from_rust_string(&jvm, "third").await?;
     let _: bool = jvm.invoke_virtual(&array_list, "add", "(Ljava/lang/Object;)Z", (third,)).await?;
@@ -1,5 +1,5 @@
 use java_runtime::classes::java::lang::Object;
-use jvm::{ClassInstanceRef, Result, runtime::JavaLangString};
+use jvm::{ClassInstance, ClassInstanceRef, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm;
 
@@ -8,7 +8,7 @@ async fn test_gregorian_calendar() -> Result<()> {
     let jvm = test_jvm().await?;
 
     let timestamp = 0i64;
-    let calendar = jvm
+    let calendar: Box<dyn ClassInstance> = jvm
         .invoke_static("java/util/Calendar", "getInstance", "()Ljava/util/Calendar;", ())
         .await?;
     let date = jvm.new_class("java/util/Date", "(J)V", (timestamp,)).await?;
@@ -47,7 +47,7 @@ async fn test_gregorian_calendar() -> Result<()> {
     assert_eq!(16, second);
 
     let _: () = jvm.invoke_virtual(&calendar, "set", "(II)V", (1, 1999)).await?;
-    let date = jvm.invoke_virtual(&calendar, "getTime", "()Ljava/util/Date;", ()).await?;
+    let date: Box<dyn ClassInstance> = jvm.invoke_virtual(&calendar, "getTime", "()Ljava/util/Date;", ()).await?;
     let timestamp: i64 = jvm.invoke_virtual(&date, "getTime", "()J", ()).await?;
     assert_eq!(926823916000, timestamp);
 
@@ -2,7 +2,7 @@ use alloc::{boxed::Box, collections::BTreeMap, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_runtime::{RuntimeClassProto, RuntimeContext, classes::java::lang::Object, classes::java::util::HashMapEntry};
-use jvm::{Array, ClassInstanceRef, JavaError, Jvm, Result, runtime::JavaLangString};
+use jvm::{Array, AsClassInstance, ClassInstanceRef, JavaError, Jvm, Result, runtime::JavaLangString};
 use jvm_rust::ClassDefinitionImpl;
 
 use test_utils::{TestRuntime, create_test_jvm, test_jvm};
@@ -73,7 +73,7 @@ impl AsymmetricQueryKey {
             return Ok(false);
         }
 
-        Ok(jvm.is_instance(&**other, "AsymmetricStoredKey"))
+        Ok(jvm.is_instance(&other, "AsymmetricStoredKey"))
     }
 }
 
@@ -107,7 +107,7 @@ impl CollisionKey {
     }
 
     async fn equals(jvm: &Jvm, _: &mut RuntimeContext, this: ClassInstanceRef<Self>, other: ClassInstanceRef<Object>) -> Result<bool> {
-        if other.is_null() || !jvm.is_instance(&**other, "HashMapCollisionKey") {
+        if other.is_null() || !jvm.is_instance(&other, "HashMapCollisionKey") {
             return Ok(false);
         }
 
@@ -210,7 +210,7 @@ async fn iterator_to_optional_strings(jvm: &Jvm, iterator: &ClassInstanceRef<Obj
 }
 
 async fn entry_to_optional_strings(jvm: &Jvm, entry: &ClassInstanceRef<Object>) -> Result<(Option<String>, Option<String>)> {
-    assert!(jvm.is_instance(&***entry, "java/util/Map$Entry"));
+    assert!(jvm.is_instance(entry, "java/util/Map$Entry"));
 
     let key: ClassInstanceRef<Object> = jvm.invoke_virtual(entry, "getKey", "()Ljava/lang/Object;", ()).await?;
     let value: ClassInstanceRef<Object> = jvm.invoke_virtual(entry, "getValue", "()Ljava/lang/Object;", ()).await?;
@@ -648,7 +648,7 @@ async fn test_hash_map_entry_direct_methods_and_assignability() -> Result<()> {
         .await?
         .into();
 
-    assert!(jvm.is_instance(&**entry, "java/util/Map$Entry"));
+    assert!(jvm.is_instance(&entry, "java/util/Map$Entry"));
 
     let found_key: ClassInstanceRef<Object> = jvm.invoke_virtual(&entry, "getKey", "()Ljava/lang/Object;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &found_key).await?, "entry-key");
@@ -739,9 +739,9 @@ async fn test_hash_map_key_set_view_backing_and_iterator_snapshot() -> Result<()
     }
 
     let key_set: ClassInstanceRef<Object> = jvm.invoke_virtual(&hash_map, "keySet", "()Ljava/util/Set;", ()).await?;
-    assert!(jvm.is_instance(&**key_set, "java/util/Set"));
-    assert!(jvm.is_instance(&**key_set, "java/util/Collection"));
-    assert!(jvm.is_instance(&**key_set, "java/util/AbstractSet"));
+    assert!(jvm.is_instance(&key_set, "java/util/Set"));
+    assert!(jvm.is_instance(&key_set, "java/util/Collection"));
+    assert!(jvm.is_instance(&key_set, "java/util/AbstractSet"));
 
     let size: i32 = jvm.invoke_virtual(&key_set, "size", "()I", ()).await?;
     assert_eq!(size, 2);
@@ -759,8 +759,11 @@ async fn test_hash_map_key_set_view_backing_and_iterator_snapshot() -> Result<()
     assert_eq!(key_array_values, vec![Some("first".to_string()), Some("second".to_string())]);
 
     let iterator: ClassInstanceRef<Object> = jvm.invoke_virtual(&key_set, "iterator", "()Ljava/util/Iterator;", ()).await?;
-    assert_eq!(iterator.class_definition().name(), "java/util/HashMap$KeyIterator");
-    assert!(jvm.is_instance(&**iterator, "java/util/Iterator"));
+    assert_eq!(
+        iterator.as_class_instance().unwrap().class_definition().name(),
+        "java/util/HashMap$KeyIterator"
+    );
+    assert!(jvm.is_instance(&iterator, "java/util/Iterator"));
     let _: ClassInstanceRef<Object> = jvm
         .invoke_virtual(
             &hash_map,
@@ -858,9 +861,9 @@ async fn test_hash_map_values_view_contains_clear_and_iterator_snapshot() -> Res
         .await?;
 
     let values: ClassInstanceRef<Object> = jvm.invoke_virtual(&hash_map, "values", "()Ljava/util/Collection;", ()).await?;
-    assert!(jvm.is_instance(&**values, "java/util/Collection"));
-    assert!(jvm.is_instance(&**values, "java/util/AbstractCollection"));
-    assert!(!jvm.is_instance(&**values, "java/util/Set"));
+    assert!(jvm.is_instance(&values, "java/util/Collection"));
+    assert!(jvm.is_instance(&values, "java/util/AbstractCollection"));
+    assert!(!jvm.is_instance(&values, "java/util/Set"));
 
     let size: i32 = jvm.invoke_virtual(&values, "size", "()I", ()).await?;
     assert_eq!(size, 2);
@@ -880,7 +883,10 @@ async fn test_hash_map_values_view_contains_clear_and_iterator_snapshot() -> Res
     assert_eq!(values_array, vec![None, Some("same-value".to_string())]);
 
     let iterator: ClassInstanceRef<Object> = jvm.invoke_virtual(&values, "iterator", "()Ljava/util/Iterator;", ()).await?;
-    assert_eq!(iterator.class_definition().name(), "java/util/HashMap$ValueIterator");
+    assert_eq!(
+        iterator.as_class_instance().unwrap().class_definition().name(),
+        "java/util/HashMap$ValueIterator"
+    );
     let _: ClassInstanceRef<Object> = jvm
         .invoke_virtual(
             &hash_map,
@@ -930,9 +936,9 @@ async fn test_hash_map_entry_set_view_backing_clear_and_iterator_snapshot() -> R
     }
 
     let entry_set: ClassInstanceRef<Object> = jvm.invoke_virtual(&hash_map, "entrySet", "()Ljava/util/Set;", ()).await?;
-    assert!(jvm.is_instance(&**entry_set, "java/util/Set"));
-    assert!(jvm.is_instance(&**entry_set, "java/util/Collection"));
-    assert!(jvm.is_instance(&**entry_set, "java/util/AbstractSet"));
+    assert!(jvm.is_instance(&entry_set, "java/util/Set"));
+    assert!(jvm.is_instance(&entry_set, "java/util/Collection"));
+    assert!(jvm.is_instance(&entry_set, "java/util/AbstractSet"));
 
     let size: i32 = jvm.invoke_virtual(&entry_set, "size", "()I", ()).await?;
     assert_eq!(size, 2);
@@ -940,8 +946,11 @@ async fn test_hash_map_entry_set_view_backing_clear_and_iterator_snapshot() -> R
     assert!(!is_empty);
 
     let iterator: ClassInstanceRef<Object> = jvm.invoke_virtual(&entry_set, "iterator", "()Ljava/util/Iterator;", ()).await?;
-    assert_eq!(iterator.class_definition().name(), "java/util/HashMap$EntryIterator");
-    assert!(jvm.is_instance(&**iterator, "java/util/Iterator"));
+    assert_eq!(
+        iterator.as_class_instance().unwrap().class_definition().name(),
+        "java/util/HashMap$EntryIterator"
+    );
+    assert!(jvm.is_instance(&iterator, "java/util/Iterator"));
     let _: ClassInstanceRef<Object> = jvm
         .invoke_virtual(
             &hash_map,
@@ -999,7 +1008,7 @@ async fn test_hash_map_entry_set_iterator_set_value_updates_backing_map() -> Res
     let entry_set: ClassInstanceRef<Object> = jvm.invoke_virtual(&hash_map, "entrySet", "()Ljava/util/Set;", ()).await?;
     let iterator: ClassInstanceRef<Object> = jvm.invoke_virtual(&entry_set, "iterator", "()Ljava/util/Iterator;", ()).await?;
     let entry: ClassInstanceRef<Object> = jvm.invoke_virtual(&iterator, "next", "()Ljava/lang/Object;", ()).await?;
-    assert!(jvm.is_instance(&**entry, "java/util/Map$Entry"));
+    assert!(jvm.is_instance(&entry, "java/util/Map$Entry"));
 
     let old_value: ClassInstanceRef<Object> = jvm
         .invoke_virtual(&entry, "setValue", "(Ljava/lang/Object;)Ljava/lang/Object;", (replacement.clone(),))
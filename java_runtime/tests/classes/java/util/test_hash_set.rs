@@ -163,7 +163,7 @@ async fn test_hash_set_to_array_and_iterator_snapshot_exhaustion_remove() -> Res
     assert_eq!(values, vec![Some("first".to_string()), Some("second".to_string())]);
 
     let iterator: ClassInstanceRef<Object> = jvm.invoke_virtual(&hash_set, "iterator", "()Ljava/util/Iterator;", ()).await?;
-    assert!(jvm.is_instance(&**iterator, "java/util/Iterator"));
+    assert!(jvm.is_instance(&iterator, "java/util/Iterator"));
 
     let _: bool = jvm.invoke_virtual(&hash_set, "add", "(Ljava/lang/Object;)Z", (third,)).await?;
 
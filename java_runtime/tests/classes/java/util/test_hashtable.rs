@@ -1,7 +1,7 @@
 use alloc::{vec, vec::Vec};
 
 use java_runtime::classes::java::lang::Object;
-use jvm::{ClassInstanceRef, JavaError, Jvm, Result, runtime::JavaLangString};
+use jvm::{AsClassInstance, ClassInstance, ClassInstanceRef, JavaError, Jvm, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm;
 
@@ -147,7 +147,7 @@ async fn test_hashtable_map_views_and_legacy_operations() -> Result<()> {
     let is_empty: bool = jvm.invoke_virtual(&hashtable, "isEmpty", "()Z", ()).await?;
     assert!(!is_empty);
 
-    let value = jvm
+    let value: Box<dyn ClassInstance> = jvm
         .invoke_virtual(&hashtable, "get", "(Ljava/lang/Object;)Ljava/lang/Object;", (test_key.clone(),))
         .await?;
 
@@ -156,7 +156,7 @@ async fn test_hashtable_map_views_and_legacy_operations() -> Result<()> {
 
     let test_key_second = JavaLangString::from_rust_string(&jvm, "testKey").await?;
 
-    let value = jvm
+    let value: Box<dyn ClassInstance> = jvm
         .invoke_virtual(&hashtable, "get", "(Ljava/lang/Object;)Ljava/lang/Object;", (test_key_second.clone(),))
         .await?;
 
@@ -178,9 +178,9 @@ async fn test_hashtable_map_views_and_legacy_operations() -> Result<()> {
     assert!(contains_value);
 
     let key_set: ClassInstanceRef<Object> = jvm.invoke_virtual(&hashtable, "keySet", "()Ljava/util/Set;", ()).await?;
-    assert!(jvm.is_instance(&**key_set, "java/util/Set"));
-    assert!(jvm.is_instance(&**key_set, "java/util/Collection"));
-    assert!(jvm.is_instance(&**key_set, "java/util/AbstractSet"));
+    assert!(jvm.is_instance(&key_set, "java/util/Set"));
+    assert!(jvm.is_instance(&key_set, "java/util/Collection"));
+    assert!(jvm.is_instance(&key_set, "java/util/AbstractSet"));
     let key_set_size: i32 = jvm.invoke_virtual(&key_set, "size", "()I", ()).await?;
     assert_eq!(key_set_size, 2);
     let contains_key: bool = jvm
@@ -193,16 +193,19 @@ async fn test_hashtable_map_views_and_legacy_operations() -> Result<()> {
         .await?;
     assert!(!contains_key);
     let key_iterator: ClassInstanceRef<Object> = jvm.invoke_virtual(&key_set, "iterator", "()Ljava/util/Iterator;", ()).await?;
-    assert_eq!(key_iterator.class_definition().name(), "java/util/Hashtable$Enumerator");
-    assert!(jvm.is_instance(&**key_iterator, "java/util/Iterator"));
+    assert_eq!(
+        key_iterator.as_class_instance().unwrap().class_definition().name(),
+        "java/util/Hashtable$Enumerator"
+    );
+    assert!(jvm.is_instance(&key_iterator, "java/util/Iterator"));
     let key_snapshot = sorted_optional_strings(iterator_to_optional_strings(&jvm, &key_iterator).await?);
     assert_eq!(key_snapshot, vec![Some("secondKey".to_string()), Some("testKey".to_string())]);
     assert_next_throws_no_such_element(&jvm, &key_iterator).await?;
     assert_remove_throws_unsupported(&jvm, &key_iterator).await?;
 
     let values: ClassInstanceRef<Object> = jvm.invoke_virtual(&hashtable, "values", "()Ljava/util/Collection;", ()).await?;
-    assert!(jvm.is_instance(&**values, "java/util/Collection"));
-    assert!(jvm.is_instance(&**values, "java/util/AbstractCollection"));
+    assert!(jvm.is_instance(&values, "java/util/Collection"));
+    assert!(jvm.is_instance(&values, "java/util/AbstractCollection"));
     let values_size: i32 = jvm.invoke_virtual(&values, "size", "()I", ()).await?;
     assert_eq!(values_size, 2);
     let contains_value: bool = jvm
@@ -215,21 +218,27 @@ async fn test_hashtable_map_views_and_legacy_operations() -> Result<()> {
         .await?;
     assert!(contains_value);
     let values_iterator: ClassInstanceRef<Object> = jvm.invoke_virtual(&values, "iterator", "()Ljava/util/Iterator;", ()).await?;
-    assert_eq!(values_iterator.class_definition().name(), "java/util/Hashtable$Enumerator");
+    assert_eq!(
+        values_iterator.as_class_instance().unwrap().class_definition().name(),
+        "java/util/Hashtable$Enumerator"
+    );
     let values_snapshot = sorted_optional_strings(iterator_to_optional_strings(&jvm, &values_iterator).await?);
     assert_eq!(values_snapshot, vec![Some("secondValue".to_string()), Some("testValue".to_string())]);
 
     let entry_set: ClassInstanceRef<Object> = jvm.invoke_virtual(&hashtable, "entrySet", "()Ljava/util/Set;", ()).await?;
-    assert!(jvm.is_instance(&**entry_set, "java/util/Set"));
-    assert!(jvm.is_instance(&**entry_set, "java/util/Collection"));
-    assert!(jvm.is_instance(&**entry_set, "java/util/AbstractSet"));
+    assert!(jvm.is_instance(&entry_set, "java/util/Set"));
+    assert!(jvm.is_instance(&entry_set, "java/util/Collection"));
+    assert!(jvm.is_instance(&entry_set, "java/util/AbstractSet"));
     let entry_set_size: i32 = jvm.invoke_virtual(&entry_set, "size", "()I", ()).await?;
     assert_eq!(entry_set_size, 2);
     let entry_iterator: ClassInstanceRef<Object> = jvm.invoke_virtual(&entry_set, "iterator", "()Ljava/util/Iterator;", ()).await?;
-    assert_eq!(entry_iterator.class_definition().name(), "java/util/Hashtable$Enumerator");
-    assert!(jvm.is_instance(&**entry_iterator, "java/util/Iterator"));
+    assert_eq!(
+        entry_iterator.as_class_instance().unwrap().class_definition().name(),
+        "java/util/Hashtable$Enumerator"
+    );
+    assert!(jvm.is_instance(&entry_iterator, "java/util/Iterator"));
     let entry: ClassInstanceRef<Object> = jvm.invoke_virtual(&entry_iterator, "next", "()Ljava/lang/Object;", ()).await?;
-    assert!(jvm.is_instance(&**entry, "java/util/Map$Entry"));
+    assert!(jvm.is_instance(&entry, "java/util/Map$Entry"));
     let contains_entry: bool = jvm
         .invoke_virtual(&entry_set, "contains", "(Ljava/lang/Object;)Z", (entry.clone(),))
         .await?;
@@ -385,7 +394,7 @@ async fn test_hashtable_legacy_null_and_snapshot_edges() -> Result<()> {
     let entry_set: ClassInstanceRef<Object> = jvm.invoke_virtual(&hashtable, "entrySet", "()Ljava/util/Set;", ()).await?;
     let entry_iterator: ClassInstanceRef<Object> = jvm.invoke_virtual(&entry_set, "iterator", "()Ljava/util/Iterator;", ()).await?;
     let entry: ClassInstanceRef<Object> = jvm.invoke_virtual(&entry_iterator, "next", "()Ljava/lang/Object;", ()).await?;
-    assert!(jvm.is_instance(&**entry, "java/util/Map$Entry"));
+    assert!(jvm.is_instance(&entry, "java/util/Map$Entry"));
     let result: Result<ClassInstanceRef<Object>> = jvm
         .invoke_virtual(&entry, "setValue", "(Ljava/lang/Object;)Ljava/lang/Object;", (null_ref.clone(),))
         .await;
@@ -1,5 +1,5 @@
 use java_runtime::classes::java::{lang::String, util::Locale};
-use jvm::{Array, ClassInstanceRef, JavaError, Result, runtime::JavaLangString};
+use jvm::{Array, ClassInstance, ClassInstanceRef, JavaError, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm;
 
@@ -18,23 +18,23 @@ async fn test_locale_constants_and_accessors() -> Result<()> {
     let jvm = test_jvm().await?;
 
     let english: ClassInstanceRef<Locale> = jvm.get_static_field("java/util/Locale", "ENGLISH", "Ljava/util/Locale;").await?;
-    let language = jvm.invoke_virtual(&english, "getLanguage", "()Ljava/lang/String;", ()).await?;
+    let language: Box<dyn ClassInstance> = jvm.invoke_virtual(&english, "getLanguage", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &language).await?, "en");
-    let country = jvm.invoke_virtual(&english, "getCountry", "()Ljava/lang/String;", ()).await?;
+    let country: Box<dyn ClassInstance> = jvm.invoke_virtual(&english, "getCountry", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &country).await?, "");
-    let variant = jvm.invoke_virtual(&english, "getVariant", "()Ljava/lang/String;", ()).await?;
+    let variant: Box<dyn ClassInstance> = jvm.invoke_virtual(&english, "getVariant", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &variant).await?, "");
 
-    let english_string = jvm.invoke_virtual(&english, "toString", "()Ljava/lang/String;", ()).await?;
+    let english_string: Box<dyn ClassInstance> = jvm.invoke_virtual(&english, "toString", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &english_string).await?, "en");
 
     let us: ClassInstanceRef<Locale> = jvm.get_static_field("java/util/Locale", "US", "Ljava/util/Locale;").await?;
-    let language = jvm.invoke_virtual(&us, "getLanguage", "()Ljava/lang/String;", ()).await?;
+    let language: Box<dyn ClassInstance> = jvm.invoke_virtual(&us, "getLanguage", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &language).await?, "en");
-    let country = jvm.invoke_virtual(&us, "getCountry", "()Ljava/lang/String;", ()).await?;
+    let country: Box<dyn ClassInstance> = jvm.invoke_virtual(&us, "getCountry", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &country).await?, "US");
 
-    let us_string = jvm.invoke_virtual(&us, "toString", "()Ljava/lang/String;", ()).await?;
+    let us_string: Box<dyn ClassInstance> = jvm.invoke_virtual(&us, "toString", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &us_string).await?, "en_US");
 
     Ok(())
@@ -56,14 +56,14 @@ async fn test_locale_constructors_to_string_equals_and_hash_code() -> Result<()>
         .await?
         .into();
 
-    let language = jvm.invoke_virtual(&locale, "getLanguage", "()Ljava/lang/String;", ()).await?;
+    let language: Box<dyn ClassInstance> = jvm.invoke_virtual(&locale, "getLanguage", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &language).await?, "en");
-    let country = jvm.invoke_virtual(&locale, "getCountry", "()Ljava/lang/String;", ()).await?;
+    let country: Box<dyn ClassInstance> = jvm.invoke_virtual(&locale, "getCountry", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &country).await?, "US");
-    let variant = jvm.invoke_virtual(&locale, "getVariant", "()Ljava/lang/String;", ()).await?;
+    let variant: Box<dyn ClassInstance> = jvm.invoke_virtual(&locale, "getVariant", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &variant).await?, "POSIX");
 
-    let text = jvm.invoke_virtual(&locale, "toString", "()Ljava/lang/String;", ()).await?;
+    let text: Box<dyn ClassInstance> = jvm.invoke_virtual(&locale, "toString", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &text).await?, "en_US_POSIX");
 
     let same: ClassInstanceRef<Locale> = jvm
@@ -102,9 +102,9 @@ async fn test_locale_default_round_trip_and_null_rejection() -> Result<()> {
     let jvm = test_jvm().await?;
 
     let default: ClassInstanceRef<Locale> = jvm.invoke_static("java/util/Locale", "getDefault", "()Ljava/util/Locale;", ()).await?;
-    let language = jvm.invoke_virtual(&default, "getLanguage", "()Ljava/lang/String;", ()).await?;
+    let language: Box<dyn ClassInstance> = jvm.invoke_virtual(&default, "getLanguage", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &language).await?, "en");
-    let country = jvm.invoke_virtual(&default, "getCountry", "()Ljava/lang/String;", ()).await?;
+    let country: Box<dyn ClassInstance> = jvm.invoke_virtual(&default, "getCountry", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &country).await?, "US");
 
     let custom: ClassInstanceRef<Locale> = jvm
@@ -123,7 +123,7 @@ async fn test_locale_default_round_trip_and_null_rejection() -> Result<()> {
         .await?;
 
     let updated: ClassInstanceRef<Locale> = jvm.invoke_static("java/util/Locale", "getDefault", "()Ljava/util/Locale;", ()).await?;
-    let country = jvm.invoke_virtual(&updated, "getCountry", "()Ljava/lang/String;", ()).await?;
+    let country: Box<dyn ClassInstance> = jvm.invoke_virtual(&updated, "getCountry", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &country).await?, "GB");
 
     let err = jvm
@@ -224,22 +224,22 @@ async fn test_locale_display_iso3_and_clone_helpers() -> Result<()> {
 
     let us: ClassInstanceRef<Locale> = jvm.get_static_field("java/util/Locale", "US", "Ljava/util/Locale;").await?;
 
-    let display_language = jvm.invoke_virtual(&us, "getDisplayLanguage", "()Ljava/lang/String;", ()).await?;
+    let display_language: Box<dyn ClassInstance> = jvm.invoke_virtual(&us, "getDisplayLanguage", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &display_language).await?, "English");
 
-    let display_country = jvm.invoke_virtual(&us, "getDisplayCountry", "()Ljava/lang/String;", ()).await?;
+    let display_country: Box<dyn ClassInstance> = jvm.invoke_virtual(&us, "getDisplayCountry", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &display_country).await?, "United States");
 
-    let display_variant = jvm.invoke_virtual(&us, "getDisplayVariant", "()Ljava/lang/String;", ()).await?;
+    let display_variant: Box<dyn ClassInstance> = jvm.invoke_virtual(&us, "getDisplayVariant", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &display_variant).await?, "");
 
-    let display_name = jvm.invoke_virtual(&us, "getDisplayName", "()Ljava/lang/String;", ()).await?;
+    let display_name: Box<dyn ClassInstance> = jvm.invoke_virtual(&us, "getDisplayName", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &display_name).await?, "English (United States)");
 
-    let iso3_language = jvm.invoke_virtual(&us, "getISO3Language", "()Ljava/lang/String;", ()).await?;
+    let iso3_language: Box<dyn ClassInstance> = jvm.invoke_virtual(&us, "getISO3Language", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &iso3_language).await?, "eng");
 
-    let iso3_country = jvm.invoke_virtual(&us, "getISO3Country", "()Ljava/lang/String;", ()).await?;
+    let iso3_country: Box<dyn ClassInstance> = jvm.invoke_virtual(&us, "getISO3Country", "()Ljava/lang/String;", ()).await?;
     assert_eq!(JavaLangString::to_rust_string(&jvm, &iso3_country).await?, "USA");
 
     let cloned: ClassInstanceRef<Locale> = jvm.invoke_virtual(&us, "clone", "()Ljava/lang/Object;", ()).await?;
@@ -53,7 +53,7 @@ async fn test_properties_inherits_hashtable_map_contract() -> Result<()> {
     let size: i32 = jvm.invoke_virtual(&properties, "size", "()I", ()).await?;
     assert_eq!(size, 2);
     let key_set: ClassInstanceRef<Object> = jvm.invoke_virtual(&properties, "keySet", "()Ljava/util/Set;", ()).await?;
-    assert!(jvm.is_instance(&**key_set, "java/util/Set"));
+    assert!(jvm.is_instance(&key_set, "java/util/Set"));
 
     Ok(())
 }
@@ -425,7 +425,7 @@ async fn test_vector_itr_wrapper() -> Result<()> {
     let _: bool = jvm.invoke_virtual(&vector, "add", "(Ljava/lang/Object;)Z", (second,)).await?;
 
     let iterator: ClassInstanceRef<Object> = jvm.invoke_virtual(&vector, "iterator", "()Ljava/util/Iterator;", ()).await?;
-    assert!(jvm.is_instance(&**iterator, "java/util/Iterator"));
+    assert!(jvm.is_instance(&iterator, "java/util/Iterator"));
 
     let third = JavaLangString::from_rust_string(&jvm, "third").await?;
     let _: bool = jvm.invoke_virtual(&vector, "add", "(Ljava/lang/Object;)Z", (third,)).await?;
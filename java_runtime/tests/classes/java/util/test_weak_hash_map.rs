@@ -0,0 +1,246 @@
+use java_runtime::classes::java::{lang::Object, util::WeakHashMap};
+use jvm::{ClassInstance, ClassInstanceRef, JavaError, Jvm, Result, runtime::JavaLangString};
+
+use test_utils::test_jvm;
+
+async fn string(jvm: &Jvm, value: &str) -> Result<ClassInstanceRef<Object>> {
+    Ok(JavaLangString::from_rust_string(jvm, value).await?.into())
+}
+
+#[tokio::test]
+async fn test_weak_hash_map() -> Result<()> {
+    let jvm = test_jvm().await?;
+
+    let map: ClassInstanceRef<WeakHashMap> = jvm.new_class("java/util/WeakHashMap", "()V", ()).await?.into();
+    let strong_key = string(&jvm, "strong").await?;
+
+    let _: ClassInstanceRef<Object> = jvm
+        .invoke_virtual(
+            &map,
+            "put",
+            "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+            (strong_key.clone(), string(&jvm, "1").await?),
+        )
+        .await?;
+    let _: ClassInstanceRef<Object> = jvm
+        .invoke_virtual(
+            &map,
+            "put",
+            "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+            (None, string(&jvm, "null").await?),
+        )
+        .await?;
+
+    let frame = jvm.push_native_frame()?;
+    let weak_key = string(&jvm, "weak").await?;
+    let _: ClassInstanceRef<Object> = jvm
+        .invoke_virtual(
+            &map,
+            "put",
+            "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+            (weak_key, string(&jvm, "2").await?),
+        )
+        .await?;
+    drop(frame);
+
+    // lookups use equals(), not identity
+    let value: Box<dyn ClassInstance> = jvm
+        .invoke_virtual(&map, "get", "(Ljava/lang/Object;)Ljava/lang/Object;", (string(&jvm, "weak").await?,))
+        .await?;
+    assert_eq!(JavaLangString::to_rust_string(&jvm, &value).await?, "2");
+    let size: i32 = jvm.invoke_virtual(&map, "size", "()I", ()).await?;
+    assert_eq!(size, 3);
+
+    // the entry goes away once its key is collected
+    jvm.collect_garbage()?;
+
+    let size: i32 = jvm.invoke_virtual(&map, "size", "()I", ()).await?;
+    assert_eq!(size, 2);
+    let contains: bool = jvm
+        .invoke_virtual(&map, "containsKey", "(Ljava/lang/Object;)Z", (string(&jvm, "weak").await?,))
+        .await?;
+    assert!(!contains);
+
+    let value: Box<dyn ClassInstance> = jvm
+        .invoke_virtual(&map, "get", "(Ljava/lang/Object;)Ljava/lang/Object;", (string(&jvm, "strong").await?,))
+        .await?;
+    assert_eq!(JavaLangString::to_rust_string(&jvm, &value).await?, "1");
+    let value: Box<dyn ClassInstance> = jvm.invoke_virtual(&map, "get", "(Ljava/lang/Object;)Ljava/lang/Object;", (None,)).await?;
+    assert_eq!(JavaLangString::to_rust_string(&jvm, &value).await?, "null");
+
+    let removed: Box<dyn ClassInstance> = jvm
+        .invoke_virtual(&map, "remove", "(Ljava/lang/Object;)Ljava/lang/Object;", (strong_key,))
+        .await?;
+    assert_eq!(JavaLangString::to_rust_string(&jvm, &removed).await?, "1");
+
+    let _: () = jvm.invoke_virtual(&map, "clear", "()V", ()).await?;
+    let is_empty: bool = jvm.invoke_virtual(&map, "isEmpty", "()Z", ()).await?;
+    assert!(is_empty);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_weak_hash_map_views() -> Result<()> {
+    let jvm = test_jvm().await?;
+
+    let map: ClassInstanceRef<WeakHashMap> = jvm.new_class("java/util/WeakHashMap", "()V", ()).await?.into();
+    let strong_key = string(&jvm, "strong").await?;
+
+    let _: ClassInstanceRef<Object> = jvm
+        .invoke_virtual(
+            &map,
+            "put",
+            "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+            (strong_key.clone(), string(&jvm, "1").await?),
+        )
+        .await?;
+    let _: ClassInstanceRef<Object> = jvm
+        .invoke_virtual(
+            &map,
+            "put",
+            "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+            (None, string(&jvm, "null").await?),
+        )
+        .await?;
+
+    let frame = jvm.push_native_frame()?;
+    let weak_key = string(&jvm, "weak").await?;
+    let _: ClassInstanceRef<Object> = jvm
+        .invoke_virtual(
+            &map,
+            "put",
+            "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+            (weak_key, string(&jvm, "2").await?),
+        )
+        .await?;
+    drop(frame);
+
+    let contains: bool = jvm
+        .invoke_virtual(&map, "containsValue", "(Ljava/lang/Object;)Z", (string(&jvm, "2").await?,))
+        .await?;
+    assert!(contains);
+
+    jvm.collect_garbage()?;
+
+    let contains: bool = jvm
+        .invoke_virtual(&map, "containsValue", "(Ljava/lang/Object;)Z", (string(&jvm, "2").await?,))
+        .await?;
+    assert!(!contains);
+
+    // views hold the live entries, with the null key unwrapped
+    let key_set: Box<dyn ClassInstance> = jvm.invoke_virtual(&map, "keySet", "()Ljava/util/Set;", ()).await?;
+    let size: i32 = jvm.invoke_virtual(&key_set, "size", "()I", ()).await?;
+    assert_eq!(size, 2);
+    let contains: bool = jvm.invoke_virtual(&key_set, "contains", "(Ljava/lang/Object;)Z", (strong_key,)).await?;
+    assert!(contains);
+    let contains: bool = jvm.invoke_virtual(&key_set, "contains", "(Ljava/lang/Object;)Z", (None,)).await?;
+    assert!(contains);
+
+    let values: Box<dyn ClassInstance> = jvm.invoke_virtual(&map, "values", "()Ljava/util/Collection;", ()).await?;
+    let size: i32 = jvm.invoke_virtual(&values, "size", "()I", ()).await?;
+    assert_eq!(size, 2);
+    let contains: bool = jvm
+        .invoke_virtual(&values, "contains", "(Ljava/lang/Object;)Z", (string(&jvm, "1").await?,))
+        .await?;
+    assert!(contains);
+
+    let entry_set: Box<dyn ClassInstance> = jvm.invoke_virtual(&map, "entrySet", "()Ljava/util/Set;", ()).await?;
+    let size: i32 = jvm.invoke_virtual(&entry_set, "size", "()I", ()).await?;
+    assert_eq!(size, 2);
+
+    let iterator: Box<dyn ClassInstance> = jvm.invoke_virtual(&entry_set, "iterator", "()Ljava/util/Iterator;", ()).await?;
+    let mut keys = Vec::new();
+    while jvm.invoke_virtual(&iterator, "hasNext", "()Z", ()).await? {
+        let entry: Box<dyn ClassInstance> = jvm.invoke_virtual(&iterator, "next", "()Ljava/lang/Object;", ()).await?;
+        let key: ClassInstanceRef<Object> = jvm.invoke_virtual(&entry, "getKey", "()Ljava/lang/Object;", ()).await?;
+        keys.push(if key.is_null() {
+            "<null>".to_string()
+        } else {
+            JavaLangString::to_rust_string(&jvm, &key).await?
+        });
+    }
+    keys.sort();
+    assert_eq!(keys, ["<null>", "strong"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_weak_hash_map_views_write_through() -> Result<()> {
+    let jvm = test_jvm().await?;
+
+    let map: ClassInstanceRef<WeakHashMap> = jvm.new_class("java/util/WeakHashMap", "()V", ()).await?.into();
+    let keys = [
+        string(&jvm, "a").await?,
+        string(&jvm, "b").await?,
+        string(&jvm, "c").await?,
+        string(&jvm, "d").await?,
+    ];
+    for (key, value) in keys.iter().zip(["1", "2", "3", "4"]) {
+        let _: ClassInstanceRef<Object> = jvm
+            .invoke_virtual(
+                &map,
+                "put",
+                "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+                (key.clone(), string(&jvm, value).await?),
+            )
+            .await?;
+    }
+
+    // views taken before the changes still see them
+    let key_set: Box<dyn ClassInstance> = jvm.invoke_virtual(&map, "keySet", "()Ljava/util/Set;", ()).await?;
+    let values: Box<dyn ClassInstance> = jvm.invoke_virtual(&map, "values", "()Ljava/util/Collection;", ()).await?;
+    let entry_set: Box<dyn ClassInstance> = jvm.invoke_virtual(&map, "entrySet", "()Ljava/util/Set;", ()).await?;
+
+    let removed: bool = jvm
+        .invoke_virtual(&key_set, "remove", "(Ljava/lang/Object;)Z", (keys[0].clone(),))
+        .await?;
+    assert!(removed);
+    let removed: bool = jvm
+        .invoke_virtual(&values, "remove", "(Ljava/lang/Object;)Z", (string(&jvm, "2").await?,))
+        .await?;
+    assert!(removed);
+    let size: i32 = jvm.invoke_virtual(&map, "size", "()I", ()).await?;
+    assert_eq!(size, 2);
+    let size: i32 = jvm.invoke_virtual(&entry_set, "size", "()I", ()).await?;
+    assert_eq!(size, 2);
+
+    // setValue and remove on the entry set iterator reach the map
+    let iterator: Box<dyn ClassInstance> = jvm.invoke_virtual(&entry_set, "iterator", "()Ljava/util/Iterator;", ()).await?;
+    while jvm.invoke_virtual(&iterator, "hasNext", "()Z", ()).await? {
+        let entry: Box<dyn ClassInstance> = jvm.invoke_virtual(&iterator, "next", "()Ljava/lang/Object;", ()).await?;
+        let key: ClassInstanceRef<Object> = jvm.invoke_virtual(&entry, "getKey", "()Ljava/lang/Object;", ()).await?;
+        if JavaLangString::to_rust_string(&jvm, &key).await? == "c" {
+            let _: () = jvm.invoke_virtual(&iterator, "remove", "()V", ()).await?;
+        } else {
+            let _: ClassInstanceRef<Object> = jvm
+                .invoke_virtual(&entry, "setValue", "(Ljava/lang/Object;)Ljava/lang/Object;", (string(&jvm, "5").await?,))
+                .await?;
+        }
+    }
+
+    let contains: bool = jvm
+        .invoke_virtual(&map, "containsKey", "(Ljava/lang/Object;)Z", (keys[2].clone(),))
+        .await?;
+    assert!(!contains);
+    let value: Box<dyn ClassInstance> = jvm
+        .invoke_virtual(&map, "get", "(Ljava/lang/Object;)Ljava/lang/Object;", (keys[3].clone(),))
+        .await?;
+    assert_eq!(JavaLangString::to_rust_string(&jvm, &value).await?, "5");
+
+    // a second remove without next() is rejected
+    let iterator: Box<dyn ClassInstance> = jvm.invoke_virtual(&key_set, "iterator", "()Ljava/util/Iterator;", ()).await?;
+    let _: ClassInstanceRef<Object> = jvm.invoke_virtual(&iterator, "next", "()Ljava/lang/Object;", ()).await?;
+    let _: () = jvm.invoke_virtual(&iterator, "remove", "()V", ()).await?;
+    let result: Result<()> = jvm.invoke_virtual(&iterator, "remove", "()V", ()).await;
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("Expected JavaException, got {:?}", result);
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/IllegalStateException"));
+
+    let is_empty: bool = jvm.invoke_virtual(&map, "isEmpty", "()Z", ()).await?;
+    assert!(is_empty);
+
+    Ok(())
+}
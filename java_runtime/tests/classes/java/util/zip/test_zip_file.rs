@@ -1,6 +1,6 @@
 use alloc::vec;
 
-use jvm::{Result, runtime::JavaLangString};
+use jvm::{ClassInstance, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm_filesystem;
 
@@ -15,13 +15,13 @@ async fn test_zip_entry() -> Result<()> {
     let zip = jvm.new_class("java/util/zip/ZipFile", "(Ljava/io/File;)V", (file,)).await?;
 
     let entry_name = JavaLangString::from_rust_string(&jvm, "test.txt").await?;
-    let entry = jvm
+    let entry: Box<dyn ClassInstance> = jvm
         .invoke_virtual(&zip, "getEntry", "(Ljava/lang/String;)Ljava/util/zip/ZipEntry;", (entry_name,))
         .await?;
 
     let size: i64 = jvm.invoke_virtual(&entry, "getSize", "()J", ()).await?;
 
-    let is = jvm
+    let is: Box<dyn ClassInstance> = jvm
         .invoke_virtual(&zip, "getInputStream", "(Ljava/util/zip/ZipEntry;)Ljava/io/InputStream;", (entry,))
         .await?;
 
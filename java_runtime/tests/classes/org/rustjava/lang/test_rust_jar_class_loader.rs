@@ -31,7 +31,7 @@ async fn test_find_class_uses_rustjar_runtime_source() -> Result<()> {
     assert!(!class.is_null());
 
     let defining_loader: ClassInstanceRef<ClassLoader> = jvm.get_field(&class, "classLoader", "Ljava/lang/ClassLoader;").await?;
-    assert!(jvm.is_instance(&**defining_loader, "org/rustjava/lang/RustJarClassLoader"));
+    assert!(jvm.is_instance(&defining_loader, "org/rustjava/lang/RustJarClassLoader"));
 
     Ok(())
 }
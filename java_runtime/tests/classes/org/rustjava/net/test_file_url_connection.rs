@@ -1,6 +1,6 @@
 use alloc::vec;
 
-use jvm::{Result, runtime::JavaLangString};
+use jvm::{ClassInstance, Result, runtime::JavaLangString};
 
 use test_utils::test_jvm_filesystem;
 
@@ -12,7 +12,7 @@ async fn test_file_url() -> Result<()> {
     let url_spec = JavaLangString::from_rust_string(&jvm, "file:test.txt").await?;
     let url = jvm.new_class("java/net/URL", "(Ljava/lang/String;)V", (url_spec,)).await?;
 
-    let stream = jvm.invoke_virtual(&url, "openStream", "()Ljava/io/InputStream;", ()).await?;
+    let stream: Box<dyn ClassInstance> = jvm.invoke_virtual(&url, "openStream", "()Ljava/io/InputStream;", ()).await?;
 
     let buf = jvm.instantiate_array("B", 17).await?;
     let len: i32 = jvm.invoke_virtual(&stream, "read", "([B)I", (buf.clone(),)).await?;
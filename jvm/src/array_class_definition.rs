@@ -7,10 +7,13 @@ use alloc::{
 use dyn_clone::clone_trait_object;
 use java_constants::ClassAccessFlags;
 
-use crate::{Jvm, Result, class_definition::ClassDefinition, class_instance::ClassInstance, field::Field, method::Method, value::JavaValue};
+use crate::{
+    JavaError, Jvm, Result, class_definition::ClassDefinition, class_instance::ClassInstance, field::Field, method::Method, value::JavaValue,
+};
 
 #[async_trait::async_trait]
 pub trait ArrayClassDefinition: ClassDefinition {
+    fn array_identity(&self) -> usize;
     fn element_type_name(&self) -> String;
     async fn instantiate_array(&self, jvm: &Jvm, length: usize) -> Result<Box<dyn ClassInstance>>;
 }
@@ -19,6 +22,10 @@ clone_trait_object!(ArrayClassDefinition);
 
 #[async_trait::async_trait]
 impl<T: ArrayClassDefinition> ClassDefinition for T {
+    fn identity(&self) -> usize {
+        self.array_identity()
+    }
+
     fn name(&self) -> String {
         format!("[{}", self.element_type_name())
     }
@@ -36,7 +43,7 @@ impl<T: ArrayClassDefinition> ClassDefinition for T {
     }
 
     async fn instantiate(&self, _: &Jvm) -> Result<Box<dyn ClassInstance>> {
-        panic!("Cannot instantiate array class")
+        Err(JavaError::internal("Cannot instantiate array class"))
     }
 
     async fn prepare(&self, _: &Jvm) -> Result<()> {
@@ -47,6 +54,10 @@ impl<T: ArrayClassDefinition> ClassDefinition for T {
         None
     }
 
+    fn methods(&self) -> Vec<Box<dyn Method>> {
+        Vec::new()
+    }
+
     fn field(&self, _name: &str, _descriptor: &str, _is_static: bool) -> Option<Box<dyn Field>> {
         None
     }
@@ -56,11 +67,11 @@ impl<T: ArrayClassDefinition> ClassDefinition for T {
     }
 
     fn get_static_field(&self, _field: &dyn Field) -> Result<JavaValue> {
-        panic!("Array classes do not have static fields")
+        Err(JavaError::internal("Array classes do not have static fields"))
     }
 
     fn put_static_field(&mut self, _field: &dyn Field, _value: JavaValue) -> Result<()> {
-        panic!("Array classes do not have static fields")
+        Err(JavaError::internal("Array classes do not have static fields"))
     }
 
     fn as_array_class_definition(&self) -> Option<&dyn ArrayClassDefinition> {
@@ -9,6 +9,7 @@ use crate::{ArrayClassDefinition, ClassInstance, Field, JavaValue, Jvm, Method,
 
 #[async_trait::async_trait]
 pub trait ClassDefinition: Sync + Send + AsAny + Debug + DynClone {
+    fn identity(&self) -> usize;
     fn name(&self) -> String;
     fn super_class_name(&self) -> Option<String>;
     fn interface_names(&self) -> Vec<String>;
@@ -16,6 +17,7 @@ pub trait ClassDefinition: Sync + Send + AsAny + Debug + DynClone {
     async fn instantiate(&self, jvm: &Jvm) -> Result<Box<dyn ClassInstance>>;
     async fn prepare(&self, jvm: &Jvm) -> Result<()>;
     fn method(&self, name: &str, descriptor: &str, is_static: bool) -> Option<Box<dyn Method>>;
+    fn methods(&self) -> Vec<Box<dyn Method>>;
     fn field(&self, name: &str, descriptor: &str, is_static: bool) -> Option<Box<dyn Field>>;
     fn fields(&self) -> Vec<Box<dyn Field>>;
     fn get_static_field(&self, field: &dyn Field) -> Result<JavaValue>; // TODO do we need to split class? or rename classdefinition?
@@ -8,7 +8,7 @@ use core::{
 use dyn_clone::{DynClone, clone_trait_object};
 use dyn_hash::{DynHash, hash_trait_object};
 
-use crate::{ArrayClassInstance, ClassDefinition, Field, Result, as_any::AsAny, value::JavaValue};
+use crate::{ArrayClassInstance, ClassDefinition, Field, JavaError, Result, as_any::AsAny, value::JavaValue};
 
 #[async_trait::async_trait]
 pub trait ClassInstance: Sync + Send + AsAny + Debug + DynHash + DynClone + 'static {
@@ -27,25 +27,42 @@ pub trait ClassInstance: Sync + Send + AsAny + Debug + DynHash + DynClone + 'sta
     }
 }
 
+// object references accepted by the jvm apis, None being null
 pub trait AsClassInstance {
-    fn as_class_instance(&self) -> &dyn ClassInstance;
+    fn as_class_instance(&self) -> Option<&dyn ClassInstance>;
+}
+
+pub trait AsClassInstanceMut: AsClassInstance {
+    fn as_class_instance_mut(&mut self) -> Option<&mut dyn ClassInstance>;
 }
 
 impl<T: ClassInstance> AsClassInstance for T {
-    fn as_class_instance(&self) -> &dyn ClassInstance {
-        self
+    fn as_class_instance(&self) -> Option<&dyn ClassInstance> {
+        Some(self)
     }
 }
 
 impl AsClassInstance for dyn ClassInstance {
-    fn as_class_instance(&self) -> &dyn ClassInstance {
-        self
+    fn as_class_instance(&self) -> Option<&dyn ClassInstance> {
+        Some(self)
     }
 }
 
 impl AsClassInstance for Box<dyn ClassInstance> {
-    fn as_class_instance(&self) -> &dyn ClassInstance {
-        self.as_ref()
+    fn as_class_instance(&self) -> Option<&dyn ClassInstance> {
+        Some(self.as_ref())
+    }
+}
+
+impl AsClassInstanceMut for Box<dyn ClassInstance> {
+    fn as_class_instance_mut(&mut self) -> Option<&mut dyn ClassInstance> {
+        Some(self.as_mut())
+    }
+}
+
+impl AsClassInstance for Option<Box<dyn ClassInstance>> {
+    fn as_class_instance(&self) -> Option<&dyn ClassInstance> {
+        self.as_deref()
     }
 }
 
@@ -55,7 +72,7 @@ hash_trait_object!(ClassInstance);
 impl Eq for dyn ClassInstance {}
 impl PartialEq for dyn ClassInstance {
     fn eq(&self, other: &Self) -> bool {
-        self.equals(other).unwrap()
+        self.equals(other).unwrap_or(false)
     }
 }
 
@@ -102,6 +119,7 @@ impl<T> Debug for ClassInstanceRef<T> {
     }
 }
 
+// panics on null. the jvm apis take AsClassInstance instead, which throws NullPointerException
 impl<T> Deref for ClassInstanceRef<T> {
     type Target = Box<dyn ClassInstance>;
     fn deref(&self) -> &Self::Target {
@@ -116,8 +134,14 @@ impl<T> DerefMut for ClassInstanceRef<T> {
 }
 
 impl<T> AsClassInstance for ClassInstanceRef<T> {
-    fn as_class_instance(&self) -> &dyn ClassInstance {
-        self.instance.as_deref().unwrap()
+    fn as_class_instance(&self) -> Option<&dyn ClassInstance> {
+        self.instance.as_deref()
+    }
+}
+
+impl<T> AsClassInstanceMut for ClassInstanceRef<T> {
+    fn as_class_instance_mut(&mut self) -> Option<&mut dyn ClassInstance> {
+        self.instance.as_deref_mut()
     }
 }
 
@@ -145,18 +169,22 @@ impl<T> From<Option<Box<dyn ClassInstance>>> for ClassInstanceRef<T> {
     }
 }
 
-impl<T> From<JavaValue> for ClassInstanceRef<T> {
-    fn from(val: JavaValue) -> Self {
-        ClassInstanceRef {
-            instance: val.into(),
+impl<T> TryFrom<JavaValue> for ClassInstanceRef<T> {
+    type Error = JavaError;
+
+    fn try_from(val: JavaValue) -> Result<Self> {
+        Ok(ClassInstanceRef {
+            instance: val.try_into()?,
             _phantom: PhantomData,
-        }
+        })
     }
 }
 
-impl<T> From<ClassInstanceRef<T>> for Box<dyn ClassInstance> {
-    fn from(value: ClassInstanceRef<T>) -> Self {
-        value.instance.unwrap()
+impl<T> TryFrom<ClassInstanceRef<T>> for Box<dyn ClassInstance> {
+    type Error = JavaError;
+
+    fn try_from(value: ClassInstanceRef<T>) -> Result<Self> {
+        value.instance.ok_or_else(|| JavaError::internal("Expected non-null reference"))
     }
 }
 
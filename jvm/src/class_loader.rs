@@ -1,13 +1,16 @@
-use alloc::{boxed::Box, sync::Arc};
+use alloc::{boxed::Box, format, sync::Arc};
 
 use event_listener::{Event, EventListener};
 use parking_lot::{Mutex, RwLock};
 
 use crate::{
-    ClassDefinition, ClassInstance, Jvm, Result,
+    ClassDefinition, ClassInstance, JavaError, Jvm, Result,
     runtime::{JavaLangClass, JavaLangClassLoader},
 };
 
+// identity of a class loader instance, None being the bootstrap class loader
+pub(crate) type ClassLoaderId = Option<usize>;
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub(crate) enum InitState {
     NotInitialized,
@@ -86,8 +89,12 @@ impl Class {
         *self.java_class.write() = Some(java_class);
     }
 
-    pub fn java_class(&self) -> Box<dyn ClassInstance> {
-        self.java_class.read().clone().unwrap()
+    // set once the java/lang/Class instance is created, right after the class is registered
+    pub fn java_class(&self) -> Result<Box<dyn ClassInstance>> {
+        self.java_class
+            .read()
+            .clone()
+            .ok_or_else(|| JavaError::internal(format!("Class {} has no java/lang/Class instance yet", self.definition.name())))
     }
 }
 
@@ -98,6 +105,7 @@ pub trait BootstrapClassLoader: Sync + Send {
 
 #[async_trait::async_trait]
 pub trait ClassLoaderWrapper: Sync + Send {
+    fn class_loader_id(&self) -> Option<usize>;
     async fn load_class(&self, jvm: &Jvm, name: &str) -> Result<Option<Class>>;
 }
 
@@ -113,14 +121,17 @@ impl<'a> BootstrapClassLoaderWrapper<'a> {
 
 #[async_trait::async_trait]
 impl ClassLoaderWrapper for BootstrapClassLoaderWrapper<'_> {
+    fn class_loader_id(&self) -> Option<usize> {
+        None
+    }
+
     async fn load_class(&self, jvm: &Jvm, name: &str) -> Result<Option<Class>> {
         let definition = self.bootstrap_class_loader.load_class(jvm, name).await?;
         if let Some(definition) = definition {
             let java_class = JavaLangClass::from_rust_class(jvm, definition.clone(), None).await?;
             let class = Class::new(definition, Some(java_class));
-            jvm.register_class_internal(class.clone(), Some(self)).await?;
 
-            Ok(Some(class))
+            Ok(Some(jvm.register_class_internal(class, Some(self)).await?))
         } else {
             Ok(None)
         }
@@ -139,6 +150,10 @@ impl JavaClassLoaderWrapper {
 
 #[async_trait::async_trait]
 impl ClassLoaderWrapper for JavaClassLoaderWrapper {
+    fn class_loader_id(&self) -> Option<usize> {
+        Some(self.class_loader.identity())
+    }
+
     async fn load_class(&self, jvm: &Jvm, name: &str) -> Result<Option<Class>> {
         let class = JavaLangClassLoader::load_class(jvm, &self.class_loader, name).await?;
 
@@ -0,0 +1,49 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display, Formatter};
+
+// a cycle of threads, each blocked entering a monitor owned by the next one
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Deadlock {
+    pub threads: Vec<DeadlockedThread>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadlockedThread {
+    pub thread_id: u64,
+    pub name: String,
+    // the monitor this thread is blocked on
+    pub object_identity: usize,
+    pub class_name: String,
+    pub owner_thread_id: u64,
+    pub owner_name: String,
+    pub stack_trace: Vec<String>,
+}
+
+// formatted like the deadlock section of a HotSpot thread dump
+impl Display for Deadlock {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Found one Java-level deadlock:")?;
+        writeln!(f, "=============================")?;
+        for thread in &self.threads {
+            writeln!(f, "\"{}\":", thread.name)?;
+            writeln!(
+                f,
+                "  waiting to lock monitor {:#x} (object of {}),",
+                thread.object_identity, thread.class_name
+            )?;
+            writeln!(f, "  which is held by \"{}\"", thread.owner_name)?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Java stack information for the threads listed above:")?;
+        writeln!(f, "===================================================")?;
+        for thread in &self.threads {
+            writeln!(f, "\"{}\":", thread.name)?;
+            for frame in &thread.stack_trace {
+                writeln!(f, "\tat {frame}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
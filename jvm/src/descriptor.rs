@@ -0,0 +1,181 @@
+use alloc::{string::String, vec::Vec};
+use core::marker::PhantomData;
+
+use crate::{Array, ClassInstanceRef, JavaChar};
+
+// rust types with a fixed java type, used to derive descriptors from rust signatures.
+// consts, so generated bindings don't build the descriptor on every call
+pub trait JavaDescriptor {
+    const DESCRIPTOR: &'static str;
+}
+
+// class marker types, the `T` of `ClassInstanceRef<T>`
+pub trait JavaClassName {
+    const NAME: &'static str;
+}
+
+impl<T: JavaClassName> JavaDescriptor for T {
+    const DESCRIPTOR: &'static str = ConstStr::concat(&["L", T::NAME, ";"]).as_str();
+}
+
+// method parameter lists, as `(T, rest)` pairs ending in `()`, so there's no limit on the parameter count
+pub trait JavaParameters {
+    const DESCRIPTOR: &'static str;
+}
+
+impl JavaParameters for () {
+    const DESCRIPTOR: &'static str = "";
+}
+
+impl<T: JavaDescriptor, U: JavaParameters> JavaParameters for (T, U) {
+    const DESCRIPTOR: &'static str = ConstStr::concat(&[T::DESCRIPTOR, U::DESCRIPTOR]).as_str();
+}
+
+// `MethodDescriptor::<(i64, (ClassInstanceRef<Object>, ())), ()>::DESCRIPTOR` is "(JLjava/lang/Object;)V"
+pub struct MethodDescriptor<P, R>(PhantomData<(P, R)>);
+
+impl<P: JavaParameters, R: JavaDescriptor> MethodDescriptor<P, R> {
+    pub const DESCRIPTOR: &'static str = ConstStr::concat(&["(", P::DESCRIPTOR, ")", R::DESCRIPTOR]).as_str();
+}
+
+// descriptors can't be concatenated with `format!` in consts, so they're built in a fixed size buffer
+const MAX_DESCRIPTOR_LENGTH: usize = 512;
+
+struct ConstStr {
+    bytes: [u8; MAX_DESCRIPTOR_LENGTH],
+    len: usize,
+}
+
+impl ConstStr {
+    const fn concat(parts: &[&str]) -> Self {
+        let mut bytes = [0; MAX_DESCRIPTOR_LENGTH];
+        let mut len = 0;
+
+        let mut i = 0;
+        while i < parts.len() {
+            let part = parts[i].as_bytes();
+            assert!(len + part.len() <= MAX_DESCRIPTOR_LENGTH, "descriptor too long");
+
+            let mut j = 0;
+            while j < part.len() {
+                bytes[len] = part[j];
+                len += 1;
+                j += 1;
+            }
+            i += 1;
+        }
+
+        Self { bytes, len }
+    }
+
+    const fn as_str(&self) -> &str {
+        match core::str::from_utf8(self.bytes.split_at(self.len).0) {
+            Ok(x) => x,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+macro_rules! impl_primitive_descriptor {
+    ($($type: ty => $descriptor: literal),*) => {
+        $(
+            impl JavaDescriptor for $type {
+                const DESCRIPTOR: &'static str = $descriptor;
+            }
+        )*
+    };
+}
+
+impl_primitive_descriptor!(
+    () => "V",
+    bool => "Z",
+    i8 => "B",
+    JavaChar => "C",
+    i16 => "S",
+    i32 => "I",
+    i64 => "J",
+    f32 => "F",
+    f64 => "D"
+);
+
+impl<T: JavaDescriptor> JavaDescriptor for Array<T> {
+    const DESCRIPTOR: &'static str = ConstStr::concat(&["[", T::DESCRIPTOR]).as_str();
+}
+
+impl<T: JavaDescriptor> JavaDescriptor for ClassInstanceRef<T> {
+    const DESCRIPTOR: &'static str = T::DESCRIPTOR;
+}
+
+impl JavaDescriptor for String {
+    const DESCRIPTOR: &'static str = "Ljava/lang/String;";
+}
+
+// nullable references
+impl JavaDescriptor for Option<String> {
+    const DESCRIPTOR: &'static str = String::DESCRIPTOR;
+}
+
+impl<T: JavaDescriptor> JavaDescriptor for Option<ClassInstanceRef<T>> {
+    const DESCRIPTOR: &'static str = T::DESCRIPTOR;
+}
+
+// arrays, `Vec<u8>` being a byte[] as well
+impl<T: JavaDescriptor> JavaDescriptor for Vec<T> {
+    const DESCRIPTOR: &'static str = ConstStr::concat(&["[", T::DESCRIPTOR]).as_str();
+}
+
+impl JavaDescriptor for Vec<u8> {
+    const DESCRIPTOR: &'static str = "[B";
+}
+
+// boxed primitives, None being null
+macro_rules! impl_boxed_descriptor {
+    ($($type: ty => $class: literal),*) => {
+        $(
+            impl JavaDescriptor for Option<$type> {
+                const DESCRIPTOR: &'static str = concat!("L", $class, ";");
+            }
+        )*
+    };
+}
+
+impl_boxed_descriptor!(
+    bool => "java/lang/Boolean",
+    i8 => "java/lang/Byte",
+    JavaChar => "java/lang/Character",
+    i16 => "java/lang/Short",
+    i32 => "java/lang/Integer",
+    i64 => "java/lang/Long",
+    f32 => "java/lang/Float",
+    f64 => "java/lang/Double"
+);
+
+#[cfg(test)]
+mod test {
+    use alloc::{string::String, vec::Vec};
+
+    use crate::{Array, ClassInstanceRef};
+
+    use super::{JavaClassName, JavaDescriptor, MethodDescriptor};
+
+    struct Object;
+
+    impl JavaClassName for Object {
+        const NAME: &'static str = "java/lang/Object";
+    }
+
+    #[test]
+    fn test_descriptor() {
+        assert_eq!(<ClassInstanceRef<Array<i8>>>::DESCRIPTOR, "[B");
+        assert_eq!(<ClassInstanceRef<Array<Array<Object>>>>::DESCRIPTOR, "[[Ljava/lang/Object;");
+        assert_eq!(
+            <MethodDescriptor<(i64, (ClassInstanceRef<Object>, ())), ()>>::DESCRIPTOR,
+            "(JLjava/lang/Object;)V"
+        );
+        assert_eq!(<MethodDescriptor<(), i32>>::DESCRIPTOR, "()I");
+        assert_eq!(<Option<String>>::DESCRIPTOR, "Ljava/lang/String;");
+        assert_eq!(<Option<i32>>::DESCRIPTOR, "Ljava/lang/Integer;");
+        assert_eq!(<Vec<u8>>::DESCRIPTOR, "[B");
+        assert_eq!(<Vec<ClassInstanceRef<Object>>>::DESCRIPTOR, "[Ljava/lang/Object;");
+    }
+}
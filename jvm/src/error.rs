@@ -1,6 +1,9 @@
+use core::convert::Infallible;
+
 use alloc::{
     boxed::Box,
     fmt::{self, Display, Formatter},
+    string::String,
 };
 
 use crate::ClassInstance;
@@ -8,16 +11,42 @@ use crate::ClassInstance;
 #[derive(Debug)]
 pub enum JavaError {
     JavaException(Box<dyn ClassInstance>),
+    // the vm reached a state it can't continue from, e.g. malformed bytecode or a broken invariant
+    Internal(String),
+    // a valid operation this vm doesn't implement
+    Unsupported(String),
+    // the calling thread was never attached with `Jvm::attach_thread`, or was already detached
+    ThreadNotAttached,
+}
+
+impl JavaError {
+    pub fn internal(message: impl Into<String>) -> Self {
+        JavaError::Internal(message.into())
+    }
+
+    pub fn unsupported(message: impl Into<String>) -> Self {
+        JavaError::Unsupported(message.into())
+    }
 }
 
 impl Display for JavaError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             JavaError::JavaException(e) => write!(f, "Java exception: {e:?}"),
+            JavaError::Internal(message) => write!(f, "Internal error: {message}"),
+            JavaError::Unsupported(message) => write!(f, "Unsupported: {message}"),
+            JavaError::ThreadNotAttached => write!(f, "Thread not attached"),
         }
     }
 }
 
+// lets `JavaValue` itself be requested wherever a `TryFrom<JavaValue>` conversion is
+impl From<Infallible> for JavaError {
+    fn from(x: Infallible) -> Self {
+        match x {}
+    }
+}
+
 impl From<JavaError> for anyhow::Error {
     fn from(e: JavaError) -> Self {
         anyhow::anyhow!("{:?}", e)
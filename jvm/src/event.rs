@@ -0,0 +1,94 @@
+use alloc::{collections::BTreeMap, sync::Arc};
+use core::sync::atomic::AtomicU64;
+
+use parking_lot::RwLock;
+
+use crate::{ClassInstance, Deadlock, Jvm};
+
+// JVMTI-like events, delivered synchronously on the thread that caused them
+#[derive(Debug)]
+pub enum JvmEvent<'a> {
+    ClassLoaded {
+        class_name: &'a str,
+    },
+    ClassInitialized {
+        class_name: &'a str,
+    },
+    ClassUnloaded {
+        class_name: &'a str,
+    },
+    MethodEntry {
+        class_name: &'a str,
+        method: &'a str,
+    },
+    MethodExit {
+        class_name: &'a str,
+        method: &'a str,
+        exception: Option<&'a dyn ClassInstance>,
+    },
+    ExceptionThrown {
+        exception: &'a dyn ClassInstance,
+    },
+    ExceptionCaught {
+        exception: &'a dyn ClassInstance,
+    },
+    MonitorContendedEnter {
+        object: &'a dyn ClassInstance,
+        owner_thread_id: u64,
+    },
+    MonitorContendedEntered {
+        object: &'a dyn ClassInstance,
+    },
+    DeadlockDetected {
+        deadlock: &'a Deadlock,
+    },
+    ThreadStart {
+        thread_id: u64,
+    },
+    ThreadEnd {
+        thread_id: u64,
+    },
+    // a java thread is about to die from an exception; sent before its uncaught exception handler runs
+    UncaughtException {
+        thread_id: u64,
+        exception: &'a dyn ClassInstance,
+    },
+    GarbageCollectionStart,
+    GarbageCollectionFinish {
+        collected: usize,
+    },
+}
+
+pub trait JvmEventListener: Sync + Send {
+    fn on_event(&self, jvm: &Jvm, event: &JvmEvent<'_>);
+}
+
+impl<F> JvmEventListener for F
+where
+    F: Fn(&Jvm, &JvmEvent<'_>) + Sync + Send,
+{
+    fn on_event(&self, jvm: &Jvm, event: &JvmEvent<'_>) {
+        self(jvm, event)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct JvmEventListenerId(pub(crate) u64);
+
+pub(crate) struct EventListeners {
+    pub(crate) next_id: AtomicU64,
+    pub(crate) listeners: RwLock<BTreeMap<u64, Arc<dyn JvmEventListener>>>,
+}
+
+impl EventListeners {
+    pub(crate) fn new() -> Self {
+        Self {
+            next_id: AtomicU64::new(0),
+            listeners: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.listeners.read().is_empty()
+    }
+}
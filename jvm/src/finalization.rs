@@ -0,0 +1,31 @@
+use alloc::{boxed::Box, collections::VecDeque};
+use core::sync::atomic::AtomicBool;
+
+use event_listener::Event;
+use hashbrown::HashSet;
+use parking_lot::{Mutex, RwLock};
+
+use crate::ClassInstance;
+
+// Objects whose class overrides finalize() are registered on construction. When the collector finds one unreachable,
+// it moves it (and everything it references) to the queue instead of destroying it. The queue is a gc root until the finalizer runs
+pub(crate) struct Finalization {
+    pub(crate) registered: RwLock<HashSet<Box<dyn ClassInstance>>>,
+    // identities of the registered class definitions overriding finalize(), decided once when the class is registered
+    pub(crate) finalizable_classes: RwLock<HashSet<usize>>,
+    pub(crate) queue: Mutex<VecDeque<Box<dyn ClassInstance>>>,
+    pub(crate) queue_event: Event,
+    pub(crate) thread_started: AtomicBool,
+}
+
+impl Finalization {
+    pub(crate) fn new() -> Self {
+        Self {
+            registered: RwLock::new(HashSet::new()),
+            finalizable_classes: RwLock::new(HashSet::new()),
+            queue: Mutex::new(VecDeque::new()),
+            queue_event: Event::new(),
+            thread_started: AtomicBool::new(false),
+        }
+    }
+}
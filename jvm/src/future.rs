@@ -0,0 +1,24 @@
+use core::{future::Future, pin::Pin, task::Poll};
+
+pub(crate) enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+// completes with whichever future finishes first, polling `a` first
+pub(crate) async fn select<A, B>(mut a: A, mut b: B) -> Either<A::Output, B::Output>
+where
+    A: Future + Unpin,
+    B: Future + Unpin,
+{
+    core::future::poll_fn(|cx| {
+        if let Poll::Ready(x) = Pin::new(&mut a).poll(cx) {
+            return Poll::Ready(Either::Left(x));
+        }
+        if let Poll::Ready(x) = Pin::new(&mut b).poll(cx) {
+            return Poll::Ready(Either::Right(x));
+        }
+        Poll::Pending
+    })
+    .await
+}
@@ -1,115 +1,392 @@
-use alloc::{boxed::Box, collections::BTreeMap, string::String, vec::Vec};
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, VecDeque},
+    string::String,
+    vec::Vec,
+};
 use java_constants::FieldAccessFlags;
 
-use hashbrown::{HashSet, hash_set::Entry};
+use hashbrown::{HashMap, HashSet, hash_set::Entry};
 
-use crate::{ClassDefinition, ClassInstance, Field, JavaValue, Jvm, class_loader::Class, thread::JvmThread};
+use crate::{
+    ClassDefinition, ClassInstance, Field, JavaValue, Jvm,
+    class_loader::{Class, ClassLoaderId},
+    reference::ReferenceKind,
+    thread::JvmThread,
+};
 
-pub fn determine_garbage(
+// automatic collection triggers, counted since the previous collection. None disables a trigger
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GcPolicy {
+    pub allocation_threshold: Option<usize>,
+    pub allocated_bytes_threshold: Option<usize>,
+    // every nth automatic collection traces the whole heap, the others only the nursery. None makes all of them full
+    pub full_collection_interval: Option<usize>,
+}
+
+impl GcPolicy {
+    pub fn disabled() -> Self {
+        Self {
+            allocation_threshold: None,
+            allocated_bytes_threshold: None,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn is_due(&self, allocations: usize, allocated_bytes: usize) -> bool {
+        self.allocation_threshold.is_some_and(|x| allocations >= x) || self.allocated_bytes_threshold.is_some_and(|x| allocated_bytes >= x)
+    }
+}
+
+impl Default for GcPolicy {
+    fn default() -> Self {
+        Self {
+            allocation_threshold: None,
+            allocated_bytes_threshold: Some(0x100000),
+            full_collection_interval: Some(8),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GcStatistics {
+    pub collections: u64,
+    pub minor_collections: u64,
+    pub automatic_collections: u64,
+    pub objects_collected: u64,
+    pub bytes_collected: u64,
+    pub classes_unloaded: u64,
+}
+
+// Objects allocated since the last collection, plus the old objects and classes that had a reference stored into them since.
+// A minor collection only traces the nursery; everything that survives it is promoted
+#[derive(Default)]
+pub(crate) struct Nursery {
+    pub(crate) objects: HashSet<Box<dyn ClassInstance>>,
+    pub(crate) remembered_objects: HashSet<Box<dyn ClassInstance>>,
+    pub(crate) remembered_classes: HashSet<(ClassLoaderId, String)>,
+}
+
+impl Nursery {
+    pub(crate) fn promote(&mut self) {
+        self.objects.clear();
+        self.remembered_objects.clear();
+        self.remembered_classes.clear();
+    }
+}
+
+// everything the collector treats as live regardless of the heap graph
+pub(crate) struct GcRoots<'a> {
+    pub(crate) threads: &'a BTreeMap<u64, JvmThread>,
+    pub(crate) global_references: &'a BTreeMap<u64, Box<dyn ClassInstance>>,
+    pub(crate) classes: &'a BTreeMap<ClassLoaderId, BTreeMap<String, Class>>,
+    pub(crate) interned_strings: &'a [Box<dyn ClassInstance>],
+    pub(crate) finalization_queue: &'a VecDeque<Box<dyn ClassInstance>>,
+    pub(crate) pending_references: &'a VecDeque<Box<dyn ClassInstance>>,
+}
+
+impl<'a> GcRoots<'a> {
+    fn iter(&self) -> impl Iterator<Item = &'a Box<dyn ClassInstance>> {
+        let locals = self
+            .threads
+            .values()
+            .flat_map(|thread| thread.iter_frame().flat_map(|stack| stack.local_variables()));
+        let java_threads = self.threads.values().filter_map(|thread| thread.java_thread());
+
+        locals
+            .chain(java_threads)
+            .chain(self.global_references.values())
+            .chain(self.interned_strings.iter())
+            .chain(self.finalization_queue.iter())
+            .chain(self.pending_references.iter())
+    }
+}
+
+// Registered java.lang.ref references whose referent field isn't traced by a full collection.
+// Soft referents are only left untraced when the heap is under pressure
+pub(crate) struct WeakReferents<'a> {
+    pub(crate) registered: &'a HashMap<Box<dyn ClassInstance>, ReferenceKind>,
+    pub(crate) clear_soft: bool,
+}
+
+impl WeakReferents<'_> {
+    #[allow(clippy::borrowed_box)]
+    fn is_weak(&self, object: &Box<dyn ClassInstance>) -> bool {
+        match self.registered.get(object) {
+            Some(ReferenceKind::Soft) => self.clear_soft,
+            Some(_) => true,
+            None => false,
+        }
+    }
+}
+
+// a live reference whose referent is otherwise unreachable, to be cleared once the collection is done
+pub(crate) struct UnreachableReferent {
+    pub(crate) reference: Box<dyn ClassInstance>,
+    pub(crate) kind: ReferenceKind,
+    pub(crate) referent: Box<dyn ClassInstance>,
+}
+
+// Also returns the class loaders found unreachable, whose classes can be unloaded
+pub(crate) fn determine_garbage(
     jvm: &Jvm,
-    threads: &BTreeMap<u64, JvmThread>,
-    global_references: &BTreeMap<u64, Box<dyn ClassInstance>>,
-    all_class_instances: &HashSet<Box<dyn ClassInstance>>,
-    classes: &BTreeMap<String, Class>,
-    interned_strings: &[Box<dyn ClassInstance>],
-) -> Vec<Box<dyn ClassInstance>> {
+    roots: &GcRoots,
+    defining_loaders: &BTreeMap<usize, ClassLoaderId>,
+    all_class_instances: &HashMap<Box<dyn ClassInstance>, usize>,
+    weak_referents: &WeakReferents,
+) -> (Vec<Box<dyn ClassInstance>>, Vec<UnreachableReferent>, Vec<usize>) {
     let mut reachable_objects = HashSet::new();
+    // objects marked but not yet checked for the class loaders they keep alive
+    let mut unscanned = Vec::new();
+
+    let trace_classes = |classes: &BTreeMap<String, Class>, reachable_objects: &mut HashSet<Box<dyn ClassInstance>>, unscanned: &mut Vec<_>| {
+        classes.values().for_each(|x| {
+            // a class still being registered has no java/lang/Class instance to trace yet
+            if let Ok(java_class) = x.java_class() {
+                find_reachable_objects(jvm, &java_class, Some(weak_referents), reachable_objects, Some(&mut *unscanned));
+            }
+            static_references(jvm, x).iter().for_each(|x| {
+                find_reachable_objects(jvm, x, Some(weak_referents), reachable_objects, Some(&mut *unscanned));
+            });
+        });
+    };
+
+    // bootstrap classes are never unloaded
+    if let Some(x) = roots.classes.get(&None) {
+        trace_classes(x, &mut reachable_objects, &mut unscanned);
+    }
 
-    classes.values().for_each(|x| {
-        find_reachable_objects(jvm, &x.java_class(), &mut reachable_objects);
-        find_static_reachable_objects(jvm, x, &mut reachable_objects);
+    roots.iter().for_each(|x| {
+        find_reachable_objects(jvm, x, Some(weak_referents), &mut reachable_objects, Some(&mut unscanned));
     });
 
-    threads
+    // Other loaders' classes stay loaded while the loader is reachable, directly or through an instance of one of its classes,
+    // or while one of its classes has a method running
+    let mut pending_class_loaders = roots
+        .threads
         .values()
-        .flat_map(|thread| thread.iter_frame().flat_map(|stack| stack.local_variables()))
+        .flat_map(|thread| thread.iter_java_frame())
+        .filter_map(|frame| defining_loaders.get(&frame.class.definition.identity()).copied().flatten())
+        .collect::<Vec<_>>();
+    let mut live_class_loaders = HashSet::new();
+    loop {
+        if let Some(object) = unscanned.pop() {
+            let defining_loader = defining_loaders.get(&object.class_definition().identity()).copied().flatten();
+            pending_class_loaders.extend([Some(object.identity()), defining_loader].into_iter().flatten());
+            continue;
+        }
+
+        let Some(class_loader) = pending_class_loaders.pop() else {
+            break;
+        };
+        if let Some(classes) = roots.classes.get(&Some(class_loader))
+            && live_class_loaders.insert(class_loader)
+        {
+            trace_classes(classes, &mut reachable_objects, &mut unscanned);
+        }
+    }
+
+    let unreachable_class_loaders = roots
+        .classes
+        .keys()
+        .flatten()
+        .filter(|x| !live_class_loaders.contains(*x))
+        .copied()
+        .collect();
+
+    let unreachable_referents = weak_referents
+        .registered
+        .iter()
+        .filter(|(reference, _)| reachable_objects.contains(*reference) && weak_referents.is_weak(reference))
+        .filter_map(|(reference, kind)| {
+            let referent = referent(jvm, reference)?;
+
+            (!reachable_objects.contains(&referent)).then(|| UnreachableReferent {
+                reference: reference.clone(),
+                kind: *kind,
+                referent,
+            })
+        })
+        .collect();
+
+    let garbage = all_class_instances.keys().filter(|x| !reachable_objects.contains(*x)).cloned().collect();
+
+    (garbage, unreachable_referents, unreachable_class_loaders)
+}
+
+// Old objects are assumed live. Young objects are reachable from the usual roots, or from old objects through the remembered set.
+// Referents are traced strongly; clearing references is left to full collections
+pub(crate) fn determine_young_garbage(jvm: &Jvm, roots: &GcRoots, nursery: &Nursery) -> Vec<Box<dyn ClassInstance>> {
+    let mut reachable_objects = HashSet::new();
+
+    roots.classes.values().flat_map(|x| x.values()).for_each(|x| {
+        if let Ok(java_class) = x.java_class() {
+            find_reachable_young_objects(jvm, &java_class, nursery, &mut reachable_objects);
+        }
+    });
+
+    nursery
+        .remembered_classes
+        .iter()
+        .filter_map(|(loader, name)| roots.classes.get(loader)?.get(name))
         .for_each(|x| {
-            find_reachable_objects(jvm, x, &mut reachable_objects);
+            static_references(jvm, x).iter().for_each(|x| {
+                find_reachable_young_objects(jvm, x, nursery, &mut reachable_objects);
+            });
         });
 
-    threads.values().filter_map(|thread| thread.java_thread()).for_each(|x| {
-        find_reachable_objects(jvm, x, &mut reachable_objects);
+    nursery.remembered_objects.iter().for_each(|x| {
+        references(jvm, x, false).iter().for_each(|x| {
+            find_reachable_young_objects(jvm, x, nursery, &mut reachable_objects);
+        });
     });
 
-    global_references.values().for_each(|object| {
-        find_reachable_objects(jvm, object, &mut reachable_objects);
+    roots.iter().for_each(|x| {
+        find_reachable_young_objects(jvm, x, nursery, &mut reachable_objects);
     });
 
-    interned_strings.iter().for_each(|x| {
-        find_reachable_objects(jvm, x, &mut reachable_objects);
+    nursery.objects.difference(&reachable_objects).cloned().collect()
+}
+
+pub(crate) fn find_all_reachable_objects(jvm: &Jvm, objects: &[Box<dyn ClassInstance>]) -> HashSet<Box<dyn ClassInstance>> {
+    let mut reachable_objects = HashSet::new();
+    objects.iter().for_each(|x| {
+        find_reachable_objects(jvm, x, None, &mut reachable_objects, None);
     });
 
-    all_class_instances.difference(&reachable_objects).cloned().collect()
+    reachable_objects
 }
 
-fn find_static_reachable_objects(jvm: &Jvm, class: &Class, reachable_objects: &mut HashSet<Box<dyn ClassInstance>>) {
-    let fields = find_all_fields(jvm, &*class.definition);
-    for field in fields {
-        if !field.access_flags().contains(FieldAccessFlags::STATIC) {
-            continue;
-        }
-
-        let descriptor = field.descriptor();
+#[allow(clippy::borrowed_box)]
+fn find_reachable_objects(
+    jvm: &Jvm,
+    object: &Box<dyn ClassInstance>,
+    weak_referents: Option<&WeakReferents>,
+    reachable_objects: &mut HashSet<Box<dyn ClassInstance>>,
+    mut marked: Option<&mut Vec<Box<dyn ClassInstance>>>,
+) {
+    let entry = reachable_objects.entry(object.clone());
+    if let Entry::Occupied(_) = entry {
+        return;
+    }
+    entry.insert();
+    if let Some(x) = marked.as_deref_mut() {
+        x.push(object.clone());
+    }
 
-        if (descriptor.starts_with('L') && descriptor.ends_with(';')) || descriptor.starts_with('[') {
-            let value = class.definition.get_static_field(&*field).unwrap();
-            if let JavaValue::Object(Some(value)) = value {
-                find_reachable_objects(jvm, &value, reachable_objects);
-            }
-        }
+    let skip_referent = weak_referents.is_some_and(|x| x.is_weak(object));
+    for reference in references(jvm, object, skip_referent) {
+        find_reachable_objects(jvm, &reference, weak_referents, reachable_objects, marked.as_deref_mut());
     }
 }
 
 #[allow(clippy::borrowed_box)]
-fn find_reachable_objects(jvm: &Jvm, object: &Box<dyn ClassInstance>, reachable_objects: &mut HashSet<Box<dyn ClassInstance>>) {
+fn find_reachable_young_objects(
+    jvm: &Jvm,
+    object: &Box<dyn ClassInstance>,
+    nursery: &Nursery,
+    reachable_objects: &mut HashSet<Box<dyn ClassInstance>>,
+) {
+    if !nursery.objects.contains(object) {
+        return;
+    }
+
     let entry = reachable_objects.entry(object.clone());
     if let Entry::Occupied(_) = entry {
         return;
     }
     entry.insert();
 
+    for reference in references(jvm, object, false) {
+        find_reachable_young_objects(jvm, &reference, nursery, reachable_objects);
+    }
+}
+
+// objects directly referenced by the fields or elements of the object
+#[allow(clippy::borrowed_box)]
+fn references(jvm: &Jvm, object: &Box<dyn ClassInstance>, skip_referent: bool) -> Vec<Box<dyn ClassInstance>> {
     let name = object.class_definition().name();
-    if name.starts_with('[') {
+    let values = if name.starts_with('[') {
         if name.starts_with("[L") || name.starts_with("[[") {
             // is object array
-            let array = object.as_array_instance().unwrap();
-            let values = array.load(0, array.length()).unwrap();
-
-            for value in values {
-                if let JavaValue::Object(Some(value)) = value {
-                    find_reachable_objects(jvm, &value, reachable_objects);
-                }
-            }
+            object
+                .as_array_instance()
+                .and_then(|array| array.load(0, array.length()).ok())
+                .unwrap_or_default()
+        } else {
+            // do nothing for primitive arrays
+            Vec::new()
         }
-        // do nothing for primitive arrays
     } else {
-        let fields = find_all_fields(jvm, &*object.class_definition());
-        for field in fields {
-            if field.access_flags().contains(FieldAccessFlags::STATIC) {
-                continue;
-            }
+        find_all_declared_fields(jvm, &*object.class_definition())
+            .into_iter()
+            .filter(|(_, x)| !x.access_flags().contains(FieldAccessFlags::STATIC) && is_reference(&x.descriptor()))
+            .filter(|(class_name, x)| !(skip_referent && is_referent_field(class_name, &**x)))
+            .filter_map(|(_, x)| object.get_field(&*x).ok())
+            .collect()
+    };
 
-            let descriptor = field.descriptor();
+    values
+        .into_iter()
+        .filter_map(|x| match x {
+            JavaValue::Object(Some(x)) => Some(x),
+            _ => None,
+        })
+        .collect()
+}
 
-            if (descriptor.starts_with('L') && descriptor.ends_with(';')) || descriptor.starts_with('[') {
-                let value = object.get_field(&*field).unwrap();
-                if let JavaValue::Object(Some(value)) = value {
-                    find_reachable_objects(jvm, &value, reachable_objects);
-                }
-            }
-        }
+#[allow(clippy::borrowed_box)]
+pub(crate) fn referent_field(jvm: &Jvm, reference: &Box<dyn ClassInstance>) -> Option<Box<dyn Field>> {
+    find_all_declared_fields(jvm, &*reference.class_definition())
+        .into_iter()
+        .find(|(class_name, x)| is_referent_field(class_name, &**x))
+        .map(|(_, x)| x)
+}
+
+#[allow(clippy::borrowed_box)]
+fn referent(jvm: &Jvm, reference: &Box<dyn ClassInstance>) -> Option<Box<dyn ClassInstance>> {
+    let field = referent_field(jvm, reference)?;
+    match reference.get_field(&*field).ok()? {
+        JavaValue::Object(x) => x,
+        _ => None,
     }
 }
 
-fn find_all_fields(jvm: &Jvm, class_definition: &dyn ClassDefinition) -> Vec<Box<dyn Field>> {
-    let result = class_definition.fields();
+// java.lang.ref.Reference.referent, not a field of the same name declared by a subclass
+fn is_referent_field(class_name: &str, field: &dyn Field) -> bool {
+    class_name == "java/lang/ref/Reference" && field.name() == "referent" && !field.access_flags().contains(FieldAccessFlags::STATIC)
+}
+
+fn static_references(jvm: &Jvm, class: &Class) -> Vec<Box<dyn ClassInstance>> {
+    find_all_fields(jvm, &*class.definition)
+        .into_iter()
+        .filter(|x| x.access_flags().contains(FieldAccessFlags::STATIC) && is_reference(&x.descriptor()))
+        .filter_map(|x| match class.definition.get_static_field(&*x) {
+            Ok(JavaValue::Object(Some(x))) => Some(x),
+            _ => None,
+        })
+        .collect()
+}
+
+fn is_reference(descriptor: &str) -> bool {
+    (descriptor.starts_with('L') && descriptor.ends_with(';')) || descriptor.starts_with('[')
+}
+
+pub(crate) fn find_all_fields(jvm: &Jvm, class_definition: &dyn ClassDefinition) -> Vec<Box<dyn Field>> {
+    find_all_declared_fields(jvm, class_definition).into_iter().map(|(_, x)| x).collect()
+}
+
+// fields of the class and its superclasses, each with the name of the class declaring it
+fn find_all_declared_fields(jvm: &Jvm, class_definition: &dyn ClassDefinition) -> Vec<(String, Box<dyn Field>)> {
+    let class_name = class_definition.name();
+    let result = class_definition.fields().into_iter().map(|x| (class_name.clone(), x));
     let super_class_name = class_definition.super_class_name();
 
-    if let Some(x) = super_class_name {
-        let super_class = jvm.get_class(&x).unwrap();
-        let super_fields = find_all_fields(jvm, &*super_class.definition);
-        result.into_iter().chain(super_fields).collect()
+    // superclasses are always registered first
+    if let Some(super_class) = super_class_name.and_then(|x| jvm.referenced_class(class_definition, &x)) {
+        let super_fields = find_all_declared_fields(jvm, &*super_class.definition);
+        result.chain(super_fields).collect()
     } else {
-        result
+        result.collect()
     }
 }
@@ -25,7 +25,7 @@ impl<T> Deref for GlobalRef<T> {
 }
 
 impl<T> AsClassInstance for GlobalRef<T> {
-    fn as_class_instance(&self) -> &dyn ClassInstance {
+    fn as_class_instance(&self) -> Option<&dyn ClassInstance> {
         self.reference.as_class_instance()
     }
 }
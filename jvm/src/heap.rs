@@ -0,0 +1,44 @@
+use java_constants::FieldAccessFlags;
+
+use crate::{ClassDefinition, ClassInstance, JavaType, Jvm, Result, garbage_collector::find_all_fields};
+
+// approximate sizes, modeled on a 32-bit vm with 4-byte references
+const OBJECT_HEADER_SIZE: usize = 8;
+const ARRAY_HEADER_SIZE: usize = 12;
+
+// computes the size of an instance of the class; Jvm::object_size caches it per class
+pub(crate) fn object_size(jvm: &Jvm, class_definition: &dyn ClassDefinition) -> Result<usize> {
+    let mut fields_size = 0;
+    for field in find_all_fields(jvm, class_definition) {
+        if !field.access_flags().contains(FieldAccessFlags::STATIC) {
+            fields_size += type_size(&JavaType::parse(&field.descriptor())?);
+        }
+    }
+
+    Ok(OBJECT_HEADER_SIZE + fields_size)
+}
+
+pub(crate) fn array_size(element_type: &JavaType, length: usize) -> usize {
+    ARRAY_HEADER_SIZE.saturating_add(type_size(element_type).saturating_mul(length))
+}
+
+pub(crate) fn instance_size(jvm: &Jvm, instance: &dyn ClassInstance) -> Result<usize> {
+    if let Some(array) = instance.as_array_instance() {
+        let element_type = JavaType::parse(&instance.class_definition().name()[1..])?;
+
+        Ok(array_size(&element_type, array.length()))
+    } else {
+        jvm.object_size(&*instance.class_definition())
+    }
+}
+
+fn type_size(r#type: &JavaType) -> usize {
+    match r#type {
+        JavaType::Boolean | JavaType::Byte => 1,
+        JavaType::Char | JavaType::Short => 2,
+        JavaType::Int | JavaType::Float => 4,
+        JavaType::Long | JavaType::Double => 8,
+        JavaType::Class(_) | JavaType::Array(_) => 4,
+        JavaType::Void | JavaType::Method(_, _) => 0,
+    }
+}
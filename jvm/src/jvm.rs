@@ -1,14 +1,25 @@
 #![allow(clippy::borrowed_box)] // We have get parameter by Box<T> to make ergonomic interface
 
-use alloc::{borrow::ToOwned, boxed::Box, collections::BTreeMap, format, string::String, sync::Arc, vec::Vec};
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::String,
+    sync::Arc,
+    vec::Vec,
+};
 use core::{
     fmt::Debug,
+    future::Future,
     iter,
-    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    pin::pin,
+    sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
 };
 
 use dyn_clone::clone_box;
-use hashbrown::HashSet;
+use event_listener::Event;
+use hashbrown::{HashMap, HashSet};
 use parking_lot::RwLock;
 
 use java_constants::{ClassAccessFlags, FieldAccessFlags, MethodAccessFlags};
@@ -17,33 +28,71 @@ use crate::{
     Result,
     array_class_instance::{ArrayRawBuffer, ArrayRawBufferMut},
     class_definition::ClassDefinition,
-    class_instance::{AsClassInstance, ClassInstance, ClassInstanceRef},
+    class_instance::{AsClassInstance, AsClassInstanceMut, ClassInstance, ClassInstanceRef},
     class_loader::{
-        BootstrapClassLoader, BootstrapClassLoaderWrapper, Class, ClassLoaderWrapper, InitState, InitializationAction, JavaClassLoaderWrapper,
+        BootstrapClassLoader, BootstrapClassLoaderWrapper, Class, ClassLoaderId, ClassLoaderWrapper, InitState, InitializationAction,
+        JavaClassLoaderWrapper,
     },
+    deadlock::{Deadlock, DeadlockedThread},
     error::JavaError,
+    event::{EventListeners, JvmEvent, JvmEventListener, JvmEventListenerId},
     field::Field,
-    garbage_collector::determine_garbage,
+    finalization::Finalization,
+    future::{Either, select},
+    garbage_collector::{
+        GcPolicy, GcRoots, GcStatistics, Nursery, UnreachableReferent, WeakReferents, determine_garbage, determine_young_garbage,
+        find_all_reachable_objects, referent_field,
+    },
     global_ref::{GlobalRef, GlobalReferences},
+    heap::{array_size, instance_size, object_size},
     invoke_arg::InvokeArg,
+    loader_constraints::{LoaderConstraints, descriptor_class_names},
     method::Method,
-    monitor::{Monitor, MonitorWait, MonitorWaitTimeout},
+    monitor::{Monitor, MonitorInfo, MonitorWait, MonitorWaitTimeout},
+    native_frame::NativeFrame,
+    native_method::{NativeMethod, NativeMethods},
+    reference::{ReferenceKind, References},
     runtime::{JavaLangClass, JavaLangClassLoader, JavaLangString},
-    thread::JvmThread,
+    thread::{JvmThread, StackFrame},
+    thread_dump::{ThreadDump, ThreadInfo, ThreadState},
     r#type::JavaType,
-    value::JavaValue,
+    value::{JavaChar, JavaValue},
 };
 
 struct JvmInner {
-    classes: RwLock<BTreeMap<String, Class>>,
+    // per class loader namespaces: classes a loader defined, and classes it initiated loading of
+    classes: RwLock<BTreeMap<ClassLoaderId, BTreeMap<String, Class>>>,
+    // class definition identity -> loader that defined it
+    defining_loaders: RwLock<BTreeMap<usize, ClassLoaderId>>,
+    loader_constraints: LoaderConstraints,
+    // class definition identity -> instance size, computed on first instantiation
+    object_sizes: RwLock<BTreeMap<usize, usize>>,
+    system_class_loader: RwLock<ClassLoaderId>,
     threads: RwLock<BTreeMap<u64, JvmThread>>,
     global_references: Arc<GlobalReferences>,
-    all_objects: RwLock<HashSet<Box<dyn ClassInstance>>>,
+    all_objects: RwLock<HashMap<Box<dyn ClassInstance>, usize>>,
+    heap_used: AtomicUsize,
+    max_heap_size: AtomicUsize,
+    gc_policy: RwLock<GcPolicy>,
+    gc_statistics: RwLock<GcStatistics>,
+    allocations_since_gc: AtomicUsize,
+    allocated_bytes_since_gc: AtomicUsize,
+    nursery: RwLock<Nursery>,
+    finalization: Finalization,
+    references: References,
     string_pool: RwLock<BTreeMap<Vec<u16>, Box<dyn ClassInstance>>>,
     monitors: RwLock<BTreeMap<usize, Arc<Monitor>>>,
+    // thread id -> identity of the object whose monitor it's blocked entering
+    blocked_threads: RwLock<BTreeMap<u64, usize>>,
+    throw_on_deadlock: AtomicBool,
+    // started java threads that keep the vm alive after main returns
+    non_daemon_threads: AtomicUsize,
+    non_daemon_threads_event: Event,
     get_current_thread_id: Box<dyn Fn() -> u64 + Sync + Send>,
     bootstrap_class_loader: Box<dyn BootstrapClassLoader>,
     bootstrapping: AtomicBool,
+    event_listeners: EventListeners,
+    native_methods: NativeMethods,
 }
 
 #[derive(Clone)]
@@ -60,24 +109,48 @@ impl Jvm {
         let jvm = Self {
             inner: Arc::new(JvmInner {
                 classes: RwLock::new(BTreeMap::new()),
+                defining_loaders: RwLock::new(BTreeMap::new()),
+                loader_constraints: LoaderConstraints::new(),
+                object_sizes: RwLock::new(BTreeMap::new()),
+                system_class_loader: RwLock::new(None),
                 threads: RwLock::new(BTreeMap::new()),
                 global_references: Arc::new(GlobalReferences {
                     next_id: AtomicU64::new(0),
                     objects: RwLock::new(BTreeMap::new()),
                 }),
-                all_objects: RwLock::new(HashSet::new()),
+                all_objects: RwLock::new(HashMap::new()),
+                heap_used: AtomicUsize::new(0),
+                max_heap_size: AtomicUsize::new(usize::MAX),
+                gc_policy: RwLock::new(GcPolicy::default()),
+                gc_statistics: RwLock::new(GcStatistics::default()),
+                allocations_since_gc: AtomicUsize::new(0),
+                allocated_bytes_since_gc: AtomicUsize::new(0),
+                nursery: RwLock::new(Nursery::default()),
+                finalization: Finalization::new(),
+                references: References::new(),
                 string_pool: RwLock::new(BTreeMap::new()),
                 monitors: RwLock::new(BTreeMap::new()),
+                blocked_threads: RwLock::new(BTreeMap::new()),
+                throw_on_deadlock: AtomicBool::new(false),
+                non_daemon_threads: AtomicUsize::new(0),
+                non_daemon_threads_event: Event::new(),
                 get_current_thread_id: Box::new(get_current_thread_id),
                 bootstrap_class_loader: Box::new(bootstrap_class_loader),
                 bootstrapping: AtomicBool::new(true),
+                event_listeners: EventListeners::new(),
+                native_methods: NativeMethods::new(),
             }),
         };
 
         // load bootstrap classes
         let bootstrap_classes = ["java/lang/Object", "java/lang/Runnable", "java/lang/Thread", "[B", "java/lang/Class"];
         for class_name in bootstrap_classes.iter() {
-            let class_definition = jvm.inner.bootstrap_class_loader.load_class(&jvm, class_name).await?.unwrap();
+            let class_definition = jvm
+                .inner
+                .bootstrap_class_loader
+                .load_class(&jvm, class_name)
+                .await?
+                .ok_or_else(|| JavaError::internal(format!("Bootstrap class {class_name} not found")))?;
             let class = Class::new(class_definition, None);
 
             jvm.register_class_internal(class, None).await?;
@@ -87,7 +160,7 @@ impl Jvm {
         jvm.attach_thread(None).await?;
 
         // set java class for bootstrap classes
-        let classes = jvm.inner.classes.read().values().cloned().collect::<Vec<_>>();
+        let classes = jvm.inner.classes.read().values().flat_map(|x| x.values()).cloned().collect::<Vec<_>>();
         for class in classes {
             let java_class = JavaLangClass::from_rust_class(&jvm, class.definition.clone(), None).await?;
             class.set_java_class(java_class);
@@ -109,19 +182,12 @@ impl Jvm {
         }
 
         // load system class loader
-        JavaLangClassLoader::get_system_class_loader(&jvm).await?;
+        let system_class_loader = JavaLangClassLoader::get_system_class_loader(&jvm).await?;
+        *jvm.inner.system_class_loader.write() = Some(system_class_loader.identity());
 
         jvm.inner.bootstrapping.store(false, Ordering::Relaxed);
 
-        let thread_id = (jvm.inner.get_current_thread_id)();
-        jvm.inner
-            .threads
-            .write()
-            .get_mut(&thread_id)
-            .unwrap()
-            .top_frame_mut()
-            .local_variables_mut()
-            .clear();
+        jvm.with_current_thread(|thread| thread.top_frame_mut().local_variables_mut().clear())?;
 
         Ok(jvm)
     }
@@ -132,26 +198,29 @@ impl Jvm {
 
         let class = self.resolve_class(class_name).await?;
 
+        self.instantiate(&class).await
+    }
+
+    pub async fn instantiate(&self, class: &Class) -> Result<Box<dyn ClassInstance>> {
         let access_flags = class.definition.access_flags();
         if access_flags.contains(ClassAccessFlags::INTERFACE) || access_flags.contains(ClassAccessFlags::ABSTRACT) {
             return Err(self
                 .exception(
                     "java/lang/InstantiationError",
-                    &format!("Cannot instantiate abstract class or interface: {}", class_name),
+                    &format!("Cannot instantiate abstract class or interface: {}", class.definition.name()),
                 )
                 .await);
         }
 
-        self.ensure_initialized(&class).await?;
+        self.ensure_initialized(class).await?;
 
-        let instance = class.definition.instantiate(self).await?;
+        let size = self.object_size(&*class.definition)?;
+        self.reserve_heap(size).await?;
 
-        let thread_id = (self.inner.get_current_thread_id)();
-        let mut threads = self.inner.threads.write();
-        let thread = threads.get_mut(&thread_id).unwrap();
+        let instance = class.definition.instantiate(self).await?;
 
-        thread.top_frame_mut().local_variables_mut().push(instance.clone());
-        self.inner.all_objects.write().insert(instance.clone());
+        self.add_local_ref(&instance)?;
+        self.track_object(instance.clone(), size);
 
         Ok(instance)
     }
@@ -173,23 +242,25 @@ impl Jvm {
         let class_name = format!("[{element_type_name}");
 
         let class = self.resolve_class(&class_name).await?.definition;
-        let array_class = class.as_array_class_definition().unwrap();
+        let array_class = class
+            .as_array_class_definition()
+            .ok_or_else(|| JavaError::internal(format!("{class_name} is not an array class")))?;
 
-        let instance = array_class.instantiate_array(self, length).await?;
+        let size = array_size(&JavaType::parse(element_type_name)?, length);
+        self.reserve_heap(size).await?;
 
-        let thread_id = (self.inner.get_current_thread_id)();
-        let mut threads = self.inner.threads.write();
-        let thread = threads.get_mut(&thread_id).unwrap();
+        let instance = array_class.instantiate_array(self, length).await?;
 
-        thread.top_frame_mut().local_variables_mut().push(instance.clone());
-        self.inner.all_objects.write().insert(instance.clone());
+        self.add_local_ref(&instance)?;
+        self.track_object(instance.clone(), size);
 
         Ok(instance)
     }
 
     pub async fn get_static_field<T>(&self, class_name: &str, name: &str, descriptor: &str) -> Result<T>
     where
-        T: From<JavaValue>,
+        T: TryFrom<JavaValue>,
+        JavaError: From<T::Error>,
     {
         tracing::trace!("Get static field {class_name}.{name}:{descriptor}");
 
@@ -202,21 +273,14 @@ impl Jvm {
                     .await);
             }
 
+            self.constrain_loaders(&*declaring_class.definition, descriptor).await?;
             self.ensure_initialized(&declaring_class).await?;
 
             let value = declaring_class.definition.get_static_field(&*field)?;
             if let JavaValue::Object(Some(instance)) = &value {
-                let thread_id = (self.inner.get_current_thread_id)();
-                self.inner
-                    .threads
-                    .write()
-                    .get_mut(&thread_id)
-                    .unwrap()
-                    .top_frame_mut()
-                    .local_variables_mut()
-                    .push(instance.clone());
+                self.add_local_ref(instance)?;
             }
-            Ok(value.into())
+            Ok(value.try_into()?)
         } else {
             Err(self
                 .exception("java/lang/NoSuchFieldError", &format!("{class_name}.{name}:{descriptor}"))
@@ -239,9 +303,16 @@ impl Jvm {
                     .await);
             }
 
+            self.constrain_loaders(&*declaring_class.definition, descriptor).await?;
             self.ensure_initialized(&declaring_class).await?;
 
-            declaring_class.definition.put_static_field(&*field, value.into())
+            let value = value.into();
+            if self.is_young(&value) {
+                let key = (self.defining_loader(&*declaring_class.definition), declaring_class.definition.name());
+                self.inner.nursery.write().remembered_classes.insert(key);
+            }
+
+            declaring_class.definition.put_static_field(&*field, value)
         } else {
             Err(self
                 .exception("java/lang/NoSuchFieldError", &format!("{class_name}.{name}:{descriptor}"))
@@ -249,28 +320,24 @@ impl Jvm {
         }
     }
 
-    pub async fn get_field<T>(&self, instance: &Box<dyn ClassInstance>, name: &str, descriptor: &str) -> Result<T>
+    pub async fn get_field<T>(&self, instance: &(impl AsClassInstance + ?Sized), name: &str, descriptor: &str) -> Result<T>
     where
-        T: From<JavaValue>,
+        T: TryFrom<JavaValue>,
+        JavaError: From<T::Error>,
     {
+        let instance = self.non_null(instance).await?;
         tracing::trace!("Get field {}.{name}:{descriptor}", instance.class_definition().name());
 
         let field = self.find_field(&*instance.class_definition(), name, descriptor)?;
 
         if let Some(field) = field {
+            self.constrain_loaders(&*instance.class_definition(), descriptor).await?;
+
             let value = instance.get_field(&*field)?;
             if let JavaValue::Object(Some(instance)) = &value {
-                let thread_id = (self.inner.get_current_thread_id)();
-                self.inner
-                    .threads
-                    .write()
-                    .get_mut(&thread_id)
-                    .unwrap()
-                    .top_frame_mut()
-                    .local_variables_mut()
-                    .push(instance.clone());
+                self.add_local_ref(instance)?;
             }
-            Ok(value.into())
+            Ok(value.try_into()?)
         } else {
             Err(self
                 .exception(
@@ -281,16 +348,24 @@ impl Jvm {
         }
     }
 
-    pub async fn put_field<T>(&self, instance: &mut Box<dyn ClassInstance>, name: &str, descriptor: &str, value: T) -> Result<()>
+    pub async fn put_field<T>(&self, instance: &mut (impl AsClassInstanceMut + ?Sized), name: &str, descriptor: &str, value: T) -> Result<()>
     where
         T: Into<JavaValue> + Debug,
     {
+        let instance = self.non_null_mut(instance).await?;
         tracing::trace!("Put field {}.{name}:{descriptor} = {value:?}", instance.class_definition().name());
 
         let field = self.find_field(&*instance.class_definition(), name, descriptor)?;
 
         if let Some(field) = field {
-            instance.put_field(&*field, value.into())
+            self.constrain_loaders(&*instance.class_definition(), descriptor).await?;
+
+            let value = value.into();
+            if self.is_young(&value) {
+                self.remember_object(instance);
+            }
+
+            instance.put_field(&*field, value)
         } else {
             Err(self
                 .exception(
@@ -304,7 +379,8 @@ impl Jvm {
     pub async fn invoke_static<T, U>(&self, class_name: &str, name: &str, descriptor: &str, args: T) -> Result<U>
     where
         T: InvokeArg,
-        U: From<JavaValue>,
+        U: TryFrom<JavaValue>,
+        JavaError: From<U::Error>,
     {
         let args = args.into_arg();
 
@@ -319,9 +395,10 @@ impl Jvm {
                     .await);
             }
 
+            self.constrain_loaders(&*declaring_class.definition, descriptor).await?;
             self.ensure_initialized(&declaring_class).await?;
 
-            Ok(self.execute_method(&declaring_class, None, &method, args).await?.into())
+            Ok(self.execute_method(&declaring_class, None, &method, args).await?.try_into()?)
         } else {
             tracing::error!("No such method: {class_name}.{name}:{descriptor}");
 
@@ -331,26 +408,30 @@ impl Jvm {
         }
     }
 
-    pub async fn invoke_virtual<T, U>(&self, instance: &Box<dyn ClassInstance>, name: &str, descriptor: &str, args: T) -> Result<U>
+    pub async fn invoke_virtual<T, U>(&self, instance: &(impl AsClassInstance + Sync + ?Sized), name: &str, descriptor: &str, args: T) -> Result<U>
     where
         T: InvokeArg,
-        U: From<JavaValue>,
+        U: TryFrom<JavaValue>,
+        JavaError: From<U::Error>,
     {
+        let instance = self.non_null(instance).await?;
         let args = args.into_arg();
         tracing::trace!("Invoke virtual {}.{name}:{descriptor}({args:?})", instance.class_definition().name());
 
         let class = instance.class_definition();
         let method = self.find_virtual_method(&*class, name, descriptor, false)?;
         if let Some(x) = method {
-            let args = iter::once(JavaValue::Object(Some(clone_box(&**instance))))
+            self.constrain_loaders(&*class, descriptor).await?;
+
+            let args = iter::once(JavaValue::Object(Some(clone_box(instance))))
                 .chain(args.into_vec())
                 .collect::<Vec<_>>();
 
-            let class = self.resolve_class(&class.name()).await?; // TODO we're resolving class twice
+            let class = self.class_of(&*class).await?;
             Ok(self
-                .execute_method(&class, Some(instance.clone()), &x, args.into_boxed_slice())
+                .execute_method(&class, Some(clone_box(instance)), &x, args.into_boxed_slice())
                 .await?
-                .into())
+                .try_into()?)
         } else {
             tracing::error!("No such method: {}.{name}:{descriptor}", class.name());
 
@@ -362,19 +443,34 @@ impl Jvm {
 
     // non-virtual
     #[async_recursion::async_recursion]
-    pub async fn invoke_special<T, U>(&self, instance: &Box<dyn ClassInstance>, class_name: &str, name: &str, descriptor: &str, args: T) -> Result<U>
+    pub async fn invoke_special<T, U>(
+        &self,
+        instance: &(impl AsClassInstance + Sync + ?Sized),
+        class_name: &str,
+        name: &str,
+        descriptor: &str,
+        args: T,
+    ) -> Result<U>
     where
         T: InvokeArg,
-        U: From<JavaValue>,
+        U: TryFrom<JavaValue>,
+        JavaError: From<U::Error>,
     {
+        let instance = self.non_null(instance).await?;
         let args = args.into_arg();
         tracing::trace!("Invoke special {class_name}.{name}:{descriptor}({args:?})");
 
-        let class = self.resolve_class(class_name).await?;
+        // the instance's own class when named, as another loader may have a class with the same name
+        let class = match self.defined_class(&*instance.class_definition()) {
+            Some(x) if x.definition.name() == class_name => x,
+            _ => self.resolve_class(class_name).await?,
+        };
         let method = class.definition.method(name, descriptor, false);
 
         if let Some(method) = method {
-            let args = iter::once(JavaValue::Object(Some(clone_box(&**instance))))
+            self.constrain_loaders(&*class.definition, descriptor).await?;
+
+            let args = iter::once(JavaValue::Object(Some(clone_box(instance))))
                 .chain(args.into_vec())
                 .collect::<Vec<_>>();
 
@@ -385,9 +481,9 @@ impl Jvm {
             }
 
             Ok(self
-                .execute_method(&class, Some(instance.clone()), &method, args.into_boxed_slice())
+                .execute_method(&class, Some(clone_box(instance)), &method, args.into_boxed_slice())
                 .await?
-                .into())
+                .try_into()?)
         } else {
             Err(self
                 .exception("java/lang/NoSuchMethodError", &format!("{class_name}.{name}:{descriptor}"))
@@ -396,11 +492,12 @@ impl Jvm {
     }
 
     #[async_recursion::async_recursion]
-    pub async fn store_array<T, U>(&self, array: &mut Box<dyn ClassInstance>, offset: usize, values: T) -> Result<()>
+    pub async fn store_array<T, U>(&self, array: &mut (impl AsClassInstanceMut + Send + ?Sized), offset: usize, values: T) -> Result<()>
     where
         T: IntoIterator<Item = U> + Send,
         U: Into<JavaValue>,
     {
+        let array = self.non_null_mut(array).await?;
         tracing::trace!("Store array {} at offset {offset}", array.class_definition().name());
 
         let values = values.into_iter().map(|x| x.into()).collect::<Vec<_>>();
@@ -415,6 +512,10 @@ impl Jvm {
                 .await);
         }
 
+        if values.iter().any(|x| self.is_young(x)) {
+            self.remember_object(array);
+        }
+
         let array = array.as_array_instance_mut();
 
         if let Some(array) = array {
@@ -426,10 +527,12 @@ impl Jvm {
         }
     }
 
-    pub async fn load_array<T>(&self, array: &Box<dyn ClassInstance>, offset: usize, count: usize) -> Result<Vec<T>>
+    pub async fn load_array<T>(&self, array: &(impl AsClassInstance + ?Sized), offset: usize, count: usize) -> Result<Vec<T>>
     where
-        T: From<JavaValue>,
+        T: TryFrom<JavaValue>,
+        JavaError: From<T::Error>,
     {
+        let array = self.non_null(array).await?;
         tracing::trace!("Load array {} at offset {offset}", array.class_definition().name());
 
         let array_size = self.array_length(array).await?;
@@ -447,22 +550,23 @@ impl Jvm {
         if let Some(array) = array {
             let values = array.load(offset, count)?;
 
-            let thread_id = (self.inner.get_current_thread_id)();
-            let mut threads = self.inner.threads.write();
-            let local_variables = threads.get_mut(&thread_id).unwrap().top_frame_mut().local_variables_mut();
-            values.iter().for_each(|value| {
-                if let JavaValue::Object(Some(instance)) = value {
-                    local_variables.push(instance.clone());
-                }
-            });
+            self.with_current_thread(|thread| {
+                let local_variables = thread.top_frame_mut().local_variables_mut();
+                values.iter().for_each(|value| {
+                    if let JavaValue::Object(Some(instance)) = value {
+                        local_variables.push(instance.clone());
+                    }
+                });
+            })?;
 
-            Ok(iter::IntoIterator::into_iter(values).map(|x| x.into()).collect::<Vec<_>>())
+            iter::IntoIterator::into_iter(values).map(|x| Ok(x.try_into()?)).collect()
         } else {
             Err(self.exception("java/lang/IllegalArgumentException", "Not an array").await)
         }
     }
 
-    pub async fn array_raw_buffer(&self, array: &Box<dyn ClassInstance>) -> Result<Box<dyn ArrayRawBuffer>> {
+    pub async fn array_raw_buffer(&self, array: &(impl AsClassInstance + ?Sized)) -> Result<Box<dyn ArrayRawBuffer>> {
+        let array = self.non_null(array).await?;
         let array = array.as_array_instance();
 
         if let Some(array) = array {
@@ -472,7 +576,8 @@ impl Jvm {
         }
     }
 
-    pub async fn array_raw_buffer_mut(&self, array: &mut Box<dyn ClassInstance>) -> Result<Box<dyn ArrayRawBufferMut>> {
+    pub async fn array_raw_buffer_mut(&self, array: &mut (impl AsClassInstanceMut + ?Sized)) -> Result<Box<dyn ArrayRawBufferMut>> {
+        let array = self.non_null_mut(array).await?;
         let array = array.as_array_instance_mut();
 
         if let Some(array) = array {
@@ -482,7 +587,8 @@ impl Jvm {
         }
     }
 
-    pub async fn array_length(&self, array: &Box<dyn ClassInstance>) -> Result<usize> {
+    pub async fn array_length(&self, array: &(impl AsClassInstance + ?Sized)) -> Result<usize> {
+        let array = self.non_null(array).await?;
         tracing::trace!("Get array length {}", array.class_definition().name());
 
         let array = array.as_array_instance();
@@ -494,7 +600,8 @@ impl Jvm {
         }
     }
 
-    pub async fn array_element_type(&self, array: &Box<dyn ClassInstance>) -> Result<JavaType> {
+    pub async fn array_element_type(&self, array: &(impl AsClassInstance + ?Sized)) -> Result<JavaType> {
+        let array = self.non_null(array).await?;
         tracing::trace!("Get array element type {}", array.class_definition().name());
 
         let array = array.as_array_instance();
@@ -504,7 +611,7 @@ impl Jvm {
 
             let type_name = &class.name()[1..]; // TODO can we store JavaType on class?
 
-            Ok(JavaType::parse(type_name))
+            JavaType::parse(type_name)
         } else {
             Err(self.exception("java/lang/IllegalArgumentException", "Not an array").await)
         }
@@ -514,27 +621,130 @@ impl Jvm {
         tracing::debug!("Destroy {}", instance.class_definition().name());
 
         self.inner.monitors.write().remove(&instance.identity());
-        self.inner.all_objects.write().remove(&instance);
+        if let Some(size) = self.inner.all_objects.write().remove(&instance) {
+            self.inner.heap_used.fetch_sub(size, Ordering::Relaxed);
+        }
+        {
+            let mut nursery = self.inner.nursery.write();
+            nursery.objects.remove(&instance);
+            nursery.remembered_objects.remove(&instance);
+        }
+        self.inner.finalization.registered.write().remove(&instance);
+        self.inner.references.registered.write().remove(&instance);
         instance.destroy();
 
         Ok(())
     }
 
-    pub fn shallow_clone(&self, instance: &Box<dyn ClassInstance>) -> Result<Box<dyn ClassInstance>> {
+    pub fn shallow_clone(&self, instance: &(impl AsClassInstance + ?Sized)) -> Result<Box<dyn ClassInstance>> {
+        let instance = instance
+            .as_class_instance()
+            .ok_or_else(|| JavaError::internal("Cloning a null reference"))?;
         let cloned = instance.shallow_clone()?;
-        let thread_id = (self.inner.get_current_thread_id)();
-        self.inner
-            .threads
-            .write()
-            .get_mut(&thread_id)
-            .unwrap()
-            .top_frame_mut()
-            .local_variables_mut()
-            .push(cloned.clone());
-        self.inner.all_objects.write().insert(cloned.clone());
+        self.add_local_ref(&cloned)?;
+        self.track_object(cloned.clone(), instance_size(self, &*cloned)?);
         Ok(cloned)
     }
 
+    // None means the heap is unbounded
+    pub fn set_max_heap_size(&self, max_heap_size: Option<usize>) {
+        self.inner.max_heap_size.store(max_heap_size.unwrap_or(usize::MAX), Ordering::Relaxed);
+    }
+
+    pub fn max_heap_size(&self) -> Option<usize> {
+        let max_heap_size = self.inner.max_heap_size.load(Ordering::Relaxed);
+
+        (max_heap_size != usize::MAX).then_some(max_heap_size)
+    }
+
+    // approximate bytes held by live (not yet collected) objects
+    pub fn heap_used(&self) -> usize {
+        self.inner.heap_used.load(Ordering::Relaxed)
+    }
+
+    // returns the previous value; other threads' allocations are still bounded while one thread builds an exception
+    fn set_allocating_exception(&self, allocating_exception: bool) -> bool {
+        let thread_id = (self.inner.get_current_thread_id)();
+        let mut threads = self.inner.threads.write();
+
+        let Some(thread) = threads.get_mut(&thread_id) else {
+            return false;
+        };
+        let previous = thread.is_allocating_exception();
+        thread.set_allocating_exception(allocating_exception);
+
+        previous
+    }
+
+    fn is_allocating_exception(&self) -> bool {
+        let thread_id = (self.inner.get_current_thread_id)();
+
+        self.inner.threads.read().get(&thread_id).is_some_and(|x| x.is_allocating_exception())
+    }
+
+    pub(crate) fn object_size(&self, definition: &dyn ClassDefinition) -> Result<usize> {
+        let identity = definition.identity();
+        if let Some(x) = self.inner.object_sizes.read().get(&identity) {
+            return Ok(*x);
+        }
+
+        let size = object_size(self, definition)?;
+        // the identity of a definition that was never registered may be reused by another one once it's dropped
+        if self.inner.defining_loaders.read().contains_key(&identity) {
+            self.inner.object_sizes.write().insert(identity, size);
+        }
+
+        Ok(size)
+    }
+
+    fn heap_fits(&self, size: usize) -> bool {
+        self.heap_used().saturating_add(size) <= self.inner.max_heap_size.load(Ordering::Relaxed)
+    }
+
+    #[async_recursion::async_recursion]
+    async fn reserve_heap(&self, size: usize) -> Result<()> {
+        if self.heap_fits(size) || self.is_allocating_exception() {
+            return Ok(());
+        }
+
+        self.collect_garbage()?;
+        if self.heap_fits(size) {
+            return Ok(());
+        }
+
+        // all softly reachable objects are cleared before throwing OutOfMemoryError
+        self.collect_garbage_internal(true)?;
+        if self.heap_fits(size) {
+            return Ok(());
+        }
+
+        Err(self.exception("java/lang/OutOfMemoryError", "Java heap space").await)
+    }
+
+    fn track_object(&self, instance: Box<dyn ClassInstance>, size: usize) {
+        if self.inner.all_objects.write().insert(instance.clone(), size).is_none() {
+            self.inner.heap_used.fetch_add(size, Ordering::Relaxed);
+            self.inner.allocations_since_gc.fetch_add(1, Ordering::Relaxed);
+            self.inner.allocated_bytes_since_gc.fetch_add(size, Ordering::Relaxed);
+            self.inner.nursery.write().objects.insert(instance);
+        }
+    }
+
+    fn is_young(&self, value: &JavaValue) -> bool {
+        match value {
+            JavaValue::Object(Some(x)) => self.inner.nursery.read().objects.contains(x),
+            _ => false,
+        }
+    }
+
+    // write barrier: an old object now referencing a young one is scanned by minor collections
+    fn remember_object(&self, object: &dyn ClassInstance) {
+        let mut nursery = self.inner.nursery.write();
+        if !nursery.objects.contains(object) {
+            nursery.remembered_objects.insert(clone_box(object));
+        }
+    }
+
     // JVMS 5.1 string interning: equal string literals (and String.intern results) share one instance
     pub async fn intern_string(&self, value: &str) -> Result<Box<dyn ClassInstance>> {
         let key = value.encode_utf16().collect::<Vec<_>>();
@@ -569,22 +779,105 @@ impl Jvm {
     }
 
     pub fn has_class(&self, class_name: &str) -> bool {
-        self.inner.classes.read().contains_key(class_name)
+        self.get_class(class_name).is_some()
     }
 
+    // class named `class_name` loaded by the system class loader, or the bootstrap loader before there is one.
+    // doesn't depend on the calling class; loaded_class looks in a given loader's namespace
     pub fn get_class(&self, class_name: &str) -> Option<Class> {
-        self.inner.classes.read().get(class_name).cloned()
+        self.find_loaded_class(*self.inner.system_class_loader.read(), class_name)
+    }
+
+    // class named `class_name` defined or initiated by `class_loader`, None being the bootstrap loader
+    pub fn loaded_class(&self, class_loader: Option<&Box<dyn ClassInstance>>, class_name: &str) -> Option<Class> {
+        self.find_loaded_class(class_loader.map(|x| x.identity()), class_name)
+    }
+
+    // every loader delegates to the bootstrap loader eventually, so its classes are visible from all namespaces
+    fn find_loaded_class(&self, class_loader: ClassLoaderId, class_name: &str) -> Option<Class> {
+        let classes = self.inner.classes.read();
+
+        classes
+            .get(&class_loader)
+            .and_then(|x| x.get(class_name))
+            .or_else(|| classes.get(&None).and_then(|x| x.get(class_name)))
+            .cloned()
+    }
+
+    pub(crate) fn defining_loader(&self, definition: &dyn ClassDefinition) -> ClassLoaderId {
+        self.inner.defining_loaders.read().get(&definition.identity()).copied().flatten()
+    }
+
+    // the registered class of a definition
+    pub fn defined_class(&self, definition: &dyn ClassDefinition) -> Option<Class> {
+        self.referenced_class(definition, &definition.name())
+    }
+
+    // names a class refers to (superclass, interfaces) resolve through its defining loader
+    pub fn referenced_class(&self, definition: &dyn ClassDefinition, class_name: &str) -> Option<Class> {
+        self.find_loaded_class(self.defining_loader(definition), class_name)
+    }
+
+    pub async fn class_of(&self, definition: &dyn ClassDefinition) -> Result<Class> {
+        match self.defined_class(definition) {
+            Some(x) => Ok(x),
+            None => self.resolve_class(&definition.name()).await,
+        }
+    }
+
+    pub async fn resolve_referenced_class(&self, definition: &dyn ClassDefinition, class_name: &str) -> Result<Class> {
+        match self.referenced_class(definition, class_name) {
+            Some(x) => Ok(x),
+            None => self.resolve_class(class_name).await,
+        }
+    }
+
+    // same loader current_class_loader picks, without calling into java
+    fn context_class_loader(&self) -> ClassLoaderId {
+        if let Ok(Some(class)) = self.find_calling_class()
+            && let Some(x) = self.defining_loader(&*class.definition)
+        {
+            return Some(x);
+        }
+
+        *self.inner.system_class_loader.read()
     }
 
     pub async fn monitor_enter(&self, obj: &(impl AsClassInstance + ?Sized)) -> Result<()> {
+        let obj = self.non_null(obj).await?;
         let thread_id = (self.inner.get_current_thread_id)();
-        self.get_or_create_monitor(obj.as_class_instance()).enter(thread_id).await;
+        let monitor = self.get_or_create_monitor(obj);
+
+        if let Err(owner_thread_id) = monitor.try_enter(thread_id) {
+            self.notify_event(JvmEvent::MonitorContendedEnter {
+                object: obj,
+                owner_thread_id,
+            });
+
+            self.inner.blocked_threads.write().insert(thread_id, obj.identity());
+            if let Some(deadlock) = self.find_deadlock_from(thread_id) {
+                tracing::error!("{deadlock}");
+                self.notify_event(JvmEvent::DeadlockDetected { deadlock: &deadlock });
+
+                if self.inner.throw_on_deadlock.load(Ordering::Relaxed) {
+                    self.inner.blocked_threads.write().remove(&thread_id);
+                    let message = format!("Deadlock detected while locking {}", obj.class_definition().name());
+                    return Err(self.exception("java/lang/Error", &message).await);
+                }
+            }
+
+            monitor.enter(thread_id).await;
+            self.inner.blocked_threads.write().remove(&thread_id);
+            self.notify_event(JvmEvent::MonitorContendedEntered { object: obj });
+        }
+
         Ok(())
     }
 
     pub async fn monitor_exit(&self, obj: &(impl AsClassInstance + ?Sized)) -> Result<()> {
+        let obj = self.non_null(obj).await?;
         let thread_id = (self.inner.get_current_thread_id)();
-        match self.get_or_create_monitor(obj.as_class_instance()).exit(thread_id) {
+        match self.get_or_create_monitor(obj).exit(thread_id) {
             Ok(()) => Ok(()),
             Err(_) => Err(self
                 .exception("java/lang/IllegalMonitorStateException", "current thread does not own the monitor")
@@ -593,8 +886,9 @@ impl Jvm {
     }
 
     pub async fn object_wait_prepare(&self, obj: &(impl AsClassInstance + ?Sized)) -> Result<(MonitorWait, MonitorWaitTimeout)> {
+        let obj = self.non_null(obj).await?;
         let thread_id = (self.inner.get_current_thread_id)();
-        match self.get_or_create_monitor(obj.as_class_instance()).prepare_wait(thread_id) {
+        match self.get_or_create_monitor(obj).prepare_wait(thread_id, obj.identity()) {
             Ok(wait) => Ok(wait),
             Err(_) => Err(self
                 .exception("java/lang/IllegalMonitorStateException", "current thread does not own the monitor")
@@ -602,14 +896,49 @@ impl Jvm {
         }
     }
 
-    pub async fn object_wait(&self, wait: MonitorWait) -> Result<()> {
-        wait.wait().await;
+    // throws InterruptedException, clearing the interrupt status, if the thread is interrupted while waiting
+    pub async fn object_wait(&self, mut wait: MonitorWait) -> Result<()> {
+        let interrupted = match self.interruptible(wait.notified()).await {
+            Some(()) => false,
+            // a notify that raced the interrupt wins, leaving the interrupt status set
+            None if !wait.cancel() => {
+                wait.notified().await;
+                false
+            }
+            None => true,
+        };
+        self.monitor_reenter(wait).await;
+
+        if interrupted {
+            self.clear_interrupted();
+            return Err(self.exception("java/lang/InterruptedException", "wait interrupted").await);
+        }
+
         Ok(())
     }
 
+    // a waiter contending for the monitor again is blocked on it like in monitor_enter. it can't give up
+    // re-acquiring, so a deadlock closed here is only reported
+    async fn monitor_reenter(&self, wait: MonitorWait) {
+        if wait.try_reenter().is_ok() {
+            return;
+        }
+
+        let thread_id = (self.inner.get_current_thread_id)();
+        self.inner.blocked_threads.write().insert(thread_id, wait.object_identity());
+        if let Some(deadlock) = self.find_deadlock_from(thread_id) {
+            tracing::error!("{deadlock}");
+            self.notify_event(JvmEvent::DeadlockDetected { deadlock: &deadlock });
+        }
+
+        wait.reenter().await;
+        self.inner.blocked_threads.write().remove(&thread_id);
+    }
+
     pub async fn object_notify(&self, obj: &(impl AsClassInstance + ?Sized), count: usize) -> Result<()> {
+        let obj = self.non_null(obj).await?;
         let thread_id = (self.inner.get_current_thread_id)();
-        match self.get_or_create_monitor(obj.as_class_instance()).notify(thread_id, count) {
+        match self.get_or_create_monitor(obj).notify(thread_id, count) {
             Ok(()) => Ok(()),
             Err(_) => Err(self
                 .exception("java/lang/IllegalMonitorStateException", "current thread does not own the monitor")
@@ -617,6 +946,73 @@ impl Jvm {
         }
     }
 
+    // debugging aid: a thread closing a deadlock cycle gets java.lang.Error instead of blocking forever
+    pub fn set_throw_on_deadlock(&self, throw_on_deadlock: bool) {
+        self.inner.throw_on_deadlock.store(throw_on_deadlock, Ordering::Relaxed);
+    }
+
+    pub fn monitors(&self) -> Vec<MonitorInfo> {
+        let blocked_threads = self.inner.blocked_threads.read();
+        let monitors = self.inner.monitors.read();
+
+        monitors
+            .iter()
+            .map(|(identity, monitor)| {
+                let entering_thread_ids = blocked_threads.iter().filter(|(_, x)| *x == identity).map(|(x, _)| *x).collect();
+                monitor.info(*identity, entering_thread_ids)
+            })
+            .collect()
+    }
+
+    // any cycle in the "thread blocked on a monitor owned by thread" graph
+    pub fn find_deadlock(&self) -> Option<Deadlock> {
+        let blocked_thread_ids = self.inner.blocked_threads.read().keys().copied().collect::<Vec<_>>();
+
+        blocked_thread_ids.into_iter().find_map(|x| self.find_deadlock_from(x))
+    }
+
+    // follows the wait-for graph from a blocked thread, returning the cycle if it leads back to it
+    fn find_deadlock_from(&self, start_thread_id: u64) -> Option<Deadlock> {
+        let cycle = {
+            let blocked_threads = self.inner.blocked_threads.read();
+            let monitors = self.inner.monitors.read();
+
+            let mut cycle = Vec::new();
+            let mut thread_id = start_thread_id;
+            loop {
+                let identity = *blocked_threads.get(&thread_id)?;
+                let monitor = monitors.get(&identity)?;
+                // a stale entry of a thread that has just acquired the monitor
+                let owner_thread_id = monitor.owner().filter(|x| *x != thread_id)?;
+
+                cycle.push((thread_id, identity, monitor.clone(), owner_thread_id));
+                if owner_thread_id == start_thread_id {
+                    break cycle;
+                }
+                if cycle.iter().any(|x| x.0 == owner_thread_id) {
+                    return None;
+                }
+                thread_id = owner_thread_id;
+            }
+        };
+
+        let threads = self.inner.threads.read();
+        let threads = cycle
+            .into_iter()
+            .map(|(thread_id, object_identity, monitor, owner_thread_id)| DeadlockedThread {
+                thread_id,
+                name: self.thread_name(thread_id, threads.get(&thread_id)),
+                object_identity,
+                class_name: monitor.class_name().to_owned(),
+                owner_thread_id,
+                owner_name: self.thread_name(owner_thread_id, threads.get(&owner_thread_id)),
+                stack_trace: threads.get(&thread_id).map(|x| self.java_stack_trace(x)).unwrap_or_default(),
+            })
+            .collect();
+
+        Some(Deadlock { threads })
+    }
+
     #[async_recursion::async_recursion]
     pub async fn resolve_class(&self, class_name: &str) -> Result<Class> {
         self.resolve_class_internal(class_name, None).await
@@ -626,15 +1022,18 @@ impl Jvm {
         let class = BootstrapClassLoaderWrapper::new(&*self.inner.bootstrap_class_loader)
             .load_class(self, class_name)
             .await?;
-        Ok(class.map(|class| class.java_class()))
+        class.map(|class| class.java_class()).transpose()
     }
 
     #[async_recursion::async_recursion]
     async fn resolve_class_internal(&self, class_name: &str, class_loader_wrapper: Option<&dyn ClassLoaderWrapper>) -> Result<Class> {
         tracing::trace!("Resolving class {class_name}");
-        let class = self.inner.classes.read().get(class_name).cloned();
+        let class_loader = match class_loader_wrapper {
+            Some(x) => x.class_loader_id(),
+            None => self.context_class_loader(),
+        };
 
-        if let Some(x) = class {
+        if let Some(x) = self.find_loaded_class(class_loader, class_name) {
             return Ok(x);
         }
 
@@ -657,38 +1056,116 @@ impl Jvm {
         let class = self.load_class(class_name, class_loader_wrapper).await?;
 
         // loader wrappers may build a fresh Class around an already-registered definition,
-        // so return the registry copy to keep init state shared
-        if let Some(registered) = self.get_class(class_name) {
-            return Ok(registered);
+        // so use the registry copy to keep init state shared
+        let class = self.defined_class(&*class.definition).unwrap_or(class);
+
+        self.record_initiated_class(class_loader_wrapper.class_loader_id(), class).await
+    }
+
+    // the initiating loader sees the class under its name from now on, and can't see two classes with the same name
+    async fn record_initiated_class(&self, class_loader: ClassLoaderId, class: Class) -> Result<Class> {
+        let class_name = class.definition.name();
+        self.check_constraints_on(class_loader, &class).await?;
+
+        let recorded = self
+            .inner
+            .classes
+            .write()
+            .entry(class_loader)
+            .or_default()
+            .entry(class_name.clone())
+            .or_insert_with(|| class.clone())
+            .clone();
+
+        if recorded.definition.identity() != class.definition.identity() {
+            return Err(self
+                .exception(
+                    "java/lang/LinkageError",
+                    &format!("a different class with name {class_name} was already loaded by the initiating loader"),
+                )
+                .await);
         }
 
-        Ok(class)
+        Ok(recorded)
+    }
+
+    // A java class using a field or method of a class with another defining loader constrains both loaders to see the same
+    // classes under the names in the member's descriptor (JVMS 5.3.4). `member_class` is where the member was looked up
+    async fn constrain_loaders(&self, member_class: &dyn ClassDefinition, descriptor: &str) -> Result<()> {
+        let class_names = descriptor_class_names(descriptor);
+        if class_names.is_empty() {
+            return Ok(());
+        }
+        let Ok(Some(calling_class)) = self.find_calling_class() else {
+            return Ok(());
+        };
+        let calling_loader = self.defining_loader(&*calling_class.definition);
+        let member_loader = self.defining_loader(member_class);
+        if calling_loader == member_loader {
+            return Ok(());
+        }
+
+        for class_name in class_names {
+            let loaders = self.inner.loader_constraints.merged_loaders(class_name, calling_loader, member_loader);
+            self.check_loader_constraints(class_name, loaders, None).await?;
+
+            self.inner.loader_constraints.add(class_name, calling_loader, member_loader);
+        }
+
+        Ok(())
+    }
+
+    // before `class_loader` defines or initiates `class`, the loaders constrained with it have to see the same class
+    async fn check_constraints_on(&self, class_loader: ClassLoaderId, class: &Class) -> Result<()> {
+        let class_name = class.definition.name();
+        let mut loaders = self.inner.loader_constraints.constrained_loaders(&class_name, class_loader);
+        loaders.remove(&class_loader);
+
+        self.check_loader_constraints(&class_name, loaders, Some(class)).await
+    }
+
+    // the loaders in `loaders` that have loaded `class_name`, and `class` if given, all see the same class
+    async fn check_loader_constraints(&self, class_name: &str, loaders: BTreeSet<ClassLoaderId>, class: Option<&Class>) -> Result<()> {
+        let mut seen = class.map(|x| x.definition.identity());
+        for class_loader in loaders {
+            let Some(loaded) = self.find_loaded_class(class_loader, class_name) else {
+                continue;
+            };
+
+            match seen {
+                Some(x) if x != loaded.definition.identity() => {
+                    return Err(self
+                        .exception("java/lang/LinkageError", &format!("loader constraint violation for class {class_name}"))
+                        .await);
+                }
+                _ => seen = Some(loaded.definition.identity()),
+            }
+        }
+
+        Ok(())
     }
 
     async fn load_class(&self, class_name: &str, class_loader_wrapper: &dyn ClassLoaderWrapper) -> Result<Class> {
         tracing::debug!("Loading class {class_name}");
 
-        let class = class_loader_wrapper.load_class(self, class_name).await?;
-
-        if class.is_none() {
+        let Some(class) = class_loader_wrapper.load_class(self, class_name).await? else {
             tracing::error!("No such class: {class_name}");
 
             return Err(self.exception("java/lang/NoClassDefFoundError", class_name).await);
-        }
+        };
 
         tracing::debug!("Loaded class {class_name}");
 
-        Ok(class.unwrap())
+        Ok(class)
     }
 
-    #[allow(clippy::type_complexity)]
-    fn find_calling_class(&self) -> Result<Option<(Class, Option<Box<dyn ClassInstance>>)>> {
+    fn find_calling_class(&self) -> Result<Option<Class>> {
         let thread_id = (self.inner.get_current_thread_id)();
 
         let threads = self.inner.threads.read();
-        let thread = threads.get(&thread_id).unwrap();
+        let thread = threads.get(&thread_id).ok_or(JavaError::ThreadNotAttached)?;
 
-        Ok(thread.top_java_frame().map(|x| (x.class.clone(), x.class_instance.clone())))
+        Ok(thread.top_java_frame().map(|x| x.class.clone()))
     }
 
     pub async fn register_class(
@@ -700,31 +1177,56 @@ impl Jvm {
 
         let java_class = Some(JavaLangClass::from_rust_class(self, class.clone(), class_loader.clone()).await?);
 
+        let identity = class.identity();
         let class = Class::new(class, java_class.clone());
 
-        if let Some(x) = class_loader {
-            self.register_class_internal(class, Some(&JavaClassLoaderWrapper::new(x))).await?;
+        let registered = if let Some(x) = class_loader {
+            self.register_class_internal(class, Some(&JavaClassLoaderWrapper::new(x))).await?
         } else {
-            self.register_class_internal(class, None).await?;
+            self.register_class_internal(class, None).await?
         };
 
+        if registered.definition.identity() != identity {
+            return Err(self
+                .exception(
+                    "java/lang/LinkageError",
+                    &format!("duplicate class definition: {}", registered.definition.name()),
+                )
+                .await);
+        }
+
         Ok(java_class)
     }
 
-    pub fn is_instance(&self, instance: &dyn ClassInstance, class_name: &str) -> bool {
-        self.is_type_assignable(
-            &JavaType::from_class_name(&instance.class_definition().name()),
-            &JavaType::from_class_name(class_name),
-        )
+    // false for null, as with instanceof, and for a malformed class name, which no instance can be of
+    pub fn is_instance(&self, instance: &(impl AsClassInstance + ?Sized), class_name: &str) -> bool {
+        let Some(instance) = instance.as_class_instance() else {
+            return false;
+        };
+
+        // the instance's own class rather than a lookup by its name, which may find another loader's class
+        let class = instance.class_definition();
+        if !class.name().starts_with('[') {
+            return self.is_inherited_from(&*class, class_name);
+        }
+
+        let (Ok(source), Ok(target)) = (
+            JavaType::from_class_name(&instance.class_definition().name()),
+            JavaType::from_class_name(class_name),
+        ) else {
+            return false;
+        };
+
+        self.is_type_assignable(&source, &target)
     }
 
     // aastore: whether value may be stored into array (JVMS 6.5 aastore)
     pub fn array_store_allowed(&self, array: &dyn ClassInstance, value: &dyn ClassInstance) -> bool {
-        let JavaType::Array(component) = JavaType::parse(&array.class_definition().name()) else {
+        let Ok(JavaType::Array(component)) = JavaType::parse(&array.class_definition().name()) else {
             return true;
         };
 
-        self.is_type_assignable(&JavaType::from_class_name(&value.class_definition().name()), &component)
+        JavaType::from_class_name(&value.class_definition().name()).is_ok_and(|x| self.is_type_assignable(&x, &component))
     }
 
     // JVMS 4.10.3 subtyping, including array covariance
@@ -745,7 +1247,7 @@ impl Jvm {
     }
 
     fn is_class_assignable(&self, source: &str, target: &str) -> bool {
-        match self.get_class(source) {
+        match self.find_loaded_class(self.context_class_loader(), source) {
             Some(class) => self.is_inherited_from(&*class.definition, target),
             None => false,
         }
@@ -761,15 +1263,15 @@ impl Jvm {
                 return true;
             }
 
-            let interface_class = self.inner.classes.read().get(&interface).unwrap().definition.clone();
-            if self.is_inherited_from(&*interface_class, class_name) {
+            let interface_class = self.referenced_class(class, &interface).map(|x| x.definition);
+            if interface_class.is_some_and(|x| self.is_inherited_from(&*x, class_name)) {
                 return true;
             }
         }
 
         if let Some(super_class) = class.super_class_name() {
-            let super_class = self.inner.classes.read().get(&super_class).unwrap().definition.clone();
-            self.is_inherited_from(&*super_class, class_name)
+            let super_class = self.referenced_class(class, &super_class).map(|x| x.definition);
+            super_class.is_some_and(|x| self.is_inherited_from(&*x, class_name))
         } else {
             false
         }
@@ -778,10 +1280,22 @@ impl Jvm {
     pub async fn exception(&self, r#type: &str, message: &str) -> JavaError {
         tracing::info!("throwing java exception: {} {message}", r#type);
 
-        let message_str = JavaLangString::from_rust_string(self, message).await.unwrap();
-        let instance = self.new_class(r#type, "(Ljava/lang/String;)V", (message_str,)).await.unwrap();
+        // exceptions (OutOfMemoryError included) must be constructible even when the heap is exhausted
+        let nested = self.set_allocating_exception(true);
+        let instance = match JavaLangString::from_rust_string(self, message).await {
+            Ok(message_str) => self.new_class(r#type, "(Ljava/lang/String;)V", (message_str,)).await,
+            Err(e) => Err(e),
+        };
+        if !nested {
+            self.set_allocating_exception(false);
+        }
+        // if the exception itself can't be created, report why instead
+        let instance = match instance {
+            Ok(x) => x,
+            Err(e) => return e,
+        };
 
-        JavaError::JavaException(instance)
+        self.throw(instance)
     }
 
     pub fn stack_trace(&self) -> Vec<String> {
@@ -789,8 +1303,11 @@ impl Jvm {
 
         let thread_id = (self.inner.get_current_thread_id)();
         let threads = self.inner.threads.read();
-        let thread = threads.get(&thread_id).unwrap();
 
+        threads.get(&thread_id).map(|x| self.java_stack_trace(x)).unwrap_or_default()
+    }
+
+    fn java_stack_trace(&self, thread: &JvmThread) -> Vec<String> {
         thread
             .iter_java_frame()
             .rev()
@@ -806,19 +1323,312 @@ impl Jvm {
     }
 
     pub fn collect_garbage(&self) -> Result<usize> {
+        // soft references are cleared once the heap is three quarters full
+        let max_heap_size = self.inner.max_heap_size.load(Ordering::Relaxed);
+        let clear_soft = max_heap_size != usize::MAX && self.heap_used() >= max_heap_size / 4 * 3;
+
+        self.collect_garbage_internal(clear_soft)
+    }
+
+    fn collect_garbage_internal(&self, clear_soft: bool) -> Result<usize> {
         tracing::trace!("Collecting garbage");
 
-        let garbage = {
+        self.notify_event(JvmEvent::GarbageCollectionStart);
+
+        self.inner.allocations_since_gc.store(0, Ordering::Relaxed);
+        self.inner.allocated_bytes_since_gc.store(0, Ordering::Relaxed);
+
+        let (garbage, unreachable_referents, unreachable_class_loaders) = {
             let threads = self.inner.threads.read();
             let global_references = self.inner.global_references.objects.read();
             let all_objects = self.inner.all_objects.read();
             let classes = self.inner.classes.read();
+            let defining_loaders = self.inner.defining_loaders.read();
             let interned_strings = self.interned_strings();
+            let finalization_queue = self.inner.finalization.queue.lock();
+            let pending_references = self.inner.references.pending.lock();
+            let registered_references = self.inner.references.registered.read();
+
+            let roots = GcRoots {
+                threads: &threads,
+                global_references: &global_references,
+                classes: &classes,
+                interned_strings: &interned_strings,
+                finalization_queue: &finalization_queue,
+                pending_references: &pending_references,
+            };
+            let weak_referents = WeakReferents {
+                registered: &registered_references,
+                clear_soft,
+            };
 
-            determine_garbage(self, &threads, &global_references, &all_objects, &classes, &interned_strings)
+            determine_garbage(self, &roots, &defining_loaders, &all_objects, &weak_referents)
         };
+        let garbage = self.enqueue_finalizable(garbage);
+        self.clear_references(unreachable_referents, &garbage);
+        self.unload_classes(unreachable_class_loaders);
+        self.inner.nursery.write().promote();
+
+        self.inner.gc_statistics.write().collections += 1;
 
+        Ok(self.destroy_garbage(garbage))
+    }
+
+    // minor collection: only traces objects allocated since the previous collection, then promotes the survivors
+    pub fn collect_young_garbage(&self) -> Result<usize> {
+        tracing::trace!("Collecting young garbage");
+
+        self.notify_event(JvmEvent::GarbageCollectionStart);
+
+        self.inner.allocations_since_gc.store(0, Ordering::Relaxed);
+        self.inner.allocated_bytes_since_gc.store(0, Ordering::Relaxed);
+
+        let garbage = {
+            let threads = self.inner.threads.read();
+            let global_references = self.inner.global_references.objects.read();
+            let classes = self.inner.classes.read();
+            let interned_strings = self.interned_strings();
+            let finalization_queue = self.inner.finalization.queue.lock();
+            let pending_references = self.inner.references.pending.lock();
+            let mut nursery = self.inner.nursery.write();
+
+            let roots = GcRoots {
+                threads: &threads,
+                global_references: &global_references,
+                classes: &classes,
+                interned_strings: &interned_strings,
+                finalization_queue: &finalization_queue,
+                pending_references: &pending_references,
+            };
+
+            let garbage = determine_young_garbage(self, &roots, &nursery);
+            nursery.promote();
+
+            garbage
+        };
+        let garbage = self.enqueue_finalizable(garbage);
+
+        {
+            let mut statistics = self.inner.gc_statistics.write();
+            statistics.collections += 1;
+            statistics.minor_collections += 1;
+        }
+
+        Ok(self.destroy_garbage(garbage))
+    }
+
+    // Weak and soft references are cleared even if finalization resurrected their referent. Phantom references wait until
+    // the referent is really reclaimed. Cleared references with a queue become pending for the reference handler
+    fn clear_references(&self, unreachable_referents: Vec<UnreachableReferent>, garbage: &[Box<dyn ClassInstance>]) {
+        if unreachable_referents.is_empty() {
+            return;
+        }
+
+        let garbage = garbage.iter().collect::<HashSet<_>>();
+        let mut cleared = Vec::new();
+        for mut unreachable in unreachable_referents {
+            if unreachable.kind == ReferenceKind::Phantom && !garbage.contains(&unreachable.referent) {
+                continue;
+            }
+
+            let Some(field) = referent_field(self, &unreachable.reference) else {
+                continue;
+            };
+            if let Err(error) = unreachable.reference.put_field(&*field, JavaValue::Object(None)) {
+                tracing::error!(?error, "failed to clear reference");
+                continue;
+            }
+            cleared.push(unreachable.reference);
+        }
+        if cleared.is_empty() {
+            return;
+        }
+
+        tracing::trace!("Clearing {} references", cleared.len());
+
+        {
+            let mut registered = self.inner.references.registered.write();
+            cleared.iter().for_each(|x| {
+                registered.remove(x);
+            });
+        }
+
+        let pending = cleared
+            .into_iter()
+            .filter(|x| {
+                let field = self
+                    .find_field(&*x.class_definition(), "queue", "Ljava/lang/ref/ReferenceQueue;")
+                    .ok()
+                    .flatten();
+
+                field.is_some_and(|field| matches!(x.get_field(&*field), Ok(JavaValue::Object(Some(_)))))
+            })
+            .collect::<Vec<_>>();
+        if pending.is_empty() {
+            return;
+        }
+
+        self.inner.references.pending.lock().extend(pending);
+        self.inner.references.pending_event.notify(usize::MAX);
+    }
+
+    // Drops the namespaces of unreachable class loaders, and with them their classes' static storage.
+    // Loaders whose instances finalization resurrected, or instances of whose classes it did, stay until a later collection
+    fn unload_classes(&self, class_loaders: Vec<usize>) {
+        if class_loaders.is_empty() {
+            return;
+        }
+
+        let pending = self.inner.finalization.queue.lock().iter().cloned().collect::<Vec<_>>();
+        let resurrected = find_all_reachable_objects(self, &pending)
+            .iter()
+            .flat_map(|x| [Some(x.identity()), self.defining_loader(&*x.class_definition())])
+            .flatten()
+            .collect::<HashSet<_>>();
+
+        let unloaded = {
+            let mut classes = self.inner.classes.write();
+            class_loaders
+                .into_iter()
+                .filter(|x| !resurrected.contains(x))
+                .filter_map(|x| Some((x, classes.remove(&Some(x))?)))
+                .collect::<Vec<_>>()
+        };
+
+        // namespaces also hold classes their loader only initiated loading of
+        let mut unloaded_class_names = Vec::new();
+        {
+            let mut defining_loaders = self.inner.defining_loaders.write();
+            for (class_loader, classes) in unloaded {
+                self.inner.native_methods.unload(class_loader);
+                self.inner.loader_constraints.remove_loader(Some(class_loader));
+                for (name, class) in classes {
+                    let identity = class.definition.identity();
+                    if defining_loaders.get(&identity) == Some(&Some(class_loader)) {
+                        defining_loaders.remove(&identity);
+                        self.inner.object_sizes.write().remove(&identity);
+                        self.inner.finalization.finalizable_classes.write().remove(&identity);
+                        unloaded_class_names.push(name);
+                    }
+                }
+            }
+        }
+
+        tracing::trace!("Unloaded {} classes", unloaded_class_names.len());
+
+        self.inner.gc_statistics.write().classes_unloaded += unloaded_class_names.len() as u64;
+        for class_name in &unloaded_class_names {
+            self.notify_event(JvmEvent::ClassUnloaded { class_name });
+        }
+    }
+
+    // called from the java.lang.ref reference constructors
+    pub fn register_reference(&self, reference: &(impl AsClassInstance + ?Sized), kind: ReferenceKind) {
+        if let Some(reference) = reference.as_class_instance() {
+            self.inner.references.registered.write().insert(clone_box(reference), kind);
+        }
+    }
+
+    // returns true exactly once, to the caller responsible for spawning the reference handler thread
+    pub fn claim_reference_handler_thread(&self) -> bool {
+        !self.inner.references.handler_started.swap(true, Ordering::Relaxed)
+    }
+
+    // a cleared reference waiting to be enqueued on its ReferenceQueue. the returned object is rooted in the current frame
+    pub fn poll_pending_reference(&self) -> Option<Box<dyn ClassInstance>> {
+        // unattached threads can't root it, so leave it queued
+        self.with_current_thread(|thread| {
+            let reference = self.inner.references.pending.lock().pop_front()?;
+            thread.top_frame_mut().local_variables_mut().push(reference.clone());
+
+            Some(reference)
+        })
+        .ok()
+        .flatten()
+    }
+
+    pub async fn next_pending_reference(&self) -> Box<dyn ClassInstance> {
+        loop {
+            let listener = self.inner.references.pending_event.listen();
+            if let Some(reference) = self.poll_pending_reference() {
+                return reference;
+            }
+            listener.await;
+        }
+    }
+
+    // unreachable objects with a pending finalizer are queued instead, keeping everything they reference alive
+    fn enqueue_finalizable(&self, garbage: Vec<Box<dyn ClassInstance>>) -> Vec<Box<dyn ClassInstance>> {
+        let finalizable = {
+            let mut registered = self.inner.finalization.registered.write();
+            garbage.iter().filter(|x| registered.remove(*x)).cloned().collect::<Vec<_>>()
+        };
+        if finalizable.is_empty() {
+            return garbage;
+        }
+
+        tracing::trace!("Queueing {} objects for finalization", finalizable.len());
+
+        let resurrected = find_all_reachable_objects(self, &finalizable);
+        self.inner.finalization.queue.lock().extend(finalizable);
+        self.inner.finalization.queue_event.notify(usize::MAX);
+
+        garbage.into_iter().filter(|x| !resurrected.contains(x)).collect()
+    }
+
+    // called from Object.<init>; returns true if the object's class overrides finalize()
+    pub fn register_finalizer(&self, object: &(impl AsClassInstance + ?Sized)) -> bool {
+        let Some(object) = object.as_class_instance() else {
+            return false;
+        };
+        let identity = object.class_definition().identity();
+        if !self.inner.finalization.finalizable_classes.read().contains(&identity) {
+            return false;
+        }
+
+        self.inner.finalization.registered.write().insert(clone_box(object));
+
+        true
+    }
+
+    fn overrides_finalize(&self, class: &Class) -> bool {
+        self.resolve_method(class, "finalize", "()V")
+            .is_some_and(|(declaring_class, _)| declaring_class.definition.name() != "java/lang/Object")
+    }
+
+    // returns true exactly once, to the caller responsible for spawning the finalizer thread
+    pub fn claim_finalizer_thread(&self) -> bool {
+        !self.inner.finalization.thread_started.swap(true, Ordering::Relaxed)
+    }
+
+    // the returned object is rooted in the current frame
+    pub fn poll_finalizable(&self) -> Option<Box<dyn ClassInstance>> {
+        self.with_current_thread(|thread| {
+            let object = self.inner.finalization.queue.lock().pop_front()?;
+            thread.top_frame_mut().local_variables_mut().push(object.clone());
+
+            Some(object)
+        })
+        .ok()
+        .flatten()
+    }
+
+    pub async fn next_finalizable(&self) -> Box<dyn ClassInstance> {
+        loop {
+            let listener = self.inner.finalization.queue_event.listen();
+            if let Some(object) = self.poll_finalizable() {
+                return object;
+            }
+            listener.await;
+        }
+    }
+
+    fn destroy_garbage(&self, garbage: Vec<Box<dyn ClassInstance>>) -> usize {
         let garbage_count = garbage.len();
+        let garbage_bytes = {
+            let all_objects = self.inner.all_objects.read();
+            garbage.iter().filter_map(|x| all_objects.get(x)).sum::<usize>()
+        };
 
         tracing::trace!("Garbage count: {garbage_count}");
 
@@ -826,31 +1636,117 @@ impl Jvm {
             let name = object.class_definition().name();
             tracing::trace!("Destroying {object:?}({name})");
 
-            self.destroy(object).unwrap();
+            if let Err(error) = self.destroy(object) {
+                tracing::error!(?error, "failed to destroy {name}");
+            }
+        }
+
+        {
+            let mut statistics = self.inner.gc_statistics.write();
+            statistics.objects_collected += garbage_count as u64;
+            statistics.bytes_collected += garbage_bytes as u64;
+        }
+
+        self.notify_event(JvmEvent::GarbageCollectionFinish { collected: garbage_count });
+
+        garbage_count
+    }
+
+    pub fn set_gc_policy(&self, policy: GcPolicy) {
+        *self.inner.gc_policy.write() = policy;
+    }
+
+    pub fn gc_policy(&self) -> GcPolicy {
+        self.inner.gc_policy.read().clone()
+    }
+
+    pub fn gc_statistics(&self) -> GcStatistics {
+        self.inner.gc_statistics.read().clone()
+    }
+
+    // Called by the interpreter between instructions, with every reference its frame still holds.
+    // Those replace the conservative roots accumulated on the current frame, then a collection runs if the policy says so.
+    pub fn safepoint<'a, I>(&self, roots: I) -> Result<()>
+    where
+        I: IntoIterator<Item = &'a Box<dyn ClassInstance>>,
+    {
+        self.with_current_thread(|thread| {
+            if let StackFrame::Java(frame) = thread.top_frame_mut() {
+                frame.local_variables = roots.into_iter().cloned().chain(frame.class_instance.clone()).collect();
+            }
+        })?;
+
+        let allocations = self.inner.allocations_since_gc.load(Ordering::Relaxed);
+        let allocated_bytes = self.inner.allocated_bytes_since_gc.load(Ordering::Relaxed);
+        if !self.inner.gc_policy.read().is_due(allocations, allocated_bytes) {
+            return Ok(());
+        }
+
+        let automatic_collections = {
+            let mut statistics = self.inner.gc_statistics.write();
+            statistics.automatic_collections += 1;
+            statistics.automatic_collections
+        };
+
+        let full_collection_interval = self.inner.gc_policy.read().full_collection_interval;
+        if full_collection_interval.is_none_or(|x| automatic_collections.is_multiple_of(x.max(1) as u64)) {
+            self.collect_garbage()?;
+        } else {
+            self.collect_young_garbage()?;
         }
 
-        Ok(garbage_count)
+        Ok(())
     }
 
-    pub(crate) async fn register_class_internal(&self, class: Class, class_loader_wrapper: Option<&dyn ClassLoaderWrapper>) -> Result<()> {
+    // registers the class in its defining loader's namespace, returning the class already there if the name is taken
+    pub(crate) async fn register_class_internal(&self, class: Class, class_loader_wrapper: Option<&dyn ClassLoaderWrapper>) -> Result<Class> {
+        let class_loader = class_loader_wrapper.and_then(|x| x.class_loader_id());
+
         if !class.definition.name().starts_with('[') {
             // ensure superclass and superinterfaces are loaded
             if let Some(super_class) = class.definition.super_class_name()
-                && !self.has_class(&super_class)
+                && self.find_loaded_class(class_loader, &super_class).is_none()
             {
                 self.resolve_class_internal(&super_class, class_loader_wrapper).await?;
             }
 
             for interface in class.definition.interface_names() {
-                if !self.has_class(&interface) {
+                if self.find_loaded_class(class_loader, &interface).is_none() {
                     self.resolve_class_internal(&interface, class_loader_wrapper).await?;
                 }
             }
         }
 
-        self.inner.classes.write().entry(class.definition.name().to_owned()).or_insert(class);
+        let class_name = class.definition.name();
+        let defined = self.inner.classes.read().get(&class_loader).is_some_and(|x| x.contains_key(&class_name));
+        if !defined {
+            self.check_constraints_on(class_loader, &class).await?;
+        }
 
-        Ok(())
+        let (registered, inserted) = {
+            let mut classes = self.inner.classes.write();
+            let namespace = classes.entry(class_loader).or_default();
+            if let Some(x) = namespace.get(&class_name) {
+                (x.clone(), false)
+            } else {
+                namespace.insert(class_name.clone(), class.clone());
+                self.inner.defining_loaders.write().insert(class.definition.identity(), class_loader);
+                (class, true)
+            }
+        };
+
+        if inserted {
+            if self.overrides_finalize(&registered) {
+                self.inner
+                    .finalization
+                    .finalizable_classes
+                    .write()
+                    .insert(registered.definition.identity());
+            }
+            self.notify_event(JvmEvent::ClassLoaded { class_name: &class_name });
+        }
+
+        Ok(registered)
     }
 
     #[async_recursion::async_recursion]
@@ -878,7 +1774,7 @@ impl Jvm {
 
         if let Some(super_name) = class.definition.super_class_name() {
             // resolution failure is not an initialization failure, so initialization may be retried
-            let super_class = match self.resolve_class(&super_name).await {
+            let super_class = match self.resolve_referenced_class(&*class.definition, &super_name).await {
                 Ok(x) => x,
                 Err(err) => {
                     class.finish_initialization(InitState::NotInitialized);
@@ -903,7 +1799,9 @@ impl Jvm {
             if let Err(err) = self.execute_method(class, None, &clinit, Box::new([])).await {
                 class.finish_initialization(InitState::Erroneous);
 
-                let JavaError::JavaException(exception) = &err;
+                let JavaError::JavaException(exception) = &err else {
+                    return Err(err);
+                };
                 if self.is_instance(&**exception, "java/lang/Error") {
                     return Err(err);
                 }
@@ -913,12 +1811,16 @@ impl Jvm {
                     .new_class("java/lang/ExceptionInInitializerError", "(Ljava/lang/Throwable;)V", (cause,))
                     .await?;
 
-                return Err(JavaError::JavaException(wrapped));
+                return Err(self.throw(wrapped));
             }
         }
 
         class.finish_initialization(InitState::Initialized);
 
+        self.notify_event(JvmEvent::ClassInitialized {
+            class_name: &class.definition.name(),
+        });
+
         Ok(())
     }
 
@@ -927,13 +1829,14 @@ impl Jvm {
     pub async fn attach_thread(&self, java_thread: Option<Box<dyn ClassInstance>>) -> Result<()> {
         let thread_id = (self.inner.get_current_thread_id)();
         self.inner.threads.write().insert(thread_id, JvmThread::new());
-        self.push_native_frame();
 
         let java_thread = match java_thread {
             Some(x) => x,
             None => self.new_class("java/lang/Thread", "(Z)V", (true,)).await?,
         };
-        self.inner.threads.write().get_mut(&thread_id).unwrap().set_java_thread(java_thread);
+        self.with_current_thread(|thread| thread.set_java_thread(java_thread))?;
+
+        self.notify_event(JvmEvent::ThreadStart { thread_id });
 
         Ok(())
     }
@@ -950,44 +1853,380 @@ impl Jvm {
         })
     }
 
+    pub fn add_event_listener<L>(&self, listener: L) -> JvmEventListenerId
+    where
+        L: JvmEventListener + 'static,
+    {
+        let id = self.inner.event_listeners.next_id.fetch_add(1, Ordering::Relaxed);
+        self.inner.event_listeners.listeners.write().insert(id, Arc::new(listener));
+
+        JvmEventListenerId(id)
+    }
+
+    pub fn remove_event_listener(&self, id: JvmEventListenerId) -> bool {
+        self.inner.event_listeners.listeners.write().remove(&id.0).is_some()
+    }
+
+    // raises an exception object, reporting it to event listeners. exceptions built in rust go through here like athrow does
+    pub fn throw(&self, exception: Box<dyn ClassInstance>) -> JavaError {
+        self.notify_event(JvmEvent::ExceptionThrown { exception: &*exception });
+
+        JavaError::JavaException(exception)
+    }
+
+    // for interpreters, once a handler in the current method catches an exception
+    pub fn exception_caught(&self, exception: &dyn ClassInstance) {
+        self.notify_event(JvmEvent::ExceptionCaught { exception });
+    }
+
+    // for thread implementations, once an exception ends the current thread's run method
+    pub fn uncaught_exception(&self, exception: &dyn ClassInstance) {
+        self.notify_event(JvmEvent::UncaughtException {
+            thread_id: self.current_thread_id(),
+            exception,
+        });
+    }
+
+    // listeners are called without holding any jvm lock, so they may call back into the jvm
+    pub(crate) fn notify_event(&self, event: JvmEvent<'_>) {
+        if self.inner.event_listeners.is_empty() {
+            return;
+        }
+
+        let listeners = self.inner.event_listeners.listeners.read().values().cloned().collect::<Vec<_>>();
+        for listener in listeners {
+            listener.on_event(self, &event);
+        }
+    }
+
+    // binds rust callbacks to native methods of a class, which doesn't have to be loaded yet.
+    // class_loader is the class's defining loader, None being the bootstrap loader
+    pub fn register_natives(&self, class_loader: Option<&Box<dyn ClassInstance>>, class_name: &str, methods: Vec<NativeMethod>) {
+        self.inner
+            .native_methods
+            .register(class_loader.map(|x| x.identity()), class_name, methods);
+    }
+
+    pub fn unregister_natives(&self, class_loader: Option<&Box<dyn ClassInstance>>, class_name: &str) {
+        self.inner.native_methods.unregister(class_loader.map(|x| x.identity()), class_name);
+    }
+
+    // natives that are bound once java code calls System.loadLibrary(name)
+    pub fn register_native_library(&self, name: &str, methods: Vec<(&str, NativeMethod)>) {
+        let methods = methods.into_iter().map(|(class_name, method)| (class_name.to_owned(), method)).collect();
+
+        self.inner.native_methods.register_library(name, methods);
+    }
+
+    // binds the library's natives to classes of the loader that defined the caller of System.loadLibrary,
+    // or of the bootstrap loader if called outside of java. returns false if no library was registered under the name
+    pub fn load_native_library(&self, name: &str) -> Result<bool> {
+        let class_loader = {
+            let thread_id = (self.inner.get_current_thread_id)();
+            let threads = self.inner.threads.read();
+            let thread = threads.get(&thread_id).ok_or(JavaError::ThreadNotAttached)?;
+
+            // the top frame is System.loadLibrary itself
+            thread
+                .iter_java_frame()
+                .rev()
+                .nth(1)
+                .and_then(|x| self.defining_loader(&*x.class.definition))
+        };
+
+        Ok(self.inner.native_methods.load_library(class_loader, name))
+    }
+
     pub fn detach_thread(&self) -> Result<()> {
         let thread_id = (self.inner.get_current_thread_id)();
+
+        self.notify_event(JvmEvent::ThreadEnd { thread_id });
+
         self.inner.threads.write().remove(&thread_id);
 
         Ok(())
     }
 
-    pub fn current_java_thread(&self) -> Box<dyn ClassInstance> {
+    pub fn current_thread_id(&self) -> u64 {
+        (self.inner.get_current_thread_id)()
+    }
+
+    pub fn current_java_thread(&self) -> Result<Box<dyn ClassInstance>> {
+        let thread_id = (self.inner.get_current_thread_id)();
+        let threads = self.inner.threads.read();
+        let thread = threads.get(&thread_id).ok_or(JavaError::ThreadNotAttached)?;
+
+        // attach_thread sets it right after creating the Thread object
+        thread
+            .java_thread()
+            .cloned()
+            .ok_or_else(|| JavaError::internal("Thread object is not created yet"))
+    }
+
+    // Thread.sleep marks the current thread so thread dumps can tell it apart from running ones
+    pub fn set_sleeping(&self, sleeping: bool) {
         let thread_id = (self.inner.get_current_thread_id)();
-        self.inner.threads.read().get(&thread_id).unwrap().java_thread().unwrap().clone()
+        if let Some(thread) = self.inner.threads.write().get_mut(&thread_id) {
+            thread.set_sleeping(sleeping);
+        }
+    }
+
+    // The interrupt status lives in the Thread's `interrupted` field, so threads interrupted before they're attached keep it.
+    // Interrupting also wakes whatever interruptible call the thread is blocked in
+    pub fn interrupt(&self, java_thread: &(impl AsClassInstance + ?Sized)) {
+        let Some(java_thread) = java_thread.as_class_instance() else {
+            return;
+        };
+        self.write_interrupted(java_thread, true);
+
+        let threads = self.inner.threads.read();
+        if let Some(thread) = threads.values().find(|x| x.java_thread().is_some_and(|x| **x == *java_thread)) {
+            thread.interrupt_event().notify(usize::MAX);
+        }
+    }
+
+    pub fn is_interrupted(&self, java_thread: &(impl AsClassInstance + ?Sized), clear: bool) -> bool {
+        let Some(java_thread) = java_thread.as_class_instance() else {
+            return false;
+        };
+        let interrupted = matches!(self.read_field(java_thread, "interrupted", "Z"), Some(JavaValue::Boolean(true)));
+        if interrupted && clear {
+            self.write_interrupted(java_thread, false);
+        }
+
+        interrupted
+    }
+
+    // Runs `future` until it completes or the current thread is interrupted, returning None in the latter case.
+    // The interrupt status is left set; callers clear it when they throw
+    pub async fn interruptible<F>(&self, future: F) -> Option<F::Output>
+    where
+        F: Future,
+    {
+        let mut future = pin!(future);
+        loop {
+            let thread_id = (self.inner.get_current_thread_id)();
+            let thread = {
+                let threads = self.inner.threads.read();
+                threads.get(&thread_id).map(|x| (x.interrupt_event().listen(), x.java_thread().cloned()))
+            };
+            let Some((listener, java_thread)) = thread else {
+                return Some(future.await);
+            };
+            if java_thread.is_some_and(|x| self.is_interrupted(&x, false)) {
+                return None;
+            }
+
+            if let Either::Left(x) = select(future.as_mut(), listener).await {
+                return Some(x);
+            }
+        }
+    }
+
+    fn clear_interrupted(&self) {
+        let thread_id = (self.inner.get_current_thread_id)();
+        let java_thread = self.inner.threads.read().get(&thread_id).and_then(|x| x.java_thread().cloned());
+        if let Some(java_thread) = java_thread {
+            self.write_interrupted(&*java_thread, false);
+        }
+    }
+
+    fn write_interrupted(&self, java_thread: &dyn ClassInstance, interrupted: bool) {
+        if let Ok(Some(field)) = self.find_field(&*java_thread.class_definition(), "interrupted", "Z") {
+            let _ = clone_box(java_thread).put_field(&*field, JavaValue::Boolean(interrupted));
+        }
+    }
+
+    // Doesn't allocate Java objects or need an attached thread, so it's safe to call from a signal handler task
+    pub fn thread_dump(&self) -> ThreadDump {
+        let deadlock = self.find_deadlock();
+        let monitors = self.monitors();
+        let blocked_threads = self.inner.blocked_threads.read().clone();
+
+        let threads = self.inner.threads.read();
+        let threads = threads
+            .iter()
+            .map(|(thread_id, thread)| {
+                let java_thread = thread.java_thread();
+                let name = self.thread_name(*thread_id, Some(thread));
+                let priority = match java_thread.and_then(|x| self.read_field(&**x, "priority", "I")) {
+                    Some(JavaValue::Int(x)) => x,
+                    _ => 5,
+                };
+                let daemon = matches!(
+                    java_thread.and_then(|x| self.read_field(&**x, "daemon", "Z")),
+                    Some(JavaValue::Boolean(true))
+                );
+
+                let state = if let Some(identity) = blocked_threads.get(thread_id) {
+                    let monitor = monitors.iter().find(|x| x.object_identity == *identity);
+                    ThreadState::Blocked {
+                        object_identity: *identity,
+                        class_name: monitor.map(|x| x.class_name.clone()).unwrap_or_default(),
+                        owner_thread_id: monitor.and_then(|x| x.owner_thread_id),
+                    }
+                } else if let Some(monitor) = monitors.iter().find(|x| x.waiting_thread_ids.contains(thread_id)) {
+                    ThreadState::Waiting {
+                        object_identity: monitor.object_identity,
+                        class_name: monitor.class_name.clone(),
+                    }
+                } else if thread.is_sleeping() {
+                    ThreadState::Sleeping
+                } else {
+                    ThreadState::Running
+                };
+
+                let stack_trace = thread
+                    .iter_frame()
+                    .rev()
+                    .map(|x| match x {
+                        StackFrame::Java(x) => format!("{}.{}", x.class.definition.name(), x.method),
+                        StackFrame::Native(_) => "<native frame>".to_owned(),
+                    })
+                    .collect();
+                let held_monitors = monitors.iter().filter(|x| x.owner_thread_id == Some(*thread_id)).cloned().collect();
+
+                ThreadInfo {
+                    thread_id: *thread_id,
+                    name,
+                    priority,
+                    daemon,
+                    state,
+                    stack_trace,
+                    held_monitors,
+                }
+            })
+            .collect();
+
+        ThreadDump { threads, deadlock }
+    }
+
+    // the java name of a thread, or a made-up one if it has none
+    fn thread_name(&self, thread_id: u64, thread: Option<&JvmThread>) -> String {
+        thread
+            .and_then(|x| x.java_thread())
+            .and_then(|x| match self.read_field(&**x, "name", "Ljava/lang/String;") {
+                Some(JavaValue::Object(Some(name))) => self.read_string(&*name),
+                _ => None,
+            })
+            .unwrap_or_else(|| format!("Thread-{thread_id}"))
+    }
+
+    // reads a field without rooting the value in the current frame
+    fn read_field(&self, object: &dyn ClassInstance, name: &str, descriptor: &str) -> Option<JavaValue> {
+        let field = self.find_field(&*object.class_definition(), name, descriptor).ok()??;
+
+        object.get_field(&*field).ok()
+    }
+
+    fn read_string(&self, string: &dyn ClassInstance) -> Option<String> {
+        let Some(JavaValue::Object(Some(value))) = self.read_field(string, "value", "[C") else {
+            return None;
+        };
+        let array = value.as_array_instance()?;
+        let values = array.load(0, array.length()).ok()?;
+        let chars = IntoIterator::into_iter(values).map(JavaChar::try_from).collect::<Result<Vec<_>>>().ok()?;
+
+        Some(String::from_utf16_lossy(&chars))
     }
 
     pub fn active_thread_count(&self) -> usize {
         self.inner.threads.read().len()
     }
 
-    // TODO we need safe, ergonomic api..
-    pub fn push_native_frame(&self) {
+    // java.lang.Thread objects of all attached threads. not rooted in the current frame: the thread table keeps them
+    // reachable while their threads are attached, so callers holding on to one past that root it themselves
+    pub fn java_threads(&self) -> Vec<Box<dyn ClassInstance>> {
+        self.inner.threads.read().values().filter_map(|x| x.java_thread().cloned()).collect()
+    }
+
+    // Thread.start calls this before spawning a non-daemon thread, and the thread calls non_daemon_thread_ended when it's done
+    pub fn non_daemon_thread_started(&self) {
+        self.inner.non_daemon_threads.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn non_daemon_thread_ended(&self) {
+        if self.inner.non_daemon_threads.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.inner.non_daemon_threads_event.notify(usize::MAX);
+        }
+    }
+
+    pub fn non_daemon_thread_count(&self) -> usize {
+        self.inner.non_daemon_threads.load(Ordering::SeqCst)
+    }
+
+    // the launcher waits here after main returns, before running shutdown hooks
+    pub async fn wait_for_non_daemon_threads(&self) {
+        loop {
+            let listener = self.inner.non_daemon_threads_event.listen();
+            if self.non_daemon_thread_count() == 0 {
+                return;
+            }
+            listener.await;
+        }
+    }
+
+    // objects the jvm hands out while the returned guard is alive are local references of its frame
+    pub fn push_native_frame(&self) -> Result<NativeFrame<'_>> {
         let thread_id = (self.inner.get_current_thread_id)();
-        self.inner.threads.write().get_mut(&thread_id).unwrap().push_native_frame();
+        let depth = self.with_current_thread(|thread| {
+            thread.push_native_frame();
+            thread.stack_depth()
+        })?;
+
+        Ok(NativeFrame { jvm: self, thread_id, depth })
     }
 
-    pub fn pop_frame(&self) {
+    fn with_current_thread<R>(&self, f: impl FnOnce(&mut JvmThread) -> R) -> Result<R> {
         let thread_id = (self.inner.get_current_thread_id)();
-        self.inner.threads.write().get_mut(&thread_id).unwrap().pop_frame();
+        let mut threads = self.inner.threads.write();
+        let thread = threads.get_mut(&thread_id).ok_or(JavaError::ThreadNotAttached)?;
+
+        Ok(f(thread))
     }
 
-    async fn current_class_loader(&self) -> Result<Box<dyn ClassInstance>> {
-        let calling_class = self.find_calling_class()?;
+    // roots a reference handed out to rust code in the current thread's top frame
+    fn add_local_ref(&self, instance: &Box<dyn ClassInstance>) -> Result<()> {
+        self.with_current_thread(|thread| thread.top_frame_mut().local_variables_mut().push(instance.clone()))
+    }
 
-        if let Some((class, class_instance)) = calling_class {
-            // called in java
+    async fn non_null<'a>(&self, instance: &'a (impl AsClassInstance + ?Sized)) -> Result<&'a dyn ClassInstance> {
+        match instance.as_class_instance() {
+            Some(x) => Ok(x),
+            None => Err(self.exception("java/lang/NullPointerException", "null").await),
+        }
+    }
 
-            if self.is_inherited_from(&*class.definition, "java/lang/ClassLoader") {
-                return Ok(class_instance.unwrap());
-            }
+    async fn non_null_mut<'a>(&self, instance: &'a mut (impl AsClassInstanceMut + ?Sized)) -> Result<&'a mut dyn ClassInstance> {
+        match instance.as_class_instance_mut() {
+            Some(x) => Ok(x),
+            None => Err(self.exception("java/lang/NullPointerException", "null").await),
+        }
+    }
+
+    // superclasses and interfaces are registered before their subclasses, so a miss here means the class table is broken
+    fn loaded_class_definition(&self, class: &dyn ClassDefinition, class_name: &str) -> Result<Box<dyn ClassDefinition>> {
+        self.referenced_class(class, class_name)
+            .map(|x| x.definition)
+            .ok_or_else(|| JavaError::internal(format!("Class {class_name} is not loaded")))
+    }
+
+    pub(crate) fn pop_native_frame(&self, thread_id: u64, depth: usize) {
+        let mut threads = self.inner.threads.write();
+        let Some(thread) = threads.get_mut(&thread_id) else {
+            return; // thread already detached
+        };
+        debug_assert_eq!(thread.stack_depth(), depth, "native frames must be dropped in reverse order");
+
+        thread.pop_frame();
+    }
 
-            let calling_class_class_loader = JavaLangClass::class_loader(self, &class.java_class()).await?;
+    async fn current_class_loader(&self) -> Result<Box<dyn ClassInstance>> {
+        let calling_class = self.find_calling_class()?;
+
+        if let Some(class) = calling_class {
+            // called in java, references resolve through the calling class's defining loader
+            let calling_class_class_loader = JavaLangClass::class_loader(self, &class.java_class()?).await?;
             if let Some(x) = calling_class_class_loader {
                 Ok(x)
             } else {
@@ -1012,7 +2251,10 @@ impl Jvm {
         drop(monitors);
 
         let mut monitors = self.inner.monitors.write();
-        monitors.entry(key).or_insert_with(|| Arc::new(Monitor::new())).clone()
+        monitors
+            .entry(key)
+            .or_insert_with(|| Arc::new(Monitor::new(&obj.class_definition().name())))
+            .clone()
     }
 
     // JVMS 5.4.3.2 field resolution: search the class, then its superinterfaces, then its superclass.
@@ -1027,14 +2269,14 @@ impl Jvm {
         }
 
         for interface in class.definition.interface_names() {
-            if let Some(interface_class) = self.get_class(&interface)
+            if let Some(interface_class) = self.referenced_class(&*class.definition, &interface)
                 && let Some(found) = self.resolve_field(&interface_class, name, descriptor)
             {
                 return Some(found);
             }
         }
 
-        let super_class = self.get_class(&class.definition.super_class_name()?)?;
+        let super_class = self.referenced_class(&*class.definition, &class.definition.super_class_name()?)?;
         self.resolve_field(&super_class, name, descriptor)
     }
 
@@ -1049,7 +2291,7 @@ impl Jvm {
             return Some((class.clone(), method));
         }
 
-        let super_class = self.get_class(&class.definition.super_class_name()?)?;
+        let super_class = self.referenced_class(&*class.definition, &class.definition.super_class_name()?)?;
         self.resolve_method(&super_class, name, descriptor)
     }
 
@@ -1059,7 +2301,7 @@ impl Jvm {
         if let Some(x) = field {
             Ok(Some(x))
         } else if let Some(x) = class.super_class_name() {
-            let super_class = self.inner.classes.read().get(&x).unwrap().definition.clone();
+            let super_class = self.loaded_class_definition(class, &x)?;
             self.find_field(&*super_class, name, descriptor)
         } else {
             Ok(None)
@@ -1074,7 +2316,7 @@ impl Jvm {
                 return Ok(Some(x));
             }
         } else if let Some(x) = class.super_class_name() {
-            let super_class = self.inner.classes.read().get(&x).unwrap().definition.clone();
+            let super_class = self.loaded_class_definition(class, &x)?;
             return self.find_virtual_method(&*super_class, name, descriptor, is_static);
         }
 
@@ -1092,7 +2334,10 @@ impl Jvm {
         let method_str = format!("{}{}", method.name(), method.descriptor());
 
         let synchronized_object = if method.access_flags().contains(MethodAccessFlags::SYNCHRONIZED) {
-            Some(class_instance.clone().unwrap_or_else(|| class.java_class()))
+            Some(match class_instance.clone() {
+                Some(x) => x,
+                None => class.java_class()?,
+            })
         } else {
             None
         };
@@ -1100,17 +2345,57 @@ impl Jvm {
             self.monitor_enter(object).await?;
         }
 
-        self.inner
-            .threads
-            .write()
-            .get_mut(&thread_id)
-            .unwrap()
-            .push_java_frame(class, class_instance, &method_str, &args);
+        let pushed = match self.inner.threads.write().get_mut(&thread_id) {
+            Some(thread) => {
+                thread.push_java_frame(class, class_instance, &method_str, &args);
+                true
+            }
+            None => false,
+        };
+        if !pushed {
+            if let Some(object) = &synchronized_object {
+                self.monitor_exit(object).await?;
+            }
+            return Err(JavaError::ThreadNotAttached);
+        }
 
-        let result = method.run(self, args).await;
+        let class_name = (!self.inner.event_listeners.is_empty()).then(|| class.definition.name());
+        if let Some(class_name) = &class_name {
+            self.notify_event(JvmEvent::MethodEntry {
+                class_name,
+                method: &method_str,
+            });
+        }
+
+        let native = if method.access_flags().contains(MethodAccessFlags::NATIVE) {
+            self.inner.native_methods.find(
+                self.defining_loader(&*class.definition),
+                &class.definition.name(),
+                &method.name(),
+                &method.descriptor(),
+            )
+        } else {
+            None
+        };
+        let result = match native {
+            Some(x) => x.call(self, args).await,
+            None => method.run(self, args).await,
+        };
 
         tracing::trace!("Execute result: {result:?}");
 
+        if let Some(class_name) = &class_name {
+            let exception = match &result {
+                Err(JavaError::JavaException(exception)) => Some(&**exception),
+                _ => None,
+            };
+            self.notify_event(JvmEvent::MethodExit {
+                class_name,
+                method: &method_str,
+                exception,
+            });
+        }
+
         let returned_reference = match &result {
             Ok(JavaValue::Object(Some(instance))) => Some(instance.clone()),
             Err(JavaError::JavaException(exception)) => Some(exception.clone()),
@@ -1118,10 +2403,11 @@ impl Jvm {
         };
         {
             let mut threads = self.inner.threads.write();
-            let thread = threads.get_mut(&thread_id).unwrap();
-            thread.pop_frame();
-            if let Some(returned_reference) = returned_reference {
-                thread.top_frame_mut().local_variables_mut().push(returned_reference);
+            if let Some(thread) = threads.get_mut(&thread_id) {
+                thread.pop_frame();
+                if let Some(returned_reference) = returned_reference {
+                    thread.top_frame_mut().local_variables_mut().push(returned_reference);
+                }
             }
         }
 
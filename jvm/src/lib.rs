@@ -7,15 +7,26 @@ mod as_any;
 mod class_definition;
 mod class_instance;
 mod class_loader;
+mod deadlock;
+mod descriptor;
 mod error;
+mod event;
 mod field;
+mod finalization;
+mod future;
 mod garbage_collector;
 mod global_ref;
+mod heap;
 mod invoke_arg;
 mod jvm;
+mod loader_constraints;
 mod method;
 mod monitor;
+mod native_frame;
+mod native_method;
+mod reference;
 mod thread;
+mod thread_dump;
 mod r#type;
 mod value;
 
@@ -35,14 +46,22 @@ pub use self::{
     array_class_definition::ArrayClassDefinition,
     array_class_instance::{ArrayClassInstance, ArrayRawBuffer, ArrayRawBufferMut},
     class_definition::ClassDefinition,
-    class_instance::{Array, AsClassInstance, ClassInstance, ClassInstanceRef},
+    class_instance::{Array, AsClassInstance, AsClassInstanceMut, ClassInstance, ClassInstanceRef},
     class_loader::BootstrapClassLoader,
+    deadlock::{Deadlock, DeadlockedThread},
+    descriptor::{JavaClassName, JavaDescriptor, JavaParameters, MethodDescriptor},
     error::JavaError,
+    event::{JvmEvent, JvmEventListener, JvmEventListenerId},
     field::Field,
+    garbage_collector::{GcPolicy, GcStatistics},
     global_ref::GlobalRef,
     jvm::Jvm,
     method::Method,
-    monitor::{MonitorWait, MonitorWaitTimeout},
+    monitor::{MonitorInfo, MonitorWait, MonitorWaitTimeout},
+    native_frame::NativeFrame,
+    native_method::NativeMethod,
+    reference::ReferenceKind,
+    thread_dump::{ThreadDump, ThreadInfo, ThreadState},
     r#type::JavaType,
     value::{JavaChar, JavaValue},
 };
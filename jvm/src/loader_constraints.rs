@@ -0,0 +1,118 @@
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use parking_lot::RwLock;
+
+use crate::class_loader::ClassLoaderId;
+
+// JVMS 5.3.4 loader constraints: when a field or method is resolved across loaders, the classes named in its descriptor
+// have to be the same classes in the loaders on both sides. Constraints on a name are kept as sets of loaders that must agree
+pub(crate) struct LoaderConstraints {
+    constraints: RwLock<BTreeMap<String, Vec<BTreeSet<ClassLoaderId>>>>,
+}
+
+impl LoaderConstraints {
+    pub(crate) fn new() -> Self {
+        Self {
+            constraints: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    // loaders that must see the same class named `class_name` as `class_loader`, including itself
+    pub(crate) fn constrained_loaders(&self, class_name: &str, class_loader: ClassLoaderId) -> BTreeSet<ClassLoaderId> {
+        self.constraints
+            .read()
+            .get(class_name)
+            .and_then(|x| x.iter().find(|x| x.contains(&class_loader)))
+            .cloned()
+            .unwrap_or_else(|| BTreeSet::from([class_loader]))
+    }
+
+    // the loaders `add` would constrain together, for checking before the constraint is added
+    pub(crate) fn merged_loaders(&self, class_name: &str, first: ClassLoaderId, second: ClassLoaderId) -> BTreeSet<ClassLoaderId> {
+        let mut loaders = self.constrained_loaders(class_name, first);
+        loaders.extend(self.constrained_loaders(class_name, second));
+
+        loaders
+    }
+
+    pub(crate) fn add(&self, class_name: &str, first: ClassLoaderId, second: ClassLoaderId) {
+        let mut constraints = self.constraints.write();
+        let sets = constraints.entry(class_name.to_string()).or_default();
+
+        let mut loaders = BTreeSet::from([first, second]);
+        sets.retain(|x| {
+            let constrained = x.contains(&first) || x.contains(&second);
+            if constrained {
+                loaders.extend(x);
+            }
+            !constrained
+        });
+        sets.push(loaders);
+    }
+
+    // an unloaded loader's id may be reused by a new loader, which starts without constraints
+    pub(crate) fn remove_loader(&self, class_loader: ClassLoaderId) {
+        let mut constraints = self.constraints.write();
+        for sets in constraints.values_mut() {
+            sets.iter_mut().for_each(|x| {
+                x.remove(&class_loader);
+            });
+            sets.retain(|x| x.len() > 1);
+        }
+        constraints.retain(|_, x| !x.is_empty());
+    }
+}
+
+// class names in a field or method descriptor, including array element classes
+pub(crate) fn descriptor_class_names(descriptor: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+
+    let mut rest = descriptor;
+    while let Some(start) = rest.find('L') {
+        let Some(end) = rest[start..].find(';') else {
+            break;
+        };
+        names.push(&rest[start + 1..start + end]);
+        rest = &rest[start + end + 1..];
+    }
+
+    names
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{collections::BTreeSet, vec};
+
+    use super::{LoaderConstraints, descriptor_class_names};
+
+    #[test]
+    fn test_descriptor_class_names() {
+        assert_eq!(
+            descriptor_class_names("(ILjava/lang/String;[[LLoader;J)[Ljava/lang/Object;"),
+            vec!["java/lang/String", "Loader", "java/lang/Object"]
+        );
+        assert!(descriptor_class_names("([IJ)V").is_empty());
+    }
+
+    #[test]
+    fn test_constraints_merge() {
+        let constraints = LoaderConstraints::new();
+        constraints.add("Shared", Some(1), Some(2));
+        constraints.add("Shared", Some(3), None);
+        constraints.add("Shared", Some(2), Some(3));
+        constraints.add("Other", Some(1), Some(4));
+
+        assert_eq!(
+            constraints.constrained_loaders("Shared", None),
+            BTreeSet::from([None, Some(1), Some(2), Some(3)])
+        );
+        assert_eq!(constraints.constrained_loaders("Shared", Some(4)), BTreeSet::from([Some(4)]));
+
+        constraints.remove_loader(Some(1));
+        assert_eq!(constraints.constrained_loaders("Other", Some(4)), BTreeSet::from([Some(4)]));
+    }
+}
@@ -1,9 +1,15 @@
-use alloc::{collections::VecDeque, sync::Arc};
+use alloc::{
+    collections::VecDeque,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
 
 use event_listener::{Event, EventListener};
 use parking_lot::Mutex;
 
 pub(crate) struct Monitor {
+    class_name: String,
     state: Mutex<MonitorState>,
     entry_event: Event,
 }
@@ -17,21 +23,23 @@ struct MonitorState {
 
 struct MonitorWaiter {
     id: u64,
+    thread_id: u64,
     event: Arc<Event>,
 }
 
 pub struct MonitorWait {
     monitor: Arc<Monitor>,
     listener: EventListener,
+    waiter_id: u64,
     depth: usize,
     thread_id: u64,
+    object_identity: usize,
 }
 
 #[derive(Clone)]
 pub struct MonitorWaitTimeout {
     monitor: Arc<Monitor>,
     waiter_id: u64,
-    event: Arc<Event>,
 }
 
 #[derive(Debug)]
@@ -39,9 +47,24 @@ pub(crate) enum MonitorError {
     NotOwner,
 }
 
+// snapshot of an object's monitor, returned by Jvm::monitors
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MonitorInfo {
+    pub object_identity: usize,
+    pub class_name: String,
+    pub owner_thread_id: Option<u64>,
+    // recursion depth of the owner
+    pub depth: usize,
+    // threads blocked in monitor_enter
+    pub entering_thread_ids: Vec<u64>,
+    // threads in Object.wait()
+    pub waiting_thread_ids: Vec<u64>,
+}
+
 impl Monitor {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(class_name: &str) -> Self {
         Self {
+            class_name: class_name.to_string(),
             state: Mutex::new(MonitorState {
                 owner: None,
                 depth: 0,
@@ -55,25 +78,51 @@ impl Monitor {
     pub(crate) async fn enter(self: &Arc<Self>, thread_id: u64) {
         loop {
             let listener = self.entry_event.listen();
-            {
-                let mut state = self.state.lock();
-                match state.owner {
-                    None => {
-                        state.owner = Some(thread_id);
-                        state.depth = 1;
-                        return;
-                    }
-                    Some(owner) if owner == thread_id => {
-                        state.depth += 1;
-                        return;
-                    }
-                    Some(_) => {}
-                }
+            if self.try_enter(thread_id).is_ok() {
+                return;
             }
             listener.await;
         }
     }
 
+    pub(crate) fn class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    pub(crate) fn owner(&self) -> Option<u64> {
+        self.state.lock().owner
+    }
+
+    pub(crate) fn info(&self, object_identity: usize, entering_thread_ids: Vec<u64>) -> MonitorInfo {
+        let state = self.state.lock();
+
+        MonitorInfo {
+            object_identity,
+            class_name: self.class_name.clone(),
+            owner_thread_id: state.owner,
+            depth: state.depth,
+            entering_thread_ids,
+            waiting_thread_ids: state.waiters.iter().map(|x| x.thread_id).collect(),
+        }
+    }
+
+    // returns the current owner if the monitor is held by another thread
+    pub(crate) fn try_enter(&self, thread_id: u64) -> core::result::Result<(), u64> {
+        let mut state = self.state.lock();
+        match state.owner {
+            None => {
+                state.owner = Some(thread_id);
+                state.depth = 1;
+                Ok(())
+            }
+            Some(owner) if owner == thread_id => {
+                state.depth += 1;
+                Ok(())
+            }
+            Some(owner) => Err(owner),
+        }
+    }
+
     pub(crate) fn exit(&self, thread_id: u64) -> core::result::Result<(), MonitorError> {
         let released = {
             let mut state = self.state.lock();
@@ -96,7 +145,11 @@ impl Monitor {
         Ok(())
     }
 
-    pub(crate) fn prepare_wait(self: &Arc<Self>, thread_id: u64) -> core::result::Result<(MonitorWait, MonitorWaitTimeout), MonitorError> {
+    pub(crate) fn prepare_wait(
+        self: &Arc<Self>,
+        thread_id: u64,
+        object_identity: usize,
+    ) -> core::result::Result<(MonitorWait, MonitorWaitTimeout), MonitorError> {
         let event = Arc::new(Event::new());
         let listener = event.listen();
 
@@ -111,7 +164,8 @@ impl Monitor {
             state.next_waiter_id = state.next_waiter_id.wrapping_add(1);
             state.waiters.push_back(MonitorWaiter {
                 id: waiter_id,
-                event: event.clone(),
+                thread_id,
+                event,
             });
             state.owner = None;
             state.depth = 0;
@@ -125,13 +179,14 @@ impl Monitor {
             MonitorWait {
                 monitor: self.clone(),
                 listener,
+                waiter_id,
                 depth,
                 thread_id,
+                object_identity,
             },
             MonitorWaitTimeout {
                 monitor: self.clone(),
                 waiter_id,
-                event,
             },
         ))
     }
@@ -155,35 +210,69 @@ impl Monitor {
         }
         Ok(())
     }
-}
-
-impl MonitorWait {
-    pub(crate) async fn wait(self) {
-        self.listener.await;
-        self.monitor.enter(self.thread_id).await;
-        self.monitor.state.lock().depth = self.depth;
-    }
-}
 
-impl MonitorWaitTimeout {
-    pub fn notify(self) {
+    // takes a waiter out of the wait set and wakes it, unless a notify already did
+    fn remove_waiter(&self, waiter_id: u64) -> bool {
         let event = {
-            let mut state = self.monitor.state.lock();
+            let mut state = self.state.lock();
             state
                 .waiters
                 .iter()
-                .position(|waiter| waiter.id == self.waiter_id)
+                .position(|waiter| waiter.id == waiter_id)
                 .and_then(|position| state.waiters.remove(position))
                 .map(|waiter| waiter.event)
         };
 
         if let Some(event) = event {
-            debug_assert!(Arc::ptr_eq(&event, &self.event));
             event.notify(1);
+            true
+        } else {
+            false
         }
     }
 }
 
+impl MonitorWait {
+    #[cfg(test)]
+    pub(crate) async fn wait(mut self) {
+        self.notified().await;
+        self.reenter().await;
+    }
+
+    // completes once the waiter is woken by a notify or a timeout
+    pub(crate) async fn notified(&mut self) {
+        (&mut self.listener).await;
+    }
+
+    // leaves the wait set without a notification. returns false if a notify already woke us, which must not be lost
+    pub(crate) fn cancel(&self) -> bool {
+        self.monitor.remove_waiter(self.waiter_id)
+    }
+
+    pub(crate) fn object_identity(&self) -> usize {
+        self.object_identity
+    }
+
+    // re-acquires the monitor if it's free, returning the owner otherwise
+    pub(crate) fn try_reenter(&self) -> core::result::Result<(), u64> {
+        self.monitor.try_enter(self.thread_id)?;
+        self.monitor.state.lock().depth = self.depth;
+
+        Ok(())
+    }
+
+    pub(crate) async fn reenter(self) {
+        self.monitor.enter(self.thread_id).await;
+        self.monitor.state.lock().depth = self.depth;
+    }
+}
+
+impl MonitorWaitTimeout {
+    pub fn notify(self) {
+        self.monitor.remove_waiter(self.waiter_id);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::sync::Arc;
@@ -196,7 +285,7 @@ mod tests {
 
     #[tokio::test]
     async fn monitor_is_reentrant_and_excludes_other_threads() {
-        let monitor = Arc::new(Monitor::new());
+        let monitor = Arc::new(Monitor::new("java/lang/Object"));
         monitor.enter(1).await;
         monitor.enter(1).await;
 
@@ -224,10 +313,10 @@ mod tests {
 
     #[tokio::test]
     async fn wait_releases_and_restores_the_full_reentrancy_depth() {
-        let monitor = Arc::new(Monitor::new());
+        let monitor = Arc::new(Monitor::new("java/lang/Object"));
         monitor.enter(1).await;
         monitor.enter(1).await;
-        let (wait, _) = monitor.prepare_wait(1).unwrap();
+        let (wait, _) = monitor.prepare_wait(1, 0).unwrap();
 
         monitor.enter(2).await;
         monitor.notify(2, 1).unwrap();
@@ -255,11 +344,11 @@ mod tests {
 
     #[tokio::test]
     async fn notify_one_and_notify_all_remove_the_expected_waiters() {
-        let monitor = Arc::new(Monitor::new());
+        let monitor = Arc::new(Monitor::new("java/lang/Object"));
         monitor.enter(1).await;
-        let (first_wait, _) = monitor.prepare_wait(1).unwrap();
+        let (first_wait, _) = monitor.prepare_wait(1, 0).unwrap();
         monitor.enter(2).await;
-        let (second_wait, _) = monitor.prepare_wait(2).unwrap();
+        let (second_wait, _) = monitor.prepare_wait(2, 0).unwrap();
 
         monitor.enter(3).await;
         monitor.notify(3, 1).unwrap();
@@ -278,15 +367,15 @@ mod tests {
 
     #[tokio::test]
     async fn a_stale_timeout_cannot_consume_a_later_notification() {
-        let monitor = Arc::new(Monitor::new());
+        let monitor = Arc::new(Monitor::new("java/lang/Object"));
         monitor.enter(1).await;
-        let (first_wait, first_timeout) = monitor.prepare_wait(1).unwrap();
+        let (first_wait, first_timeout) = monitor.prepare_wait(1, 0).unwrap();
         first_timeout.clone().notify();
         first_wait.wait().await;
         monitor.exit(1).unwrap();
 
         monitor.enter(2).await;
-        let (second_wait, _) = monitor.prepare_wait(2).unwrap();
+        let (second_wait, _) = monitor.prepare_wait(2, 0).unwrap();
         first_timeout.notify();
         assert_eq!(monitor.state.lock().waiters.len(), 1);
 
@@ -296,4 +385,23 @@ mod tests {
         second_wait.wait().await;
         monitor.exit(2).unwrap();
     }
+
+    #[tokio::test]
+    async fn cancel_leaves_the_wait_set_unless_already_notified() {
+        let monitor = Arc::new(Monitor::new("java/lang/Object"));
+        monitor.enter(1).await;
+        let (mut cancelled, _) = monitor.prepare_wait(1, 0).unwrap();
+        assert!(cancelled.cancel());
+        assert!(monitor.state.lock().waiters.is_empty());
+        cancelled.notified().await;
+        cancelled.reenter().await;
+
+        let (notified, _) = monitor.prepare_wait(1, 0).unwrap();
+        monitor.enter(2).await;
+        monitor.notify(2, 1).unwrap();
+        monitor.exit(2).unwrap();
+        assert!(!notified.cancel());
+        notified.wait().await;
+        monitor.exit(1).unwrap();
+    }
 }
@@ -0,0 +1,16 @@
+use crate::Jvm;
+
+// A native stack frame pushed by rust code; popped when dropped.
+// References returned by jvm apis while it's the top frame are rooted in it, so they stay alive across awaits until the guard goes away.
+#[must_use = "the frame is popped as soon as the guard is dropped"]
+pub struct NativeFrame<'a> {
+    pub(crate) jvm: &'a Jvm,
+    pub(crate) thread_id: u64,
+    pub(crate) depth: usize,
+}
+
+impl Drop for NativeFrame<'_> {
+    fn drop(&mut self) {
+        self.jvm.pop_native_frame(self.thread_id, self.depth);
+    }
+}
@@ -0,0 +1,102 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+
+use hashbrown::{Equivalent, HashMap};
+use parking_lot::RwLock;
+
+use crate::{JvmCallback, class_loader::ClassLoaderId};
+
+// RegisterNatives-like binding of a rust callback to a native method.
+// instance methods receive `this` as the first argument
+#[derive(Clone)]
+pub struct NativeMethod {
+    pub name: String,
+    pub descriptor: String,
+    pub callback: Arc<dyn JvmCallback>,
+}
+
+impl NativeMethod {
+    pub fn new<C>(name: &str, descriptor: &str, callback: C) -> Self
+    where
+        C: JvmCallback + 'static,
+    {
+        Self {
+            name: name.to_string(),
+            descriptor: descriptor.to_string(),
+            callback: Arc::new(callback),
+        }
+    }
+}
+
+type NativeKey = (ClassLoaderId, String, String, String);
+
+// borrowed form of NativeKey for lookups, hashing the same as the owned tuple
+#[derive(Hash)]
+struct NativeKeyRef<'a>(ClassLoaderId, &'a str, &'a str, &'a str);
+
+impl Equivalent<NativeKey> for NativeKeyRef<'_> {
+    fn equivalent(&self, key: &NativeKey) -> bool {
+        self.0 == key.0 && self.1 == key.1 && self.2 == key.2 && self.3 == key.3
+    }
+}
+
+pub(crate) struct NativeMethods {
+    // bound methods, keyed by (defining class loader, class name, method name, descriptor)
+    methods: RwLock<HashMap<NativeKey, Arc<dyn JvmCallback>>>,
+    // libraries System.loadLibrary can bind, as (class name, method) pairs
+    libraries: RwLock<BTreeMap<String, Vec<(String, NativeMethod)>>>,
+}
+
+impl NativeMethods {
+    pub(crate) fn new() -> Self {
+        Self {
+            methods: RwLock::new(HashMap::new()),
+            libraries: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    pub(crate) fn register(&self, class_loader: ClassLoaderId, class_name: &str, methods: impl IntoIterator<Item = NativeMethod>) {
+        let mut bound = self.methods.write();
+        for method in methods {
+            bound.insert((class_loader, class_name.to_string(), method.name, method.descriptor), method.callback);
+        }
+    }
+
+    pub(crate) fn unregister(&self, class_loader: ClassLoaderId, class_name: &str) {
+        self.methods.write().retain(|(x, y, _, _), _| *x != class_loader || y != class_name);
+    }
+
+    // drops the bindings of an unloaded class loader's classes
+    pub(crate) fn unload(&self, class_loader: usize) {
+        self.methods.write().retain(|(x, _, _, _), _| *x != Some(class_loader));
+    }
+
+    pub(crate) fn find(&self, class_loader: ClassLoaderId, class_name: &str, name: &str, descriptor: &str) -> Option<Arc<dyn JvmCallback>> {
+        let methods = self.methods.read();
+        if methods.is_empty() {
+            return None;
+        }
+
+        methods.get(&NativeKeyRef(class_loader, class_name, name, descriptor)).cloned()
+    }
+
+    pub(crate) fn register_library(&self, name: &str, methods: Vec<(String, NativeMethod)>) {
+        self.libraries.write().insert(name.to_string(), methods);
+    }
+
+    pub(crate) fn load_library(&self, class_loader: ClassLoaderId, name: &str) -> bool {
+        let Some(methods) = self.libraries.read().get(name).cloned() else {
+            return false;
+        };
+
+        for (class_name, method) in methods {
+            self.register(class_loader, &class_name, [method]);
+        }
+
+        true
+    }
+}
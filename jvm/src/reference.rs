@@ -0,0 +1,36 @@
+use alloc::{boxed::Box, collections::VecDeque};
+use core::sync::atomic::AtomicBool;
+
+use event_listener::Event;
+use hashbrown::HashMap;
+use parking_lot::{Mutex, RwLock};
+
+use crate::ClassInstance;
+
+// java.lang.ref reference strengths
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReferenceKind {
+    Soft,
+    Weak,
+    Phantom,
+}
+
+// Reference objects registered on construction. Full collections don't trace their `referent` field, and clear it once the
+// referent is otherwise unreachable. Cleared references with a queue wait in `pending` (a gc root) until they're enqueued
+pub(crate) struct References {
+    pub(crate) registered: RwLock<HashMap<Box<dyn ClassInstance>, ReferenceKind>>,
+    pub(crate) pending: Mutex<VecDeque<Box<dyn ClassInstance>>>,
+    pub(crate) pending_event: Event,
+    pub(crate) handler_started: AtomicBool,
+}
+
+impl References {
+    pub(crate) fn new() -> Self {
+        Self {
+            registered: RwLock::new(HashMap::new()),
+            pending: Mutex::new(VecDeque::new()),
+            pending_event: Event::new(),
+            handler_started: AtomicBool::new(false),
+        }
+    }
+}
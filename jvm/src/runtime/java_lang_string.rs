@@ -1,13 +1,12 @@
 use alloc::{boxed::Box, string::String, vec::Vec};
 
-use crate::{JavaChar, Result, class_instance::ClassInstance, jvm::Jvm};
+use crate::{AsClassInstance, JavaChar, Result, class_instance::ClassInstance, jvm::Jvm};
 
 pub struct JavaLangString;
 
 impl JavaLangString {
-    #[allow(clippy::borrowed_box)]
-    pub async fn to_rust_string(jvm: &Jvm, this: &Box<dyn ClassInstance>) -> Result<String> {
-        let value = jvm.get_field(this, "value", "[C").await?;
+    pub async fn to_rust_string(jvm: &Jvm, this: &(impl AsClassInstance + Sync + ?Sized)) -> Result<String> {
+        let value: Box<dyn ClassInstance> = jvm.get_field(this, "value", "[C").await?;
 
         let length = jvm.array_length(&value).await?;
         let string: Vec<JavaChar> = jvm.load_array(&value, 0, length).await?;
@@ -3,6 +3,9 @@ use alloc::{
     string::{String, ToString},
     vec::Vec,
 };
+use core::iter;
+
+use event_listener::Event;
 
 use crate::{ClassInstance, JavaValue, class_loader::Class};
 
@@ -28,15 +31,26 @@ impl StackFrame {
 }
 
 pub struct JvmThread {
+    // native frame at the bottom of every thread; it's never popped, so there's always a top frame
+    root_frame: StackFrame,
     stack: Vec<StackFrame>,
     java_thread: Option<Box<dyn ClassInstance>>,
+    sleeping: bool,
+    // set while the thread constructs an exception, which may exceed the heap limit (OutOfMemoryError must be constructible)
+    allocating_exception: bool,
+    // wakes the thread's interruptible blocking calls when it's interrupted
+    interrupt_event: Event,
 }
 
 impl JvmThread {
     pub fn new() -> Self {
         Self {
+            root_frame: StackFrame::Native(NativeStackFrame { local_variables: Vec::new() }),
             stack: Vec::new(),
             java_thread: None,
+            sleeping: false,
+            allocating_exception: false,
+            interrupt_event: Event::new(),
         }
     }
 
@@ -49,6 +63,26 @@ impl JvmThread {
         self.java_thread = Some(java_thread);
     }
 
+    pub fn is_sleeping(&self) -> bool {
+        self.sleeping
+    }
+
+    pub fn set_sleeping(&mut self, sleeping: bool) {
+        self.sleeping = sleeping;
+    }
+
+    pub fn is_allocating_exception(&self) -> bool {
+        self.allocating_exception
+    }
+
+    pub fn set_allocating_exception(&mut self, allocating_exception: bool) {
+        self.allocating_exception = allocating_exception;
+    }
+
+    pub fn interrupt_event(&self) -> &Event {
+        &self.interrupt_event
+    }
+
     pub fn push_java_frame(&mut self, class: &Class, class_instance: Option<Box<dyn ClassInstance>>, method: &str, args: &[JavaValue]) {
         self.stack.push(StackFrame::Java(JavaStackFrame {
             class: class.clone(),
@@ -72,8 +106,12 @@ impl JvmThread {
         self.stack.pop()
     }
 
+    pub fn stack_depth(&self) -> usize {
+        self.stack.len() + 1
+    }
+
     pub fn top_frame_mut(&mut self) -> &mut StackFrame {
-        self.stack.last_mut().unwrap()
+        self.stack.last_mut().unwrap_or(&mut self.root_frame)
     }
 
     pub fn top_java_frame(&self) -> Option<&JavaStackFrame> {
@@ -91,7 +129,7 @@ impl JvmThread {
     }
 
     pub fn iter_frame(&self) -> impl DoubleEndedIterator<Item = &StackFrame> {
-        self.stack.iter()
+        iter::once(&self.root_frame).chain(self.stack.iter())
     }
 }
 
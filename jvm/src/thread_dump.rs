@@ -0,0 +1,84 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display, Formatter};
+
+use crate::{Deadlock, MonitorInfo};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ThreadState {
+    Running,
+    // blocked entering a monitor
+    Blocked {
+        object_identity: usize,
+        class_name: String,
+        owner_thread_id: Option<u64>,
+    },
+    // in Object.wait()
+    Waiting {
+        object_identity: usize,
+        class_name: String,
+    },
+    Sleeping,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ThreadInfo {
+    pub thread_id: u64,
+    pub name: String,
+    pub priority: i32,
+    pub daemon: bool,
+    pub state: ThreadState,
+    // innermost frame first. native frames are included as "<native frame>"
+    pub stack_trace: Vec<String>,
+    pub held_monitors: Vec<MonitorInfo>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ThreadDump {
+    pub threads: Vec<ThreadInfo>,
+    pub deadlock: Option<Deadlock>,
+}
+
+// formatted like a HotSpot thread dump
+impl Display for ThreadDump {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Full thread dump:")?;
+
+        for thread in &self.threads {
+            writeln!(f)?;
+            write!(f, "\"{}\" #{}", thread.name, thread.thread_id)?;
+            if thread.daemon {
+                write!(f, " daemon")?;
+            }
+            writeln!(f, " prio={}", thread.priority)?;
+
+            let state = match &thread.state {
+                ThreadState::Running => "RUNNABLE",
+                ThreadState::Blocked { .. } => "BLOCKED (on object monitor)",
+                ThreadState::Waiting { .. } => "WAITING (on object monitor)",
+                ThreadState::Sleeping => "TIMED_WAITING (sleeping)",
+            };
+            writeln!(f, "   java.lang.Thread.State: {state}")?;
+
+            for frame in &thread.stack_trace {
+                writeln!(f, "\tat {frame}")?;
+            }
+            match &thread.state {
+                ThreadState::Blocked {
+                    object_identity, class_name, ..
+                } => writeln!(f, "\t- waiting to lock <{object_identity:#x}> (a {class_name})")?,
+                ThreadState::Waiting { object_identity, class_name } => writeln!(f, "\t- waiting on <{object_identity:#x}> (a {class_name})")?,
+                _ => {}
+            }
+            for monitor in &thread.held_monitors {
+                writeln!(f, "\t- locked <{:#x}> (a {})", monitor.object_identity, monitor.class_name)?;
+            }
+        }
+
+        if let Some(deadlock) = &self.deadlock {
+            writeln!(f)?;
+            write!(f, "{deadlock}")?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use alloc::{
     boxed::Box,
+    format,
     string::{String, ToString},
     vec::Vec,
 };
@@ -12,7 +13,7 @@ use nom::{
     sequence::terminated,
 };
 
-use crate::JavaValue;
+use crate::{JavaError, JavaValue, Result};
 
 #[derive(Eq, PartialEq, Clone, Debug)]
 pub enum JavaType {
@@ -31,9 +32,9 @@ pub enum JavaType {
 }
 
 impl JavaType {
-    pub fn default(&self) -> JavaValue {
-        match self {
-            Self::Void => panic!("Cannot create default value for void"),
+    pub fn default(&self) -> Result<JavaValue> {
+        Ok(match self {
+            Self::Void | Self::Method(_, _) => return Err(JavaError::internal(format!("No default value for {}", self.descriptor()))),
             Self::Boolean => JavaValue::Boolean(false),
             Self::Byte => JavaValue::Byte(0),
             Self::Char => JavaValue::Char(0),
@@ -44,12 +45,11 @@ impl JavaType {
             Self::Double => JavaValue::Double(0.0),
             Self::Class(_) => JavaValue::Object(None),
             Self::Array(_) => JavaValue::Object(None),
-            Self::Method(_, _) => panic!("Cannot create default value for method"),
-        }
+        })
     }
 
-    pub fn parse(descriptor: &str) -> Self {
-        Self::try_parse(descriptor).expect("invalid Java type descriptor")
+    pub fn parse(descriptor: &str) -> Result<Self> {
+        Self::try_parse(descriptor).ok_or_else(|| JavaError::internal(format!("Invalid type descriptor {descriptor}")))
     }
 
     pub fn try_parse(descriptor: &str) -> Option<Self> {
@@ -59,19 +59,39 @@ impl JavaType {
 
     // a CONSTANT_Class_info name (JVMS 4.4.1): a class binary name in internal form (java/lang/String)
     // or an array type descriptor ([Ljava/lang/String;, [I)
-    pub fn from_class_name(name: &str) -> Self {
+    pub fn from_class_name(name: &str) -> Result<Self> {
         if name.starts_with('[') {
             Self::parse(name)
         } else {
-            Self::Class(name.to_string())
+            Ok(Self::Class(name.to_string()))
+        }
+    }
+
+    pub fn descriptor(&self) -> String {
+        match self {
+            Self::Void => "V".to_string(),
+            Self::Boolean => "Z".to_string(),
+            Self::Byte => "B".to_string(),
+            Self::Char => "C".to_string(),
+            Self::Short => "S".to_string(),
+            Self::Int => "I".to_string(),
+            Self::Long => "J".to_string(),
+            Self::Float => "F".to_string(),
+            Self::Double => "D".to_string(),
+            Self::Class(name) => format!("L{name};"),
+            Self::Array(element_type) => format!("[{}", element_type.descriptor()),
+            Self::Method(params, return_type) => {
+                let params = params.iter().map(|x| x.descriptor()).collect::<String>();
+                format!("({params}){}", return_type.descriptor())
+            }
         }
     }
 
-    pub fn as_method(&self) -> (&[Self], &Self) {
+    pub fn as_method(&self) -> Result<(&[Self], &Self)> {
         if let Self::Method(params, return_type) = self {
-            (params, return_type)
+            Ok((params, return_type))
         } else {
-            panic!("Invalid type");
+            Err(JavaError::internal(format!("{} isn't a method descriptor", self.descriptor())))
         }
     }
 
@@ -136,7 +156,7 @@ mod test {
     #[test]
     fn test_parse_method_descriptor() {
         assert!(
-            JavaType::parse("(Ljava/lang/String;I)V")
+            JavaType::parse("(Ljava/lang/String;I)V").unwrap()
                 == JavaType::Method(vec![JavaType::Class("java/lang/String".into()), JavaType::Int], Box::new(JavaType::Void))
         );
     }
@@ -144,26 +164,34 @@ mod test {
     #[test]
     fn test_parse_method_descriptor_array() {
         assert!(
-            JavaType::parse("([CI)V") == JavaType::Method(vec![JavaType::Array(Box::new(JavaType::Char)), JavaType::Int], Box::new(JavaType::Void))
+            JavaType::parse("([CI)V").unwrap()
+                == JavaType::Method(vec![JavaType::Array(Box::new(JavaType::Char)), JavaType::Int], Box::new(JavaType::Void))
         )
     }
 
     #[test]
     fn test_parse() {
-        assert!(JavaType::parse("V") == JavaType::Void);
-        assert!(JavaType::parse("I") == JavaType::Int);
-        assert!(JavaType::parse("J") == JavaType::Long);
-        assert!(JavaType::parse("F") == JavaType::Float);
-        assert!(JavaType::parse("D") == JavaType::Double);
-        assert!(JavaType::parse("C") == JavaType::Char);
-        assert!(JavaType::parse("Ljava/lang/String;") == JavaType::Class("java/lang/String".into()));
-        assert!(JavaType::parse("[Ljava/lang/String;") == JavaType::Array(Box::new(JavaType::Class("java/lang/String".into()))));
+        assert!(JavaType::parse("V").unwrap() == JavaType::Void);
+        assert!(JavaType::parse("I").unwrap() == JavaType::Int);
+        assert!(JavaType::parse("J").unwrap() == JavaType::Long);
+        assert!(JavaType::parse("F").unwrap() == JavaType::Float);
+        assert!(JavaType::parse("D").unwrap() == JavaType::Double);
+        assert!(JavaType::parse("C").unwrap() == JavaType::Char);
+        assert!(JavaType::parse("Ljava/lang/String;").unwrap() == JavaType::Class("java/lang/String".into()));
+        assert!(JavaType::parse("[Ljava/lang/String;").unwrap() == JavaType::Array(Box::new(JavaType::Class("java/lang/String".into()))));
         assert!(
-            JavaType::parse("[[Ljava/lang/String;")
+            JavaType::parse("[[Ljava/lang/String;").unwrap()
                 == JavaType::Array(Box::new(JavaType::Array(Box::new(JavaType::Class("java/lang/String".into())))))
         );
     }
 
+    #[test]
+    fn test_descriptor_round_trip() {
+        for descriptor in ["V", "I", "Ljava/lang/String;", "[[J", "([Ljava/lang/Object;IZ)Ljava/lang/Class;"] {
+            assert_eq!(JavaType::parse(descriptor).unwrap().descriptor(), descriptor);
+        }
+    }
+
     #[test]
     fn test_try_parse_rejects_malformed_descriptors() {
         assert!(JavaType::try_parse("").is_none());
@@ -173,4 +201,13 @@ mod test {
         assert!(JavaType::try_parse("(I").is_none());
         assert!(JavaType::try_parse("L;").is_none());
     }
+
+    #[test]
+    fn test_invalid_uses_return_errors() {
+        assert!(JavaType::parse("Igarbage").is_err());
+        assert!(JavaType::from_class_name("[V").is_err());
+        assert!(JavaType::Void.default().is_err());
+        assert!(JavaType::Int.as_method().is_err());
+        assert!(JavaType::parse("()V").unwrap().default().is_err());
+    }
 }
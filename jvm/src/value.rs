@@ -1,6 +1,6 @@
-use alloc::boxed::Box;
+use alloc::{boxed::Box, format};
 
-use crate::class_instance::ClassInstance;
+use crate::{JavaError, class_instance::ClassInstance};
 
 pub type JavaChar = u16;
 
@@ -18,110 +18,134 @@ pub enum JavaValue {
     Object(Option<Box<dyn ClassInstance>>),
 }
 
-impl From<JavaValue> for bool {
-    fn from(x: JavaValue) -> Self {
+impl TryFrom<JavaValue> for bool {
+    type Error = JavaError;
+
+    fn try_from(x: JavaValue) -> Result<Self, Self::Error> {
         match x {
-            JavaValue::Boolean(x) => x,
-            _ => panic!("Expected boolean, got {:?}", x),
+            JavaValue::Boolean(x) => Ok(x),
+            _ => Err(JavaError::internal(format!("Expected boolean, got {:?}", x))),
         }
     }
 }
 
-impl From<JavaValue> for i8 {
-    fn from(x: JavaValue) -> Self {
+impl TryFrom<JavaValue> for i8 {
+    type Error = JavaError;
+
+    fn try_from(x: JavaValue) -> Result<Self, Self::Error> {
         match x {
-            JavaValue::Byte(x) => x,
-            _ => panic!("Expected byte, got {:?}", x),
+            JavaValue::Byte(x) => Ok(x),
+            _ => Err(JavaError::internal(format!("Expected byte, got {:?}", x))),
         }
     }
 }
 
-impl From<JavaValue> for JavaChar {
-    fn from(x: JavaValue) -> Self {
+impl TryFrom<JavaValue> for JavaChar {
+    type Error = JavaError;
+
+    fn try_from(x: JavaValue) -> Result<Self, Self::Error> {
         match x {
-            JavaValue::Char(x) => x,
-            _ => panic!("Expected char, got {:?}", x),
+            JavaValue::Char(x) => Ok(x),
+            _ => Err(JavaError::internal(format!("Expected char, got {:?}", x))),
         }
     }
 }
 
-impl From<JavaValue> for i16 {
-    fn from(x: JavaValue) -> Self {
+impl TryFrom<JavaValue> for i16 {
+    type Error = JavaError;
+
+    fn try_from(x: JavaValue) -> Result<Self, Self::Error> {
         match x {
-            JavaValue::Short(x) => x,
-            _ => panic!("Expected short, got {:?}", x),
+            JavaValue::Short(x) => Ok(x),
+            _ => Err(JavaError::internal(format!("Expected short, got {:?}", x))),
         }
     }
 }
 
-impl From<JavaValue> for i32 {
-    fn from(x: JavaValue) -> Self {
+impl TryFrom<JavaValue> for i32 {
+    type Error = JavaError;
+
+    fn try_from(x: JavaValue) -> Result<Self, Self::Error> {
         match x {
-            JavaValue::Int(x) => x,
-            _ => panic!("Expected int, got {:?}", x),
+            JavaValue::Int(x) => Ok(x),
+            _ => Err(JavaError::internal(format!("Expected int, got {:?}", x))),
         }
     }
 }
 
-impl From<JavaValue> for i64 {
-    fn from(x: JavaValue) -> Self {
+impl TryFrom<JavaValue> for i64 {
+    type Error = JavaError;
+
+    fn try_from(x: JavaValue) -> Result<Self, Self::Error> {
         match x {
-            JavaValue::Long(x) => x,
-            _ => panic!("Expected long, got {:?}", x),
+            JavaValue::Long(x) => Ok(x),
+            _ => Err(JavaError::internal(format!("Expected long, got {:?}", x))),
         }
     }
 }
 
-impl From<JavaValue> for f32 {
-    fn from(x: JavaValue) -> Self {
+impl TryFrom<JavaValue> for f32 {
+    type Error = JavaError;
+
+    fn try_from(x: JavaValue) -> Result<Self, Self::Error> {
         match x {
-            JavaValue::Float(x) => x,
-            _ => panic!("Expected float, got {:?}", x),
+            JavaValue::Float(x) => Ok(x),
+            _ => Err(JavaError::internal(format!("Expected float, got {:?}", x))),
         }
     }
 }
 
-impl From<JavaValue> for f64 {
-    fn from(x: JavaValue) -> Self {
+impl TryFrom<JavaValue> for f64 {
+    type Error = JavaError;
+
+    fn try_from(x: JavaValue) -> Result<Self, Self::Error> {
         match x {
-            JavaValue::Double(x) => x,
-            _ => panic!("Expected double, got {:?}", x),
+            JavaValue::Double(x) => Ok(x),
+            _ => Err(JavaError::internal(format!("Expected double, got {:?}", x))),
         }
     }
 }
 
-impl<'a> From<&'a JavaValue> for &'a Option<Box<dyn ClassInstance>> {
-    fn from(x: &'a JavaValue) -> Self {
+impl<'a> TryFrom<&'a JavaValue> for &'a Option<Box<dyn ClassInstance>> {
+    type Error = JavaError;
+
+    fn try_from(x: &'a JavaValue) -> Result<Self, Self::Error> {
         match x {
-            JavaValue::Object(x) => x,
-            _ => panic!("Expected object, got {:?}", x),
+            JavaValue::Object(x) => Ok(x),
+            _ => Err(JavaError::internal(format!("Expected object, got {:?}", x))),
         }
     }
 }
 
-impl From<JavaValue> for Option<Box<dyn ClassInstance>> {
-    fn from(x: JavaValue) -> Self {
+impl TryFrom<JavaValue> for Option<Box<dyn ClassInstance>> {
+    type Error = JavaError;
+
+    fn try_from(x: JavaValue) -> Result<Self, Self::Error> {
         match x {
-            JavaValue::Object(x) => x,
-            _ => panic!("Expected object, got {:?}", x),
+            JavaValue::Object(x) => Ok(x),
+            _ => Err(JavaError::internal(format!("Expected object, got {:?}", x))),
         }
     }
 }
 
-impl From<JavaValue> for Box<dyn ClassInstance> {
-    fn from(x: JavaValue) -> Self {
+impl TryFrom<JavaValue> for Box<dyn ClassInstance> {
+    type Error = JavaError;
+
+    fn try_from(x: JavaValue) -> Result<Self, Self::Error> {
         match x {
-            JavaValue::Object(x) => x.unwrap(),
-            _ => panic!("Expected object, got {:?}", x),
+            JavaValue::Object(Some(x)) => Ok(x),
+            _ => Err(JavaError::internal(format!("Expected object, got {:?}", x))),
         }
     }
 }
 
-impl From<JavaValue> for () {
-    fn from(x: JavaValue) -> Self {
+impl TryFrom<JavaValue> for () {
+    type Error = JavaError;
+
+    fn try_from(x: JavaValue) -> Result<Self, Self::Error> {
         match x {
-            JavaValue::Void => (),
-            _ => panic!("Expected void, got {:?}", x),
+            JavaValue::Void => Ok(()),
+            _ => Err(JavaError::internal(format!("Expected void, got {:?}", x))),
         }
     }
 }
@@ -185,3 +209,22 @@ impl From<Option<Box<dyn ClassInstance>>> for JavaValue {
         JavaValue::Object(x)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use alloc::boxed::Box;
+
+    use crate::ClassInstance;
+
+    use super::JavaValue;
+
+    #[test]
+    fn test_mismatched_conversion_returns_error() {
+        assert_eq!(i32::try_from(JavaValue::Int(1)).unwrap(), 1);
+        assert!(i32::try_from(JavaValue::Long(1)).is_err());
+        assert!(bool::try_from(JavaValue::Void).is_err());
+        assert!(<()>::try_from(JavaValue::Int(1)).is_err());
+        assert!(Option::<Box<dyn ClassInstance>>::try_from(JavaValue::Object(None)).unwrap().is_none());
+        assert!(Box::<dyn ClassInstance>::try_from(JavaValue::Object(None)).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use jvm::{ClassInstance, JavaError, Result as JvmResult, runtime::JavaLangClassLoader};
+use jvm_rust::ClassDefinitionImpl;
+
+use test_utils::test_jvm;
+
+#[tokio::test]
+async fn test_same_name_in_different_loaders() -> JvmResult<()> {
+    let jvm = test_jvm().await?;
+
+    let system_class_loader = JavaLangClassLoader::get_system_class_loader(&jvm).await?;
+
+    let bootstrap_class = ClassDefinitionImpl::from_classfile(include_bytes!("../../test_data/Exception.class")).unwrap();
+    let bootstrap_java_class = jvm.register_class(Box::new(bootstrap_class.clone()), None).await?.unwrap();
+
+    let system_class = ClassDefinitionImpl::from_classfile(include_bytes!("../../test_data/Exception.class")).unwrap();
+    let system_java_class = jvm
+        .register_class(Box::new(system_class.clone()), Some(system_class_loader.clone()))
+        .await?
+        .unwrap();
+
+    assert!(!bootstrap_java_class.equals(&*system_java_class)?);
+
+    let loaded = jvm.loaded_class(Some(&system_class_loader), "Exception").unwrap();
+    assert!(loaded.java_class()?.equals(&*system_java_class)?);
+    let loaded = jvm.loaded_class(None, "Exception").unwrap();
+    assert!(loaded.java_class()?.equals(&*bootstrap_java_class)?);
+
+    assert!(
+        jvm.defined_class(&bootstrap_class)
+            .unwrap()
+            .java_class()?
+            .equals(&*bootstrap_java_class)?
+    );
+    assert!(jvm.defined_class(&system_class).unwrap().java_class()?.equals(&*system_java_class)?);
+
+    let class_loader: Option<Box<dyn ClassInstance>> = jvm.get_field(&system_java_class, "classLoader", "Ljava/lang/ClassLoader;").await?;
+    assert!(class_loader.unwrap().equals(&*system_class_loader)?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_duplicate_class_definition() -> JvmResult<()> {
+    let jvm = test_jvm().await?;
+
+    let class = ClassDefinitionImpl::from_classfile(include_bytes!("../../test_data/Exception.class")).unwrap();
+    jvm.register_class(Box::new(class), None).await?;
+
+    let class = ClassDefinitionImpl::from_classfile(include_bytes!("../../test_data/Exception.class")).unwrap();
+    let result = jvm.register_class(Box::new(class), None).await;
+
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("expected LinkageError");
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/LinkageError"));
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use std::sync::{Arc, Mutex};
+
+use jvm::{JavaValue, Jvm, JvmEvent, JvmEventListenerId, Result as JvmResult, runtime::JavaLangString};
+use jvm_rust::ClassDefinitionImpl;
+
+use test_utils::test_jvm;
+
+fn record_events(jvm: &Jvm) -> (JvmEventListenerId, Arc<Mutex<Vec<String>>>) {
+    let events = Arc::new(Mutex::new(Vec::new()));
+
+    let recorded = events.clone();
+    let id = jvm.add_event_listener(move |_: &Jvm, event: &JvmEvent<'_>| {
+        let event = match event {
+            JvmEvent::ClassLoaded { class_name } => format!("loaded {class_name}"),
+            JvmEvent::ClassInitialized { class_name } => format!("initialized {class_name}"),
+            JvmEvent::MethodEntry { class_name, method } => format!("entry {class_name}.{method}"),
+            JvmEvent::MethodExit {
+                class_name,
+                method,
+                exception,
+            } => format!("exit {class_name}.{method} {}", exception.is_some()),
+            JvmEvent::ExceptionThrown { exception } => format!("thrown {}", exception.class_definition().name()),
+            JvmEvent::ExceptionCaught { exception } => format!("caught {}", exception.class_definition().name()),
+            JvmEvent::GarbageCollectionStart => "gc start".into(),
+            JvmEvent::GarbageCollectionFinish { collected } => format!("gc finish {collected}"),
+            other => format!("{other:?}"),
+        };
+        recorded.lock().unwrap().push(event);
+    });
+
+    (id, events)
+}
+
+#[tokio::test]
+async fn class_and_method_events_are_reported() -> JvmResult<()> {
+    let jvm = test_jvm().await?;
+    let (_, events) = record_events(&jvm);
+
+    let _ = jvm.new_class("java/util/Random", "()V", ()).await?;
+
+    let events = events.lock().unwrap();
+    let loaded = events.iter().position(|x| x == "loaded java/util/Random").unwrap();
+    let initialized = events.iter().position(|x| x == "initialized java/util/Random").unwrap();
+    let entry = events.iter().position(|x| x == "entry java/util/Random.<init>()V").unwrap();
+    let exit = events.iter().position(|x| x == "exit java/util/Random.<init>()V false").unwrap();
+
+    assert!(loaded < initialized);
+    assert!(initialized < entry);
+    assert!(entry < exit);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exception_and_garbage_collection_events_are_reported() -> JvmResult<()> {
+    let jvm = test_jvm().await?;
+
+    let vector = jvm.new_class("java/util/Vector", "()V", ()).await?;
+
+    let (id, events) = record_events(&jvm);
+    let result: JvmResult<JavaValue> = jvm.invoke_virtual(&vector, "elementAt", "(I)Ljava/lang/Object;", (0,)).await;
+    assert!(result.is_err());
+
+    let garbage_count = jvm.collect_garbage()?;
+
+    {
+        let events = events.lock().unwrap();
+        assert!(events.contains(&"thrown java/lang/ArrayIndexOutOfBoundsException".into()));
+        assert!(events.contains(&"exit java/util/Vector.elementAt(I)Ljava/lang/Object; true".into()));
+        assert_eq!(events[events.len() - 2..], ["gc start".to_string(), format!("gc finish {garbage_count}")]);
+    }
+
+    assert!(jvm.remove_event_listener(id));
+    assert!(!jvm.remove_event_listener(id));
+
+    let count = events.lock().unwrap().len();
+    jvm.collect_garbage()?;
+    assert_eq!(events.lock().unwrap().len(), count);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn interpreter_reports_athrow() -> JvmResult<()> {
+    let jvm = test_jvm().await?;
+
+    let class = ClassDefinitionImpl::from_classfile(include_bytes!("../../test_data/Exception.class")).unwrap();
+    jvm.register_class(Box::new(class), None).await?;
+
+    let (_, events) = record_events(&jvm);
+
+    let result: JvmResult<()> = jvm.invoke_static("Exception", "throwsException", "()V", ()).await;
+    assert!(result.is_err());
+
+    let events = events.lock().unwrap();
+    let thrown = events.iter().position(|x| x == "thrown java/lang/UnsupportedOperationException").unwrap();
+    let exit = events.iter().position(|x| x == "exit Exception.throwsException()V true").unwrap();
+    assert!(thrown < exit);
+    assert!(!events.iter().any(|x| x.starts_with("caught")));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exceptions_built_in_rust_are_reported() -> JvmResult<()> {
+    let jvm = test_jvm().await?;
+
+    let pattern = JavaLangString::from_rust_string(&jvm, "yyyy").await?;
+    let format = jvm.new_class("java/text/SimpleDateFormat", "(Ljava/lang/String;)V", (pattern,)).await?;
+    let source = JavaLangString::from_rust_string(&jvm, "not a date").await?;
+
+    let (_, events) = record_events(&jvm);
+
+    // DateFormat.parse constructs the ParseException itself instead of going through Jvm::exception
+    let result: JvmResult<JavaValue> = jvm
+        .invoke_virtual(&format, "parse", "(Ljava/lang/String;)Ljava/util/Date;", (source,))
+        .await;
+    assert!(result.is_err());
+
+    let events = events.lock().unwrap();
+    assert!(events.contains(&"thrown java/text/ParseException".into()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn thread_events_are_reported() -> JvmResult<()> {
+    let jvm = test_jvm().await?;
+    let (_, events) = record_events(&jvm);
+
+    let thread = jvm.new_class("java/lang/Thread", "()V", ()).await?;
+    let _: () = jvm.invoke_virtual(&thread, "start", "()V", ()).await?;
+    let _: () = jvm.invoke_virtual(&thread, "join", "()V", ()).await?;
+
+    let events = events.lock().unwrap();
+    assert!(events.iter().any(|x| x.starts_with("ThreadStart")));
+    assert!(events.iter().any(|x| x.starts_with("ThreadEnd")));
+
+    Ok(())
+}
@@ -1,4 +1,8 @@
-use jvm::{Array, ClassInstanceRef, JavaValue, Result as JvmResult, runtime::JavaLangString};
+use jvm::{
+    Array, ClassInstanceRef, JavaValue, Jvm, Result as JvmResult,
+    runtime::{JavaLangClassLoader, JavaLangString},
+};
+use jvm_rust::ClassDefinitionImpl;
 
 use std::collections::BTreeMap;
 
@@ -11,13 +15,13 @@ async fn test_garbage_collection() -> JvmResult<()> {
     // collect garbage before test to ensure a clean state
     jvm.collect_garbage()?;
 
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let _string = JavaLangString::from_rust_string(&jvm, "test").await?;
 
     let garbage_count = jvm.collect_garbage()?;
     assert_eq!(garbage_count, 0);
 
-    jvm.pop_frame();
+    drop(frame);
 
     let garbage_count = jvm.collect_garbage()?;
 
@@ -25,26 +29,26 @@ async fn test_garbage_collection() -> JvmResult<()> {
     assert_eq!(garbage_count, 3);
 
     // load a class
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let _ = jvm.resolve_class("java/util/Random").await?;
-    jvm.pop_frame();
+    drop(frame);
 
     let garbage_count = jvm.collect_garbage()?;
 
     assert_eq!(garbage_count, 3);
 
     // use loaded class
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let _random = jvm.new_class("java/util/Random", "()V", ()).await?;
-    jvm.pop_frame();
+    drop(frame);
 
     let garbage_count = jvm.collect_garbage()?;
     assert_eq!(garbage_count, 1);
 
     // load another class
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let _ = jvm.resolve_class("java/util/Vector").await?;
-    jvm.pop_frame();
+    drop(frame);
 
     let garbage_count = jvm.collect_garbage()?;
 
@@ -55,7 +59,7 @@ async fn test_garbage_collection() -> JvmResult<()> {
     assert_eq!(remaining_garbage_count, 0);
 
     // use loaded class
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let vector = jvm.new_class("java/util/Vector", "()V", ()).await?;
     let _: () = jvm
         .invoke_virtual(
@@ -69,7 +73,7 @@ async fn test_garbage_collection() -> JvmResult<()> {
     let garbage_count = jvm.collect_garbage()?;
     assert_eq!(garbage_count, 3);
 
-    jvm.pop_frame();
+    drop(frame);
 
     // vector, elementData, string, and its internal [C should be garbage collected
     let garbage_count = jvm.collect_garbage()?;
@@ -85,7 +89,7 @@ async fn test_garbage_collection_hashtable() -> JvmResult<()> {
     // collect garbage before test to ensure a clean state
     jvm.collect_garbage()?;
 
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let hashtable = jvm.new_class("java/util/Hashtable", "()V", ()).await?;
 
     let key = JavaLangString::from_rust_string(&jvm, "key").await?;
@@ -104,7 +108,7 @@ async fn test_garbage_collection_hashtable() -> JvmResult<()> {
     let garbage_count = jvm.collect_garbage()?;
     assert_eq!(garbage_count, 0);
 
-    jvm.pop_frame();
+    drop(frame);
 
     // hashtable, table array, entry, key string, key [C, value string, value [C, and 2 temporaries from string construction
     let garbage_count = jvm.collect_garbage()?;
@@ -121,11 +125,11 @@ async fn global_references_are_independent_garbage_collection_roots() -> JvmResu
 
     struct Object;
 
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let object: ClassInstanceRef<Object> = jvm.new_class("java/lang/Object", "()V", ()).await?.into();
     let first = jvm.new_global_ref(&object).unwrap();
     let second = jvm.new_global_ref(&object).unwrap();
-    jvm.pop_frame();
+    drop(frame);
 
     assert_eq!(jvm.collect_garbage()?, 0);
     drop(first);
@@ -145,26 +149,26 @@ async fn array_load_result_is_a_local_reference() -> JvmResult<()> {
 
     struct Object;
 
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let _: ClassInstanceRef<Array<Object>> = jvm.instantiate_array("Ljava/lang/Object;", 0).await?.into();
-    jvm.pop_frame();
+    drop(frame);
     jvm.collect_garbage()?;
 
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let mut array: ClassInstanceRef<Array<Object>> = jvm.instantiate_array("Ljava/lang/Object;", 1).await?.into();
     let object: ClassInstanceRef<Object> = jvm.new_class("java/lang/Object", "()V", ()).await?.into();
     jvm.store_array(&mut array, 0, [object]).await?;
     let array = jvm.new_global_ref(&array).unwrap();
-    jvm.pop_frame();
+    drop(frame);
 
     assert_eq!(jvm.collect_garbage()?, 0);
 
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let _: Vec<ClassInstanceRef<Object>> = jvm.load_array(&array, 0, 1).await?;
     let mut mutable_array = (*array).clone();
     jvm.store_array(&mut mutable_array, 0, [ClassInstanceRef::<Object>::new(None)]).await?;
     assert_eq!(jvm.collect_garbage()?, 0);
-    jvm.pop_frame();
+    drop(frame);
 
     assert_eq!(jvm.collect_garbage()?, 1);
     drop(array);
@@ -180,21 +184,21 @@ async fn field_and_method_results_are_local_references() -> JvmResult<()> {
     struct Object;
     struct Vector;
 
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let _: ClassInstanceRef<Vector> = jvm.new_class("java/util/Vector", "()V", ()).await?.into();
-    jvm.pop_frame();
+    drop(frame);
     jvm.collect_garbage()?;
 
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let vector: ClassInstanceRef<Vector> = jvm.new_class("java/util/Vector", "(I)V", (1,)).await?.into();
     let object: ClassInstanceRef<Object> = jvm.new_class("java/lang/Object", "()V", ()).await?.into();
     let _: () = jvm.invoke_virtual(&vector, "addElement", "(Ljava/lang/Object;)V", (object,)).await?;
     let vector = jvm.new_global_ref(&vector).unwrap();
-    jvm.pop_frame();
+    drop(frame);
 
     assert_eq!(jvm.collect_garbage()?, 0);
 
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let _: ClassInstanceRef<Array<Object>> = jvm.get_field(&vector, "elementData", "[Ljava/lang/Object;").await?;
     let mut mutable_vector = (*vector).clone();
     jvm.put_field(
@@ -205,23 +209,23 @@ async fn field_and_method_results_are_local_references() -> JvmResult<()> {
     )
     .await?;
     assert_eq!(jvm.collect_garbage()?, 0);
-    jvm.pop_frame();
+    drop(frame);
 
     assert_eq!(jvm.collect_garbage()?, 2);
     drop(vector);
     assert_eq!(jvm.collect_garbage()?, 1);
 
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let vector: ClassInstanceRef<Vector> = jvm.new_class("java/util/Vector", "(I)V", (1,)).await?.into();
     let object: ClassInstanceRef<Object> = jvm.new_class("java/lang/Object", "()V", ()).await?.into();
     let _: () = jvm.invoke_virtual(&vector, "addElement", "(Ljava/lang/Object;)V", (object,)).await?;
     let vector = jvm.new_global_ref(&vector).unwrap();
-    jvm.pop_frame();
+    drop(frame);
 
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let _: ClassInstanceRef<Object> = jvm.invoke_virtual(&vector, "remove", "(I)Ljava/lang/Object;", (0,)).await?;
     assert_eq!(jvm.collect_garbage()?, 0);
-    jvm.pop_frame();
+    drop(frame);
 
     assert_eq!(jvm.collect_garbage()?, 1);
     drop(vector);
@@ -238,12 +242,12 @@ async fn static_field_result_is_a_local_reference() -> JvmResult<()> {
 
     jvm.collect_garbage()?;
 
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let _: ClassInstanceRef<Object> = jvm.get_static_field("java/lang/System", "out", "Ljava/io/PrintStream;").await?;
     jvm.put_static_field("java/lang/System", "out", "Ljava/io/PrintStream;", ClassInstanceRef::<Object>::new(None))
         .await?;
     assert_eq!(jvm.collect_garbage()?, 0);
-    jvm.pop_frame();
+    drop(frame);
 
     assert!(jvm.collect_garbage()? > 0);
 
@@ -256,16 +260,16 @@ async fn returned_exception_is_a_local_reference() -> JvmResult<()> {
 
     struct Vector;
 
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let vector: ClassInstanceRef<Vector> = jvm.new_class("java/util/Vector", "()V", ()).await?.into();
     let _: jvm::JavaError = jvm
         .invoke_virtual::<_, ClassInstanceRef<()>>(&vector, "elementAt", "(I)Ljava/lang/Object;", (0,))
         .await
         .unwrap_err();
-    jvm.pop_frame();
+    drop(frame);
     jvm.collect_garbage()?;
 
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let vector: ClassInstanceRef<Vector> = jvm.new_class("java/util/Vector", "()V", ()).await?.into();
     let _: jvm::JavaError = jvm
         .invoke_virtual::<_, ClassInstanceRef<()>>(&vector, "elementAt", "(I)Ljava/lang/Object;", (0,))
@@ -274,7 +278,7 @@ async fn returned_exception_is_a_local_reference() -> JvmResult<()> {
 
     assert_eq!(jvm.collect_garbage()?, 2);
     assert_eq!(jvm.collect_garbage()?, 0);
-    jvm.pop_frame();
+    drop(frame);
     assert_eq!(jvm.collect_garbage()?, 8);
 
     Ok(())
@@ -285,12 +289,18 @@ async fn thread_start_keeps_the_thread_alive_until_spawn_callback_runs() -> JvmR
     let runtime = TestRuntime::new_with_queued_spawns(BTreeMap::new());
     let jvm = create_test_jvm(runtime.clone()).await?;
 
+    // the first Thread constructed loads and initializes ThreadGroup, whose class loading garbage would skew the counts
+    let frame = jvm.push_native_frame()?;
+    let _: ClassInstanceRef<()> = jvm
+        .get_static_field("java/lang/ThreadGroup", "mainGroup", "Ljava/lang/ThreadGroup;")
+        .await?;
+    drop(frame);
     jvm.collect_garbage()?;
 
-    jvm.push_native_frame();
+    let frame = jvm.push_native_frame()?;
     let thread = jvm.new_class("java/lang/Thread", "()V", ()).await?;
     let _: () = jvm.invoke_virtual(&thread, "start", "()V", ()).await?;
-    jvm.pop_frame();
+    drop(frame);
 
     assert_eq!(jvm.collect_garbage()?, 1);
     assert_eq!(jvm.collect_garbage()?, 0);
@@ -300,3 +310,132 @@ async fn thread_start_keeps_the_thread_alive_until_spawn_callback_runs() -> JvmR
 
     Ok(())
 }
+
+#[tokio::test]
+async fn minor_collection_keeps_young_objects_referenced_from_old_ones() -> JvmResult<()> {
+    let jvm = test_jvm().await?;
+
+    struct Object;
+
+    let frame = jvm.push_native_frame()?;
+    let _: ClassInstanceRef<Array<Object>> = jvm.instantiate_array("Ljava/lang/Object;", 0).await?.into();
+    drop(frame);
+    jvm.collect_garbage()?;
+
+    // the array survives a minor collection and is promoted
+    let outer_frame = jvm.push_native_frame()?;
+    let mut array: ClassInstanceRef<Array<Object>> = jvm.instantiate_array("Ljava/lang/Object;", 1).await?.into();
+    assert_eq!(jvm.collect_young_garbage()?, 0);
+
+    // young string is only reachable through the old array, via the remembered set
+    let frame = jvm.push_native_frame()?;
+    let string = JavaLangString::from_rust_string(&jvm, "young").await?;
+    jvm.store_array(&mut array, 0, [string]).await?;
+    drop(frame);
+
+    // only the temporary [C from string construction is garbage
+    assert_eq!(jvm.collect_young_garbage()?, 1);
+
+    let frame = jvm.push_native_frame()?;
+    let strings: Vec<JavaValue> = jvm.load_array(&array, 0, 1).await?;
+    let JavaValue::Object(Some(string)) = &strings[0] else {
+        panic!("expected string");
+    };
+    assert_eq!(JavaLangString::to_rust_string(&jvm, string).await?, "young");
+    drop(frame);
+
+    // once promoted, the string is left to full collections
+    jvm.store_array(&mut array, 0, [ClassInstanceRef::<Object>::new(None)]).await?;
+    assert_eq!(jvm.collect_young_garbage()?, 0);
+    assert_eq!(jvm.collect_garbage()?, 2);
+
+    drop(outer_frame);
+    assert_eq!(jvm.collect_young_garbage()?, 0);
+    assert_eq!(jvm.collect_garbage()?, 1);
+
+    let statistics = jvm.gc_statistics();
+    assert_eq!(statistics.minor_collections, 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn native_frame_is_popped_on_early_return() -> JvmResult<()> {
+    let jvm = test_jvm().await?;
+
+    async fn fail_after_allocating(jvm: &Jvm) -> JvmResult<()> {
+        let _frame = jvm.push_native_frame()?;
+        let vector = jvm.new_class("java/util/Vector", "()V", ()).await?;
+        let _: ClassInstanceRef<()> = jvm.invoke_virtual(&vector, "elementAt", "(I)Ljava/lang/Object;", (0,)).await?;
+
+        Ok(())
+    }
+
+    jvm.collect_garbage()?;
+
+    assert!(fail_after_allocating(&jvm).await.is_err());
+    assert!(jvm.collect_garbage()? > 0);
+    assert_eq!(jvm.collect_garbage()?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_class_unloading() -> JvmResult<()> {
+    let jvm = test_jvm().await?;
+    jvm.collect_garbage()?;
+
+    let frame = jvm.push_native_frame()?;
+    let system_class_loader = JavaLangClassLoader::get_system_class_loader(&jvm).await?;
+    let class_loader = jvm
+        .new_class("java/lang/ClassLoader", "(Ljava/lang/ClassLoader;)V", (system_class_loader,))
+        .await?;
+    let class = ClassDefinitionImpl::from_classfile(include_bytes!("../../test_data/Exception.class")).unwrap();
+    jvm.register_class(Box::new(class), Some(class_loader.clone())).await?;
+
+    // reachable loader
+    jvm.collect_garbage()?;
+    assert_eq!(jvm.gc_statistics().classes_unloaded, 0);
+
+    // an instance keeps its class and the loader's namespace alive
+    let class = jvm.loaded_class(Some(&class_loader), "Exception").unwrap();
+    let instance: ClassInstanceRef<()> = jvm.instantiate(&class).await?.into();
+    let instance = jvm.new_global_ref(&instance).unwrap();
+    drop(frame);
+
+    jvm.collect_garbage()?;
+    assert_eq!(jvm.gc_statistics().classes_unloaded, 0);
+
+    drop(instance);
+    assert!(jvm.collect_garbage()? > 0);
+    assert_eq!(jvm.gc_statistics().classes_unloaded, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_class_unloading_with_running_method() -> JvmResult<()> {
+    let jvm = test_jvm().await?;
+
+    let frame = jvm.push_native_frame()?;
+    let system_class_loader = JavaLangClassLoader::get_system_class_loader(&jvm).await?;
+    let class_loader = jvm
+        .new_class("java/lang/ClassLoader", "(Ljava/lang/ClassLoader;)V", (system_class_loader,))
+        .await?;
+    let class = ClassDefinitionImpl::from_classfile(include_bytes!("../../test_data/ClinitCollect.class")).unwrap();
+    jvm.register_class(Box::new(class), Some(class_loader.clone())).await?;
+    let class = jvm.loaded_class(Some(&class_loader), "ClinitCollect").unwrap();
+    drop(class_loader);
+    drop(frame);
+
+    // the static initializer collects while it runs, with only its frame keeping the loader alive
+    let frame = jvm.push_native_frame()?;
+    jvm.instantiate(&class).await?;
+    assert_eq!(jvm.gc_statistics().classes_unloaded, 0);
+    drop(frame);
+
+    jvm.collect_garbage()?;
+    assert_eq!(jvm.gc_statistics().classes_unloaded, 1);
+
+    Ok(())
+}
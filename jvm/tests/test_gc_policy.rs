@@ -0,0 +1,89 @@
+use jvm::{GcPolicy, Jvm, Result as JvmResult};
+use jvm_rust::ClassDefinitionImpl;
+
+use test_utils::test_jvm;
+
+async fn allocation_loop_jvm(policy: GcPolicy) -> JvmResult<Jvm> {
+    let jvm = test_jvm().await?;
+
+    let class = ClassDefinitionImpl::from_classfile(include_bytes!("../../test_data/AllocationLoop.class")).unwrap();
+    jvm.register_class(Box::new(class), None).await?;
+    jvm.set_gc_policy(policy);
+
+    Ok(jvm)
+}
+
+#[tokio::test]
+async fn test_allocation_count_trigger() -> JvmResult<()> {
+    let jvm = allocation_loop_jvm(GcPolicy {
+        allocation_threshold: Some(100),
+        allocated_bytes_threshold: None,
+        ..GcPolicy::default()
+    })
+    .await?;
+
+    let result: i32 = jvm.invoke_static("AllocationLoop", "allocate", "(I)I", (5000,)).await?;
+    assert_eq!(result, 47008);
+
+    let statistics = jvm.gc_statistics();
+    assert!(statistics.automatic_collections > 0);
+    assert!(statistics.minor_collections > 0 && statistics.minor_collections < statistics.automatic_collections);
+    assert!(statistics.objects_collected > 5000);
+    assert!(statistics.bytes_collected > 5000 * 64);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_allocated_bytes_trigger() -> JvmResult<()> {
+    let jvm = allocation_loop_jvm(GcPolicy {
+        allocation_threshold: None,
+        allocated_bytes_threshold: Some(0x4000),
+        ..GcPolicy::default()
+    })
+    .await?;
+
+    let _: i32 = jvm.invoke_static("AllocationLoop", "allocate", "(I)I", (5000,)).await?;
+
+    // each iteration allocates a 76-byte array and a 16-byte node, so roughly every 178 iterations
+    let statistics = jvm.gc_statistics();
+    assert!(statistics.automatic_collections >= 20);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_collection_runs_in_native_frame() -> JvmResult<()> {
+    let jvm = allocation_loop_jvm(GcPolicy {
+        allocation_threshold: Some(100),
+        allocated_bytes_threshold: None,
+        ..GcPolicy::default()
+    })
+    .await?;
+
+    let _frame = jvm.push_native_frame()?;
+    let vector = jvm.new_class("java/util/Vector", "()V", ()).await?;
+    let _: i32 = jvm.invoke_static("AllocationLoop", "allocate", "(I)I", (1000,)).await?;
+
+    let statistics = jvm.gc_statistics();
+    assert!(statistics.automatic_collections > 0);
+
+    // the frame's local references survived
+    let _: bool = jvm.invoke_virtual(&vector, "add", "(Ljava/lang/Object;)Z", (vector.clone(),)).await?;
+    let size: i32 = jvm.invoke_virtual(&vector, "size", "()I", ()).await?;
+    assert_eq!(size, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_disabled_policy() -> JvmResult<()> {
+    let jvm = allocation_loop_jvm(GcPolicy::disabled()).await?;
+
+    let _: i32 = jvm.invoke_static("AllocationLoop", "allocate", "(I)I", (1000,)).await?;
+
+    let statistics = jvm.gc_statistics();
+    assert_eq!(statistics.collections, 0);
+
+    Ok(())
+}
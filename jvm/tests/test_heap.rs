@@ -0,0 +1,58 @@
+use jvm::{JavaError, Result as JvmResult};
+
+use test_utils::test_jvm;
+
+#[tokio::test]
+async fn test_heap_accounting() -> JvmResult<()> {
+    let jvm = test_jvm().await?;
+    jvm.resolve_class("[I").await?;
+    jvm.resolve_class("[J").await?;
+    jvm.collect_garbage()?;
+
+    let used = jvm.heap_used();
+
+    let frame = jvm.push_native_frame()?;
+    let _array = jvm.instantiate_array("I", 100).await?;
+    assert_eq!(jvm.heap_used(), used + 12 + 4 * 100);
+
+    let _array = jvm.instantiate_array("J", 10).await?;
+    assert_eq!(jvm.heap_used(), used + 12 + 4 * 100 + 12 + 8 * 10);
+    drop(frame);
+
+    jvm.collect_garbage()?;
+    assert_eq!(jvm.heap_used(), used);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_out_of_memory() -> JvmResult<()> {
+    let jvm = test_jvm().await?;
+    jvm.collect_garbage()?;
+
+    assert_eq!(jvm.max_heap_size(), None);
+    jvm.set_max_heap_size(Some(jvm.heap_used() + 0x10000));
+
+    // unreachable arrays are collected to make room
+    for _ in 0..10 {
+        let frame = jvm.push_native_frame()?;
+        let _array = jvm.instantiate_array("B", 0x8000).await?;
+        drop(frame);
+    }
+
+    // reachable arrays are not
+    let frame = jvm.push_native_frame()?;
+    let _array = jvm.instantiate_array("B", 0x8000).await?;
+    let result = jvm.instantiate_array("B", 0x10000).await;
+
+    let Err(JavaError::JavaException(exception)) = result else {
+        panic!("expected OutOfMemoryError");
+    };
+    assert!(jvm.is_instance(&*exception, "java/lang/OutOfMemoryError"));
+    drop(frame);
+
+    jvm.set_max_heap_size(None);
+    assert_eq!(jvm.max_heap_size(), None);
+
+    Ok(())
+}
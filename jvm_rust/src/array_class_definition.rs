@@ -30,12 +30,16 @@ impl ArrayClassDefinitionImpl {
 
 #[async_trait::async_trait]
 impl ArrayClassDefinition for ArrayClassDefinitionImpl {
+    fn array_identity(&self) -> usize {
+        Arc::as_ptr(&self.inner) as usize
+    }
+
     fn element_type_name(&self) -> String {
         self.inner.element_type_name.clone()
     }
 
     async fn instantiate_array(&self, _: &Jvm, length: usize) -> Result<Box<dyn ClassInstance>> {
-        Ok(Box::new(ArrayClassInstanceImpl::new(self, length)))
+        Ok(Box::new(ArrayClassInstanceImpl::new(self, length)?))
     }
 }
 
@@ -1,4 +1,4 @@
-use alloc::{boxed::Box, sync::Arc, vec, vec::Vec};
+use alloc::{boxed::Box, format, sync::Arc, vec, vec::Vec};
 use core::{
     fmt::{self, Debug, Formatter},
     hash::{Hash, Hasher},
@@ -7,7 +7,8 @@ use core::{
 use parking_lot::RwLock;
 
 use jvm::{
-    ArrayClassDefinition, ArrayClassInstance, ArrayRawBuffer, ArrayRawBufferMut, ClassDefinition, ClassInstance, Field, JavaType, JavaValue, Result,
+    ArrayClassDefinition, ArrayClassInstance, ArrayRawBuffer, ArrayRawBufferMut, ClassDefinition, ClassInstance, Field, JavaError, JavaType,
+    JavaValue, Result,
 };
 
 use crate::array_class_definition::ArrayClassDefinitionImpl;
@@ -31,29 +32,29 @@ pub struct ArrayClassInstanceImpl {
 }
 
 impl ArrayClassInstanceImpl {
-    pub fn new(class: &ArrayClassDefinitionImpl, length: usize) -> Self {
-        let element_type = JavaType::parse(&class.element_type_name());
+    pub fn new(class: &ArrayClassDefinitionImpl, length: usize) -> Result<Self> {
+        let element_type = JavaType::parse(&class.element_type_name())?;
 
         let elements = if matches!(element_type, JavaType::Class(_) | JavaType::Array(_)) {
-            let default_value = element_type.default();
+            let default_value = element_type.default()?;
             ArrayElements::NonPrimitive(vec![default_value; length])
         } else {
-            let element_size = Self::primitive_element_size(&element_type);
+            let element_size = Self::primitive_element_size(&element_type)?;
             ArrayElements::Primitive(vec![0; length * element_size])
         };
 
-        Self {
+        Ok(Self {
             inner: Arc::new(ArrayClassInstanceInner {
                 class: Box::new(class.clone()),
                 length,
                 element_type,
                 elements: RwLock::new(elements),
             }),
-        }
+        })
     }
 
-    fn primitive_element_size(element_type: &JavaType) -> usize {
-        match element_type {
+    fn primitive_element_size(element_type: &JavaType) -> Result<usize> {
+        Ok(match element_type {
             JavaType::Boolean => 1,
             JavaType::Byte => 1,
             JavaType::Char => 2,
@@ -62,54 +63,71 @@ impl ArrayClassInstanceImpl {
             JavaType::Long => 8,
             JavaType::Float => 4,
             JavaType::Double => 8,
-            _ => unreachable!(),
-        }
+            element_type => return Err(JavaError::internal(format!("{element_type:?} is not a primitive array element type"))),
+        })
     }
 
-    fn convert_values_to_primitive(&self, values: Box<[JavaValue]>) -> Vec<u8> {
-        match self.inner.element_type {
-            JavaType::Boolean => values.into_vec().into_iter().map(|x| bool::from(x) as u8).collect::<Vec<_>>(),
-            JavaType::Byte => values.into_vec().into_iter().map(|x| i8::from(x) as u8).collect::<Vec<_>>(),
-            JavaType::Char => values.into_vec().into_iter().flat_map(|x| u16::from(x).to_le_bytes()).collect::<Vec<_>>(),
-            JavaType::Short => values.into_vec().into_iter().flat_map(|x| i16::from(x).to_le_bytes()).collect::<Vec<_>>(),
-            JavaType::Int => values.into_vec().into_iter().flat_map(|x| i32::from(x).to_le_bytes()).collect::<Vec<_>>(),
-            JavaType::Long => values.into_vec().into_iter().flat_map(|x| i64::from(x).to_le_bytes()).collect::<Vec<_>>(),
-            JavaType::Float => values.into_vec().into_iter().flat_map(|x| f32::from(x).to_le_bytes()).collect::<Vec<_>>(),
-            JavaType::Double => values.into_vec().into_iter().flat_map(|x| f64::from(x).to_le_bytes()).collect::<Vec<_>>(),
-            _ => unreachable!(),
+    fn convert_values_to_primitive(&self, values: Box<[JavaValue]>) -> Result<Vec<u8>> {
+        let mut result = Vec::with_capacity(values.len() * Self::primitive_element_size(&self.inner.element_type)?);
+        for value in values {
+            match (&self.inner.element_type, value) {
+                (JavaType::Boolean, JavaValue::Boolean(x)) => result.push(x as u8),
+                (JavaType::Byte, JavaValue::Byte(x)) => result.push(x as u8),
+                (JavaType::Char, JavaValue::Char(x)) => result.extend(x.to_le_bytes()),
+                (JavaType::Short, JavaValue::Short(x)) => result.extend(x.to_le_bytes()),
+                (JavaType::Int, JavaValue::Int(x)) => result.extend(x.to_le_bytes()),
+                (JavaType::Long, JavaValue::Long(x)) => result.extend(x.to_le_bytes()),
+                (JavaType::Float, JavaValue::Float(x)) => result.extend(x.to_le_bytes()),
+                (JavaType::Double, JavaValue::Double(x)) => result.extend(x.to_le_bytes()),
+                (element_type, value) => return Err(JavaError::internal(format!("Cannot store {value:?} in an array of {element_type:?}"))),
+            }
         }
+
+        Ok(result)
     }
 
-    fn convert_primitive_to_values(&self, values_raw: &[u8]) -> Vec<JavaValue> {
-        match self.inner.element_type {
+    fn convert_primitive_to_values(&self, values_raw: &[u8]) -> Result<Vec<JavaValue>> {
+        Ok(match &self.inner.element_type {
             JavaType::Boolean => values_raw.iter().map(|&x| JavaValue::Boolean(x != 0)).collect::<Vec<_>>(),
             JavaType::Byte => values_raw.iter().map(|&x| JavaValue::Byte(x as i8)).collect::<Vec<_>>(),
             JavaType::Char => values_raw
-                .chunks(2)
-                .map(|x| JavaValue::Char(u16::from_le_bytes(x.try_into().unwrap())))
+                .as_chunks::<2>()
+                .0
+                .iter()
+                .map(|x| JavaValue::Char(u16::from_le_bytes(*x)))
                 .collect::<Vec<_>>(),
             JavaType::Short => values_raw
-                .chunks(2)
-                .map(|x| JavaValue::Short(i16::from_le_bytes(x.try_into().unwrap())))
+                .as_chunks::<2>()
+                .0
+                .iter()
+                .map(|x| JavaValue::Short(i16::from_le_bytes(*x)))
                 .collect::<Vec<_>>(),
             JavaType::Int => values_raw
-                .chunks(4)
-                .map(|x| JavaValue::Int(i32::from_le_bytes(x.try_into().unwrap())))
+                .as_chunks::<4>()
+                .0
+                .iter()
+                .map(|x| JavaValue::Int(i32::from_le_bytes(*x)))
                 .collect::<Vec<_>>(),
             JavaType::Long => values_raw
-                .chunks(8)
-                .map(|x| JavaValue::Long(i64::from_le_bytes(x.try_into().unwrap())))
+                .as_chunks::<8>()
+                .0
+                .iter()
+                .map(|x| JavaValue::Long(i64::from_le_bytes(*x)))
                 .collect::<Vec<_>>(),
             JavaType::Float => values_raw
-                .chunks(4)
-                .map(|x| JavaValue::Float(f32::from_le_bytes(x.try_into().unwrap())))
+                .as_chunks::<4>()
+                .0
+                .iter()
+                .map(|x| JavaValue::Float(f32::from_le_bytes(*x)))
                 .collect::<Vec<_>>(),
             JavaType::Double => values_raw
-                .chunks(8)
-                .map(|x| JavaValue::Double(f64::from_le_bytes(x.try_into().unwrap())))
+                .as_chunks::<8>()
+                .0
+                .iter()
+                .map(|x| JavaValue::Double(f64::from_le_bytes(*x)))
                 .collect::<Vec<_>>(),
-            _ => unreachable!(),
-        }
+            element_type => return Err(JavaError::internal(format!("{element_type:?} is not a primitive array element type"))),
+        })
     }
 }
 
@@ -137,11 +155,9 @@ impl ClassInstance for ArrayClassInstanceImpl {
     }
 
     fn equals(&self, other: &dyn ClassInstance) -> Result<bool> {
-        let other = other.as_any().downcast_ref::<ArrayClassInstanceImpl>();
-        if other.is_none() {
+        let Some(other) = other.as_any().downcast_ref::<ArrayClassInstanceImpl>() else {
             return Ok(false);
-        }
-        let other = other.unwrap();
+        };
 
         Ok(Arc::ptr_eq(&self.inner, &other.inner))
     }
@@ -155,11 +171,11 @@ impl ClassInstance for ArrayClassInstanceImpl {
     }
 
     fn get_field(&self, _field: &dyn Field) -> Result<JavaValue> {
-        panic!("Array classes do not have fields")
+        Err(JavaError::internal("Array classes do not have fields"))
     }
 
     fn put_field(&mut self, _field: &dyn Field, _value: JavaValue) -> Result<()> {
-        panic!("Array classes do not have fields")
+        Err(JavaError::internal("Array classes do not have fields"))
     }
 }
 
@@ -167,8 +183,8 @@ impl ArrayClassInstance for ArrayClassInstanceImpl {
     fn store(&mut self, offset: usize, values: Box<[JavaValue]>) -> Result<()> {
         match &mut *self.inner.elements.write() {
             ArrayElements::Primitive(x) => {
-                let element_size = Self::primitive_element_size(&self.inner.element_type);
-                let values_raw = self.convert_values_to_primitive(values);
+                let element_size = Self::primitive_element_size(&self.inner.element_type)?;
+                let values_raw = self.convert_values_to_primitive(values)?;
 
                 x.splice(offset * element_size..offset * element_size + values_raw.len(), values_raw);
             }
@@ -183,10 +199,10 @@ impl ArrayClassInstance for ArrayClassInstanceImpl {
     fn load(&self, offset: usize, length: usize) -> Result<Vec<JavaValue>> {
         Ok(match &*self.inner.elements.read() {
             ArrayElements::Primitive(x) => {
-                let element_size = Self::primitive_element_size(&self.inner.element_type);
+                let element_size = Self::primitive_element_size(&self.inner.element_type)?;
                 let values_raw = &x[offset * element_size..offset * element_size + length * element_size];
 
-                self.convert_primitive_to_values(values_raw)
+                self.convert_primitive_to_values(values_raw)?
             }
             ArrayElements::NonPrimitive(x) => x[offset..offset + length].to_vec(),
         })
@@ -225,13 +241,13 @@ impl ArrayRawBuffer for ArrayRawBufferImpl {
     fn read(&self, offset: usize, buffer: &mut [u8]) -> Result<()> {
         match &*self.inner.elements.read() {
             ArrayElements::Primitive(x) => {
-                let element_size = ArrayClassInstanceImpl::primitive_element_size(&self.inner.element_type);
+                let element_size = ArrayClassInstanceImpl::primitive_element_size(&self.inner.element_type)?;
                 let values_raw = &x[offset * element_size..offset * element_size + buffer.len() * element_size];
 
                 buffer.copy_from_slice(values_raw);
             }
             ArrayElements::NonPrimitive(_) => {
-                panic!("Expected primitive array");
+                return Err(JavaError::internal("Expected primitive array"));
             }
         }
 
@@ -242,11 +258,11 @@ impl ArrayRawBuffer for ArrayRawBufferImpl {
 impl ArrayRawBufferMut for ArrayRawBufferImpl {
     fn write(&mut self, offset: usize, buffer: &[u8]) -> Result<()> {
         if let ArrayElements::Primitive(x) = &mut *self.inner.elements.write() {
-            let element_size = ArrayClassInstanceImpl::primitive_element_size(&self.inner.element_type);
+            let element_size = ArrayClassInstanceImpl::primitive_element_size(&self.inner.element_type)?;
 
             x[offset * element_size..offset * element_size + buffer.len() * element_size].copy_from_slice(buffer);
         } else {
-            panic!("Expected primitive array");
+            return Err(JavaError::internal("Expected primitive array"));
         }
 
         Ok(())
@@ -143,6 +143,10 @@ impl ClassDefinitionImpl {
 
 #[async_trait::async_trait]
 impl ClassDefinition for ClassDefinitionImpl {
+    fn identity(&self) -> usize {
+        Arc::as_ptr(&self.inner) as usize
+    }
+
     fn name(&self) -> String {
         self.inner.name.clone()
     }
@@ -196,6 +200,10 @@ impl ClassDefinition for ClassDefinitionImpl {
             .map(|x| Box::new(x.clone()) as Box<dyn Method>)
     }
 
+    fn methods(&self) -> Vec<Box<dyn Method>> {
+        self.inner.methods.iter().map(|x| Box::new(x.clone()) as Box<dyn Method>).collect()
+    }
+
     fn field(&self, name: &str, descriptor: &str, is_static: bool) -> Option<Box<dyn Field>> {
         self.inner
             .fields
@@ -211,7 +219,7 @@ impl ClassDefinition for ClassDefinitionImpl {
     }
 
     fn get_static_field(&self, field: &dyn Field) -> Result<JavaValue> {
-        let field = field.as_any().downcast_ref::<FieldImpl>().unwrap();
+        let field = FieldImpl::downcast(field)?;
 
         let storage = self.inner.storage.read();
         let value = storage.get(field);
@@ -219,12 +227,12 @@ impl ClassDefinition for ClassDefinitionImpl {
         if let Some(x) = value {
             Ok(x.clone())
         } else {
-            Ok(JavaType::parse(&field.descriptor()).default())
+            JavaType::parse(&field.descriptor())?.default()
         }
     }
 
     fn put_static_field(&mut self, field: &dyn Field, value: JavaValue) -> Result<()> {
-        let field = field.as_any().downcast_ref::<FieldImpl>().unwrap();
+        let field = FieldImpl::downcast(field)?;
 
         self.inner.storage.write().insert(field.clone(), value);
 
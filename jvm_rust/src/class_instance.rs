@@ -53,17 +53,15 @@ impl ClassInstance for ClassInstanceImpl {
     }
 
     fn equals(&self, other: &dyn ClassInstance) -> Result<bool> {
-        let other = other.as_any().downcast_ref::<ClassInstanceImpl>();
-        if other.is_none() {
+        let Some(other) = other.as_any().downcast_ref::<ClassInstanceImpl>() else {
             return Ok(false);
-        }
-        let other = other.unwrap();
+        };
 
         Ok(Arc::ptr_eq(&self.inner, &other.inner))
     }
 
     fn get_field(&self, field: &dyn Field) -> Result<JavaValue> {
-        let field = field.as_any().downcast_ref::<FieldImpl>().unwrap();
+        let field = FieldImpl::downcast(field)?;
 
         let storage = self.inner.storage.read();
         let value = storage.get(field);
@@ -71,12 +69,12 @@ impl ClassInstance for ClassInstanceImpl {
         if let Some(x) = value {
             Ok(x.clone())
         } else {
-            Ok(JavaType::parse(&field.descriptor()).default())
+            JavaType::parse(&field.descriptor())?.default()
         }
     }
 
     fn put_field(&mut self, field: &dyn Field, value: JavaValue) -> Result<()> {
-        let field = field.as_any().downcast_ref::<FieldImpl>().unwrap();
+        let field = FieldImpl::downcast(field)?;
 
         self.inner.storage.write().insert(field.clone(), value);
 
@@ -1,4 +1,5 @@
 use alloc::{
+    format,
     string::{String, ToString},
     sync::Arc,
 };
@@ -6,7 +7,7 @@ use alloc::{
 use classfile::FieldInfo;
 use java_class_proto::JavaFieldProto;
 use java_constants::FieldAccessFlags;
-use jvm::Field;
+use jvm::{Field, JavaError, Result};
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd)]
 struct FieldInner {
@@ -44,6 +45,14 @@ impl FieldImpl {
             }),
         }
     }
+
+    // fields handed to a jvm_rust class always come from a jvm_rust class definition
+    pub(crate) fn downcast(field: &dyn Field) -> Result<&Self> {
+        field
+            .as_any()
+            .downcast_ref::<Self>()
+            .ok_or_else(|| JavaError::internal(format!("Field {} does not belong to this class", field.name())))
+    }
 }
 
 impl Field for FieldImpl {
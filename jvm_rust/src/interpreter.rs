@@ -1,10 +1,10 @@
 use alloc::{boxed::Box, format, vec, vec::Vec};
 use core::iter;
 
-use classfile::{AttributeInfoCode, ConstantPoolReference, Opcode};
+use classfile::{AttributeInfoCode, ConstantPoolReference, FieldMethodref, Opcode};
 use jvm::{ClassInstance, JavaChar, JavaError, JavaType, JavaValue, Jvm, Result};
 
-use crate::stack_frame::StackFrame;
+use crate::stack_frame::{StackFrame, int_value};
 
 enum ExecuteNext {
     Continue,
@@ -36,6 +36,9 @@ impl Interpreter {
             .local_variables
             .extend(iter::repeat_n(JavaValue::Void, code_attribute.max_locals as usize));
 
+        // method entry and backward branches are gc safepoints
+        jvm.safepoint(stack_frame.references())?;
+
         let mut iter = code_attribute.code.range(0..);
         while let Some((offset, opcode)) = iter.next() {
             tracing::trace!("Opcode {opcode:?}");
@@ -43,13 +46,18 @@ impl Interpreter {
             let result = Self::execute_opcode(jvm, *offset, opcode, &mut stack_frame, return_type).await;
             match result {
                 Ok(ExecuteNext::Continue) => {}
-                Ok(ExecuteNext::Jump(offset)) => {
-                    iter = code_attribute.code.range(offset..);
+                Ok(ExecuteNext::Jump(target)) => {
+                    if target <= *offset {
+                        jvm.safepoint(stack_frame.references())?;
+                    }
+                    iter = code_attribute.code.range(target..);
                 }
                 Ok(ExecuteNext::Return(value)) => return Ok(value),
                 Err(JavaError::JavaException(e)) => {
                     let exception_handler = Self::find_exception_handler(jvm, &*e, code_attribute, *offset).await;
                     if let Some(x) = exception_handler {
+                        jvm.exception_caught(&*e);
+
                         stack_frame.operand_stack.clear();
                         stack_frame.operand_stack.push(JavaValue::Object(Some(e)));
 
@@ -58,10 +66,12 @@ impl Interpreter {
                         return Err(JavaError::JavaException(e));
                     }
                 }
+                // vm errors can't be caught by java code
+                Err(e) => return Err(e),
             }
         }
 
-        panic!("Should not reach here")
+        Err(JavaError::internal("Execution fell off the end of the code"))
     }
 
     async fn execute_opcode(
@@ -74,16 +84,19 @@ impl Interpreter {
         match opcode {
             Opcode::Aaload | Opcode::Baload | Opcode::Caload | Opcode::Daload | Opcode::Faload | Opcode::Iaload | Opcode::Laload | Opcode::Saload => {
                 // TODO type checking
-                let index: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let index = stack_frame.pop_int()?;
                 if index < 0 {
                     return Err(jvm.exception("java/lang/ArrayIndexOutOfBoundsException", &format!("{}", index)).await);
                 }
-                let array: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
-                if array.is_none() {
+                let Some(array) = stack_frame.pop_object()? else {
                     return Err(jvm.exception("java/lang/NullPointerException", "Array is null").await);
-                }
+                };
 
-                let value = jvm.load_array(&array.unwrap(), index as usize, 1).await?.pop().unwrap();
+                let value = jvm
+                    .load_array(&array, index as usize, 1)
+                    .await?
+                    .pop()
+                    .ok_or_else(|| JavaError::internal("Array load returned no value"))?;
 
                 stack_frame.operand_stack.push(Self::to_stack_frame_type(value));
             }
@@ -96,65 +109,64 @@ impl Interpreter {
             | Opcode::Lastore
             | Opcode::Sastore => {
                 // TODO type checking
-                let value = stack_frame.operand_stack.pop().unwrap();
-                let index: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop()?;
+                let index = stack_frame.pop_int()?;
                 if index < 0 {
                     return Err(jvm.exception("java/lang/ArrayIndexOutOfBoundsException", &format!("{}", index)).await);
                 }
-                let mut array: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
-                if array.is_none() {
+                let Some(mut array) = stack_frame.pop_object()? else {
                     return Err(jvm.exception("java/lang/NullPointerException", "Array is null").await);
-                }
+                };
 
                 // JVMS: aastore reports an out-of-bounds index before any ArrayStoreException type check
-                let length = jvm.array_length(array.as_ref().unwrap()).await?;
+                let length = jvm.array_length(&array).await?;
                 if index as usize >= length {
                     return Err(jvm.exception("java/lang/ArrayIndexOutOfBoundsException", &format!("{}", index)).await);
                 }
 
-                let element_type = jvm.array_element_type(array.as_ref().unwrap()).await?;
+                let element_type = jvm.array_element_type(&array).await?;
 
                 // operand stack has only integer, so convert it to the correct type
                 let value = match element_type {
-                    JavaType::Boolean => JavaValue::Boolean(i32::from(value) & 1 != 0),
-                    JavaType::Byte => JavaValue::Byte(i32::from(value) as _),
-                    JavaType::Char => JavaValue::Char(i32::from(value) as _),
-                    JavaType::Short => JavaValue::Short(i32::from(value) as _),
-                    JavaType::Int => JavaValue::Int(i32::from(value)),
+                    JavaType::Boolean => JavaValue::Boolean(int_value(value)? & 1 != 0),
+                    JavaType::Byte => JavaValue::Byte(int_value(value)? as _),
+                    JavaType::Char => JavaValue::Char(int_value(value)? as _),
+                    JavaType::Short => JavaValue::Short(int_value(value)? as _),
+                    JavaType::Int => JavaValue::Int(int_value(value)?),
                     _ => value,
                 };
 
                 if matches!(opcode, Opcode::Aastore) {
-                    let stored: &Option<Box<dyn ClassInstance>> = (&value).into();
+                    let stored: &Option<Box<dyn ClassInstance>> = (&value).try_into()?;
                     if let Some(stored) = stored
-                        && !jvm.array_store_allowed(&**array.as_ref().unwrap(), &**stored)
+                        && !jvm.array_store_allowed(&*array, &**stored)
                     {
                         return Err(jvm.exception("java/lang/ArrayStoreException", &stored.class_definition().name()).await);
                     }
                 }
 
-                jvm.store_array(array.as_mut().unwrap(), index as usize, [value]).await?;
+                jvm.store_array(&mut array, index as usize, [value]).await?;
             }
             Opcode::AconstNull => stack_frame.operand_stack.push(JavaValue::Object(None)),
             Opcode::Aload(x) | Opcode::Dload(x) | Opcode::Fload(x) | Opcode::Iload(x) | Opcode::Lload(x) => {
-                let value = stack_frame.local_variables[*x as usize].clone();
+                let value = stack_frame.local(*x as usize)?;
                 stack_frame.operand_stack.push(value);
             }
             Opcode::Athrow => {
-                let exception: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
+                let exception = stack_frame.pop_object()?;
 
-                if exception.is_none() {
+                let Some(exception) = exception else {
                     return Err(jvm.exception("java/lang/NullPointerException", "null").await);
-                }
+                };
 
-                return Err(JavaError::JavaException(exception.unwrap()));
+                return Err(jvm.throw(exception));
             }
             Opcode::Anewarray(x) => {
-                let length: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let length = stack_frame.pop_int()?;
                 if length < 0 {
                     return Err(jvm.exception("java/lang/NegativeArraySizeException", &format!("{}", length)).await);
                 }
-                let class_name = x.as_class();
+                let class_name = Self::class_ref(x)?;
                 let element_type_name = if class_name.starts_with('[') {
                     alloc::string::String::from(class_name)
                 } else {
@@ -165,9 +177,9 @@ impl Interpreter {
                 stack_frame.operand_stack.push(JavaValue::Object(Some(array)));
             }
             Opcode::Areturn | Opcode::Dreturn | Opcode::Freturn | Opcode::Ireturn | Opcode::Lreturn => {
-                let return_value = stack_frame.operand_stack.pop().unwrap();
+                let return_value = stack_frame.pop()?;
                 if matches!(opcode, Opcode::Ireturn) {
-                    let value: i32 = return_value.into();
+                    let value = int_value(return_value)?;
                     if *return_type == JavaType::Boolean {
                         return Ok(ExecuteNext::Return(JavaValue::Boolean(value & 1 != 0)));
                     } else if *return_type == JavaType::Char {
@@ -183,96 +195,94 @@ impl Interpreter {
                 return Ok(ExecuteNext::Return(return_value));
             }
             Opcode::Arraylength => {
-                let array: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
-                if array.is_none() {
+                let Some(array) = stack_frame.pop_object()? else {
                     return Err(jvm.exception("java/lang/NullPointerException", "Array is null").await);
-                }
+                };
 
-                let length = jvm.array_length(&array.unwrap()).await?;
+                let length = jvm.array_length(&array).await?;
                 stack_frame.operand_stack.push(JavaValue::Int(length as _));
             }
             Opcode::Astore(x) | Opcode::Dstore(x) | Opcode::Fstore(x) | Opcode::Istore(x) | Opcode::Lstore(x) => {
-                let value = stack_frame.operand_stack.pop();
-                stack_frame.local_variables[*x as usize] = value.unwrap();
+                let value = stack_frame.pop()?;
+                stack_frame.set_local(*x as usize, value)?;
             }
             Opcode::Bipush(x) => stack_frame.operand_stack.push(JavaValue::Int(*x as i32)),
             Opcode::Checkcast(x) => {
-                let top_stack: &Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.last().unwrap().into();
+                let top_stack = stack_frame.pop_object()?;
+                let class_name = Self::class_ref(x)?;
+                let is_castable = top_stack.as_ref().is_none_or(|instance| jvm.is_instance(&**instance, class_name));
+                stack_frame.operand_stack.push(JavaValue::Object(top_stack));
 
-                if !top_stack.is_none() && !jvm.is_instance(&**top_stack.as_ref().unwrap(), x.as_class()) {
+                if !is_castable {
                     return Err(jvm.exception("java/lang/ClassCastException", "Invalid cast").await);
                 }
             }
             Opcode::D2f => {
-                let value: f64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_double()?;
                 stack_frame.operand_stack.push(JavaValue::Float(value as _));
             }
             Opcode::D2i => {
-                let value: f64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_double()?;
                 stack_frame.operand_stack.push(JavaValue::Int(value as _));
             }
             Opcode::D2l => {
-                let value: f64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_double()?;
                 stack_frame.operand_stack.push(JavaValue::Long(value as _));
             }
             Opcode::Dadd => {
-                let value2: f64 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: f64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_double()?;
+                let value1 = stack_frame.pop_double()?;
 
                 stack_frame.operand_stack.push(JavaValue::Double(value1 + value2));
             }
             Opcode::Dcmpg => {
-                let value2: f64 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: f64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_double()?;
+                let value1 = stack_frame.pop_double()?;
 
-                if value1.is_nan() || value2.is_nan() {
-                    stack_frame.operand_stack.push(JavaValue::Int(1));
-                } else {
-                    stack_frame.operand_stack.push(JavaValue::Int(value1.partial_cmp(&value2).unwrap() as _));
-                }
+                // NaN compares as 1
+                let result = value1.partial_cmp(&value2).map_or(1, |x| x as i32);
+                stack_frame.operand_stack.push(JavaValue::Int(result));
             }
             Opcode::Dcmpl => {
-                let value2: f64 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: f64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_double()?;
+                let value1 = stack_frame.pop_double()?;
 
-                if value1.is_nan() || value2.is_nan() {
-                    stack_frame.operand_stack.push(JavaValue::Int(-1));
-                } else {
-                    stack_frame.operand_stack.push(JavaValue::Int(value1.partial_cmp(&value2).unwrap() as _));
-                }
+                // NaN compares as -1
+                let result = value1.partial_cmp(&value2).map_or(-1, |x| x as i32);
+                stack_frame.operand_stack.push(JavaValue::Int(result));
             }
             Opcode::Dconst(x) => {
                 stack_frame.operand_stack.push(JavaValue::Double(*x as f64));
             }
             Opcode::Ddiv => {
-                let value2: f64 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: f64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_double()?;
+                let value1 = stack_frame.pop_double()?;
 
                 stack_frame.operand_stack.push(JavaValue::Double(value1 / value2));
             }
             Opcode::Dmul => {
-                let value2: f64 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: f64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_double()?;
+                let value1 = stack_frame.pop_double()?;
 
                 stack_frame.operand_stack.push(JavaValue::Double(value1 * value2));
             }
             Opcode::Dneg => {
-                let value: f64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_double()?;
 
                 stack_frame.operand_stack.push(JavaValue::Double(-value));
             }
             Opcode::Dup => {
-                let value = stack_frame.operand_stack.pop().unwrap();
+                let value = stack_frame.pop()?;
                 stack_frame.operand_stack.push(value.clone());
                 stack_frame.operand_stack.push(value);
             }
             Opcode::Dup2 => {
-                let value = stack_frame.operand_stack.pop().unwrap();
+                let value = stack_frame.pop()?;
                 if matches!(value, JavaValue::Long(_) | JavaValue::Double(_)) {
                     stack_frame.operand_stack.push(value.clone());
                     stack_frame.operand_stack.push(value);
                 } else {
-                    let value2 = stack_frame.operand_stack.pop().unwrap();
+                    let value2 = stack_frame.pop()?;
                     stack_frame.operand_stack.push(value2.clone());
                     stack_frame.operand_stack.push(value.clone());
                     stack_frame.operand_stack.push(value2);
@@ -280,15 +290,15 @@ impl Interpreter {
                 }
             }
             Opcode::Dup2X1 => {
-                let value1 = stack_frame.operand_stack.pop().unwrap();
-                let value2 = stack_frame.operand_stack.pop().unwrap();
+                let value1 = stack_frame.pop()?;
+                let value2 = stack_frame.pop()?;
 
                 if matches!(value1, JavaValue::Long(_) | JavaValue::Double(_)) {
                     stack_frame.operand_stack.push(value1.clone());
                     stack_frame.operand_stack.push(value2);
                     stack_frame.operand_stack.push(value1);
                 } else {
-                    let value3 = stack_frame.operand_stack.pop().unwrap();
+                    let value3 = stack_frame.pop()?;
                     stack_frame.operand_stack.push(value2.clone());
                     stack_frame.operand_stack.push(value1.clone());
                     stack_frame.operand_stack.push(value3);
@@ -297,8 +307,8 @@ impl Interpreter {
                 }
             }
             Opcode::Dup2X2 => {
-                let value1 = stack_frame.operand_stack.pop().unwrap();
-                let value2 = stack_frame.operand_stack.pop().unwrap();
+                let value1 = stack_frame.pop()?;
+                let value2 = stack_frame.pop()?;
 
                 if matches!(value1, JavaValue::Long(_) | JavaValue::Double(_)) && matches!(value2, JavaValue::Long(_) | JavaValue::Double(_)) {
                     // form4
@@ -307,14 +317,14 @@ impl Interpreter {
                     stack_frame.operand_stack.push(value1);
                 } else if matches!(value1, JavaValue::Long(_) | JavaValue::Double(_)) {
                     // form2
-                    let value3 = stack_frame.operand_stack.pop().unwrap();
+                    let value3 = stack_frame.pop()?;
 
                     stack_frame.operand_stack.push(value1.clone());
                     stack_frame.operand_stack.push(value3);
                     stack_frame.operand_stack.push(value2);
                     stack_frame.operand_stack.push(value1);
                 } else {
-                    let value3 = stack_frame.operand_stack.pop().unwrap();
+                    let value3 = stack_frame.pop()?;
 
                     if matches!(value3, JavaValue::Long(_) | JavaValue::Double(_)) {
                         // form3
@@ -325,7 +335,7 @@ impl Interpreter {
                         stack_frame.operand_stack.push(value1);
                     } else {
                         // form1
-                        let value4 = stack_frame.operand_stack.pop().unwrap();
+                        let value4 = stack_frame.pop()?;
 
                         stack_frame.operand_stack.push(value2.clone());
                         stack_frame.operand_stack.push(value1.clone());
@@ -337,22 +347,22 @@ impl Interpreter {
                 }
             }
             Opcode::DupX1 => {
-                let value1 = stack_frame.operand_stack.pop().unwrap();
-                let value2 = stack_frame.operand_stack.pop().unwrap();
+                let value1 = stack_frame.pop()?;
+                let value2 = stack_frame.pop()?;
 
                 stack_frame.operand_stack.push(value1.clone());
                 stack_frame.operand_stack.push(value2.clone());
                 stack_frame.operand_stack.push(value1);
             }
             Opcode::DupX2 => {
-                let value1 = stack_frame.operand_stack.pop().unwrap();
-                let value2 = stack_frame.operand_stack.pop().unwrap();
+                let value1 = stack_frame.pop()?;
+                let value2 = stack_frame.pop()?;
                 if matches!(value2, JavaValue::Long(_) | JavaValue::Double(_)) {
                     stack_frame.operand_stack.push(value1.clone());
                     stack_frame.operand_stack.push(value2.clone());
                     stack_frame.operand_stack.push(value1);
                 } else {
-                    let value3 = stack_frame.operand_stack.pop().unwrap();
+                    let value3 = stack_frame.pop()?;
                     stack_frame.operand_stack.push(value1.clone());
                     stack_frame.operand_stack.push(value3.clone());
                     stack_frame.operand_stack.push(value2.clone());
@@ -360,101 +370,95 @@ impl Interpreter {
                 }
             }
             Opcode::Drem => {
-                let value2: f64 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: f64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_double()?;
+                let value1 = stack_frame.pop_double()?;
 
                 stack_frame.operand_stack.push(JavaValue::Double(value1 % value2));
             }
             Opcode::Dsub => {
-                let value2: f64 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: f64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_double()?;
+                let value1 = stack_frame.pop_double()?;
 
                 stack_frame.operand_stack.push(JavaValue::Double(value1 - value2));
             }
             Opcode::F2d => {
-                let value: f32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_float()?;
                 stack_frame.operand_stack.push(JavaValue::Double(value as _));
             }
             Opcode::F2i => {
-                let value: f32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_float()?;
                 stack_frame.operand_stack.push(JavaValue::Int(value as _));
             }
             Opcode::F2l => {
-                let value: f32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_float()?;
                 stack_frame.operand_stack.push(JavaValue::Long(value as _));
             }
             Opcode::Fadd => {
-                let value2: f32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: f32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_float()?;
+                let value1 = stack_frame.pop_float()?;
 
                 stack_frame.operand_stack.push(JavaValue::Float(value1 + value2));
             }
             Opcode::Fcmpg => {
-                let value2: f32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: f32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_float()?;
+                let value1 = stack_frame.pop_float()?;
 
-                if value1.is_nan() || value2.is_nan() {
-                    stack_frame.operand_stack.push(JavaValue::Int(1));
-                } else {
-                    stack_frame.operand_stack.push(JavaValue::Int(value1.partial_cmp(&value2).unwrap() as _));
-                }
+                // NaN compares as 1
+                let result = value1.partial_cmp(&value2).map_or(1, |x| x as i32);
+                stack_frame.operand_stack.push(JavaValue::Int(result));
             }
             Opcode::Fcmpl => {
-                let value2: f32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: f32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_float()?;
+                let value1 = stack_frame.pop_float()?;
 
-                if value1.is_nan() || value2.is_nan() {
-                    stack_frame.operand_stack.push(JavaValue::Int(-1));
-                } else {
-                    stack_frame.operand_stack.push(JavaValue::Int(value1.partial_cmp(&value2).unwrap() as _));
-                }
+                // NaN compares as -1
+                let result = value1.partial_cmp(&value2).map_or(-1, |x| x as i32);
+                stack_frame.operand_stack.push(JavaValue::Int(result));
             }
             Opcode::Fconst(x) => {
                 stack_frame.operand_stack.push(JavaValue::Float(*x as f32));
             }
             Opcode::Fdiv => {
-                let value2: f32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: f32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_float()?;
+                let value1 = stack_frame.pop_float()?;
 
                 stack_frame.operand_stack.push(JavaValue::Float(value1 / value2));
             }
             Opcode::Fmul => {
-                let value2: f32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: f32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_float()?;
+                let value1 = stack_frame.pop_float()?;
 
                 stack_frame.operand_stack.push(JavaValue::Float(value1 * value2));
             }
             Opcode::Fneg => {
-                let value: f32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_float()?;
 
                 stack_frame.operand_stack.push(JavaValue::Float(-value));
             }
             Opcode::Frem => {
-                let value2: f32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: f32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_float()?;
+                let value1 = stack_frame.pop_float()?;
 
                 stack_frame.operand_stack.push(JavaValue::Float(value1 % value2));
             }
             Opcode::Fsub => {
-                let value2: f32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: f32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_float()?;
+                let value1 = stack_frame.pop_float()?;
 
                 stack_frame.operand_stack.push(JavaValue::Float(value1 - value2));
             }
             Opcode::Getfield(x) => {
-                let x = x.as_field_ref();
-                let instance: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
-
-                if instance.is_none() {
+                let x = Self::field_ref(x)?;
+                let Some(instance) = stack_frame.pop_object()? else {
                     return Err(jvm.exception("java/lang/NullPointerException", "null").await);
-                }
+                };
 
-                let value = jvm.get_field(&instance.unwrap(), &x.name, &x.descriptor).await?;
+                let value = jvm.get_field(&instance, &x.name, &x.descriptor).await?;
 
                 stack_frame.operand_stack.push(Self::to_stack_frame_type(value));
             }
             Opcode::Getstatic(x) => {
-                let x = x.as_field_ref();
+                let x = Self::field_ref(x)?;
                 let value = jvm.get_static_field(&x.class, &x.name, &x.descriptor).await?;
 
                 stack_frame.operand_stack.push(Self::to_stack_frame_type(value));
@@ -462,275 +466,271 @@ impl Interpreter {
             Opcode::Goto(x) => return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32)),
             Opcode::GotoW(x) => return Ok(ExecuteNext::Jump((current_offset as i32 + *x) as u32)),
             Opcode::I2b => {
-                let value: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_int()?;
                 stack_frame.operand_stack.push(JavaValue::Int((value as i8) as i32));
             }
             Opcode::I2c => {
-                let value: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_int()?;
                 stack_frame.operand_stack.push(JavaValue::Int(value as JavaChar as _));
             }
             Opcode::I2d => {
-                let value: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_int()?;
                 stack_frame.operand_stack.push(JavaValue::Double(value as _));
             }
             Opcode::I2f => {
-                let value: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_int()?;
                 stack_frame.operand_stack.push(JavaValue::Float(value as _));
             }
             Opcode::I2l => {
-                let value: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_int()?;
                 stack_frame.operand_stack.push(JavaValue::Long(value as _));
             }
             Opcode::I2s => {
-                let value: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_int()?;
                 stack_frame.operand_stack.push(JavaValue::Int((value as i16) as i32));
             }
             Opcode::Iadd => {
-                let value2: i32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_int()?;
+                let value1 = stack_frame.pop_int()?;
 
                 stack_frame.operand_stack.push(JavaValue::Int(value1.wrapping_add(value2)));
             }
             Opcode::Iand => {
-                let value2: i32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_int()?;
+                let value1 = stack_frame.pop_int()?;
 
                 stack_frame.operand_stack.push(JavaValue::Int(value1 & value2));
             }
             Opcode::Iconst(x) => stack_frame.operand_stack.push(JavaValue::Int(*x as i32)),
             Opcode::Idiv => {
-                let value2: i32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_int()?;
+                let value1 = stack_frame.pop_int()?;
 
                 if value2 == 0 {
-                    return Err(jvm.exception("java/lang/ArithmeticException", "Division by zero").await);
+                    return Err(jvm.exception("java/lang/ArithmeticException", "/ by zero").await);
                 }
 
                 stack_frame.operand_stack.push(JavaValue::Int(value1.wrapping_div(value2)));
             }
             Opcode::IfAcmpeq(x) => {
-                let value2: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_object()?;
+                let value1 = stack_frame.pop_object()?;
 
                 if value1 == value2 {
                     return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32));
                 }
             }
             Opcode::IfAcmpne(x) => {
-                let value2: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_object()?;
+                let value1 = stack_frame.pop_object()?;
 
                 if value1 != value2 {
                     return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32));
                 }
             }
             Opcode::IfIcmpeq(x) => {
-                if Self::integer_condition(stack_frame, |x, y| x == y) {
+                if Self::integer_condition(stack_frame, |x, y| x == y)? {
                     return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32));
                 }
             }
             Opcode::IfIcmpge(x) => {
-                if Self::integer_condition(stack_frame, |x, y| x >= y) {
+                if Self::integer_condition(stack_frame, |x, y| x >= y)? {
                     return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32));
                 }
             }
             Opcode::IfIcmpgt(x) => {
-                if Self::integer_condition(stack_frame, |x, y| x > y) {
+                if Self::integer_condition(stack_frame, |x, y| x > y)? {
                     return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32));
                 }
             }
             Opcode::IfIcmple(x) => {
-                if Self::integer_condition(stack_frame, |x, y| x <= y) {
+                if Self::integer_condition(stack_frame, |x, y| x <= y)? {
                     return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32));
                 }
             }
             Opcode::IfIcmplt(x) => {
-                if Self::integer_condition(stack_frame, |x, y| x < y) {
+                if Self::integer_condition(stack_frame, |x, y| x < y)? {
                     return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32));
                 }
             }
             Opcode::IfIcmpne(x) => {
-                if Self::integer_condition(stack_frame, |x, y| x != y) {
+                if Self::integer_condition(stack_frame, |x, y| x != y)? {
                     return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32));
                 }
             }
             Opcode::Ifeq(x) => {
-                if Self::integer_condition_single(stack_frame, |x| x == 0) {
+                if Self::integer_condition_single(stack_frame, |x| x == 0)? {
                     return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32));
                 }
             }
             Opcode::Ifge(x) => {
-                if Self::integer_condition_single(stack_frame, |x| x >= 0) {
+                if Self::integer_condition_single(stack_frame, |x| x >= 0)? {
                     return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32));
                 }
             }
             Opcode::Ifgt(x) => {
-                if Self::integer_condition_single(stack_frame, |x| x > 0) {
+                if Self::integer_condition_single(stack_frame, |x| x > 0)? {
                     return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32));
                 }
             }
             Opcode::Ifle(x) => {
-                if Self::integer_condition_single(stack_frame, |x| x <= 0) {
+                if Self::integer_condition_single(stack_frame, |x| x <= 0)? {
                     return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32));
                 }
             }
             Opcode::Iflt(x) => {
-                if Self::integer_condition_single(stack_frame, |x| x < 0) {
+                if Self::integer_condition_single(stack_frame, |x| x < 0)? {
                     return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32));
                 }
             }
             Opcode::Ifne(x) => {
-                if Self::integer_condition_single(stack_frame, |x| x != 0) {
+                if Self::integer_condition_single(stack_frame, |x| x != 0)? {
                     return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32));
                 }
             }
             Opcode::Ifnonnull(x) => {
-                let value: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_object()?;
 
                 if value.is_some() {
                     return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32));
                 }
             }
             Opcode::Ifnull(x) => {
-                let value: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_object()?;
 
                 if value.is_none() {
                     return Ok(ExecuteNext::Jump((current_offset as i32 + *x as i32) as u32));
                 }
             }
             Opcode::Iinc(x, y) => {
-                let value = stack_frame.local_variables[*x as usize].clone();
-                let value: i32 = value.into();
+                let value = int_value(stack_frame.local(*x as usize)?)?;
 
-                stack_frame.local_variables[*x as usize] = JavaValue::Int(value.wrapping_add(*y as i32));
+                stack_frame.set_local(*x as usize, JavaValue::Int(value.wrapping_add(*y as i32)))?;
             }
             Opcode::Imul => {
-                let value2: i32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_int()?;
+                let value1 = stack_frame.pop_int()?;
 
                 stack_frame.operand_stack.push(JavaValue::Int(value1.wrapping_mul(value2)));
             }
             Opcode::Ineg => {
-                let value: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_int()?;
 
                 stack_frame.operand_stack.push(JavaValue::Int(value.wrapping_neg()));
             }
             Opcode::Instanceof(x) => {
-                let instance: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
+                let instance = stack_frame.pop_object()?;
 
                 let result = if let Some(instance) = instance {
-                    jvm.is_instance(&*instance, x.as_class())
+                    jvm.is_instance(&*instance, Self::class_ref(x)?)
                 } else {
                     false
                 };
                 stack_frame.operand_stack.push(JavaValue::Int(result as _));
             }
             Opcode::Invokedynamic(_) => {
-                todo!()
+                return Err(JavaError::unsupported("invokedynamic"));
             }
             Opcode::Invokeinterface(x, _count, _zero) => {
-                let x = x.as_interface_method_ref();
-                let params = Self::extract_invoke_params(stack_frame, &x.descriptor);
+                let x = Self::method_ref(x)?;
+                let params = Self::extract_invoke_params(stack_frame, &x.descriptor)?;
 
-                let instance: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
-                if instance.is_none() {
+                let Some(instance) = stack_frame.pop_object()? else {
                     return Err(jvm
                         .exception(
                             "java/lang/NullPointerException",
                             &format!("Method {}::{}:{} is called on null", x.class, x.name, x.descriptor),
                         )
                         .await);
-                }
+                };
 
-                let result = jvm.invoke_virtual(&instance.unwrap(), &x.name, &x.descriptor, params).await?;
+                let result = jvm.invoke_virtual(&instance, &x.name, &x.descriptor, params).await?;
                 Self::push_invoke_result(stack_frame, result);
             }
             Opcode::Invokespecial(x) => {
-                let x = x.as_method_ref();
-                let params = Self::extract_invoke_params(stack_frame, &x.descriptor);
+                let x = Self::method_ref(x)?;
+                let params = Self::extract_invoke_params(stack_frame, &x.descriptor)?;
 
-                let instance: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
-                if instance.is_none() {
+                let Some(instance) = stack_frame.pop_object()? else {
                     return Err(jvm
                         .exception(
                             "java/lang/NullPointerException",
                             &format!("Method {}::{}:{} is called on null", x.class, x.name, x.descriptor),
                         )
                         .await);
-                }
+                };
 
-                let result = jvm.invoke_special(&instance.unwrap(), &x.class, &x.name, &x.descriptor, params).await?;
+                let result = jvm.invoke_special(&instance, &x.class, &x.name, &x.descriptor, params).await?;
                 Self::push_invoke_result(stack_frame, result);
             }
             Opcode::Invokestatic(x) => {
-                let x = x.as_method_ref();
-                let params = Self::extract_invoke_params(stack_frame, &x.descriptor);
+                let x = Self::method_ref(x)?;
+                let params = Self::extract_invoke_params(stack_frame, &x.descriptor)?;
 
                 let result = jvm.invoke_static(&x.class, &x.name, &x.descriptor, params).await?;
                 Self::push_invoke_result(stack_frame, result);
             }
             Opcode::Invokevirtual(x) => {
-                let x = x.as_method_ref();
-                let params = Self::extract_invoke_params(stack_frame, &x.descriptor);
+                let x = Self::method_ref(x)?;
+                let params = Self::extract_invoke_params(stack_frame, &x.descriptor)?;
 
-                let instance: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
-                if instance.is_none() {
+                let Some(instance) = stack_frame.pop_object()? else {
                     return Err(jvm
                         .exception(
                             "java/lang/NullPointerException",
                             &format!("Method {}::{}:{} is called on null", x.class, x.name, x.descriptor),
                         )
                         .await);
-                }
+                };
 
-                let result = jvm.invoke_virtual(&instance.unwrap(), &x.name, &x.descriptor, params).await?;
+                let result = jvm.invoke_virtual(&instance, &x.name, &x.descriptor, params).await?;
                 Self::push_invoke_result(stack_frame, result);
             }
             Opcode::Ior => {
-                let value2: i32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_int()?;
+                let value1 = stack_frame.pop_int()?;
 
                 stack_frame.operand_stack.push(JavaValue::Int(value1 | value2));
             }
             Opcode::Irem => {
-                let value2: i32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_int()?;
+                let value1 = stack_frame.pop_int()?;
 
                 if value2 == 0 {
-                    return Err(jvm.exception("java/lang/ArithmeticException", "Division by zero").await);
+                    return Err(jvm.exception("java/lang/ArithmeticException", "/ by zero").await);
                 }
 
                 stack_frame.operand_stack.push(JavaValue::Int(value1.wrapping_rem(value2)));
             }
             Opcode::Ishl => {
-                let value2: i32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_int()?;
+                let value1 = stack_frame.pop_int()?;
 
                 stack_frame.operand_stack.push(JavaValue::Int(value1 << (value2 & 0x1f)));
             }
             Opcode::Ishr => {
-                let value2: i32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_int()?;
+                let value1 = stack_frame.pop_int()?;
 
                 stack_frame.operand_stack.push(JavaValue::Int(value1 >> (value2 & 0x1f)));
             }
             Opcode::Isub => {
-                let value2: i32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_int()?;
+                let value1 = stack_frame.pop_int()?;
 
                 stack_frame.operand_stack.push(JavaValue::Int(value1.wrapping_sub(value2)));
             }
             Opcode::Iushr => {
-                let value2: i32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_int()?;
+                let value1 = stack_frame.pop_int()?;
 
                 stack_frame
                     .operand_stack
                     .push(JavaValue::Int(((value1 as u32) >> ((value2 as u32) & 0x1f)) as _));
             }
             Opcode::Ixor => {
-                let value2: i32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_int()?;
+                let value1 = stack_frame.pop_int()?;
 
                 stack_frame.operand_stack.push(JavaValue::Int(value1 ^ value2));
             }
@@ -745,32 +745,32 @@ impl Interpreter {
                 return Ok(ExecuteNext::Jump((current_offset as i32 + *x) as u32));
             }
             Opcode::L2d => {
-                let value: i64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_long()?;
                 stack_frame.operand_stack.push(JavaValue::Double(value as _));
             }
             Opcode::L2f => {
-                let value: i64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_long()?;
                 stack_frame.operand_stack.push(JavaValue::Float(value as _));
             }
             Opcode::L2i => {
-                let value: i64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_long()?;
                 stack_frame.operand_stack.push(JavaValue::Int(value as _));
             }
             Opcode::Ladd => {
-                let value2: i64 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_long()?;
+                let value1 = stack_frame.pop_long()?;
 
                 stack_frame.operand_stack.push(JavaValue::Long(value1.wrapping_add(value2)));
             }
             Opcode::Land => {
-                let value2: i64 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_long()?;
+                let value1 = stack_frame.pop_long()?;
 
                 stack_frame.operand_stack.push(JavaValue::Long(value1 & value2));
             }
             Opcode::Lcmp => {
-                let value2: i64 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_long()?;
+                let value1 = stack_frame.pop_long()?;
 
                 stack_frame.operand_stack.push(JavaValue::Int(value1.cmp(&value2) as _));
             }
@@ -778,76 +778,76 @@ impl Interpreter {
             Opcode::Ldc(x) | Opcode::LdcW(x) => stack_frame.operand_stack.push(Self::constant_to_value(jvm, x).await?),
             Opcode::Ldc2W(x) => stack_frame.operand_stack.push(Self::constant_to_value(jvm, x).await?),
             Opcode::Ldiv => {
-                let value2: i64 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_long()?;
+                let value1 = stack_frame.pop_long()?;
 
                 if value2 == 0 {
-                    return Err(jvm.exception("java/lang/ArithmeticException", "Division by zero").await);
+                    return Err(jvm.exception("java/lang/ArithmeticException", "/ by zero").await);
                 }
 
                 stack_frame.operand_stack.push(JavaValue::Long(value1.wrapping_div(value2)));
             }
             Opcode::Lmul => {
-                let value2: i64 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_long()?;
+                let value1 = stack_frame.pop_long()?;
 
                 stack_frame.operand_stack.push(JavaValue::Long(value1.wrapping_mul(value2)));
             }
             Opcode::Lneg => {
-                let value: i64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value = stack_frame.pop_long()?;
 
                 stack_frame.operand_stack.push(JavaValue::Long(value.wrapping_neg()));
             }
             Opcode::Lor => {
-                let value2: i64 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_long()?;
+                let value1 = stack_frame.pop_long()?;
 
                 stack_frame.operand_stack.push(JavaValue::Long(value1 | value2));
             }
             Opcode::Lrem => {
-                let value2: i64 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_long()?;
+                let value1 = stack_frame.pop_long()?;
 
                 if value2 == 0 {
-                    return Err(jvm.exception("java/lang/ArithmeticException", "Division by zero").await);
+                    return Err(jvm.exception("java/lang/ArithmeticException", "/ by zero").await);
                 }
 
                 stack_frame.operand_stack.push(JavaValue::Long(value1.wrapping_rem(value2)));
             }
             Opcode::Lshl => {
-                let value2: i32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_int()?;
+                let value1 = stack_frame.pop_long()?;
 
                 stack_frame.operand_stack.push(JavaValue::Long(value1 << (value2 & 0x3f)));
             }
             Opcode::Lshr => {
-                let value2: i32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_int()?;
+                let value1 = stack_frame.pop_long()?;
 
                 stack_frame.operand_stack.push(JavaValue::Long(value1 >> (value2 & 0x3f)));
             }
             Opcode::Lsub => {
-                let value2: i64 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_long()?;
+                let value1 = stack_frame.pop_long()?;
 
                 stack_frame.operand_stack.push(JavaValue::Long(value1.wrapping_sub(value2)));
             }
             Opcode::Lushr => {
-                let value2: i32 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_int()?;
+                let value1 = stack_frame.pop_long()?;
 
                 stack_frame
                     .operand_stack
                     .push(JavaValue::Long(((value1 as u64) >> ((value2 as u64) & 0x3f)) as _));
             }
             Opcode::Lxor => {
-                let value2: i64 = stack_frame.operand_stack.pop().unwrap().into();
-                let value1: i64 = stack_frame.operand_stack.pop().unwrap().into();
+                let value2 = stack_frame.pop_long()?;
+                let value1 = stack_frame.pop_long()?;
 
                 stack_frame.operand_stack.push(JavaValue::Long(value1 ^ value2));
             }
             Opcode::Lookupswitch(default, pairs) | Opcode::Tableswitch(default, pairs) => {
-                let key = stack_frame.operand_stack.pop().unwrap().into();
+                let key = stack_frame.pop_int()?;
 
                 for (k, offset) in pairs {
                     if *k == key {
@@ -858,21 +858,21 @@ impl Interpreter {
                 return Ok(ExecuteNext::Jump((current_offset as i32 + *default) as u32));
             }
             Opcode::Monitorenter => {
-                let object: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
+                let object = stack_frame.pop_object()?;
                 let Some(object) = object else {
                     return Err(jvm.exception("java/lang/NullPointerException", "monitorenter on null").await);
                 };
                 jvm.monitor_enter(&object).await?;
             }
             Opcode::Monitorexit => {
-                let object: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
+                let object = stack_frame.pop_object()?;
                 let Some(object) = object else {
                     return Err(jvm.exception("java/lang/NullPointerException", "monitorexit on null").await);
                 };
                 jvm.monitor_exit(&object).await?;
             }
             Opcode::Multianewarray(x, d) => {
-                let mut dimensions: Vec<i32> = (0..*d).map(|_| stack_frame.operand_stack.pop().unwrap().into()).collect();
+                let mut dimensions = (0..*d).map(|_| stack_frame.pop_int()).collect::<Result<Vec<_>>>()?;
                 dimensions.reverse();
                 for dim in &dimensions {
                     if *dim < 0 {
@@ -880,12 +880,12 @@ impl Interpreter {
                     }
                 }
 
-                let array = Self::new_multi_array(jvm, x.as_class(), &dimensions).await?;
+                let array = Self::new_multi_array(jvm, Self::class_ref(x)?, &dimensions).await?;
 
                 stack_frame.operand_stack.push(JavaValue::Object(Some(array)));
             }
             Opcode::New(x) => {
-                let class = jvm.instantiate_class(x.as_class()).await?;
+                let class = jvm.instantiate_class(Self::class_ref(x)?).await?;
 
                 stack_frame.operand_stack.push(JavaValue::Object(Some(class)));
             }
@@ -899,10 +899,10 @@ impl Interpreter {
                     9 => "S",
                     10 => "I",
                     11 => "J",
-                    _ => panic!("Invalid array type {}", x),
+                    _ => return Err(JavaError::internal(format!("Invalid array type {x}"))),
                 };
 
-                let length: i32 = stack_frame.operand_stack.pop().unwrap().into();
+                let length = stack_frame.pop_int()?;
                 if length < 0 {
                     return Err(jvm.exception("java/lang/NegativeArraySizeException", &format!("{}", length)).await);
                 }
@@ -912,44 +912,41 @@ impl Interpreter {
             }
             Opcode::Nop => {}
             Opcode::Pop => {
-                stack_frame.operand_stack.pop().unwrap();
+                stack_frame.pop()?;
             }
             Opcode::Pop2 => {
-                let value = stack_frame.operand_stack.pop().unwrap();
+                let value = stack_frame.pop()?;
                 if !matches!(value, JavaValue::Long(_) | JavaValue::Double(_)) {
-                    stack_frame.operand_stack.pop().unwrap();
+                    stack_frame.pop()?;
                 }
             }
             Opcode::Putfield(x) => {
-                let x = x.as_field_ref();
-                let value = stack_frame.operand_stack.pop().unwrap();
-                let mut instance: Option<Box<dyn ClassInstance>> = stack_frame.operand_stack.pop().unwrap().into();
-
-                if instance.is_none() {
+                let x = Self::field_ref(x)?;
+                let value = stack_frame.pop()?;
+                let Some(mut instance) = stack_frame.pop_object()? else {
                     return Err(jvm.exception("java/lang/NullPointerException", "null").await);
-                }
+                };
 
-                let value = Self::to_field_type(&x.descriptor, value);
+                let value = Self::to_field_type(&x.descriptor, value)?;
 
-                jvm.put_field(instance.as_mut().unwrap(), &x.name, &x.descriptor, value).await?;
+                jvm.put_field(&mut instance, &x.name, &x.descriptor, value).await?;
             }
             Opcode::Putstatic(x) => {
-                let x = x.as_field_ref();
-                let value = Self::to_field_type(&x.descriptor, stack_frame.operand_stack.pop().unwrap());
+                let x = Self::field_ref(x)?;
+                let value = Self::to_field_type(&x.descriptor, stack_frame.pop()?)?;
 
                 jvm.put_static_field(&x.class, &x.name, &x.descriptor, value).await?
             }
             Opcode::Ret(x) => {
-                let value = stack_frame.local_variables[*x as usize].clone();
-                let value: i32 = value.into();
+                let value = int_value(stack_frame.local(*x as usize)?)?;
 
                 return Ok(ExecuteNext::Jump(value as u32));
             }
             Opcode::Return => return Ok(ExecuteNext::Return(JavaValue::Void)),
             Opcode::Sipush(x) => stack_frame.operand_stack.push(JavaValue::Int(*x as i32)),
             Opcode::Swap => {
-                let value1 = stack_frame.operand_stack.pop().unwrap();
-                let value2 = stack_frame.operand_stack.pop().unwrap();
+                let value1 = stack_frame.pop()?;
+                let value2 = stack_frame.pop()?;
 
                 stack_frame.operand_stack.push(value1);
                 stack_frame.operand_stack.push(value2);
@@ -962,12 +959,11 @@ impl Interpreter {
     async fn find_exception_handler(jvm: &Jvm, exception: &dyn ClassInstance, code_attribute: &AttributeInfoCode, pc: u32) -> Option<u32> {
         for exception_table in &code_attribute.exception_table {
             if exception_table.start_pc <= pc as u16 && exception_table.end_pc > pc as u16 {
-                if exception_table.catch_type.is_none() {
-                    return Some(exception_table.handler_pc as _);
-                }
-
-                let catch_type = exception_table.catch_type.as_ref().unwrap();
-                if jvm.is_instance(exception, catch_type) {
+                let catches = exception_table
+                    .catch_type
+                    .as_ref()
+                    .is_none_or(|catch_type| jvm.is_instance(exception, catch_type));
+                if catches {
                     return Some(exception_table.handler_pc as _);
                 }
             }
@@ -976,47 +972,48 @@ impl Interpreter {
         None
     }
 
-    fn integer_condition<T>(stack_frame: &mut StackFrame, pred: T) -> bool
+    fn integer_condition<T>(stack_frame: &mut StackFrame, pred: T) -> Result<bool>
     where
         T: Fn(i32, i32) -> bool,
     {
-        let value2 = stack_frame.operand_stack.pop().unwrap().into();
-        let value1 = stack_frame.operand_stack.pop().unwrap().into();
+        let value2 = stack_frame.pop_int()?;
+        let value1 = stack_frame.pop_int()?;
 
-        pred(value1, value2)
+        Ok(pred(value1, value2))
     }
 
-    fn integer_condition_single<T>(stack_frame: &mut StackFrame, pred: T) -> bool
+    fn integer_condition_single<T>(stack_frame: &mut StackFrame, pred: T) -> Result<bool>
     where
         T: Fn(i32) -> bool,
     {
-        let value = stack_frame.operand_stack.pop().unwrap().into();
+        let value = stack_frame.pop_int()?;
 
-        pred(value)
+        Ok(pred(value))
     }
 
-    fn extract_invoke_params(stack_frame: &mut StackFrame, descriptor: &str) -> Vec<JavaValue> {
-        let method_type = JavaType::parse(descriptor);
-        let (param_type, _) = method_type.as_method();
+    fn extract_invoke_params(stack_frame: &mut StackFrame, descriptor: &str) -> Result<Vec<JavaValue>> {
+        let Some(JavaType::Method(param_type, _)) = JavaType::try_parse(descriptor) else {
+            return Err(JavaError::internal(format!("Invalid method descriptor {descriptor}")));
+        };
 
         let mut values = param_type
             .iter()
             .rev()
             .map(|x| {
-                let value = stack_frame.operand_stack.pop().unwrap();
-                match x {
-                    JavaType::Boolean => JavaValue::Boolean(i32::from(value) & 1 != 0),
-                    JavaType::Byte => JavaValue::Byte(i32::from(value) as _),
-                    JavaType::Char => JavaValue::Char(i32::from(value) as _),
-                    JavaType::Short => JavaValue::Short(i32::from(value) as _),
+                let value = stack_frame.pop()?;
+                Ok(match x {
+                    JavaType::Boolean => JavaValue::Boolean(int_value(value)? & 1 != 0),
+                    JavaType::Byte => JavaValue::Byte(int_value(value)? as _),
+                    JavaType::Char => JavaValue::Char(int_value(value)? as _),
+                    JavaType::Short => JavaValue::Short(int_value(value)? as _),
                     _ => value,
-                }
+                })
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>>>()?;
 
         values.reverse();
 
-        values
+        Ok(values)
     }
 
     fn push_invoke_result(stack_frame: &mut StackFrame, value: JavaValue) {
@@ -1027,13 +1024,35 @@ impl Interpreter {
     }
 
     // operand stack has only integer for small number types, so convert it to the field type
-    fn to_field_type(descriptor: &str, value: JavaValue) -> JavaValue {
-        match descriptor {
-            "Z" => JavaValue::Boolean(i32::from(value) & 1 != 0),
-            "B" => JavaValue::Byte(i32::from(value) as i8),
-            "C" => JavaValue::Char(i32::from(value) as u16),
-            "S" => JavaValue::Short(i32::from(value) as i16),
+    fn to_field_type(descriptor: &str, value: JavaValue) -> Result<JavaValue> {
+        Ok(match descriptor {
+            "Z" => JavaValue::Boolean(int_value(value)? & 1 != 0),
+            "B" => JavaValue::Byte(int_value(value)? as i8),
+            "C" => JavaValue::Char(int_value(value)? as u16),
+            "S" => JavaValue::Short(int_value(value)? as i16),
             _ => value,
+        })
+    }
+
+    fn class_ref(reference: &ConstantPoolReference) -> Result<&str> {
+        match reference {
+            ConstantPoolReference::Class(x) => Ok(x),
+            x => Err(JavaError::internal(format!("Expected a class reference, got {x:?}"))),
+        }
+    }
+
+    fn field_ref(reference: &ConstantPoolReference) -> Result<&FieldMethodref> {
+        match reference {
+            ConstantPoolReference::Field(x) => Ok(x),
+            x => Err(JavaError::internal(format!("Expected a field reference, got {x:?}"))),
+        }
+    }
+
+    // invokespecial and invokestatic may name interface methods since class file version 52
+    fn method_ref(reference: &ConstantPoolReference) -> Result<&FieldMethodref> {
+        match reference {
+            ConstantPoolReference::Method(x) | ConstantPoolReference::InterfaceMethodref(x) => Ok(x),
+            x => Err(JavaError::internal(format!("Expected a method reference, got {x:?}"))),
         }
     }
 
@@ -1055,8 +1074,8 @@ impl Interpreter {
             ConstantPoolReference::Long(x) => JavaValue::Long(*x),
             ConstantPoolReference::Double(x) => JavaValue::Double(*x),
             ConstantPoolReference::String(x) => JavaValue::Object(Some(jvm.intern_string(x).await?)),
-            ConstantPoolReference::Class(x) => JavaValue::Object(Some(jvm.resolve_class(x).await?.java_class())),
-            _ => unimplemented!(),
+            ConstantPoolReference::Class(x) => JavaValue::Object(Some(jvm.resolve_class(x).await?.java_class()?)),
+            x => return Err(JavaError::unsupported(format!("Loading constant {x:?}"))),
         })
     }
 
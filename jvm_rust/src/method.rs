@@ -142,8 +142,8 @@ impl Method for MethodImpl {
 
         Ok(match body {
             MethodBody::ByteCode(x) => {
-                let r#type = JavaType::parse(&self.inner.descriptor);
-                Interpreter::run(jvm, x, args, r#type.as_method().1).await?
+                let r#type = JavaType::parse(&self.inner.descriptor)?;
+                Interpreter::run(jvm, x, args, r#type.as_method()?.1).await?
             }
             MethodBody::Rust(x) => x.call(jvm, args).await?,
         })
@@ -1,6 +1,6 @@
-use alloc::vec::Vec;
+use alloc::{boxed::Box, format, vec::Vec};
 
-use jvm::JavaValue;
+use jvm::{ClassInstance, JavaError, JavaValue, Result};
 
 #[derive(Default)]
 pub struct StackFrame {
@@ -8,6 +8,7 @@ pub struct StackFrame {
     pub operand_stack: Vec<JavaValue>,
 }
 
+// Accessors below fail instead of panicking, so malformed bytecode surfaces as JavaError::Internal
 impl StackFrame {
     pub fn new() -> Self {
         Self {
@@ -15,4 +16,79 @@ impl StackFrame {
             operand_stack: Vec::new(),
         }
     }
+
+    pub fn references(&self) -> impl Iterator<Item = &Box<dyn ClassInstance>> {
+        self.local_variables.iter().chain(self.operand_stack.iter()).filter_map(|x| match x {
+            JavaValue::Object(Some(x)) => Some(x),
+            _ => None,
+        })
+    }
+
+    pub fn pop(&mut self) -> Result<JavaValue> {
+        self.operand_stack.pop().ok_or_else(|| JavaError::internal("Operand stack underflow"))
+    }
+
+    pub fn pop_int(&mut self) -> Result<i32> {
+        match self.pop()? {
+            JavaValue::Int(x) => Ok(x),
+            x => Err(unexpected_value("int", &x)),
+        }
+    }
+
+    pub fn pop_long(&mut self) -> Result<i64> {
+        match self.pop()? {
+            JavaValue::Long(x) => Ok(x),
+            x => Err(unexpected_value("long", &x)),
+        }
+    }
+
+    pub fn pop_float(&mut self) -> Result<f32> {
+        match self.pop()? {
+            JavaValue::Float(x) => Ok(x),
+            x => Err(unexpected_value("float", &x)),
+        }
+    }
+
+    pub fn pop_double(&mut self) -> Result<f64> {
+        match self.pop()? {
+            JavaValue::Double(x) => Ok(x),
+            x => Err(unexpected_value("double", &x)),
+        }
+    }
+
+    pub fn pop_object(&mut self) -> Result<Option<Box<dyn ClassInstance>>> {
+        match self.pop()? {
+            JavaValue::Object(x) => Ok(x),
+            x => Err(unexpected_value("reference", &x)),
+        }
+    }
+
+    pub fn local(&self, index: usize) -> Result<JavaValue> {
+        self.local_variables
+            .get(index)
+            .cloned()
+            .ok_or_else(|| JavaError::internal(format!("Local variable index {index} out of range")))
+    }
+
+    pub fn set_local(&mut self, index: usize, value: JavaValue) -> Result<()> {
+        let slot = self
+            .local_variables
+            .get_mut(index)
+            .ok_or_else(|| JavaError::internal(format!("Local variable index {index} out of range")))?;
+        *slot = value;
+
+        Ok(())
+    }
+}
+
+// operand stack and local variables hold ints for all the small integral types
+pub fn int_value(value: JavaValue) -> Result<i32> {
+    match value {
+        JavaValue::Int(x) => Ok(x),
+        x => Err(unexpected_value("int", &x)),
+    }
+}
+
+fn unexpected_value(expected: &str, value: &JavaValue) -> JavaError {
+    JavaError::internal(format!("Expected {expected} on the operand stack, got {value:?}"))
 }
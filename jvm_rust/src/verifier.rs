@@ -4,6 +4,11 @@ use jvm::JavaType;
 use crate::ClassDefinitionError;
 
 pub(crate) fn verify(class: &ClassInfo) -> Result<(), ClassDefinitionError> {
+    // descriptors are parsed on demand later, so malformed ones have to be rejected here
+    if !class.fields.iter().all(|x| is_field_descriptor(&x.descriptor)) || !class.methods.iter().all(|x| is_method_descriptor(&x.descriptor)) {
+        return Err(ClassDefinitionError::Verification);
+    }
+
     for method in &class.methods {
         for attribute in &method.attributes {
             let AttributeInfo::Code(code) = attribute else {
@@ -25,6 +30,29 @@ pub(crate) fn verify(class: &ClassInfo) -> Result<(), ClassDefinitionError> {
                         }
                     }
                     Opcode::Invokedynamic(_) => return Err(ClassDefinitionError::UnsupportedFeature("invokedynamic")),
+                    Opcode::Getfield(ConstantPoolReference::Field(x))
+                    | Opcode::Getstatic(ConstantPoolReference::Field(x))
+                    | Opcode::Putfield(ConstantPoolReference::Field(x))
+                    | Opcode::Putstatic(ConstantPoolReference::Field(x))
+                        if !is_field_descriptor(&x.descriptor) =>
+                    {
+                        return Err(ClassDefinitionError::Verification);
+                    }
+                    Opcode::Invokeinterface(ConstantPoolReference::InterfaceMethodref(x), _, _)
+                    | Opcode::Invokespecial(ConstantPoolReference::Method(x) | ConstantPoolReference::InterfaceMethodref(x))
+                    | Opcode::Invokestatic(ConstantPoolReference::Method(x) | ConstantPoolReference::InterfaceMethodref(x))
+                    | Opcode::Invokevirtual(ConstantPoolReference::Method(x))
+                        if !is_method_descriptor(&x.descriptor) =>
+                    {
+                        return Err(ClassDefinitionError::Verification);
+                    }
+                    Opcode::Anewarray(ConstantPoolReference::Class(name))
+                    | Opcode::Checkcast(ConstantPoolReference::Class(name))
+                    | Opcode::Instanceof(ConstantPoolReference::Class(name))
+                        if name.starts_with('[') && !is_field_descriptor(name) =>
+                    {
+                        return Err(ClassDefinitionError::Verification);
+                    }
                     _ => {}
                 }
             }
@@ -34,8 +62,18 @@ pub(crate) fn verify(class: &ClassInfo) -> Result<(), ClassDefinitionError> {
     Ok(())
 }
 
+fn is_field_descriptor(descriptor: &str) -> bool {
+    !matches!(JavaType::try_parse(descriptor), None | Some(JavaType::Void | JavaType::Method(_, _)))
+}
+
+fn is_method_descriptor(descriptor: &str) -> bool {
+    matches!(JavaType::try_parse(descriptor), Some(JavaType::Method(_, _)))
+}
+
 #[cfg(test)]
 mod tests {
+    use alloc::{string::String, sync::Arc};
+
     use classfile::{AttributeInfo, ClassInfo, Opcode};
 
     use crate::{ClassDefinitionError, verifier::verify};
@@ -61,4 +99,13 @@ mod tests {
         assert!(changed);
         assert_eq!(verify(&class), Err(ClassDefinitionError::Verification));
     }
+
+    #[test]
+    fn rejects_malformed_method_descriptors() {
+        let mut class = ClassInfo::parse(include_bytes!("../../test_data/MultiArray.class")).unwrap();
+        assert_eq!(verify(&class), Ok(()));
+
+        class.methods[0].descriptor = Arc::new(String::from("(I"));
+        assert_eq!(verify(&class), Err(ClassDefinitionError::Verification));
+    }
 }
@@ -2,10 +2,15 @@ extern crate alloc;
 
 mod runtime;
 
-use std::{env, io::Write, path::Path};
+use std::{
+    env,
+    io::{Seek, Write},
+    path::Path,
+};
 
-use java_runtime::{Runtime, get_bootstrap_class_loader};
-use jvm::{JavaError, JavaValue, Jvm, Result, runtime::JavaLangString};
+use java_constants::{ClassAccessFlags, FieldAccessFlags, MethodAccessFlags};
+use java_runtime::{Runtime, get_bootstrap_class_loader, get_runtime_class_protos};
+use jvm::{ClassInstance, JavaError, JavaValue, Jvm, Result, runtime::JavaLangString};
 
 use runtime::RuntimeImpl;
 
@@ -21,7 +26,24 @@ where
 {
     let jvm = create_jvm(stdout, &start_type, class_path).await?;
 
-    let result = invoke_entrypoint(&jvm, &start_type, args).await;
+    run_jvm(&jvm, start_type, args).await
+}
+
+// runs the entrypoint on a jvm from create_jvm, for embedders that need the jvm before the program starts
+pub async fn run_jvm<S>(jvm: &Jvm, start_type: StartType<'_>, args: &[S]) -> anyhow::Result<()>
+where
+    S: AsRef<str>,
+{
+    let result = invoke_entrypoint(jvm, &start_type, args).await;
+
+    // like the jdk, the vm stays up until every non-daemon thread ends, even when main threw
+    jvm.wait_for_non_daemon_threads().await;
+    let hooks_result: Result<()> = jvm.invoke_static("java/lang/Runtime", "runShutdownHooks", "()V", ()).await;
+    match hooks_result {
+        // main's exception is what gets reported
+        Err(error) if matches!(result, Err(JavaError::JavaException(_))) => tracing::error!(?error, "shutdown hooks failed"),
+        x => x?,
+    }
 
     if let Err(JavaError::JavaException(x)) = result {
         let string_writer = jvm.new_class("java/io/StringWriter", "()V", ()).await.unwrap();
@@ -35,18 +57,70 @@ where
             .await
             .unwrap();
 
-        let trace = jvm.invoke_virtual(&string_writer, "toString", "()Ljava/lang/String;", []).await.unwrap();
+        let trace: Box<dyn ClassInstance> = jvm.invoke_virtual(&string_writer, "toString", "()Ljava/lang/String;", []).await.unwrap();
 
         Err(anyhow::anyhow!(
             "Java Exception:\n{}",
-            JavaLangString::to_rust_string(&jvm, &trace).await.unwrap()
+            JavaLangString::to_rust_string(jvm, &trace).await.unwrap()
         ))
     } else {
         Ok(result?)
     }
 }
 
-async fn create_jvm<T>(stdout: T, start_type: &StartType<'_>, class_path: &[&Path]) -> anyhow::Result<Jvm>
+// writes a jar of stub classes for everything java_runtime implements, to use as javac's bootclasspath.
+// protos don't record throws clauses or generic signatures, so the stubs have neither
+pub fn write_stub_jar<W>(writer: W) -> anyhow::Result<()>
+where
+    W: Write + Seek,
+{
+    // org/rustjava classes and synthetic members are the runtime's own, not api to compile against
+    let mut protos = get_runtime_class_protos()
+        .into_iter()
+        .filter(|x| !x.name.starts_with("org/rustjava/"))
+        .collect::<Vec<_>>();
+    protos.sort_by_key(|x| x.name);
+
+    // protos mostly leave visibility unset, which the runtime treats as public
+    let method_visibility = MethodAccessFlags::PUBLIC | MethodAccessFlags::PROTECTED | MethodAccessFlags::PRIVATE;
+    let field_visibility = FieldAccessFlags::PUBLIC | FieldAccessFlags::PROTECTED | FieldAccessFlags::PRIVATE;
+    for proto in &mut protos {
+        proto.access_flags |= ClassAccessFlags::PUBLIC;
+        if proto.access_flags.contains(ClassAccessFlags::INTERFACE) {
+            proto.access_flags |= ClassAccessFlags::ABSTRACT;
+        }
+
+        proto.methods.retain(|x| !x.access_flags.contains(MethodAccessFlags::SYNTHETIC));
+        proto.fields.retain(|x| !x.access_flags.contains(FieldAccessFlags::SYNTHETIC));
+
+        for method in &mut proto.methods {
+            if !method.access_flags.intersects(method_visibility) {
+                method.access_flags |= MethodAccessFlags::PUBLIC;
+            }
+        }
+        for field in &mut proto.fields {
+            if !field.access_flags.intersects(field_visibility) {
+                field.access_flags |= FieldAccessFlags::PUBLIC;
+            }
+        }
+    }
+
+    let mut jar = zip::ZipWriter::new(writer);
+    let options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    jar.start_file("META-INF/MANIFEST.MF", options)?;
+    jar.write_all(b"Manifest-Version: 1.0\r\n\r\n")?;
+
+    for proto in protos {
+        jar.start_file(format!("{}.class", proto.name), options)?;
+        jar.write_all(&proto.stub_class())?;
+    }
+    jar.finish()?;
+
+    Ok(())
+}
+
+pub async fn create_jvm<T>(stdout: T, start_type: &StartType<'_>, class_path: &[&Path]) -> anyhow::Result<Jvm>
 where
     T: Sync + Send + Write + 'static,
 {
@@ -101,12 +175,12 @@ async fn get_jar_main_class(jvm: &Jvm, jar_path: &Path) -> Result<String> {
     let file = jvm.new_class("java/io/File", "(Ljava/lang/String;)V", (filename,)).await?;
     let jar_file = jvm.new_class("java/util/jar/JarFile", "(Ljava/io/File;)V", (file,)).await?;
 
-    let manifest = jvm.invoke_virtual(&jar_file, "getManifest", "()Ljava/util/jar/Manifest;", ()).await?;
-    let attributes = jvm
+    let manifest: Box<dyn ClassInstance> = jvm.invoke_virtual(&jar_file, "getManifest", "()Ljava/util/jar/Manifest;", ()).await?;
+    let attributes: Box<dyn ClassInstance> = jvm
         .invoke_virtual(&manifest, "getMainAttributes", "()Ljava/util/jar/Attributes;", ())
         .await?;
 
-    let main_class = jvm
+    let main_class: Box<dyn ClassInstance> = jvm
         .invoke_virtual(
             &attributes,
             "getValue",
@@ -1,15 +1,18 @@
 use std::{
     env,
     ffi::OsString,
+    fs::File,
     io::{self, stderr},
     path::PathBuf,
 };
 
 use anyhow::bail;
 
-use rust_java::{StartType, run};
+use jvm::Jvm;
+use rust_java::{StartType, create_jvm, run_jvm, write_stub_jar};
 
 struct Opts {
+    stubs: Option<PathBuf>,
     jar: Option<PathBuf>,
     main_class: Option<PathBuf>,
     args: Vec<String>,
@@ -33,6 +36,12 @@ pub fn main() -> anyhow::Result<()> {
 pub async fn async_main() -> anyhow::Result<()> {
     let opts = parse_args()?;
 
+    if let Some(stubs) = &opts.stubs {
+        write_stub_jar(File::create(stubs)?)?;
+
+        return Ok(());
+    }
+
     let start_type = if let Some(main_class) = &opts.main_class {
         StartType::Class(main_class)
     } else {
@@ -45,9 +54,36 @@ pub async fn async_main() -> anyhow::Result<()> {
         opts.class_path.iter().map(PathBuf::as_path).collect()
     };
 
-    run(io::stdout(), start_type, &opts.args, &class_path).await?;
+    let jvm = create_jvm(io::stdout(), &start_type, &class_path).await?;
+    #[cfg(unix)]
+    let thread_dump_handler = spawn_thread_dump_handler(&jvm);
+
+    let result = run_jvm(&jvm, start_type, &opts.args).await;
+
+    #[cfg(unix)]
+    if let Some(x) = thread_dump_handler {
+        x.abort();
+    }
+
+    result
+}
+
+// prints a thread dump to stderr on SIGQUIT (Ctrl-\), like HotSpot. stdout belongs to the program
+#[cfg(unix)]
+fn spawn_thread_dump_handler(jvm: &Jvm) -> Option<tokio::task::JoinHandle<()>> {
+    use tokio::signal::unix::{SignalKind, signal};
 
-    Ok(())
+    let Ok(mut signals) = signal(SignalKind::quit()) else {
+        tracing::warn!("Failed to install SIGQUIT handler");
+        return None;
+    };
+
+    let jvm = jvm.clone();
+    Some(tokio::spawn(async move {
+        while signals.recv().await.is_some() {
+            eprintln!("{}", jvm.thread_dump());
+        }
+    }))
 }
 
 fn parse_args() -> anyhow::Result<Opts> {
@@ -69,11 +105,23 @@ where
                 bail!("Missing class path after {argument}");
             };
             class_path = env::split_paths(&value).collect();
+        } else if argument == "-stubs" {
+            let Some(stubs) = args.next() else {
+                bail!("Missing jar file after {argument}");
+            };
+            return Ok(Opts {
+                stubs: Some(stubs.into()),
+                jar: None,
+                main_class: None,
+                args: Vec::new(),
+                class_path,
+            });
         } else if argument == "-jar" {
             let Some(jar) = args.next() else {
                 bail!("Missing jar file after -jar");
             };
             return Ok(Opts {
+                stubs: None,
                 jar: Some(jar.into()),
                 main_class: None,
                 args: args.collect(),
@@ -81,6 +129,7 @@ where
             });
         } else {
             return Ok(Opts {
+                stubs: None,
                 jar: None,
                 main_class: Some(argument.into()),
                 args: args.collect(),
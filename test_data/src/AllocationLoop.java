@@ -0,0 +1,33 @@
+class AllocationLoop {
+    int value;
+    AllocationLoop next;
+
+    AllocationLoop(int value, AllocationLoop next) {
+        this.value = value;
+        this.next = next;
+    }
+
+    // only the last few nodes stay reachable; everything else is garbage once the loop moves on
+    static int allocate(int count) {
+        AllocationLoop head = null;
+        int sum = 0;
+        for (int i = 0; i < count; i++) {
+            byte[] scratch = new byte[64];
+            scratch[i % 64] = (byte) i;
+            head = new AllocationLoop(scratch[i % 64], i % 8 == 0 ? null : head);
+            sum += head.value;
+        }
+
+        int length = 0;
+        for (AllocationLoop node = head; node != null; node = node.next) {
+            length++;
+        }
+
+        return sum * 10 + length;
+    }
+
+    public static void main(String[] args) {
+        System.out.println(allocate(10));
+        System.out.println(allocate(5000));
+    }
+}
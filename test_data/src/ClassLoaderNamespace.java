@@ -0,0 +1,67 @@
+import java.io.ByteArrayOutputStream;
+import java.io.IOException;
+import java.io.InputStream;
+
+public class ClassLoaderNamespace {
+    public static class Counter implements Runnable {
+        static int count;
+
+        public void run() {
+            count++;
+            System.out.println(count);
+        }
+    }
+
+    static class IsolatingLoader extends ClassLoader {
+        IsolatingLoader() {
+            super(null);
+        }
+
+        protected Class findClass(String name) throws ClassNotFoundException {
+            byte[] bytes = read(name);
+            if (bytes == null) {
+                throw new ClassNotFoundException(name);
+            }
+            return defineClass(name, bytes, 0, bytes.length);
+        }
+    }
+
+    static byte[] read(String name) {
+        InputStream is = ClassLoaderNamespace.class.getResourceAsStream(new StringBuffer("/").append(name.replace('.', '/')).append(".class").toString());
+        if (is == null) {
+            return null;
+        }
+
+        try {
+            ByteArrayOutputStream out = new ByteArrayOutputStream();
+            byte[] buf = new byte[256];
+            int read;
+            while ((read = is.read(buf)) > 0) {
+                out.write(buf, 0, read);
+            }
+            return out.toByteArray();
+        } catch (IOException e) {
+            return null;
+        }
+    }
+
+    public static void main(String[] args) throws Exception {
+        IsolatingLoader first = new IsolatingLoader();
+        IsolatingLoader second = new IsolatingLoader();
+
+        Class a = first.loadClass("ClassLoaderNamespace$Counter");
+        Class b = second.loadClass("ClassLoaderNamespace$Counter");
+
+        System.out.println(a == b);
+        System.out.println(a.getName().equals(b.getName()));
+        System.out.println(a.getClassLoader() == first);
+        System.out.println(b.getClassLoader() == second);
+        System.out.println(Counter.class.getClassLoader() == ClassLoaderNamespace.class.getClassLoader());
+        System.out.println(a != Counter.class);
+
+        ((Runnable) a.newInstance()).run();
+        ((Runnable) a.newInstance()).run();
+        ((Runnable) b.newInstance()).run();
+        new Counter().run();
+    }
+}
@@ -0,0 +1,12 @@
+public class ClinitCollect {
+    static int value;
+
+    static {
+        System.gc();
+        value = 42;
+    }
+
+    public static void main(String[] args) {
+        System.out.println(value);
+    }
+}
@@ -0,0 +1,83 @@
+import java.io.ByteArrayOutputStream;
+import java.io.IOException;
+import java.io.InputStream;
+
+public class DefineClass {
+    public static class Plugin {
+        public String toString() {
+            return "plugin";
+        }
+    }
+
+    static class BytesLoader extends ClassLoader {
+        BytesLoader() {
+            super(null);
+        }
+
+        protected Class findClass(String name) throws ClassNotFoundException {
+            byte[] bytes = read(name);
+            if (bytes == null) {
+                throw new ClassNotFoundException(name);
+            }
+            Class result = defineClass(name, bytes, 0, bytes.length);
+            resolveClass(result);
+            return result;
+        }
+
+        Class define(String name, byte[] bytes) {
+            return defineClass(name, bytes, 0, bytes.length);
+        }
+    }
+
+    static byte[] read(String name) {
+        InputStream is = DefineClass.class.getResourceAsStream(new StringBuffer("/").append(name.replace('.', '/')).append(".class").toString());
+        if (is == null) {
+            return null;
+        }
+
+        try {
+            ByteArrayOutputStream out = new ByteArrayOutputStream();
+            byte[] buf = new byte[256];
+            int read;
+            while ((read = is.read(buf)) > 0) {
+                out.write(buf, 0, read);
+            }
+            return out.toByteArray();
+        } catch (IOException e) {
+            return null;
+        }
+    }
+
+    public static void main(String[] args) throws Exception {
+        BytesLoader loader = new BytesLoader();
+        System.out.println(loader.getParent() == null);
+        System.out.println(new ClassLoader() {}.getParent() == ClassLoader.getSystemClassLoader());
+
+        Class plugin = loader.loadClass("DefineClass$Plugin");
+        System.out.println(plugin.getName());
+        System.out.println(plugin.getClassLoader() == loader);
+        System.out.println(loader.loadClass("DefineClass$Plugin") == plugin);
+        System.out.println(plugin.newInstance());
+
+        try {
+            loader.define("DefineClass$Plugin", read("DefineClass$Plugin"));
+            System.out.println("redefined");
+        } catch (LinkageError e) {
+            System.out.println("LinkageError");
+        }
+
+        try {
+            new BytesLoader().define("WrongName", read("DefineClass$Plugin"));
+            System.out.println("defined");
+        } catch (NoClassDefFoundError e) {
+            System.out.println("NoClassDefFoundError");
+        }
+
+        try {
+            loader.loadClass("Missing");
+            System.out.println("found");
+        } catch (ClassNotFoundException e) {
+            System.out.println("ClassNotFoundException");
+        }
+    }
+}
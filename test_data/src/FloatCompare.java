@@ -0,0 +1,49 @@
+class FloatCompare {
+    static float fval(float x) {
+        return x;
+    }
+
+    static double dval(double x) {
+        return x;
+    }
+
+    static void print(boolean value) {
+        System.out.println(value ? "true" : "false");
+    }
+
+    public static void main(String[] args) {
+        float fnan = fval(0.0f) / fval(0.0f);
+        double dnan = dval(0.0) / dval(0.0);
+        float one = fval(1.0f);
+        double done = dval(1.0);
+
+        // javac uses fcmpg for < and <=, fcmpl for > and >=; both must make every NaN comparison false
+        System.out.println("fcmp:");
+        print(fnan < one);
+        print(fnan <= one);
+        print(fnan > one);
+        print(fnan >= one);
+        print(fnan == fnan);
+        print(fnan != fnan);
+        print(one < fnan);
+        print(one > fnan);
+        print(fval(-0.0f) == fval(0.0f));
+        print(fval(-0.0f) < fval(0.0f));
+        print(one < fval(2.0f));
+        print(fval(2.0f) > one);
+
+        System.out.println("dcmp:");
+        print(dnan < done);
+        print(dnan <= done);
+        print(dnan > done);
+        print(dnan >= done);
+        print(dnan == dnan);
+        print(dnan != dnan);
+        print(done < dnan);
+        print(done > dnan);
+        print(dval(-0.0) == dval(0.0));
+        print(dval(-0.0) < dval(0.0));
+        print(done < dval(2.0));
+        print(dval(2.0) > done);
+    }
+}
@@ -0,0 +1,46 @@
+class FloatRemainder {
+    static float fval(float x) {
+        return x;
+    }
+
+    static double dval(double x) {
+        return x;
+    }
+
+    static void print(boolean value) {
+        System.out.println(value ? "true" : "false");
+    }
+
+    static boolean isNegativeZero(double x) {
+        return x == 0.0 && 1.0 / x < 0.0;
+    }
+
+    public static void main(String[] args) {
+        // frem/drem truncate like C fmod, so the result takes the sign of the dividend
+        System.out.println("frem:");
+        System.out.println((int) (fval(5.5f) % fval(2.0f) * 10));
+        System.out.println((int) (fval(-5.5f) % fval(2.0f) * 10));
+        System.out.println((int) (fval(5.5f) % fval(-2.0f) * 10));
+        System.out.println((int) (fval(-5.5f) % fval(-2.0f) * 10));
+        float fnan = fval(5.0f) % fval(0.0f);
+        print(fnan != fnan);
+        float finf = fval(1.0f) / fval(0.0f);
+        float fnan2 = finf % fval(2.0f);
+        print(fnan2 != fnan2);
+        print(fval(3.0f) % finf == fval(3.0f));
+        print(isNegativeZero(fval(-4.0f) % fval(2.0f)));
+
+        System.out.println("drem:");
+        System.out.println((int) (dval(5.5) % dval(2.0) * 10));
+        System.out.println((int) (dval(-5.5) % dval(2.0) * 10));
+        System.out.println((int) (dval(5.5) % dval(-2.0) * 10));
+        System.out.println((int) (dval(-5.5) % dval(-2.0) * 10));
+        double dnan = dval(5.0) % dval(0.0);
+        print(dnan != dnan);
+        double dinf = dval(1.0) / dval(0.0);
+        double dnan2 = dinf % dval(2.0);
+        print(dnan2 != dnan2);
+        print(dval(3.0) % dinf == dval(3.0));
+        print(isNegativeZero(dval(-4.0) % dval(2.0)));
+    }
+}
@@ -0,0 +1,55 @@
+class FloatToInteger {
+    static float fval(float x) {
+        return x;
+    }
+
+    static double dval(double x) {
+        return x;
+    }
+
+    public static void main(String[] args) {
+        float fnan = fval(0.0f) / fval(0.0f);
+        float finf = fval(1.0f) / fval(0.0f);
+        double dnan = dval(0.0) / dval(0.0);
+        double dinf = dval(1.0) / dval(0.0);
+
+        // f2i/f2l: NaN converts to zero, out of range values saturate
+        System.out.println("f2i:");
+        System.out.println((int) fnan);
+        System.out.println((int) finf);
+        System.out.println((int) -finf);
+        System.out.println((int) fval(3.9f));
+        System.out.println((int) fval(-3.9f));
+        System.out.println((int) fval(1e20f));
+        System.out.println((int) fval(-1e20f));
+        System.out.println("f2l:");
+        System.out.println((long) fnan);
+        System.out.println((long) finf);
+        System.out.println((long) -finf);
+        System.out.println((long) fval(-2.5f));
+        System.out.println((long) fval(1e30f));
+
+        // d2i/d2l: same rules for doubles
+        System.out.println("d2i:");
+        System.out.println((int) dnan);
+        System.out.println((int) dinf);
+        System.out.println((int) -dinf);
+        System.out.println((int) dval(2147483647.9));
+        System.out.println((int) dval(-2147483648.9));
+        System.out.println((int) dval(1e100));
+        System.out.println("d2l:");
+        System.out.println((long) dnan);
+        System.out.println((long) dinf);
+        System.out.println((long) -dinf);
+        System.out.println((long) dval(-0.0));
+        System.out.println((long) dval(9.3e18));
+        System.out.println((long) dval(-9.3e18));
+
+        // narrowing through int for byte/char/short
+        System.out.println("d2i narrowing:");
+        System.out.println((byte) dval(300.7));
+        System.out.println((short) dval(-40000.2));
+        System.out.println((int) (char) dval(70000.0));
+        System.out.println((byte) dnan);
+    }
+}
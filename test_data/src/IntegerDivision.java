@@ -0,0 +1,70 @@
+class IntegerDivision {
+    static int idiv(int a, int b) {
+        return a / b;
+    }
+
+    static int irem(int a, int b) {
+        return a % b;
+    }
+
+    static long ldiv(long a, long b) {
+        return a / b;
+    }
+
+    static long lrem(long a, long b) {
+        return a % b;
+    }
+
+    public static void main(String[] args) {
+        // idiv/irem: truncate toward zero, remainder takes the sign of the dividend
+        System.out.println("idiv:");
+        System.out.println(idiv(7, 2));
+        System.out.println(idiv(-7, 2));
+        System.out.println(idiv(7, -2));
+        System.out.println(idiv(-7, -2));
+        System.out.println(idiv(Integer.MIN_VALUE, -1));
+        System.out.println(idiv(Integer.MIN_VALUE, 1));
+        System.out.println("irem:");
+        System.out.println(irem(7, 2));
+        System.out.println(irem(-7, 2));
+        System.out.println(irem(7, -2));
+        System.out.println(irem(-7, -2));
+        System.out.println(irem(Integer.MIN_VALUE, -1));
+
+        System.out.println("ldiv:");
+        System.out.println(ldiv(7L, 2L));
+        System.out.println(ldiv(-7L, 2L));
+        System.out.println(ldiv(Long.MIN_VALUE, -1L));
+        System.out.println("lrem:");
+        System.out.println(lrem(-7L, 2L));
+        System.out.println(lrem(7L, -2L));
+        System.out.println(lrem(Long.MIN_VALUE, -1L));
+
+        // division by zero throws ArithmeticException with the HotSpot message
+        System.out.println("zero:");
+        try {
+            idiv(1, 0);
+            System.out.println("should not reach");
+        } catch (ArithmeticException e) {
+            System.out.println(e.getMessage());
+        }
+        try {
+            irem(1, 0);
+            System.out.println("should not reach");
+        } catch (ArithmeticException e) {
+            System.out.println(e.getMessage());
+        }
+        try {
+            ldiv(1L, 0L);
+            System.out.println("should not reach");
+        } catch (ArithmeticException e) {
+            System.out.println(e.getMessage());
+        }
+        try {
+            lrem(1L, 0L);
+            System.out.println("should not reach");
+        } catch (ArithmeticException e) {
+            System.out.println(e.getMessage());
+        }
+    }
+}
@@ -0,0 +1,103 @@
+import java.io.ByteArrayOutputStream;
+import java.io.IOException;
+import java.io.InputStream;
+
+public class LoaderConstraint {
+    public static class Shared {
+    }
+
+    public static class Provider {
+        public static Shared create() {
+            return new Shared();
+        }
+    }
+
+    public static class Consumer implements Runnable {
+        public void run() {
+            System.out.println(Provider.create() != null);
+        }
+    }
+
+    // defines the test classes itself, except for the ones delegated to another loader
+    static class IsolatingLoader extends ClassLoader {
+        ClassLoader delegate;
+        String[] delegated;
+
+        IsolatingLoader(ClassLoader delegate, String[] delegated) {
+            super(null);
+            this.delegate = delegate;
+            this.delegated = delegated;
+        }
+
+        protected synchronized Class loadClass(String name, boolean resolve) throws ClassNotFoundException {
+            for (int i = 0; i < delegated.length; i++) {
+                if (delegated[i].equals(name)) {
+                    return delegate.loadClass(name);
+                }
+            }
+
+            Class loaded = findLoadedClass(name);
+            if (loaded != null) {
+                return loaded;
+            }
+
+            if (!name.startsWith("LoaderConstraint$")) {
+                return super.loadClass(name, resolve);
+            }
+            byte[] bytes = read(name);
+            return defineClass(name, bytes, 0, bytes.length);
+        }
+    }
+
+    static byte[] read(String name) {
+        InputStream is = LoaderConstraint.class.getResourceAsStream(new StringBuffer("/").append(name.replace('.', '/')).append(".class").toString());
+        if (is == null) {
+            return null;
+        }
+
+        try {
+            ByteArrayOutputStream out = new ByteArrayOutputStream();
+            byte[] buf = new byte[256];
+            int read;
+            while ((read = is.read(buf)) > 0) {
+                out.write(buf, 0, read);
+            }
+            return out.toByteArray();
+        } catch (IOException e) {
+            return null;
+        }
+    }
+
+    static ClassLoader run(String[] delegated, boolean loadShared) throws Exception {
+        ClassLoader provider = new IsolatingLoader(null, new String[0]);
+        ClassLoader consumer = new IsolatingLoader(provider, delegated);
+        if (loadShared) {
+            provider.loadClass("LoaderConstraint$Shared");
+            consumer.loadClass("LoaderConstraint$Shared");
+        }
+
+        try {
+            ((Runnable) consumer.loadClass("LoaderConstraint$Consumer").newInstance()).run();
+        } catch (LinkageError e) {
+            System.out.println("LinkageError");
+        }
+
+        return consumer;
+    }
+
+    public static void main(String[] args) throws Exception {
+        // both loaders see the provider's Shared
+        run(new String[] { "LoaderConstraint$Provider", "LoaderConstraint$Shared" }, true);
+        // each loader has its own Shared when Provider.create is resolved
+        run(new String[] { "LoaderConstraint$Provider" }, true);
+
+        // the consumer defines its own Shared after the constraint is recorded
+        ClassLoader consumer = run(new String[] { "LoaderConstraint$Provider" }, false);
+        try {
+            consumer.loadClass("LoaderConstraint$Shared");
+            System.out.println("loaded");
+        } catch (LinkageError e) {
+            System.out.println("LinkageError");
+        }
+    }
+}
@@ -0,0 +1,20 @@
+public class NativeLibrary {
+    static native int add(int a, int b);
+
+    native int scale(int value);
+
+    public static void main(String[] args) {
+        try {
+            System.loadLibrary("rustjava_test");
+            System.out.println(add(1, 2));
+        } catch (UnsatisfiedLinkError e) {
+            System.out.println("UnsatisfiedLinkError");
+        }
+
+        try {
+            System.out.println(new NativeLibrary().scale(2));
+        } catch (UnsatisfiedLinkError e) {
+            System.out.println("UnsatisfiedLinkError");
+        }
+    }
+}
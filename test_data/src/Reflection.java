@@ -0,0 +1,229 @@
+import java.lang.reflect.Array;
+import java.lang.reflect.Constructor;
+import java.lang.reflect.Field;
+import java.lang.reflect.InvocationTargetException;
+import java.lang.reflect.Method;
+import java.lang.reflect.Modifier;
+
+class Reflection {
+    interface Shape {
+        int area();
+    }
+
+    static class Base {
+        public int id;
+
+        public String describe() {
+            return "base";
+        }
+    }
+
+    public abstract static class Blank {
+        public Blank() {}
+    }
+
+    public static class Rect extends Base implements Shape {
+        public static int created;
+        public final int width;
+        private int height;
+
+        public Rect(int width, int height) {
+            this.width = width;
+            this.height = height;
+            created += 1;
+        }
+
+        private Rect() {
+            this(1, 1);
+        }
+
+        public int area() {
+            return width * height;
+        }
+
+        public String describe() {
+            return "rect";
+        }
+
+        private int secret(int x) {
+            return x + height;
+        }
+
+        public static long scale(long value, short factor) {
+            return value * factor;
+        }
+
+        public void fail() {
+            throw new IllegalStateException("failed");
+        }
+    }
+
+    public static void main(String[] args) throws Exception {
+        Class<?> rect = Rect.class;
+
+        System.out.println(rect.getSuperclass().getName());
+        Class<?>[] interfaces = rect.getInterfaces();
+        System.out.println(interfaces.length);
+        System.out.println(interfaces[0].getName());
+
+        Rect r = new Rect(3, 4);
+
+        Method area = rect.getMethod("area");
+        System.out.println(area.getName());
+        System.out.println(area.invoke(r));
+        System.out.println(area.getReturnType() == int.class);
+
+        Method describe = Base.class.getMethod("describe");
+        System.out.println(describe.invoke(r));
+
+        Method secret = rect.getDeclaredMethod("secret", int.class);
+        secret.setAccessible(true);
+        System.out.println(Modifier.isPrivate(secret.getModifiers()));
+        System.out.println(secret.invoke(r, Integer.valueOf(10)));
+
+        Method scale = rect.getMethod("scale", long.class, short.class);
+        System.out.println(Modifier.toString(scale.getModifiers()));
+        System.out.println(scale.invoke(null, Integer.valueOf(3), new Short((short) 2)));
+        Class<?>[] parameters = scale.getParameterTypes();
+        System.out.println(parameters.length);
+        System.out.println(parameters[1] == short.class);
+        System.out.println(scale.toString());
+
+        try {
+            rect.getMethod("fail").invoke(r);
+        } catch (InvocationTargetException e) {
+            System.out.println(e.getCause().getMessage());
+        }
+
+        try {
+            area.invoke("not a rect");
+        } catch (IllegalArgumentException e) {
+            System.out.println("IllegalArgumentException");
+        }
+
+        try {
+            secret.invoke(r, "wrong");
+        } catch (IllegalArgumentException e) {
+            System.out.println(e.getMessage());
+        }
+
+        try {
+            rect.getMethod("secret", int.class);
+        } catch (NoSuchMethodException e) {
+            System.out.println("NoSuchMethodException");
+        }
+
+        Field width = rect.getField("width");
+        System.out.println(width.get(r));
+        System.out.println(width.getType() == int.class);
+        System.out.println(width.toString());
+        try {
+            width.set(r, Integer.valueOf(7));
+        } catch (IllegalAccessException e) {
+            System.out.println("IllegalAccessException");
+        }
+        width.setAccessible(true);
+        width.set(r, Integer.valueOf(7));
+        System.out.println(r.area());
+
+        Field height = rect.getDeclaredField("height");
+        height.setAccessible(true);
+        height.set(r, Integer.valueOf(2));
+        System.out.println(r.area());
+
+        System.out.println(rect.getField("id").getDeclaringClass().getName());
+
+        try {
+            rect.getField("height");
+        } catch (NoSuchFieldException e) {
+            System.out.println(e.getMessage());
+        }
+
+        Field created = rect.getField("created");
+        System.out.println(created.get(null));
+        created.set(null, Integer.valueOf(100));
+        System.out.println(Rect.created);
+
+        System.out.println(rect.getConstructors().length);
+        System.out.println(rect.getDeclaredConstructors().length);
+        Constructor<?> constructor = rect.getConstructor(int.class, int.class);
+        System.out.println(constructor.toString());
+        Rect made = (Rect) constructor.newInstance(Integer.valueOf(5), Integer.valueOf(6));
+        System.out.println(made.area());
+        Constructor<?> hidden = rect.getDeclaredConstructor();
+        hidden.setAccessible(true);
+        Rect unit = (Rect) hidden.newInstance();
+        System.out.println(unit.area());
+
+        System.out.println(Shape.class.getDeclaredConstructors().length);
+        try {
+            Blank.class.getConstructor().newInstance();
+        } catch (InstantiationException e) {
+            System.out.println("InstantiationException");
+        }
+
+        int[] ints = (int[]) Array.newInstance(int.class, 3);
+        Array.set(ints, 1, Integer.valueOf(42));
+        System.out.println(Array.getLength(ints));
+        System.out.println(Array.get(ints, 1));
+        System.out.println(ints.getClass().getComponentType() == int.class);
+
+        String[][] grid = (String[][]) Array.newInstance(String.class, 2, 3);
+        System.out.println(grid.length);
+        System.out.println(grid[1].length);
+        System.out.println(grid.getClass().getName());
+
+        try {
+            Array.get(ints, 3);
+        } catch (ArrayIndexOutOfBoundsException e) {
+            System.out.println("ArrayIndexOutOfBoundsException");
+        }
+
+        boolean found = false;
+        Method[] methods = rect.getMethods();
+        for (int i = 0; i < methods.length; i++) {
+            if (methods[i].getName().equals("hashCode")) {
+                found = true;
+                System.out.println(methods[i].getDeclaringClass().getName());
+            }
+        }
+        System.out.println(found);
+
+        Method length = String.class.getMethod("length");
+        System.out.println(length.invoke("hello"));
+        System.out.println(Modifier.isPublic(length.getModifiers()));
+
+        boolean foundLength = false;
+        Method[] stringMethods = String.class.getMethods();
+        for (int i = 0; i < stringMethods.length; i++) {
+            if (stringMethods[i].getName().equals("charAt")) {
+                System.out.println(stringMethods[i].invoke("hello", Integer.valueOf(1)));
+            }
+            if (stringMethods[i].getName().equals("length")) {
+                foundLength = true;
+            }
+        }
+        System.out.println(foundLength);
+
+        System.out.println(Integer.class.getField("MAX_VALUE").get(null));
+        boolean foundMaxValue = false;
+        Field[] integerFields = Integer.class.getFields();
+        for (int i = 0; i < integerFields.length; i++) {
+            if (integerFields[i].getName().equals("MAX_VALUE")) {
+                foundMaxValue = true;
+            }
+        }
+        System.out.println(foundMaxValue);
+
+        Constructor<?> fromChars = String.class.getConstructor(char[].class);
+        System.out.println(fromChars.newInstance(new Object[] { new char[] { 'h', 'i' } }));
+        boolean foundFromChars = false;
+        Constructor<?>[] stringConstructors = String.class.getConstructors();
+        for (int i = 0; i < stringConstructors.length; i++) {
+            if (stringConstructors[i].getParameterTypes().length == 1 && stringConstructors[i].getParameterTypes()[0] == char[].class) {
+                foundFromChars = true;
+            }
+        }
+        System.out.println(foundFromChars);
+    }
+}
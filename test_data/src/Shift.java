@@ -0,0 +1,43 @@
+class Shift {
+    static int ival(int x) {
+        return x;
+    }
+
+    static long lval(long x) {
+        return x;
+    }
+
+    public static void main(String[] args) {
+        // int shifts use only the low 5 bits of the count
+        System.out.println("ishl:");
+        System.out.println(ival(1) << ival(31));
+        System.out.println(ival(1) << ival(32));
+        System.out.println(ival(1) << ival(33));
+        System.out.println(ival(1) << ival(-1));
+        System.out.println("ishr:");
+        System.out.println(ival(-16) >> ival(2));
+        System.out.println(ival(-16) >> ival(34));
+        System.out.println(ival(Integer.MIN_VALUE) >> ival(31));
+        System.out.println("iushr:");
+        System.out.println(ival(-16) >>> ival(2));
+        System.out.println(ival(-16) >>> ival(32));
+        System.out.println(ival(-1) >>> ival(31));
+        System.out.println(ival(-1) >>> ival(-1));
+
+        // long shifts use only the low 6 bits of the count
+        System.out.println("lshl:");
+        System.out.println(lval(1L) << ival(63));
+        System.out.println(lval(1L) << ival(64));
+        System.out.println(lval(1L) << ival(65));
+        System.out.println(lval(1L) << ival(-1));
+        System.out.println("lshr:");
+        System.out.println(lval(-16L) >> ival(2));
+        System.out.println(lval(-16L) >> ival(66));
+        System.out.println(lval(Long.MIN_VALUE) >> ival(63));
+        System.out.println("lushr:");
+        System.out.println(lval(-16L) >>> ival(2));
+        System.out.println(lval(-16L) >>> ival(64));
+        System.out.println(lval(-1L) >>> ival(63));
+        System.out.println(lval(-1L) >>> ival(-1));
+    }
+}
@@ -0,0 +1,36 @@
+class ShutdownHook {
+    public static void main(String[] args) {
+        Thread worker = new Thread() {
+            public void run() {
+                try {
+                    Thread.sleep(50);
+                } catch (InterruptedException e) {
+                }
+                System.out.println("worker done");
+            }
+        };
+
+        // a daemon thread that never ends must not keep the vm alive
+        Thread daemon = new Thread() {
+            public void run() {
+                while (true) {
+                    try {
+                        Thread.sleep(10);
+                    } catch (InterruptedException e) {
+                    }
+                }
+            }
+        };
+        daemon.setDaemon(true);
+
+        Runtime.getRuntime().addShutdownHook(new Thread() {
+            public void run() {
+                System.out.println("hook");
+            }
+        });
+
+        worker.start();
+        daemon.start();
+        System.out.println("main done");
+    }
+}
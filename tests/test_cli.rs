@@ -1,4 +1,4 @@
-use std::{env, process::Command};
+use std::{env, fs::File, io::Read, process::Command};
 
 #[test]
 fn cli_classpath_options_load_classes_from_directories_and_jars() {
@@ -100,3 +100,38 @@ fn cli_reports_missing_classpath_value() {
     assert!(!output.status.success());
     assert!(String::from_utf8(output.stderr).unwrap().contains("Missing class path after -cp"));
 }
+
+#[test]
+fn cli_writes_runtime_stub_jar() {
+    let path = env::temp_dir().join(format!("rust_java_stubs_{}.jar", std::process::id()));
+    let output = Command::new(env!("CARGO_BIN_EXE_rust_java")).arg("-stubs").arg(&path).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let mut jar = zip::ZipArchive::new(File::open(&path).unwrap()).unwrap();
+    let names = jar.file_names().collect::<Vec<_>>();
+    assert!(names.contains(&"META-INF/MANIFEST.MF"));
+    assert!(names.contains(&"java/lang/Object.class"));
+    assert!(names.contains(&"java/util/HashMap.class"));
+    assert!(!names.iter().any(|x| x.starts_with("org/rustjava/")));
+    let api_protos = java_runtime::get_runtime_class_protos()
+        .iter()
+        .filter(|x| !x.name.starts_with("org/rustjava/"))
+        .count();
+    assert_eq!(names.len(), api_protos + 1);
+
+    // the runtime's internal members aren't part of the api
+    let mut read_class = |name: &str| {
+        let mut bytes = Vec::new();
+        jar.by_name(name).unwrap().read_to_end(&mut bytes).unwrap();
+        bytes
+    };
+    let contains = |bytes: &[u8], text: &str| bytes.windows(text.len()).any(|x| x == text.as_bytes());
+    let method = read_class("java/lang/reflect/Method.class");
+    assert!(contains(&method, "invoke"));
+    assert!(!contains(&method, "<init>"));
+    let runtime = read_class("java/lang/Runtime.class");
+    assert!(contains(&runtime, "addShutdownHook"));
+    assert!(!contains(&runtime, "runShutdownHooks"));
+
+    std::fs::remove_file(path).unwrap();
+}
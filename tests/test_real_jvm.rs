@@ -1,6 +1,6 @@
 mod test_helper;
 
-use std::{fs, path::Path, process::Command};
+use std::{env, fs, path::Path, process::Command};
 
 use jvm::Result;
 
@@ -57,3 +57,25 @@ async fn test_real_jvm() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+#[ignore]
+fn test_compile_against_stub_jar() {
+    let out = env::temp_dir().join(format!("rust_java_stub_test_{}", std::process::id()));
+    fs::create_dir_all(&out).unwrap();
+    let jar = out.join("rt.jar");
+
+    rust_java::write_stub_jar(fs::File::create(&jar).unwrap()).unwrap();
+
+    let javac_result = Command::new("javac")
+        .args(["-source", "8", "-target", "8", "-bootclasspath"])
+        .arg(&jar)
+        .arg("-d")
+        .arg(&out)
+        .arg("test_data/src/Hello.java")
+        .output()
+        .unwrap();
+    assert!(javac_result.status.success(), "{}", String::from_utf8_lossy(&javac_result.stderr));
+
+    fs::remove_dir_all(out).unwrap();
+}